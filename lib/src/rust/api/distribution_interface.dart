@@ -0,0 +1,242 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:aw_flutter/src/rust/distribution/data.dart';
+import 'package:aw_flutter/src/rust/excel/data.dart';
+import 'package:aw_flutter/src/rust/frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `calendar_or_default`
+Future<DistributionProject> readDistributionProject({
+  required String filePath,
+}) => RustLib.instance.api.crateApiDistributionInterfaceReadDistributionProject(
+  filePath: filePath,
+);
+
+Future<void> writeDistributionProject({
+  required String filePath,
+  required DistributionProject project,
+}) => RustLib
+    .instance
+    .api
+    .crateApiDistributionInterfaceWriteDistributionProject(
+      filePath: filePath,
+      project: project,
+    );
+
+Future<UniversityForm1> form1FromParsedFile({
+  required ParsedExcelFile file,
+  required String sheetName,
+  required int academicYear,
+}) => RustLib.instance.api.crateApiDistributionInterfaceForm1FromParsedFile(
+  file: file,
+  sheetName: sheetName,
+  academicYear: academicYear,
+);
+
+Future<double> getUndistributedWorkload({
+  required DistributionProject project,
+  required DisciplineKey key,
+  required WorkloadField field,
+}) => RustLib
+    .instance
+    .api
+    .crateApiDistributionInterfaceGetUndistributedWorkload(
+      project: project,
+      key: key,
+      field: field,
+    );
+
+Future<List<UndistributedWorkload>> listUndistributedWorkload({
+  required DistributionProject project,
+}) => RustLib
+    .instance
+    .api
+    .crateApiDistributionInterfaceListUndistributedWorkload(project: project);
+
+Future<DistributionProject> addWorkloadItem({
+  required DistributionProject project,
+  required String employeeId,
+  required String rateId,
+  required UniversityForm3WorkloadItem item,
+}) => RustLib.instance.api.crateApiDistributionInterfaceAddWorkloadItem(
+  project: project,
+  employeeId: employeeId,
+  rateId: rateId,
+  item: item,
+);
+
+Future<DistributionProject> updateWorkloadField({
+  required DistributionProject project,
+  required String employeeId,
+  required String rateId,
+  required String itemId,
+  required WorkloadField field,
+  required double value,
+}) => RustLib.instance.api.crateApiDistributionInterfaceUpdateWorkloadField(
+  project: project,
+  employeeId: employeeId,
+  rateId: rateId,
+  itemId: itemId,
+  field: field,
+  value: value,
+);
+
+Future<DistributionProject> removeWorkloadItem({
+  required DistributionProject project,
+  required String employeeId,
+  required String rateId,
+  required String itemId,
+}) => RustLib.instance.api.crateApiDistributionInterfaceRemoveWorkloadItem(
+  project: project,
+  employeeId: employeeId,
+  rateId: rateId,
+  itemId: itemId,
+);
+
+/// `None` for the default calendar of the project's academic year.
+/// `supervision_hours_per_postgraduate` is the department's yearly allowance
+/// for supervising one postgraduate.
+Future<List<EmployeeWorkloadSummary>> getEmployeeSummaries({
+  required DistributionProject project,
+  AcademicCalendar? calendar,
+  required double supervisionHoursPerPostgraduate,
+}) => RustLib.instance.api.crateApiDistributionInterfaceGetEmployeeSummaries(
+  project: project,
+  calendar: calendar,
+  supervisionHoursPerPostgraduate: supervisionHoursPerPostgraduate,
+);
+
+Future<AcademicCalendar> defaultAcademicCalendar({required int academicYear}) => RustLib
+    .instance
+    .api
+    .crateApiDistributionInterfaceDefaultAcademicCalendar(
+      academicYear: academicYear,
+    );
+
+/// Norm hours of every employee per semester and for the year, counted by
+/// the exact months their rates last. `None` for the default calendar of
+/// the project's academic year. `supervision_hours_per_postgraduate` is the
+/// department's yearly allowance for supervising one postgraduate.
+Future<List<EmployeeNorms>> getEmployeeNorms({
+  required DistributionProject project,
+  AcademicCalendar? calendar,
+  required double supervisionHoursPerPostgraduate,
+}) => RustLib.instance.api.crateApiDistributionInterfaceGetEmployeeNorms(
+  project: project,
+  calendar: calendar,
+  supervisionHoursPerPostgraduate: supervisionHoursPerPostgraduate,
+);
+
+/// Hours assigned in a semester the employee's rate does not last in.
+Future<List<DistributionIssue>> checkSemesterCoverage({
+  required DistributionProject project,
+  AcademicCalendar? calendar,
+}) => RustLib.instance.api.crateApiDistributionInterfaceCheckSemesterCoverage(
+  project: project,
+  calendar: calendar,
+);
+
+Future<List<DistributionIssue>> validateDistribution({
+  required DistributionProject project,
+}) => RustLib.instance.api.crateApiDistributionInterfaceValidateDistribution(
+  project: project,
+);
+
+Future<ExportedData> buildProjectExportedData({
+  required DistributionProject project,
+  required String typeName,
+  AcademicCalendar? calendar,
+}) => RustLib
+    .instance
+    .api
+    .crateApiDistributionInterfaceBuildProjectExportedData(
+      project: project,
+      typeName: typeName,
+      calendar: calendar,
+    );
+
+/// `None` for the default calendar of the project's academic year.
+/// `supervision_hours_per_postgraduate` is the department's yearly allowance
+/// for supervising one postgraduate.
+Future<SolverResult> distributeWorkload({
+  required DistributionProject project,
+  required SolverOptions options,
+  AcademicCalendar? calendar,
+  required double supervisionHoursPerPostgraduate,
+}) => RustLib.instance.api.crateApiDistributionInterfaceDistributeWorkload(
+  project: project,
+  options: options,
+  calendar: calendar,
+  supervisionHoursPerPostgraduate: supervisionHoursPerPostgraduate,
+);
+
+/// `min_similarity` of the discipline names from 0 to 1, `None` for 0.8.
+Future<CarryOverResult> carryOverDistribution({
+  required DistributionProject previous,
+  required UniversityForm1 form1,
+  double? minSimilarity,
+}) => RustLib.instance.api.crateApiDistributionInterfaceCarryOverDistribution(
+  previous: previous,
+  form1: form1,
+  minSimilarity: minSimilarity,
+);
+
+/// Rows of `sheet_name` in `old_file` against the same sheet of `new_file`.
+/// With a `project`, the entries list the employees they affect.
+Future<Form1Diff> diffForm1Files({
+  required ParsedExcelFile oldFile,
+  required ParsedExcelFile newFile,
+  required String sheetName,
+  DistributionProject? project,
+}) => RustLib.instance.api.crateApiDistributionInterfaceDiffForm1Files(
+  oldFile: oldFile,
+  newFile: newFile,
+  sheetName: sheetName,
+  project: project,
+);
+
+/// `project` moved onto the rows of `sheet_name` in a revised form 1.
+Future<ReconcileResult> reconcileForm1File({
+  required DistributionProject project,
+  required ParsedExcelFile file,
+  required String sheetName,
+}) => RustLib.instance.api.crateApiDistributionInterfaceReconcileForm1File(
+  project: project,
+  file: file,
+  sheetName: sheetName,
+);
+
+/// Rows of `sheet_name` that contradict themselves or each other.
+Future<List<Form1Finding>> checkForm1File({
+  required ParsedExcelFile file,
+  required String sheetName,
+}) => RustLib.instance.api.crateApiDistributionInterfaceCheckForm1File(
+  file: file,
+  sheetName: sheetName,
+);
+
+Future<NormsTable> defaultNormsTable() =>
+    RustLib.instance.api.crateApiDistributionInterfaceDefaultNormsTable();
+
+/// Rows of `sheet_name` whose hours disagree with `norms`, `None` for
+/// `default_norms_table`.
+Future<List<NormFinding>> checkForm1Norms({
+  required ParsedExcelFile file,
+  required String sheetName,
+  NormsTable? norms,
+}) => RustLib.instance.api.crateApiDistributionInterfaceCheckForm1Norms(
+  file: file,
+  sheetName: sheetName,
+  norms: norms,
+);
+
+Future<UniversityForm1> applyForm1Norms({
+  required UniversityForm1 form1,
+  required NormsTable norms,
+}) => RustLib.instance.api.crateApiDistributionInterfaceApplyForm1Norms(
+  form1: form1,
+  norms: norms,
+);
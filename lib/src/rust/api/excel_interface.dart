@@ -12,10 +12,79 @@ Future<ParsedExcelFile> parseExcelFile({required String filePath}) => RustLib
     .api
     .crateApiExcelInterfaceParseExcelFile(filePath: filePath);
 
-Future<void> writeExcelFile({
+Future<List<PersonalSheetName>> writeExcelFile({
   required String filePath,
   required ExportedData exportedTables,
+  required ExportOptions options,
 }) => RustLib.instance.api.crateApiExcelInterfaceWriteExcelFile(
   filePath: filePath,
   exportedTables: exportedTables,
+  options: options,
+);
+
+Future<void> writePdfFile({
+  required String filePath,
+  required ExportedData exportedTables,
+  required ExportOptions options,
+  required PdfFonts fonts,
+}) => RustLib.instance.api.crateApiExcelInterfaceWritePdfFile(
+  filePath: filePath,
+  exportedTables: exportedTables,
+  options: options,
+  fonts: fonts,
+);
+
+Future<List<String>> writePersonalFiles({
+  required String directory,
+  required ExportedData exportedTables,
+  required ExportOptions options,
+  PdfFonts? pdfFonts,
+}) => RustLib.instance.api.crateApiExcelInterfaceWritePersonalFiles(
+  directory: directory,
+  exportedTables: exportedTables,
+  options: options,
+  pdfFonts: pdfFonts,
+);
+
+Future<void> writeHtmlFile({
+  required String filePath,
+  required ExportedData exportedTables,
+  required ExportOptions options,
+}) => RustLib.instance.api.crateApiExcelInterfaceWriteHtmlFile(
+  filePath: filePath,
+  exportedTables: exportedTables,
+  options: options,
+);
+
+Future<void> writeJsonFile({
+  required String filePath,
+  required ExportedData exportedTables,
+}) => RustLib.instance.api.crateApiExcelInterfaceWriteJsonFile(
+  filePath: filePath,
+  exportedTables: exportedTables,
+);
+
+Future<ExportedData> readJsonFile({required String filePath}) =>
+    RustLib.instance.api.crateApiExcelInterfaceReadJsonFile(filePath: filePath);
+
+Future<void> writeCsvFile({
+  required String filePath,
+  required ExportedData exportedTables,
+  required ExportOptions options,
+}) => RustLib.instance.api.crateApiExcelInterfaceWriteCsvFile(
+  filePath: filePath,
+  exportedTables: exportedTables,
+  options: options,
+);
+
+Future<List<WorkloadColumn>> defaultWorkloadColumns() =>
+    RustLib.instance.api.crateApiExcelInterfaceDefaultWorkloadColumns();
+
+Future<ExportedData> buildExportedData({required WorkloadInput input}) =>
+    RustLib.instance.api.crateApiExcelInterfaceBuildExportedData(input: input);
+
+Future<List<TotalsMismatch>> validateExportedData({
+  required ExportedData exportedTables,
+}) => RustLib.instance.api.crateApiExcelInterfaceValidateExportedData(
+  exportedTables: exportedTables,
 );
@@ -0,0 +1,1148 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:aw_flutter/src/rust/frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// First and last days of the semesters of an academic year, `YYYY-MM-DD`.
+class AcademicCalendar {
+  final String firstSemesterStart;
+  final String firstSemesterEnd;
+  final String secondSemesterStart;
+  final String secondSemesterEnd;
+
+  const AcademicCalendar({
+    required this.firstSemesterStart,
+    required this.firstSemesterEnd,
+    required this.secondSemesterStart,
+    required this.secondSemesterEnd,
+  });
+
+  @override
+  int get hashCode =>
+      firstSemesterStart.hashCode ^
+      firstSemesterEnd.hashCode ^
+      secondSemesterStart.hashCode ^
+      secondSemesterEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AcademicCalendar &&
+          runtimeType == other.runtimeType &&
+          firstSemesterStart == other.firstSemesterStart &&
+          firstSemesterEnd == other.firstSemesterEnd &&
+          secondSemesterStart == other.secondSemesterStart &&
+          secondSemesterEnd == other.secondSemesterEnd;
+}
+
+/// Stored as 1 and 2, like the Dart `AcademicSemester`.
+enum AcademicSemester { first, second }
+
+/// A row matched to last year's one under another name or with other hours.
+class CarriedOverItem {
+  final DisciplineKey key;
+  final DisciplineKey previousKey;
+  /// Similarity of the names from 0 to 1, 1 when they are the same.
+  final double similarity;
+  final List<WorkloadField> changedFields;
+
+  const CarriedOverItem({
+    required this.key,
+    required this.previousKey,
+    required this.similarity,
+    required this.changedFields,
+  });
+
+  @override
+  int get hashCode =>
+      key.hashCode ^
+      previousKey.hashCode ^
+      similarity.hashCode ^
+      changedFields.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CarriedOverItem &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          previousKey == other.previousKey &&
+          similarity == other.similarity &&
+          changedFields == other.changedFields;
+}
+
+/// This year's project started from last year's distribution.
+class CarryOverResult {
+  final DistributionProject project;
+  /// Rows of this year's form 1 nobody taught last year, left undistributed.
+  final List<DisciplineKey> newItems;
+  /// Rows of last year's form 1 gone this year, their assignments dropped.
+  final List<DisciplineKey> removedItems;
+  final List<CarriedOverItem> changedItems;
+
+  const CarryOverResult({
+    required this.project,
+    required this.newItems,
+    required this.removedItems,
+    required this.changedItems,
+  });
+
+  @override
+  int get hashCode =>
+      project.hashCode ^
+      newItems.hashCode ^
+      removedItems.hashCode ^
+      changedItems.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CarryOverResult &&
+          runtimeType == other.runtimeType &&
+          project == other.project &&
+          newItems == other.newItems &&
+          removedItems == other.removedItems &&
+          changedItems == other.changedItems;
+}
+
+/// Identifies a form 1 row and the form 3 items distributed from it.
+class DisciplineKey {
+  final LearningForm learningForm;
+  final String specialty;
+  final String disciplineName;
+  final String course;
+  final AcademicSemester semester;
+
+  const DisciplineKey({
+    required this.learningForm,
+    required this.specialty,
+    required this.disciplineName,
+    required this.course,
+    required this.semester,
+  });
+
+  @override
+  int get hashCode =>
+      learningForm.hashCode ^
+      specialty.hashCode ^
+      disciplineName.hashCode ^
+      course.hashCode ^
+      semester.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DisciplineKey &&
+          runtimeType == other.runtimeType &&
+          learningForm == other.learningForm &&
+          specialty == other.specialty &&
+          disciplineName == other.disciplineName &&
+          course == other.course &&
+          semester == other.semester;
+}
+
+/// A problem of a distribution that the app would have refused to create,
+/// e.g. a project edited by hand or by an older version.
+class DistributionIssue {
+  final String? employeeId;
+  final String? itemId;
+  final String message;
+
+  const DistributionIssue({
+    this.employeeId,
+    this.itemId,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => employeeId.hashCode ^ itemId.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DistributionIssue &&
+          runtimeType == other.runtimeType &&
+          employeeId == other.employeeId &&
+          itemId == other.itemId &&
+          message == other.message;
+}
+
+/// Workload distribution project as the app stores it: the form 1 workload
+/// of the department and the employees it is distributed among (form 3).
+/// Field names follow the JSON of the Dart `WorkloadDistributionProject`,
+/// the timestamps are kept as the ISO 8601 strings Dart writes.
+class DistributionProject {
+  final PlatformInt64 id;
+  final String title;
+  final UniversityForm1 universityForm1;
+  final UniversityForm3 universityForm3;
+  final String createdAt;
+  final String updatedAt;
+
+  const DistributionProject({
+    required this.id,
+    required this.title,
+    required this.universityForm1,
+    required this.universityForm3,
+    required this.createdAt,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      universityForm1.hashCode ^
+      universityForm3.hashCode ^
+      createdAt.hashCode ^
+      updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DistributionProject &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          universityForm1 == other.universityForm1 &&
+          universityForm3 == other.universityForm3 &&
+          createdAt == other.createdAt &&
+          updatedAt == other.updatedAt;
+}
+
+class Employee {
+  final String id;
+  final String firstName;
+  final String lastName;
+  final String patronymic;
+  final EmployeeRank rank;
+  final List<EmployeeRate> rates;
+
+  const Employee({
+    required this.id,
+    required this.firstName,
+    required this.lastName,
+    required this.patronymic,
+    required this.rank,
+    required this.rates,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      firstName.hashCode ^
+      lastName.hashCode ^
+      patronymic.hashCode ^
+      rank.hashCode ^
+      rates.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Employee &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          firstName == other.firstName &&
+          lastName == other.lastName &&
+          patronymic == other.patronymic &&
+          rank == other.rank &&
+          rates == other.rates;
+}
+
+/// Norm hours of an employee, the sum of those of their rates.
+class EmployeeNorms {
+  final String employeeId;
+  final String fullName;
+  /// The largest sum of the values of rates lasting on the same day.
+  final double peakRate;
+  final List<RateNorms> rates;
+  final PeriodNorm firstSemester;
+  final PeriodNorm secondSemester;
+  final PeriodNorm year;
+
+  const EmployeeNorms({
+    required this.employeeId,
+    required this.fullName,
+    required this.peakRate,
+    required this.rates,
+    required this.firstSemester,
+    required this.secondSemester,
+    required this.year,
+  });
+
+  @override
+  int get hashCode =>
+      employeeId.hashCode ^
+      fullName.hashCode ^
+      peakRate.hashCode ^
+      rates.hashCode ^
+      firstSemester.hashCode ^
+      secondSemester.hashCode ^
+      year.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmployeeNorms &&
+          runtimeType == other.runtimeType &&
+          employeeId == other.employeeId &&
+          fullName == other.fullName &&
+          peakRate == other.peakRate &&
+          rates == other.rates &&
+          firstSemester == other.firstSemester &&
+          secondSemester == other.secondSemester &&
+          year == other.year;
+}
+
+/// Disciplines an employee can teach, by name. Employees without
+/// preferences can be given any discipline.
+class EmployeePreferences {
+  final String employeeId;
+  final List<String> disciplines;
+
+  const EmployeePreferences({
+    required this.employeeId,
+    required this.disciplines,
+  });
+
+  @override
+  int get hashCode => employeeId.hashCode ^ disciplines.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmployeePreferences &&
+          runtimeType == other.runtimeType &&
+          employeeId == other.employeeId &&
+          disciplines == other.disciplines;
+}
+
+enum EmployeeRank { head, professor, associate, seniorLecturer, assistant }
+
+/// A rate of an employee and the workload assigned under it. The dates are
+/// ISO 8601 strings as Dart writes them, only the date part is used.
+class EmployeeRate {
+  final String id;
+  final double rateValue;
+  final String dateStart;
+  final String dateEnd;
+  final int postgraduateCount;
+  final List<UniversityForm3WorkloadItem> workloadItems;
+
+  const EmployeeRate({
+    required this.id,
+    required this.rateValue,
+    required this.dateStart,
+    required this.dateEnd,
+    required this.postgraduateCount,
+    required this.workloadItems,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      rateValue.hashCode ^
+      dateStart.hashCode ^
+      dateEnd.hashCode ^
+      postgraduateCount.hashCode ^
+      workloadItems.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmployeeRate &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          rateValue == other.rateValue &&
+          dateStart == other.dateStart &&
+          dateEnd == other.dateEnd &&
+          postgraduateCount == other.postgraduateCount &&
+          workloadItems == other.workloadItems;
+}
+
+/// Hours of an employee, supervision included, against the yearly norm of
+/// their rates.
+class EmployeeWorkloadSummary {
+  final String employeeId;
+  final String fullName;
+  final double hours;
+  final double minPossibleHours;
+  final double maxPossibleHours;
+
+  const EmployeeWorkloadSummary({
+    required this.employeeId,
+    required this.fullName,
+    required this.hours,
+    required this.minPossibleHours,
+    required this.maxPossibleHours,
+  });
+
+  @override
+  int get hashCode =>
+      employeeId.hashCode ^
+      fullName.hashCode ^
+      hours.hashCode ^
+      minPossibleHours.hashCode ^
+      maxPossibleHours.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmployeeWorkloadSummary &&
+          runtimeType == other.runtimeType &&
+          employeeId == other.employeeId &&
+          fullName == other.fullName &&
+          hours == other.hours &&
+          minPossibleHours == other.minPossibleHours &&
+          maxPossibleHours == other.maxPossibleHours;
+}
+
+class FieldChange {
+  final WorkloadField field;
+  final double oldValue;
+  final double newValue;
+
+  const FieldChange({
+    required this.field,
+    required this.oldValue,
+    required this.newValue,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ oldValue.hashCode ^ newValue.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FieldChange &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          oldValue == other.oldValue &&
+          newValue == other.newValue;
+}
+
+/// `field` may only be assigned to employees of `min_rank` or above, e.g.
+/// lectures only to associate professors.
+class FieldRankRule {
+  final WorkloadField field;
+  final EmployeeRank minRank;
+
+  const FieldRankRule({required this.field, required this.minRank});
+
+  @override
+  int get hashCode => field.hashCode ^ minRank.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FieldRankRule &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          minRank == other.minRank;
+}
+
+/// Differences between two versions of form 1.
+class Form1Diff {
+  final List<Form1DiffEntry> added;
+  final List<Form1DiffEntry> removed;
+  final List<Form1DiffEntry> changed;
+
+  const Form1Diff({
+    required this.added,
+    required this.removed,
+    required this.changed,
+  });
+
+  @override
+  int get hashCode => added.hashCode ^ removed.hashCode ^ changed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Form1Diff &&
+          runtimeType == other.runtimeType &&
+          added == other.added &&
+          removed == other.removed &&
+          changed == other.changed;
+}
+
+/// A row of form 1 that appeared, disappeared or changed. Added rows list
+/// their values as changes from 0, removed ones as changes to 0.
+class Form1DiffEntry {
+  final DisciplineKey key;
+  final List<FieldChange> changes;
+  /// Full names of the employees the row is distributed to.
+  final List<String> affectedEmployees;
+
+  const Form1DiffEntry({
+    required this.key,
+    required this.changes,
+    required this.affectedEmployees,
+  });
+
+  @override
+  int get hashCode =>
+      key.hashCode ^ changes.hashCode ^ affectedEmployees.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Form1DiffEntry &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          changes == other.changes &&
+          affectedEmployees == other.affectedEmployees;
+}
+
+/// A form 1 row that contradicts itself or another row.
+class Form1Finding {
+  /// 1-based position of the row in the sheet.
+  final int row;
+  final Form1FindingKind kind;
+  /// `None` when the learning form of the row is unknown.
+  final DisciplineKey? key;
+  final WorkloadField? field;
+  final String message;
+
+  const Form1Finding({
+    required this.row,
+    required this.kind,
+    this.key,
+    this.field,
+    required this.message,
+  });
+
+  @override
+  int get hashCode =>
+      row.hashCode ^
+      kind.hashCode ^
+      key.hashCode ^
+      field.hashCode ^
+      message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Form1Finding &&
+          runtimeType == other.runtimeType &&
+          row == other.row &&
+          kind == other.kind &&
+          key == other.key &&
+          field == other.field &&
+          message == other.message;
+}
+
+/// What is wrong with a form 1 row.
+enum Form1FindingKind {
+  unknownLearningForm,
+  unreadableValue,
+  negativeValue,
+  totalMismatch,
+  semesterMismatch,
+  duplicateKey,
+}
+
+enum LearningForm { daytime, evening, correspondence }
+
+/// Count of a form 1 row a norm is given per.
+enum NormBasis { students, flows, groups, subgroups, weeks }
+
+/// A form 1 value that disagrees with the norm.
+class NormFinding {
+  /// 1-based position of the row in the sheet.
+  final int row;
+  final DisciplineKey key;
+  final WorkloadField field;
+  final double fileValue;
+  final double normValue;
+
+  const NormFinding({
+    required this.row,
+    required this.key,
+    required this.field,
+    required this.fileValue,
+    required this.normValue,
+  });
+
+  @override
+  int get hashCode =>
+      row.hashCode ^
+      key.hashCode ^
+      field.hashCode ^
+      fileValue.hashCode ^
+      normValue.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NormFinding &&
+          runtimeType == other.runtimeType &&
+          row == other.row &&
+          key == other.key &&
+          field == other.field &&
+          fileValue == other.fileValue &&
+          normValue == other.normValue;
+}
+
+/// `field` takes `hours_per_unit` hours per unit of `basis`, e.g. exams
+/// 0.33 hours per student.
+class NormRule {
+  final WorkloadField field;
+  final NormBasis basis;
+  final double hoursPerUnit;
+
+  const NormRule({
+    required this.field,
+    required this.basis,
+    required this.hoursPerUnit,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ basis.hashCode ^ hoursPerUnit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NormRule &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          basis == other.basis &&
+          hoursPerUnit == other.hoursPerUnit;
+}
+
+/// University norms form 1 hours are calculated by.
+class NormsTable {
+  final List<NormRule> rules;
+  /// Hours the file may differ from the norm by without being flagged.
+  final double tolerance;
+
+  const NormsTable({required this.rules, required this.tolerance});
+
+  @override
+  int get hashCode => rules.hashCode ^ tolerance.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NormsTable &&
+          runtimeType == other.runtimeType &&
+          rules == other.rules &&
+          tolerance == other.tolerance;
+}
+
+/// Norm hours of a rate, or of all the rates of an employee, over a
+/// semester or the academic year.
+class PeriodNorm {
+  /// Months of the period the rates last, a month partly covered counting
+  /// as the covered share of its days, each rate weighted by its value.
+  final double rateMonths;
+  /// Hours of postgraduate supervision, counted towards the workload.
+  final double supervisionHours;
+  final double minHours;
+  final double maxHours;
+  /// Hours assigned, supervision included.
+  final double hours;
+
+  const PeriodNorm({
+    required this.rateMonths,
+    required this.supervisionHours,
+    required this.minHours,
+    required this.maxHours,
+    required this.hours,
+  });
+
+  @override
+  int get hashCode =>
+      rateMonths.hashCode ^
+      supervisionHours.hashCode ^
+      minHours.hashCode ^
+      maxHours.hashCode ^
+      hours.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeriodNorm &&
+          runtimeType == other.runtimeType &&
+          rateMonths == other.rateMonths &&
+          supervisionHours == other.supervisionHours &&
+          minHours == other.minHours &&
+          maxHours == other.maxHours &&
+          hours == other.hours;
+}
+
+class RateNorms {
+  final String rateId;
+  final double rateValue;
+  /// Calendar months of the academic year the rate lasts.
+  final double months;
+  final PeriodNorm firstSemester;
+  final PeriodNorm secondSemester;
+  final PeriodNorm year;
+
+  const RateNorms({
+    required this.rateId,
+    required this.rateValue,
+    required this.months,
+    required this.firstSemester,
+    required this.secondSemester,
+    required this.year,
+  });
+
+  @override
+  int get hashCode =>
+      rateId.hashCode ^
+      rateValue.hashCode ^
+      months.hashCode ^
+      firstSemester.hashCode ^
+      secondSemester.hashCode ^
+      year.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RateNorms &&
+          runtimeType == other.runtimeType &&
+          rateId == other.rateId &&
+          rateValue == other.rateValue &&
+          months == other.months &&
+          firstSemester == other.firstSemester &&
+          secondSemester == other.secondSemester &&
+          year == other.year;
+}
+
+/// Something the reconciliation could not decide and left to the user.
+class ReconcileConflict {
+  final ReconcileConflictKind kind;
+  final DisciplineKey key;
+  /// `None` for a vanished discipline, whose hours are summed.
+  final WorkloadField? field;
+  final double total;
+  final double distributed;
+  final List<String> employees;
+  final String message;
+
+  const ReconcileConflict({
+    required this.kind,
+    required this.key,
+    this.field,
+    required this.total,
+    required this.distributed,
+    required this.employees,
+    required this.message,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      key.hashCode ^
+      field.hashCode ^
+      total.hashCode ^
+      distributed.hashCode ^
+      employees.hashCode ^
+      message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReconcileConflict &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          key == other.key &&
+          field == other.field &&
+          total == other.total &&
+          distributed == other.distributed &&
+          employees == other.employees &&
+          message == other.message;
+}
+
+enum ReconcileConflictKind {
+  /// The row is gone from the revised form 1 but is still distributed. vanishedDiscipline,
+  /// More of `field` is distributed than the revised form 1 plans. overDistributed,
+}
+
+/// The distribution moved onto a revised form 1.
+class ReconcileResult {
+  final DistributionProject project;
+  final Form1Diff diff;
+  final List<ReconcileConflict> conflicts;
+
+  const ReconcileResult({
+    required this.project,
+    required this.diff,
+    required this.conflicts,
+  });
+
+  @override
+  int get hashCode => project.hashCode ^ diff.hashCode ^ conflicts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReconcileResult &&
+          runtimeType == other.runtimeType &&
+          project == other.project &&
+          diff == other.diff &&
+          conflicts == other.conflicts;
+}
+
+/// What `distribute_workload` may assign to whom.
+class SolverOptions {
+  final List<EmployeePreferences> preferences;
+  final List<FieldRankRule> rankRules;
+
+  const SolverOptions({required this.preferences, required this.rankRules});
+
+  @override
+  int get hashCode => preferences.hashCode ^ rankRules.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SolverOptions &&
+          runtimeType == other.runtimeType &&
+          preferences == other.preferences &&
+          rankRules == other.rankRules;
+}
+
+/// The project with the proposed assignment added to it.
+class SolverResult {
+  final DistributionProject project;
+  /// Hours nobody could take within their norm and preferences.
+  final List<UndistributedWorkload> unplaced;
+  /// Form 1 rows whose hours went to more than one employee.
+  final List<DisciplineKey> splitItems;
+  final List<EmployeeWorkloadSummary> employees;
+
+  const SolverResult({
+    required this.project,
+    required this.unplaced,
+    required this.splitItems,
+    required this.employees,
+  });
+
+  @override
+  int get hashCode =>
+      project.hashCode ^
+      unplaced.hashCode ^
+      splitItems.hashCode ^
+      employees.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SolverResult &&
+          runtimeType == other.runtimeType &&
+          project == other.project &&
+          unplaced == other.unplaced &&
+          splitItems == other.splitItems &&
+          employees == other.employees;
+}
+
+/// Planned, distributed and remaining value of one field of a form 1 row.
+class UndistributedWorkload {
+  final DisciplineKey workloadKey;
+  final WorkloadField field;
+  final double total;
+  final double distributed;
+  final double undistributed;
+
+  const UndistributedWorkload({
+    required this.workloadKey,
+    required this.field,
+    required this.total,
+    required this.distributed,
+    required this.undistributed,
+  });
+
+  @override
+  int get hashCode =>
+      workloadKey.hashCode ^
+      field.hashCode ^
+      total.hashCode ^
+      distributed.hashCode ^
+      undistributed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UndistributedWorkload &&
+          runtimeType == other.runtimeType &&
+          workloadKey == other.workloadKey &&
+          field == other.field &&
+          total == other.total &&
+          distributed == other.distributed &&
+          undistributed == other.undistributed;
+}
+
+class UniversityForm1 {
+  final String id;
+  final int academicYear;
+  final List<UniversityForm1WorkloadItem> workloadItems;
+
+  const UniversityForm1({
+    required this.id,
+    required this.academicYear,
+    required this.workloadItems,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^ academicYear.hashCode ^ workloadItems.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UniversityForm1 &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          academicYear == other.academicYear &&
+          workloadItems == other.workloadItems;
+}
+
+/// A form 1 row, the hours planned for one discipline of one group of
+/// students. The `*_total` hours are the ones to distribute.
+class UniversityForm1WorkloadItem {
+  final String id;
+  final DisciplineKey workloadKey;
+  final double weekCount;
+  final int studentCount;
+  final double flowCount;
+  final double groupCount;
+  final double subgroupCount;
+  final double lecturesPlanned;
+  final double lecturesTotal;
+  final double practicesPlanned;
+  final double practicesTotal;
+  final double labsPlanned;
+  final double labsTotal;
+  final double exams;
+  final double examConsults;
+  final double tests;
+  final double qualificationWorks;
+  final double certificationExams;
+  final double productionPractices;
+  final double teachingPractices;
+  final double currentConsults;
+  final double individualWorks;
+  final double courseWorks;
+  final double postgraduateExams;
+
+  const UniversityForm1WorkloadItem({
+    required this.id,
+    required this.workloadKey,
+    required this.weekCount,
+    required this.studentCount,
+    required this.flowCount,
+    required this.groupCount,
+    required this.subgroupCount,
+    required this.lecturesPlanned,
+    required this.lecturesTotal,
+    required this.practicesPlanned,
+    required this.practicesTotal,
+    required this.labsPlanned,
+    required this.labsTotal,
+    required this.exams,
+    required this.examConsults,
+    required this.tests,
+    required this.qualificationWorks,
+    required this.certificationExams,
+    required this.productionPractices,
+    required this.teachingPractices,
+    required this.currentConsults,
+    required this.individualWorks,
+    required this.courseWorks,
+    required this.postgraduateExams,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      workloadKey.hashCode ^
+      weekCount.hashCode ^
+      studentCount.hashCode ^
+      flowCount.hashCode ^
+      groupCount.hashCode ^
+      subgroupCount.hashCode ^
+      lecturesPlanned.hashCode ^
+      lecturesTotal.hashCode ^
+      practicesPlanned.hashCode ^
+      practicesTotal.hashCode ^
+      labsPlanned.hashCode ^
+      labsTotal.hashCode ^
+      exams.hashCode ^
+      examConsults.hashCode ^
+      tests.hashCode ^
+      qualificationWorks.hashCode ^
+      certificationExams.hashCode ^
+      productionPractices.hashCode ^
+      teachingPractices.hashCode ^
+      currentConsults.hashCode ^
+      individualWorks.hashCode ^
+      courseWorks.hashCode ^
+      postgraduateExams.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UniversityForm1WorkloadItem &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          workloadKey == other.workloadKey &&
+          weekCount == other.weekCount &&
+          studentCount == other.studentCount &&
+          flowCount == other.flowCount &&
+          groupCount == other.groupCount &&
+          subgroupCount == other.subgroupCount &&
+          lecturesPlanned == other.lecturesPlanned &&
+          lecturesTotal == other.lecturesTotal &&
+          practicesPlanned == other.practicesPlanned &&
+          practicesTotal == other.practicesTotal &&
+          labsPlanned == other.labsPlanned &&
+          labsTotal == other.labsTotal &&
+          exams == other.exams &&
+          examConsults == other.examConsults &&
+          tests == other.tests &&
+          qualificationWorks == other.qualificationWorks &&
+          certificationExams == other.certificationExams &&
+          productionPractices == other.productionPractices &&
+          teachingPractices == other.teachingPractices &&
+          currentConsults == other.currentConsults &&
+          individualWorks == other.individualWorks &&
+          courseWorks == other.courseWorks &&
+          postgraduateExams == other.postgraduateExams;
+}
+
+class UniversityForm3 {
+  final String id;
+  final int academicYear;
+  final List<Employee> employees;
+
+  const UniversityForm3({
+    required this.id,
+    required this.academicYear,
+    required this.employees,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ academicYear.hashCode ^ employees.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UniversityForm3 &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          academicYear == other.academicYear &&
+          employees == other.employees;
+}
+
+/// Part of a form 1 row assigned to an employee.
+class UniversityForm3WorkloadItem {
+  final String id;
+  final DisciplineKey workloadKey;
+  final int studentCount;
+  final List<String> academicGroups;
+  final double lectures;
+  final double practices;
+  final double labs;
+  final double exams;
+  final double examConsults;
+  final double tests;
+  final double qualificationWorks;
+  final double certificationExams;
+  final double productionPractices;
+  final double teachingPractices;
+  final double currentConsults;
+  final double individualWorks;
+  final double courseWorks;
+  final double postgraduateExams;
+
+  const UniversityForm3WorkloadItem({
+    required this.id,
+    required this.workloadKey,
+    required this.studentCount,
+    required this.academicGroups,
+    required this.lectures,
+    required this.practices,
+    required this.labs,
+    required this.exams,
+    required this.examConsults,
+    required this.tests,
+    required this.qualificationWorks,
+    required this.certificationExams,
+    required this.productionPractices,
+    required this.teachingPractices,
+    required this.currentConsults,
+    required this.individualWorks,
+    required this.courseWorks,
+    required this.postgraduateExams,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      workloadKey.hashCode ^
+      studentCount.hashCode ^
+      academicGroups.hashCode ^
+      lectures.hashCode ^
+      practices.hashCode ^
+      labs.hashCode ^
+      exams.hashCode ^
+      examConsults.hashCode ^
+      tests.hashCode ^
+      qualificationWorks.hashCode ^
+      certificationExams.hashCode ^
+      productionPractices.hashCode ^
+      teachingPractices.hashCode ^
+      currentConsults.hashCode ^
+      individualWorks.hashCode ^
+      courseWorks.hashCode ^
+      postgraduateExams.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UniversityForm3WorkloadItem &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          workloadKey == other.workloadKey &&
+          studentCount == other.studentCount &&
+          academicGroups == other.academicGroups &&
+          lectures == other.lectures &&
+          practices == other.practices &&
+          labs == other.labs &&
+          exams == other.exams &&
+          examConsults == other.examConsults &&
+          tests == other.tests &&
+          qualificationWorks == other.qualificationWorks &&
+          certificationExams == other.certificationExams &&
+          productionPractices == other.productionPractices &&
+          teachingPractices == other.teachingPractices &&
+          currentConsults == other.currentConsults &&
+          individualWorks == other.individualWorks &&
+          courseWorks == other.courseWorks &&
+          postgraduateExams == other.postgraduateExams;
+}
+
+/// A distributable value of a form 1 row and of the items assigned from it.
+enum WorkloadField {
+  studentCount,
+  lectures,
+  practices,
+  labs,
+  exams,
+  examConsults,
+  tests,
+  qualificationWorks,
+  certificationExams,
+  productionPractices,
+  teachingPractices,
+  currentConsults,
+  individualWorks,
+  courseWorks,
+  postgraduateExams,
+}
@@ -6,6 +6,130 @@
 import 'package:aw_flutter/src/rust/frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+class EmployeeWorkloadInput {
+  final int id;
+  final String firstName;
+  final String middleName;
+  final String lastName;
+  final String rank;
+  final String commentSemester1;
+  final String commentSemester2;
+  final List<OutputPersonalSemesterRow> semester1;
+  final double semester1Rate;
+  final List<OutputPersonalSemesterRow> semester2;
+  final double semester2Rate;
+
+  const EmployeeWorkloadInput({
+    required this.id,
+    required this.firstName,
+    required this.middleName,
+    required this.lastName,
+    required this.rank,
+    required this.commentSemester1,
+    required this.commentSemester2,
+    required this.semester1,
+    required this.semester1Rate,
+    required this.semester2,
+    required this.semester2Rate,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      firstName.hashCode ^
+      middleName.hashCode ^
+      lastName.hashCode ^
+      rank.hashCode ^
+      commentSemester1.hashCode ^
+      commentSemester2.hashCode ^
+      semester1.hashCode ^
+      semester1Rate.hashCode ^
+      semester2.hashCode ^
+      semester2Rate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmployeeWorkloadInput &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          firstName == other.firstName &&
+          middleName == other.middleName &&
+          lastName == other.lastName &&
+          rank == other.rank &&
+          commentSemester1 == other.commentSemester1 &&
+          commentSemester2 == other.commentSemester2 &&
+          semester1 == other.semester1 &&
+          semester1Rate == other.semester1Rate &&
+          semester2 == other.semester2 &&
+          semester2Rate == other.semester2Rate;
+}
+
+/// Options of `write_excel_file`.
+class ExportOptions {
+  /// Drop the workload columns that have no hours anywhere in the export.
+  final bool hideEmptyColumns;
+  /// Workload columns in the order they are written, `None` for
+  /// `default_workload_columns`.
+  final List<WorkloadColumn>? columns;
+  /// Font of the written cells, `None` for Times New Roman 10pt.
+  final ExportTheme? theme;
+  /// Colour the yearly total of the teachers whose hours are outside the
+  /// 580–600 hours per rate norm and comment it with the norm. The rate is
+  /// the mean of the semester rates, so a semester without one halves it.
+  final bool highlightWorkloadNorms;
+  /// Add the "Аналітика" sheet with hours per discipline, speciality,
+  /// learning form and semester and lectures per rank, with charts.
+  final bool includeAnalytics;
+
+  const ExportOptions({
+    required this.hideEmptyColumns,
+    this.columns,
+    this.theme,
+    required this.highlightWorkloadNorms,
+    required this.includeAnalytics,
+  });
+
+  @override
+  int get hashCode =>
+      hideEmptyColumns.hashCode ^
+      columns.hashCode ^
+      theme.hashCode ^
+      highlightWorkloadNorms.hashCode ^
+      includeAnalytics.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportOptions &&
+          runtimeType == other.runtimeType &&
+          hideEmptyColumns == other.hideEmptyColumns &&
+          columns == other.columns &&
+          theme == other.theme &&
+          highlightWorkloadNorms == other.highlightWorkloadNorms &&
+          includeAnalytics == other.includeAnalytics;
+}
+
+/// Font every cell of an export is written with; the named styles of
+/// `StyleRegistry` only add weight, alignment and borders on top of it.
+class ExportTheme {
+  final String fontName;
+  final double fontSize;
+
+  const ExportTheme({required this.fontName, required this.fontSize});
+
+  @override
+  int get hashCode => fontName.hashCode ^ fontSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportTheme &&
+          runtimeType == other.runtimeType &&
+          fontName == other.fontName &&
+          fontSize == other.fontSize;
+}
+
 class ExportedData {
   final int year;
   final String typeName;
@@ -172,6 +296,52 @@ class InputRawRow {
           internship == other.internship;
 }
 
+/// Subtotal of a semester for one learning form, rendered as a
+/// "Разом (<form> форма)" row. `learning_form` is the adjective, e.g. "денна".
+class LearningFormTotal {
+  final String learningForm;
+  final OutputHoursRow hours;
+
+  const LearningFormTotal({required this.learningForm, required this.hours});
+
+  @override
+  int get hashCode => learningForm.hashCode ^ hours.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LearningFormTotal &&
+          runtimeType == other.runtimeType &&
+          learningForm == other.learningForm &&
+          hours == other.hours;
+}
+
+/// A group of workers in the main table (e.g. professors) followed by its
+/// subtotal row. `title` is the caption of that subtotal row.
+class MainTableSection {
+  final String title;
+  final List<OutputMainWorkerRow> rows;
+  final OutputMainRateAndHours total;
+
+  const MainTableSection({
+    required this.title,
+    required this.rows,
+    required this.total,
+  });
+
+  @override
+  int get hashCode => title.hashCode ^ rows.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MainTableSection &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          rows == other.rows &&
+          total == other.total;
+}
+
 class OutputHoursRow {
   final double lectures;
   final double practices;
@@ -180,12 +350,15 @@ class OutputHoursRow {
   final double examConsults;
   final double tests;
   final double qualWorks;
+  final double certificationExams;
   final double workingPractice;
   final double teachingPractice;
   final double consults;
   final double individualWorks;
   final double courseWorks;
+  final double postgraduateExams;
   final double supervising;
+  final double internship;
   final double total;
 
   const OutputHoursRow({
@@ -196,12 +369,15 @@ class OutputHoursRow {
     required this.examConsults,
     required this.tests,
     required this.qualWorks,
+    required this.certificationExams,
     required this.workingPractice,
     required this.teachingPractice,
     required this.consults,
     required this.individualWorks,
     required this.courseWorks,
+    required this.postgraduateExams,
     required this.supervising,
+    required this.internship,
     required this.total,
   });
 
@@ -214,12 +390,15 @@ class OutputHoursRow {
       examConsults.hashCode ^
       tests.hashCode ^
       qualWorks.hashCode ^
+      certificationExams.hashCode ^
       workingPractice.hashCode ^
       teachingPractice.hashCode ^
       consults.hashCode ^
       individualWorks.hashCode ^
       courseWorks.hashCode ^
+      postgraduateExams.hashCode ^
       supervising.hashCode ^
+      internship.hashCode ^
       total.hashCode;
 
   @override
@@ -234,12 +413,15 @@ class OutputHoursRow {
           examConsults == other.examConsults &&
           tests == other.tests &&
           qualWorks == other.qualWorks &&
+          certificationExams == other.certificationExams &&
           workingPractice == other.workingPractice &&
           teachingPractice == other.teachingPractice &&
           consults == other.consults &&
           individualWorks == other.individualWorks &&
           courseWorks == other.courseWorks &&
+          postgraduateExams == other.postgraduateExams &&
           supervising == other.supervising &&
+          internship == other.internship &&
           total == other.total;
 }
 
@@ -272,69 +454,20 @@ class OutputMainRateAndHours {
 }
 
 class OutputMainTable {
-  final List<OutputMainWorkerRow> heads;
-  final OutputMainRateAndHours headsTotal;
-  final List<OutputMainWorkerRow> professors;
-  final OutputMainRateAndHours professorsTotal;
-  final List<OutputMainWorkerRow> associateProfessors;
-  final OutputMainRateAndHours associateProfessorsTotal;
-  final List<OutputMainWorkerRow> lecturers;
-  final OutputMainRateAndHours lecturersTotal;
-  final List<OutputMainWorkerRow> assistants;
-  final OutputMainRateAndHours assistantsTotal;
-  final List<OutputMainWorkerRow> partTimers;
-  final OutputMainRateAndHours partTimersTotal;
+  final List<MainTableSection> sections;
   final OutputMainRateAndHours total;
 
-  const OutputMainTable({
-    required this.heads,
-    required this.headsTotal,
-    required this.professors,
-    required this.professorsTotal,
-    required this.associateProfessors,
-    required this.associateProfessorsTotal,
-    required this.lecturers,
-    required this.lecturersTotal,
-    required this.assistants,
-    required this.assistantsTotal,
-    required this.partTimers,
-    required this.partTimersTotal,
-    required this.total,
-  });
+  const OutputMainTable({required this.sections, required this.total});
 
   @override
-  int get hashCode =>
-      heads.hashCode ^
-      headsTotal.hashCode ^
-      professors.hashCode ^
-      professorsTotal.hashCode ^
-      associateProfessors.hashCode ^
-      associateProfessorsTotal.hashCode ^
-      lecturers.hashCode ^
-      lecturersTotal.hashCode ^
-      assistants.hashCode ^
-      assistantsTotal.hashCode ^
-      partTimers.hashCode ^
-      partTimersTotal.hashCode ^
-      total.hashCode;
+  int get hashCode => sections.hashCode ^ total.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is OutputMainTable &&
           runtimeType == other.runtimeType &&
-          heads == other.heads &&
-          headsTotal == other.headsTotal &&
-          professors == other.professors &&
-          professorsTotal == other.professorsTotal &&
-          associateProfessors == other.associateProfessors &&
-          associateProfessorsTotal == other.associateProfessorsTotal &&
-          lecturers == other.lecturers &&
-          lecturersTotal == other.lecturersTotal &&
-          assistants == other.assistants &&
-          assistantsTotal == other.assistantsTotal &&
-          partTimers == other.partTimers &&
-          partTimersTotal == other.partTimersTotal &&
+          sections == other.sections &&
           total == other.total;
 }
 
@@ -380,6 +513,7 @@ class OutputPersonalSemesterRow {
   final String group;
   final String course;
   final int studentsCount;
+  final String individualWorkTypes;
   final OutputHoursRow hours;
   final bool mergeLecturesWithNext;
 
@@ -390,6 +524,7 @@ class OutputPersonalSemesterRow {
     required this.group,
     required this.course,
     required this.studentsCount,
+    required this.individualWorkTypes,
     required this.hours,
     required this.mergeLecturesWithNext,
   });
@@ -402,6 +537,7 @@ class OutputPersonalSemesterRow {
       group.hashCode ^
       course.hashCode ^
       studentsCount.hashCode ^
+      individualWorkTypes.hashCode ^
       hours.hashCode ^
       mergeLecturesWithNext.hashCode;
 
@@ -416,6 +552,7 @@ class OutputPersonalSemesterRow {
           group == other.group &&
           course == other.course &&
           studentsCount == other.studentsCount &&
+          individualWorkTypes == other.individualWorkTypes &&
           hours == other.hours &&
           mergeLecturesWithNext == other.mergeLecturesWithNext;
 }
@@ -430,13 +567,11 @@ class OutputPersonalTable {
   final String rank;
   final List<OutputPersonalSemesterRow> semester1;
   final double semester1Rate;
-  final OutputHoursRow semester1TotalDay;
-  final OutputHoursRow semester1TotalEvening;
+  final List<LearningFormTotal> semester1FormTotals;
   final OutputHoursRow semester1Total;
   final List<OutputPersonalSemesterRow> semester2;
   final double semester2Rate;
-  final OutputHoursRow semester2TotalDay;
-  final OutputHoursRow semester2TotalEvening;
+  final List<LearningFormTotal> semester2FormTotals;
   final OutputHoursRow semester2Total;
   final OutputHoursRow yearTotal;
 
@@ -450,13 +585,11 @@ class OutputPersonalTable {
     required this.rank,
     required this.semester1,
     required this.semester1Rate,
-    required this.semester1TotalDay,
-    required this.semester1TotalEvening,
+    required this.semester1FormTotals,
     required this.semester1Total,
     required this.semester2,
     required this.semester2Rate,
-    required this.semester2TotalDay,
-    required this.semester2TotalEvening,
+    required this.semester2FormTotals,
     required this.semester2Total,
     required this.yearTotal,
   });
@@ -472,13 +605,11 @@ class OutputPersonalTable {
       rank.hashCode ^
       semester1.hashCode ^
       semester1Rate.hashCode ^
-      semester1TotalDay.hashCode ^
-      semester1TotalEvening.hashCode ^
+      semester1FormTotals.hashCode ^
       semester1Total.hashCode ^
       semester2.hashCode ^
       semester2Rate.hashCode ^
-      semester2TotalDay.hashCode ^
-      semester2TotalEvening.hashCode ^
+      semester2FormTotals.hashCode ^
       semester2Total.hashCode ^
       yearTotal.hashCode;
 
@@ -496,13 +627,11 @@ class OutputPersonalTable {
           rank == other.rank &&
           semester1 == other.semester1 &&
           semester1Rate == other.semester1Rate &&
-          semester1TotalDay == other.semester1TotalDay &&
-          semester1TotalEvening == other.semester1TotalEvening &&
+          semester1FormTotals == other.semester1FormTotals &&
           semester1Total == other.semester1Total &&
           semester2 == other.semester2 &&
           semester2Rate == other.semester2Rate &&
-          semester2TotalDay == other.semester2TotalDay &&
-          semester2TotalEvening == other.semester2TotalEvening &&
+          semester2FormTotals == other.semester2FormTotals &&
           semester2Total == other.semester2Total &&
           yearTotal == other.yearTotal;
 }
@@ -543,3 +672,192 @@ class ParsedExcelFile {
           runtimeType == other.runtimeType &&
           data == other.data;
 }
+
+/// TrueType files embedded into `write_pdf_file` output. They must cover
+/// Cyrillic; a missing style falls back to `regular`.
+class PdfFonts {
+  final String regular;
+  final String? bold;
+  final String? italic;
+  final String? boldItalic;
+
+  const PdfFonts({
+    required this.regular,
+    this.bold,
+    this.italic,
+    this.boldItalic,
+  });
+
+  @override
+  int get hashCode =>
+      regular.hashCode ^ bold.hashCode ^ italic.hashCode ^ boldItalic.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfFonts &&
+          runtimeType == other.runtimeType &&
+          regular == other.regular &&
+          bold == other.bold &&
+          italic == other.italic &&
+          boldItalic == other.boldItalic;
+}
+
+/// Sheet the personal tables of one `OutputPersonalTables` entry were
+/// written to, `table_ids` being the ids of its tables.
+class PersonalSheetName {
+  final String workerLastName;
+  final Uint32List tableIds;
+  final String sheetName;
+
+  const PersonalSheetName({
+    required this.workerLastName,
+    required this.tableIds,
+    required this.sheetName,
+  });
+
+  @override
+  int get hashCode =>
+      workerLastName.hashCode ^ tableIds.hashCode ^ sheetName.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PersonalSheetName &&
+          runtimeType == other.runtimeType &&
+          workerLastName == other.workerLastName &&
+          tableIds == other.tableIds &&
+          sheetName == other.sheetName;
+}
+
+/// A supplied total that differs from the one computed from its rows.
+class TotalsMismatch {
+  final String location;
+  final String field;
+  final double expected;
+  final double actual;
+
+  const TotalsMismatch({
+    required this.location,
+    required this.field,
+    required this.expected,
+    required this.actual,
+  });
+
+  @override
+  int get hashCode =>
+      location.hashCode ^ field.hashCode ^ expected.hashCode ^ actual.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TotalsMismatch &&
+          runtimeType == other.runtimeType &&
+          location == other.location &&
+          field == other.field &&
+          expected == other.expected &&
+          actual == other.actual;
+}
+
+class WorkloadColumn {
+  final WorkloadKey key;
+  final String caption;
+  final double width;
+  /// Excel number format of the hours, e.g. "0.00".
+  final String numberFormat;
+  /// Whether the hours are summed into the `WorkloadKey::Total` column.
+  final bool includeInTotal;
+
+  const WorkloadColumn({
+    required this.key,
+    required this.caption,
+    required this.width,
+    required this.numberFormat,
+    required this.includeInTotal,
+  });
+
+  @override
+  int get hashCode =>
+      key.hashCode ^
+      caption.hashCode ^
+      width.hashCode ^
+      numberFormat.hashCode ^
+      includeInTotal.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkloadColumn &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          caption == other.caption &&
+          width == other.width &&
+          numberFormat == other.numberFormat &&
+          includeInTotal == other.includeInTotal;
+}
+
+/// Workload without any totals. `totals::build_exported_data` derives the
+/// per-form, semester, year, section and department totals from it.
+class WorkloadInput {
+  final int year;
+  final String typeName;
+  final List<WorkloadSectionInput> sections;
+
+  const WorkloadInput({
+    required this.year,
+    required this.typeName,
+    required this.sections,
+  });
+
+  @override
+  int get hashCode => year.hashCode ^ typeName.hashCode ^ sections.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkloadInput &&
+          runtimeType == other.runtimeType &&
+          year == other.year &&
+          typeName == other.typeName &&
+          sections == other.sections;
+}
+
+/// Workload type of an `OutputHoursRow` value, in the order of its fields.
+enum WorkloadKey {
+  lectures,
+  practices,
+  labs,
+  exams,
+  examConsults,
+  tests,
+  qualWorks,
+  certificationExams,
+  workingPractice,
+  teachingPractice,
+  consults,
+  individualWorks,
+  courseWorks,
+  postgraduateExams,
+  supervising,
+  internship,
+  total,
+}
+
+/// Employees of one main table section, `title` is its subtotal caption.
+class WorkloadSectionInput {
+  final String title;
+  final List<EmployeeWorkloadInput> employees;
+
+  const WorkloadSectionInput({required this.title, required this.employees});
+
+  @override
+  int get hashCode => title.hashCode ^ employees.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkloadSectionInput &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          employees == other.employees;
+}
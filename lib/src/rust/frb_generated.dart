@@ -3,9 +3,11 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'package:aw_flutter/src/rust/api/distribution_interface.dart';
 import 'package:aw_flutter/src/rust/api/excel_interface.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'package:aw_flutter/src/rust/distribution/data.dart';
 import 'package:aw_flutter/src/rust/excel/data.dart';
 import 'package:aw_flutter/src/rust/frb_generated.dart';
 import 'package:aw_flutter/src/rust/frb_generated.io.dart'
@@ -67,7 +69,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 1127398859;
+  int get rustContentHash => -645375997;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -78,15 +80,189 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<DistributionProject> crateApiDistributionInterfaceAddWorkloadItem({
+    required DistributionProject project,
+    required String employeeId,
+    required String rateId,
+    required UniversityForm3WorkloadItem item,
+  });
+
+  Future<UniversityForm1> crateApiDistributionInterfaceApplyForm1Norms({
+    required UniversityForm1 form1,
+    required NormsTable norms,
+  });
+
+  Future<ExportedData> crateApiExcelInterfaceBuildExportedData({
+    required WorkloadInput input,
+  });
+
+  Future<ExportedData> crateApiDistributionInterfaceBuildProjectExportedData({
+    required DistributionProject project,
+    required String typeName,
+    AcademicCalendar? calendar,
+  });
+
+  Future<CarryOverResult> crateApiDistributionInterfaceCarryOverDistribution({
+    required DistributionProject previous,
+    required UniversityForm1 form1,
+    double? minSimilarity,
+  });
+
+  Future<List<Form1Finding>> crateApiDistributionInterfaceCheckForm1File({
+    required ParsedExcelFile file,
+    required String sheetName,
+  });
+
+  Future<List<NormFinding>> crateApiDistributionInterfaceCheckForm1Norms({
+    required ParsedExcelFile file,
+    required String sheetName,
+    NormsTable? norms,
+  });
+
+  Future<List<DistributionIssue>>
+  crateApiDistributionInterfaceCheckSemesterCoverage({
+    required DistributionProject project,
+    AcademicCalendar? calendar,
+  });
+
+  Future<AcademicCalendar> crateApiDistributionInterfaceDefaultAcademicCalendar({
+    required int academicYear,
+  });
+
+  Future<NormsTable> crateApiDistributionInterfaceDefaultNormsTable();
+
+  Future<List<WorkloadColumn>> crateApiExcelInterfaceDefaultWorkloadColumns();
+
+  Future<Form1Diff> crateApiDistributionInterfaceDiffForm1Files({
+    required ParsedExcelFile oldFile,
+    required ParsedExcelFile newFile,
+    required String sheetName,
+    DistributionProject? project,
+  });
+
+  Future<SolverResult> crateApiDistributionInterfaceDistributeWorkload({
+    required DistributionProject project,
+    required SolverOptions options,
+    AcademicCalendar? calendar,
+    required double supervisionHoursPerPostgraduate,
+  });
+
+  Future<UniversityForm1> crateApiDistributionInterfaceForm1FromParsedFile({
+    required ParsedExcelFile file,
+    required String sheetName,
+    required int academicYear,
+  });
+
+  Future<List<EmployeeNorms>> crateApiDistributionInterfaceGetEmployeeNorms({
+    required DistributionProject project,
+    AcademicCalendar? calendar,
+    required double supervisionHoursPerPostgraduate,
+  });
+
+  Future<List<EmployeeWorkloadSummary>>
+  crateApiDistributionInterfaceGetEmployeeSummaries({
+    required DistributionProject project,
+    AcademicCalendar? calendar,
+    required double supervisionHoursPerPostgraduate,
+  });
+
+  Future<double> crateApiDistributionInterfaceGetUndistributedWorkload({
+    required DistributionProject project,
+    required DisciplineKey key,
+    required WorkloadField field,
+  });
+
   Future<void> crateApiExcelInterfaceInitApp();
 
+  Future<List<UndistributedWorkload>>
+  crateApiDistributionInterfaceListUndistributedWorkload({
+    required DistributionProject project,
+  });
+
   Future<ParsedExcelFile> crateApiExcelInterfaceParseExcelFile({
     required String filePath,
   });
 
-  Future<void> crateApiExcelInterfaceWriteExcelFile({
+  Future<DistributionProject>
+  crateApiDistributionInterfaceReadDistributionProject({
+    required String filePath,
+  });
+
+  Future<ExportedData> crateApiExcelInterfaceReadJsonFile({
+    required String filePath,
+  });
+
+  Future<ReconcileResult> crateApiDistributionInterfaceReconcileForm1File({
+    required DistributionProject project,
+    required ParsedExcelFile file,
+    required String sheetName,
+  });
+
+  Future<DistributionProject> crateApiDistributionInterfaceRemoveWorkloadItem({
+    required DistributionProject project,
+    required String employeeId,
+    required String rateId,
+    required String itemId,
+  });
+
+  Future<DistributionProject> crateApiDistributionInterfaceUpdateWorkloadField({
+    required DistributionProject project,
+    required String employeeId,
+    required String rateId,
+    required String itemId,
+    required WorkloadField field,
+    required double value,
+  });
+
+  Future<List<DistributionIssue>>
+  crateApiDistributionInterfaceValidateDistribution({
+    required DistributionProject project,
+  });
+
+  Future<List<TotalsMismatch>> crateApiExcelInterfaceValidateExportedData({
+    required ExportedData exportedTables,
+  });
+
+  Future<void> crateApiExcelInterfaceWriteCsvFile({
+    required String filePath,
+    required ExportedData exportedTables,
+    required ExportOptions options,
+  });
+
+  Future<void> crateApiDistributionInterfaceWriteDistributionProject({
+    required String filePath,
+    required DistributionProject project,
+  });
+
+  Future<List<PersonalSheetName>> crateApiExcelInterfaceWriteExcelFile({
+    required String filePath,
+    required ExportedData exportedTables,
+    required ExportOptions options,
+  });
+
+  Future<void> crateApiExcelInterfaceWriteHtmlFile({
+    required String filePath,
+    required ExportedData exportedTables,
+    required ExportOptions options,
+  });
+
+  Future<void> crateApiExcelInterfaceWriteJsonFile({
+    required String filePath,
+    required ExportedData exportedTables,
+  });
+
+  Future<void> crateApiExcelInterfaceWritePdfFile({
     required String filePath,
     required ExportedData exportedTables,
+    required ExportOptions options,
+    required PdfFonts fonts,
+  });
+
+  Future<List<String>> crateApiExcelInterfaceWritePersonalFiles({
+    required String directory,
+    required ExportedData exportedTables,
+    required ExportOptions options,
+    PdfFonts? pdfFonts,
   });
 }
 
@@ -99,11 +275,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<void> crateApiExcelInterfaceInitApp() {
+  Future<DistributionProject> crateApiDistributionInterfaceAddWorkloadItem({
+    required DistributionProject project,
+    required String employeeId,
+    required String rateId,
+    required UniversityForm3WorkloadItem item,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_String(employeeId, serializer);
+          sse_encode_String(rateId, serializer);
+          sse_encode_box_autoadd_university_form_3_workload_item(
+            item,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -112,28 +300,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_distribution_project,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiExcelInterfaceInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDistributionInterfaceAddWorkloadItemConstMeta,
+        argValues: [project, employeeId, rateId, item],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiExcelInterfaceInitAppConstMeta =>
-      const TaskConstMeta(debugName: 'init_app', argNames: []);
+  TaskConstMeta get kCrateApiDistributionInterfaceAddWorkloadItemConstMeta =>
+      const TaskConstMeta(
+        debugName: 'add_workload_item',
+        argNames: ['project', 'employeeId', 'rateId', 'item'],
+      );
 
   @override
-  Future<ParsedExcelFile> crateApiExcelInterfaceParseExcelFile({
-    required String filePath,
+  Future<UniversityForm1> crateApiDistributionInterfaceApplyForm1Norms({
+    required UniversityForm1 form1,
+    required NormsTable norms,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
+          sse_encode_box_autoadd_university_form_1(form1, serializer);
+          sse_encode_box_autoadd_norms_table(norms, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -142,33 +335,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_parsed_excel_file,
+          decodeSuccessData: sse_decode_university_form_1,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiExcelInterfaceParseExcelFileConstMeta,
-        argValues: [filePath],
+        constMeta: kCrateApiDistributionInterfaceApplyForm1NormsConstMeta,
+        argValues: [form1, norms],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiExcelInterfaceParseExcelFileConstMeta =>
+  TaskConstMeta get kCrateApiDistributionInterfaceApplyForm1NormsConstMeta =>
       const TaskConstMeta(
-        debugName: 'parse_excel_file',
-        argNames: ['filePath'],
+        debugName: 'apply_form1_norms',
+        argNames: ['form1', 'norms'],
       );
 
   @override
-  Future<void> crateApiExcelInterfaceWriteExcelFile({
-    required String filePath,
-    required ExportedData exportedTables,
+  Future<ExportedData> crateApiExcelInterfaceBuildExportedData({
+    required WorkloadInput input,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
-          sse_encode_box_autoadd_exported_data(exportedTables, serializer);
+          sse_encode_box_autoadd_workload_input(input, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -177,965 +368,5349 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_exported_data,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiExcelInterfaceWriteExcelFileConstMeta,
-        argValues: [filePath, exportedTables],
+        constMeta: kCrateApiExcelInterfaceBuildExportedDataConstMeta,
+        argValues: [input],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiExcelInterfaceWriteExcelFileConstMeta =>
+  TaskConstMeta get kCrateApiExcelInterfaceBuildExportedDataConstMeta =>
       const TaskConstMeta(
-        debugName: 'write_excel_file',
-        argNames: ['filePath', 'exportedTables'],
+        debugName: 'build_exported_data',
+        argNames: ['input'],
       );
 
-  @protected
-  Map<String, List<InputRawRow>> dco_decode_Map_String_list_input_raw_row_None(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_list_input_raw_row(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
+  @override
+  Future<ExportedData> crateApiDistributionInterfaceBuildProjectExportedData({
+    required DistributionProject project,
+    required String typeName,
+    AcademicCalendar? calendar,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_String(typeName, serializer);
+          sse_encode_opt_box_autoadd_academic_calendar(calendar, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_exported_data,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceBuildProjectExportedDataConstMeta,
+        argValues: [project, typeName, calendar],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiDistributionInterfaceBuildProjectExportedDataConstMeta =>
+      const TaskConstMeta(
+        debugName: 'build_project_exported_data',
+        argNames: ['project', 'typeName', 'calendar'],
+      );
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  @override
+  Future<CarryOverResult> crateApiDistributionInterfaceCarryOverDistribution({
+    required DistributionProject previous,
+    required UniversityForm1 form1,
+    double? minSimilarity,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(previous, serializer);
+          sse_encode_box_autoadd_university_form_1(form1, serializer);
+          sse_encode_opt_box_autoadd_f_64(minSimilarity, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_carry_over_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceCarryOverDistributionConstMeta,
+        argValues: [previous, form1, minSimilarity],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  ExportedData dco_decode_box_autoadd_exported_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_exported_data(raw);
-  }
+  TaskConstMeta get kCrateApiDistributionInterfaceCarryOverDistributionConstMeta =>
+      const TaskConstMeta(
+        debugName: 'carry_over_distribution',
+        argNames: ['previous', 'form1', 'minSimilarity'],
+      );
 
-  @protected
-  ExportedData dco_decode_exported_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ExportedData(
-      year: dco_decode_u_32(arr[0]),
-      typeName: dco_decode_String(arr[1]),
-      mainTable: dco_decode_output_main_table(arr[2]),
-      personalTables: dco_decode_list_output_personal_tables(arr[3]),
+  @override
+  Future<List<Form1Finding>> crateApiDistributionInterfaceCheckForm1File({
+    required ParsedExcelFile file,
+    required String sheetName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_parsed_excel_file(file, serializer);
+          sse_encode_String(sheetName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_form_1_finding,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDistributionInterfaceCheckForm1FileConstMeta,
+        argValues: [file, sheetName],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
+  TaskConstMeta get kCrateApiDistributionInterfaceCheckForm1FileConstMeta =>
+      const TaskConstMeta(
+        debugName: 'check_form1_file',
+        argNames: ['file', 'sheetName'],
+      );
 
-  @protected
-  InputRawRow dco_decode_input_raw_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 30)
-      throw Exception('unexpected arr length: expect 30 but see ${arr.length}');
-    return InputRawRow(
-      learningForm: dco_decode_String(arr[0]),
-      speciality: dco_decode_String(arr[1]),
-      name: dco_decode_String(arr[2]),
-      course: dco_decode_String(arr[3]),
-      semester: dco_decode_String(arr[4]),
-      weeksCount: dco_decode_String(arr[5]),
-      studentsCount: dco_decode_String(arr[6]),
-      flowsCount: dco_decode_String(arr[7]),
-      groupsCount: dco_decode_String(arr[8]),
-      subgroupsCount: dco_decode_String(arr[9]),
-      lecturesPlannedCount: dco_decode_String(arr[10]),
-      lecturesTotalCount: dco_decode_String(arr[11]),
-      practicesPlannedCount: dco_decode_String(arr[12]),
-      practicesTotalCount: dco_decode_String(arr[13]),
-      labsPlannedCount: dco_decode_String(arr[14]),
-      labsTotalCount: dco_decode_String(arr[15]),
-      exams: dco_decode_String(arr[16]),
-      examConsults: dco_decode_String(arr[17]),
-      tests: dco_decode_String(arr[18]),
-      qualWorks: dco_decode_String(arr[19]),
-      certificationExams: dco_decode_String(arr[20]),
-      workingPractice: dco_decode_String(arr[21]),
-      teachingPractice: dco_decode_String(arr[22]),
-      consults: dco_decode_String(arr[23]),
-      individualWorks: dco_decode_String(arr[24]),
-      individualWorkTypes: dco_decode_String(arr[25]),
-      courseWorks: dco_decode_String(arr[26]),
-      postgraduateExams: dco_decode_String(arr[27]),
-      supervising: dco_decode_String(arr[28]),
-      internship: dco_decode_String(arr[29]),
+  @override
+  Future<List<NormFinding>> crateApiDistributionInterfaceCheckForm1Norms({
+    required ParsedExcelFile file,
+    required String sheetName,
+    NormsTable? norms,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_parsed_excel_file(file, serializer);
+          sse_encode_String(sheetName, serializer);
+          sse_encode_opt_box_autoadd_norms_table(norms, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_norm_finding,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceCheckForm1NormsConstMeta,
+        argValues: [file, sheetName, norms],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  List<InputRawRow> dco_decode_list_input_raw_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_input_raw_row).toList();
+  TaskConstMeta get kCrateApiDistributionInterfaceCheckForm1NormsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'check_form1_norms',
+        argNames: ['file', 'sheetName', 'norms'],
+      );
+
+  @override
+  Future<List<DistributionIssue>>
+  crateApiDistributionInterfaceCheckSemesterCoverage({
+    required DistributionProject project,
+    AcademicCalendar? calendar,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_opt_box_autoadd_academic_calendar(calendar, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_distribution_issue,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceCheckSemesterCoverageConstMeta,
+        argValues: [project, calendar],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceCheckSemesterCoverageConstMeta =>
+      const TaskConstMeta(
+        debugName: 'check_semester_coverage',
+        argNames: ['project', 'calendar'],
+      );
+
+  @override
+  Future<AcademicCalendar> crateApiDistributionInterfaceDefaultAcademicCalendar({
+    required int academicYear,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(academicYear, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_academic_calendar,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDistributionInterfaceDefaultAcademicCalendarConstMeta,
+        argValues: [academicYear],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceDefaultAcademicCalendarConstMeta =>
+      const TaskConstMeta(
+        debugName: 'default_academic_calendar',
+        argNames: ['academicYear'],
+      );
+
+  @override
+  Future<NormsTable> crateApiDistributionInterfaceDefaultNormsTable() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_norms_table,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDistributionInterfaceDefaultNormsTableConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceDefaultNormsTableConstMeta =>
+      const TaskConstMeta(debugName: 'default_norms_table', argNames: []);
+
+  @override
+  Future<List<WorkloadColumn>> crateApiExcelInterfaceDefaultWorkloadColumns() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_workload_column,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiExcelInterfaceDefaultWorkloadColumnsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceDefaultWorkloadColumnsConstMeta =>
+      const TaskConstMeta(debugName: 'default_workload_columns', argNames: []);
+
+  @override
+  Future<Form1Diff> crateApiDistributionInterfaceDiffForm1Files({
+    required ParsedExcelFile oldFile,
+    required ParsedExcelFile newFile,
+    required String sheetName,
+    DistributionProject? project,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_parsed_excel_file(oldFile, serializer);
+          sse_encode_box_autoadd_parsed_excel_file(newFile, serializer);
+          sse_encode_String(sheetName, serializer);
+          sse_encode_opt_box_autoadd_distribution_project(project, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_form_1_diff,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceDiffForm1FilesConstMeta,
+        argValues: [oldFile, newFile, sheetName, project],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceDiffForm1FilesConstMeta =>
+      const TaskConstMeta(
+        debugName: 'diff_form1_files',
+        argNames: ['oldFile', 'newFile', 'sheetName', 'project'],
+      );
+
+  @override
+  Future<SolverResult> crateApiDistributionInterfaceDistributeWorkload({
+    required DistributionProject project,
+    required SolverOptions options,
+    AcademicCalendar? calendar,
+    required double supervisionHoursPerPostgraduate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_box_autoadd_solver_options(options, serializer);
+          sse_encode_opt_box_autoadd_academic_calendar(calendar, serializer);
+          sse_encode_f_64(supervisionHoursPerPostgraduate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_solver_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceDistributeWorkloadConstMeta,
+        argValues: [
+          project,
+          options,
+          calendar,
+          supervisionHoursPerPostgraduate,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceDistributeWorkloadConstMeta =>
+      const TaskConstMeta(
+        debugName: 'distribute_workload',
+        argNames: [
+          'project',
+          'options',
+          'calendar',
+          'supervisionHoursPerPostgraduate',
+        ],
+      );
+
+  @override
+  Future<UniversityForm1> crateApiDistributionInterfaceForm1FromParsedFile({
+    required ParsedExcelFile file,
+    required String sheetName,
+    required int academicYear,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_parsed_excel_file(file, serializer);
+          sse_encode_String(sheetName, serializer);
+          sse_encode_u_32(academicYear, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_university_form_1,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceForm1FromParsedFileConstMeta,
+        argValues: [file, sheetName, academicYear],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceForm1FromParsedFileConstMeta =>
+      const TaskConstMeta(
+        debugName: 'form1_from_parsed_file',
+        argNames: ['file', 'sheetName', 'academicYear'],
+      );
+
+  @override
+  Future<List<EmployeeNorms>> crateApiDistributionInterfaceGetEmployeeNorms({
+    required DistributionProject project,
+    AcademicCalendar? calendar,
+    required double supervisionHoursPerPostgraduate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_opt_box_autoadd_academic_calendar(calendar, serializer);
+          sse_encode_f_64(supervisionHoursPerPostgraduate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_employee_norms,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceGetEmployeeNormsConstMeta,
+        argValues: [project, calendar, supervisionHoursPerPostgraduate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceGetEmployeeNormsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_employee_norms',
+        argNames: ['project', 'calendar', 'supervisionHoursPerPostgraduate'],
+      );
+
+  @override
+  Future<List<EmployeeWorkloadSummary>>
+  crateApiDistributionInterfaceGetEmployeeSummaries({
+    required DistributionProject project,
+    AcademicCalendar? calendar,
+    required double supervisionHoursPerPostgraduate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_opt_box_autoadd_academic_calendar(calendar, serializer);
+          sse_encode_f_64(supervisionHoursPerPostgraduate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_employee_workload_summary,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceGetEmployeeSummariesConstMeta,
+        argValues: [project, calendar, supervisionHoursPerPostgraduate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceGetEmployeeSummariesConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_employee_summaries',
+        argNames: ['project', 'calendar', 'supervisionHoursPerPostgraduate'],
+      );
+
+  @override
+  Future<double> crateApiDistributionInterfaceGetUndistributedWorkload({
+    required DistributionProject project,
+    required DisciplineKey key,
+    required WorkloadField field,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_box_autoadd_discipline_key(key, serializer);
+          sse_encode_workload_field(field, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceGetUndistributedWorkloadConstMeta,
+        argValues: [project, key, field],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceGetUndistributedWorkloadConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_undistributed_workload',
+        argNames: ['project', 'key', 'field'],
+      );
+
+  @override
+  Future<void> crateApiExcelInterfaceInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiExcelInterfaceInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceInitAppConstMeta =>
+      const TaskConstMeta(debugName: 'init_app', argNames: []);
+
+  @override
+  Future<List<UndistributedWorkload>>
+  crateApiDistributionInterfaceListUndistributedWorkload({
+    required DistributionProject project,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_undistributed_workload,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDistributionInterfaceListUndistributedWorkloadConstMeta,
+        argValues: [project],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceListUndistributedWorkloadConstMeta =>
+      const TaskConstMeta(
+        debugName: 'list_undistributed_workload',
+        argNames: ['project'],
+      );
+
+  @override
+  Future<ParsedExcelFile> crateApiExcelInterfaceParseExcelFile({
+    required String filePath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_parsed_excel_file,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiExcelInterfaceParseExcelFileConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceParseExcelFileConstMeta =>
+      const TaskConstMeta(
+        debugName: 'parse_excel_file',
+        argNames: ['filePath'],
+      );
+
+  @override
+  Future<DistributionProject>
+  crateApiDistributionInterfaceReadDistributionProject({
+    required String filePath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_distribution_project,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceReadDistributionProjectConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceReadDistributionProjectConstMeta =>
+      const TaskConstMeta(
+        debugName: 'read_distribution_project',
+        argNames: ['filePath'],
+      );
+
+  @override
+  Future<ExportedData> crateApiExcelInterfaceReadJsonFile({
+    required String filePath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_exported_data,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiExcelInterfaceReadJsonFileConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceReadJsonFileConstMeta =>
+      const TaskConstMeta(debugName: 'read_json_file', argNames: ['filePath']);
+
+  @override
+  Future<ReconcileResult> crateApiDistributionInterfaceReconcileForm1File({
+    required DistributionProject project,
+    required ParsedExcelFile file,
+    required String sheetName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_box_autoadd_parsed_excel_file(file, serializer);
+          sse_encode_String(sheetName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reconcile_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceReconcileForm1FileConstMeta,
+        argValues: [project, file, sheetName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceReconcileForm1FileConstMeta =>
+      const TaskConstMeta(
+        debugName: 'reconcile_form1_file',
+        argNames: ['project', 'file', 'sheetName'],
+      );
+
+  @override
+  Future<DistributionProject> crateApiDistributionInterfaceRemoveWorkloadItem({
+    required DistributionProject project,
+    required String employeeId,
+    required String rateId,
+    required String itemId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_String(employeeId, serializer);
+          sse_encode_String(rateId, serializer);
+          sse_encode_String(itemId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_distribution_project,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceRemoveWorkloadItemConstMeta,
+        argValues: [project, employeeId, rateId, itemId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceRemoveWorkloadItemConstMeta =>
+      const TaskConstMeta(
+        debugName: 'remove_workload_item',
+        argNames: ['project', 'employeeId', 'rateId', 'itemId'],
+      );
+
+  @override
+  Future<DistributionProject> crateApiDistributionInterfaceUpdateWorkloadField({
+    required DistributionProject project,
+    required String employeeId,
+    required String rateId,
+    required String itemId,
+    required WorkloadField field,
+    required double value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          sse_encode_String(employeeId, serializer);
+          sse_encode_String(rateId, serializer);
+          sse_encode_String(itemId, serializer);
+          sse_encode_workload_field(field, serializer);
+          sse_encode_f_64(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_distribution_project,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceUpdateWorkloadFieldConstMeta,
+        argValues: [project, employeeId, rateId, itemId, field, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceUpdateWorkloadFieldConstMeta =>
+      const TaskConstMeta(
+        debugName: 'update_workload_field',
+        argNames: [
+          'project',
+          'employeeId',
+          'rateId',
+          'itemId',
+          'field',
+          'value',
+        ],
+      );
+
+  @override
+  Future<List<DistributionIssue>>
+  crateApiDistributionInterfaceValidateDistribution({
+    required DistributionProject project,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_distribution_issue,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDistributionInterfaceValidateDistributionConstMeta,
+        argValues: [project],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceValidateDistributionConstMeta =>
+      const TaskConstMeta(
+        debugName: 'validate_distribution',
+        argNames: ['project'],
+      );
+
+  @override
+  Future<List<TotalsMismatch>> crateApiExcelInterfaceValidateExportedData({
+    required ExportedData exportedTables,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_exported_data(exportedTables, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_totals_mismatch,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiExcelInterfaceValidateExportedDataConstMeta,
+        argValues: [exportedTables],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceValidateExportedDataConstMeta =>
+      const TaskConstMeta(
+        debugName: 'validate_exported_data',
+        argNames: ['exportedTables'],
+      );
+
+  @override
+  Future<void> crateApiExcelInterfaceWriteCsvFile({
+    required String filePath,
+    required ExportedData exportedTables,
+    required ExportOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_box_autoadd_exported_data(exportedTables, serializer);
+          sse_encode_box_autoadd_export_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiExcelInterfaceWriteCsvFileConstMeta,
+        argValues: [filePath, exportedTables, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceWriteCsvFileConstMeta =>
+      const TaskConstMeta(
+        debugName: 'write_csv_file',
+        argNames: ['filePath', 'exportedTables', 'options'],
+      );
+
+  @override
+  Future<void> crateApiDistributionInterfaceWriteDistributionProject({
+    required String filePath,
+    required DistributionProject project,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_box_autoadd_distribution_project(project, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDistributionInterfaceWriteDistributionProjectConstMeta,
+        argValues: [filePath, project],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDistributionInterfaceWriteDistributionProjectConstMeta =>
+      const TaskConstMeta(
+        debugName: 'write_distribution_project',
+        argNames: ['filePath', 'project'],
+      );
+
+  @override
+  Future<List<PersonalSheetName>> crateApiExcelInterfaceWriteExcelFile({
+    required String filePath,
+    required ExportedData exportedTables,
+    required ExportOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_box_autoadd_exported_data(exportedTables, serializer);
+          sse_encode_box_autoadd_export_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_personal_sheet_name,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiExcelInterfaceWriteExcelFileConstMeta,
+        argValues: [filePath, exportedTables, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceWriteExcelFileConstMeta =>
+      const TaskConstMeta(
+        debugName: 'write_excel_file',
+        argNames: ['filePath', 'exportedTables', 'options'],
+      );
+
+  @override
+  Future<void> crateApiExcelInterfaceWriteHtmlFile({
+    required String filePath,
+    required ExportedData exportedTables,
+    required ExportOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_box_autoadd_exported_data(exportedTables, serializer);
+          sse_encode_box_autoadd_export_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiExcelInterfaceWriteHtmlFileConstMeta,
+        argValues: [filePath, exportedTables, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceWriteHtmlFileConstMeta =>
+      const TaskConstMeta(
+        debugName: 'write_html_file',
+        argNames: ['filePath', 'exportedTables', 'options'],
+      );
+
+  @override
+  Future<void> crateApiExcelInterfaceWriteJsonFile({
+    required String filePath,
+    required ExportedData exportedTables,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_box_autoadd_exported_data(exportedTables, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiExcelInterfaceWriteJsonFileConstMeta,
+        argValues: [filePath, exportedTables],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceWriteJsonFileConstMeta =>
+      const TaskConstMeta(
+        debugName: 'write_json_file',
+        argNames: ['filePath', 'exportedTables'],
+      );
+
+  @override
+  Future<void> crateApiExcelInterfaceWritePdfFile({
+    required String filePath,
+    required ExportedData exportedTables,
+    required ExportOptions options,
+    required PdfFonts fonts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_box_autoadd_exported_data(exportedTables, serializer);
+          sse_encode_box_autoadd_export_options(options, serializer);
+          sse_encode_box_autoadd_pdf_fonts(fonts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiExcelInterfaceWritePdfFileConstMeta,
+        argValues: [filePath, exportedTables, options, fonts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceWritePdfFileConstMeta =>
+      const TaskConstMeta(
+        debugName: 'write_pdf_file',
+        argNames: ['filePath', 'exportedTables', 'options', 'fonts'],
+      );
+
+  @override
+  Future<List<String>> crateApiExcelInterfaceWritePersonalFiles({
+    required String directory,
+    required ExportedData exportedTables,
+    required ExportOptions options,
+    PdfFonts? pdfFonts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(directory, serializer);
+          sse_encode_box_autoadd_exported_data(exportedTables, serializer);
+          sse_encode_box_autoadd_export_options(options, serializer);
+          sse_encode_opt_box_autoadd_pdf_fonts(pdfFonts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiExcelInterfaceWritePersonalFilesConstMeta,
+        argValues: [directory, exportedTables, options, pdfFonts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExcelInterfaceWritePersonalFilesConstMeta =>
+      const TaskConstMeta(
+        debugName: 'write_personal_files',
+        argNames: ['directory', 'exportedTables', 'options', 'pdfFonts'],
+      );
+
+  @protected
+  Map<String, List<InputRawRow>> dco_decode_Map_String_list_input_raw_row_None(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_list_input_raw_row(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AcademicCalendar dco_decode_academic_calendar(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AcademicCalendar(
+      firstSemesterStart: dco_decode_String(arr[0]),
+      firstSemesterEnd: dco_decode_String(arr[1]),
+      secondSemesterStart: dco_decode_String(arr[2]),
+      secondSemesterEnd: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  AcademicSemester dco_decode_academic_semester(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AcademicSemester.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AcademicCalendar dco_decode_box_autoadd_academic_calendar(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_academic_calendar(raw);
+  }
+
+  @protected
+  DisciplineKey dco_decode_box_autoadd_discipline_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_discipline_key(raw);
+  }
+
+  @protected
+  DistributionProject dco_decode_box_autoadd_distribution_project(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_distribution_project(raw);
+  }
+
+  @protected
+  ExportOptions dco_decode_box_autoadd_export_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_export_options(raw);
+  }
+
+  @protected
+  ExportTheme dco_decode_box_autoadd_export_theme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_export_theme(raw);
+  }
+
+  @protected
+  ExportedData dco_decode_box_autoadd_exported_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_exported_data(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  NormsTable dco_decode_box_autoadd_norms_table(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_norms_table(raw);
+  }
+
+  @protected
+  ParsedExcelFile dco_decode_box_autoadd_parsed_excel_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_parsed_excel_file(raw);
+  }
+
+  @protected
+  PdfFonts dco_decode_box_autoadd_pdf_fonts(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pdf_fonts(raw);
+  }
+
+  @protected
+  SolverOptions dco_decode_box_autoadd_solver_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_solver_options(raw);
+  }
+
+  @protected
+  UniversityForm1 dco_decode_box_autoadd_university_form_1(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_university_form_1(raw);
+  }
+
+  @protected
+  UniversityForm3WorkloadItem
+  dco_decode_box_autoadd_university_form_3_workload_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_university_form_3_workload_item(raw);
+  }
+
+  @protected
+  WorkloadField dco_decode_box_autoadd_workload_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_workload_field(raw);
+  }
+
+  @protected
+  WorkloadInput dco_decode_box_autoadd_workload_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_workload_input(raw);
+  }
+
+  @protected
+  CarriedOverItem dco_decode_carried_over_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CarriedOverItem(
+      key: dco_decode_discipline_key(arr[0]),
+      previousKey: dco_decode_discipline_key(arr[1]),
+      similarity: dco_decode_f_64(arr[2]),
+      changedFields: dco_decode_list_workload_field(arr[3]),
+    );
+  }
+
+  @protected
+  CarryOverResult dco_decode_carry_over_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CarryOverResult(
+      project: dco_decode_distribution_project(arr[0]),
+      newItems: dco_decode_list_discipline_key(arr[1]),
+      removedItems: dco_decode_list_discipline_key(arr[2]),
+      changedItems: dco_decode_list_carried_over_item(arr[3]),
+    );
+  }
+
+  @protected
+  DisciplineKey dco_decode_discipline_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DisciplineKey(
+      learningForm: dco_decode_learning_form(arr[0]),
+      specialty: dco_decode_String(arr[1]),
+      disciplineName: dco_decode_String(arr[2]),
+      course: dco_decode_String(arr[3]),
+      semester: dco_decode_academic_semester(arr[4]),
+    );
+  }
+
+  @protected
+  DistributionIssue dco_decode_distribution_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DistributionIssue(
+      employeeId: dco_decode_opt_String(arr[0]),
+      itemId: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  DistributionProject dco_decode_distribution_project(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DistributionProject(
+      id: dco_decode_i_64(arr[0]),
+      title: dco_decode_String(arr[1]),
+      universityForm1: dco_decode_university_form_1(arr[2]),
+      universityForm3: dco_decode_university_form_3(arr[3]),
+      createdAt: dco_decode_String(arr[4]),
+      updatedAt: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  Employee dco_decode_employee(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return Employee(
+      id: dco_decode_String(arr[0]),
+      firstName: dco_decode_String(arr[1]),
+      lastName: dco_decode_String(arr[2]),
+      patronymic: dco_decode_String(arr[3]),
+      rank: dco_decode_employee_rank(arr[4]),
+      rates: dco_decode_list_employee_rate(arr[5]),
+    );
+  }
+
+  @protected
+  EmployeeNorms dco_decode_employee_norms(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return EmployeeNorms(
+      employeeId: dco_decode_String(arr[0]),
+      fullName: dco_decode_String(arr[1]),
+      peakRate: dco_decode_f_64(arr[2]),
+      rates: dco_decode_list_rate_norms(arr[3]),
+      firstSemester: dco_decode_period_norm(arr[4]),
+      secondSemester: dco_decode_period_norm(arr[5]),
+      year: dco_decode_period_norm(arr[6]),
+    );
+  }
+
+  @protected
+  EmployeePreferences dco_decode_employee_preferences(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EmployeePreferences(
+      employeeId: dco_decode_String(arr[0]),
+      disciplines: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  EmployeeRank dco_decode_employee_rank(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EmployeeRank.values[raw as int];
+  }
+
+  @protected
+  EmployeeRate dco_decode_employee_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return EmployeeRate(
+      id: dco_decode_String(arr[0]),
+      rateValue: dco_decode_f_64(arr[1]),
+      dateStart: dco_decode_String(arr[2]),
+      dateEnd: dco_decode_String(arr[3]),
+      postgraduateCount: dco_decode_u_32(arr[4]),
+      workloadItems: dco_decode_list_university_form_3_workload_item(arr[5]),
+    );
+  }
+
+  @protected
+  EmployeeWorkloadInput dco_decode_employee_workload_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return EmployeeWorkloadInput(
+      id: dco_decode_u_32(arr[0]),
+      firstName: dco_decode_String(arr[1]),
+      middleName: dco_decode_String(arr[2]),
+      lastName: dco_decode_String(arr[3]),
+      rank: dco_decode_String(arr[4]),
+      commentSemester1: dco_decode_String(arr[5]),
+      commentSemester2: dco_decode_String(arr[6]),
+      semester1: dco_decode_list_output_personal_semester_row(arr[7]),
+      semester1Rate: dco_decode_f_64(arr[8]),
+      semester2: dco_decode_list_output_personal_semester_row(arr[9]),
+      semester2Rate: dco_decode_f_64(arr[10]),
+    );
+  }
+
+  @protected
+  EmployeeWorkloadSummary dco_decode_employee_workload_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return EmployeeWorkloadSummary(
+      employeeId: dco_decode_String(arr[0]),
+      fullName: dco_decode_String(arr[1]),
+      hours: dco_decode_f_64(arr[2]),
+      minPossibleHours: dco_decode_f_64(arr[3]),
+      maxPossibleHours: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  ExportOptions dco_decode_export_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ExportOptions(
+      hideEmptyColumns: dco_decode_bool(arr[0]),
+      columns: dco_decode_opt_list_workload_column(arr[1]),
+      theme: dco_decode_opt_box_autoadd_export_theme(arr[2]),
+      highlightWorkloadNorms: dco_decode_bool(arr[3]),
+      includeAnalytics: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  ExportTheme dco_decode_export_theme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ExportTheme(
+      fontName: dco_decode_String(arr[0]),
+      fontSize: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  ExportedData dco_decode_exported_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ExportedData(
+      year: dco_decode_u_32(arr[0]),
+      typeName: dco_decode_String(arr[1]),
+      mainTable: dco_decode_output_main_table(arr[2]),
+      personalTables: dco_decode_list_output_personal_tables(arr[3]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FieldChange dco_decode_field_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FieldChange(
+      field: dco_decode_workload_field(arr[0]),
+      oldValue: dco_decode_f_64(arr[1]),
+      newValue: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  FieldRankRule dco_decode_field_rank_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FieldRankRule(
+      field: dco_decode_workload_field(arr[0]),
+      minRank: dco_decode_employee_rank(arr[1]),
+    );
+  }
+
+  @protected
+  Form1Diff dco_decode_form_1_diff(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Form1Diff(
+      added: dco_decode_list_form_1_diff_entry(arr[0]),
+      removed: dco_decode_list_form_1_diff_entry(arr[1]),
+      changed: dco_decode_list_form_1_diff_entry(arr[2]),
+    );
+  }
+
+  @protected
+  Form1DiffEntry dco_decode_form_1_diff_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Form1DiffEntry(
+      key: dco_decode_discipline_key(arr[0]),
+      changes: dco_decode_list_field_change(arr[1]),
+      affectedEmployees: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  Form1Finding dco_decode_form_1_finding(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Form1Finding(
+      row: dco_decode_u_32(arr[0]),
+      kind: dco_decode_form_1_finding_kind(arr[1]),
+      key: dco_decode_opt_box_autoadd_discipline_key(arr[2]),
+      field: dco_decode_opt_box_autoadd_workload_field(arr[3]),
+      message: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  Form1FindingKind dco_decode_form_1_finding_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Form1FindingKind.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  InputRawRow dco_decode_input_raw_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 30)
+      throw Exception('unexpected arr length: expect 30 but see ${arr.length}');
+    return InputRawRow(
+      learningForm: dco_decode_String(arr[0]),
+      speciality: dco_decode_String(arr[1]),
+      name: dco_decode_String(arr[2]),
+      course: dco_decode_String(arr[3]),
+      semester: dco_decode_String(arr[4]),
+      weeksCount: dco_decode_String(arr[5]),
+      studentsCount: dco_decode_String(arr[6]),
+      flowsCount: dco_decode_String(arr[7]),
+      groupsCount: dco_decode_String(arr[8]),
+      subgroupsCount: dco_decode_String(arr[9]),
+      lecturesPlannedCount: dco_decode_String(arr[10]),
+      lecturesTotalCount: dco_decode_String(arr[11]),
+      practicesPlannedCount: dco_decode_String(arr[12]),
+      practicesTotalCount: dco_decode_String(arr[13]),
+      labsPlannedCount: dco_decode_String(arr[14]),
+      labsTotalCount: dco_decode_String(arr[15]),
+      exams: dco_decode_String(arr[16]),
+      examConsults: dco_decode_String(arr[17]),
+      tests: dco_decode_String(arr[18]),
+      qualWorks: dco_decode_String(arr[19]),
+      certificationExams: dco_decode_String(arr[20]),
+      workingPractice: dco_decode_String(arr[21]),
+      teachingPractice: dco_decode_String(arr[22]),
+      consults: dco_decode_String(arr[23]),
+      individualWorks: dco_decode_String(arr[24]),
+      individualWorkTypes: dco_decode_String(arr[25]),
+      courseWorks: dco_decode_String(arr[26]),
+      postgraduateExams: dco_decode_String(arr[27]),
+      supervising: dco_decode_String(arr[28]),
+      internship: dco_decode_String(arr[29]),
+    );
+  }
+
+  @protected
+  LearningForm dco_decode_learning_form(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LearningForm.values[raw as int];
+  }
+
+  @protected
+  LearningFormTotal dco_decode_learning_form_total(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LearningFormTotal(
+      learningForm: dco_decode_String(arr[0]),
+      hours: dco_decode_output_hours_row(arr[1]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<CarriedOverItem> dco_decode_list_carried_over_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_carried_over_item).toList();
+  }
+
+  @protected
+  List<DisciplineKey> dco_decode_list_discipline_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_discipline_key).toList();
+  }
+
+  @protected
+  List<DistributionIssue> dco_decode_list_distribution_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_distribution_issue).toList();
+  }
+
+  @protected
+  List<Employee> dco_decode_list_employee(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_employee).toList();
+  }
+
+  @protected
+  List<EmployeeNorms> dco_decode_list_employee_norms(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_employee_norms).toList();
+  }
+
+  @protected
+  List<EmployeePreferences> dco_decode_list_employee_preferences(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_employee_preferences).toList();
+  }
+
+  @protected
+  List<EmployeeRate> dco_decode_list_employee_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_employee_rate).toList();
+  }
+
+  @protected
+  List<EmployeeWorkloadInput> dco_decode_list_employee_workload_input(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_employee_workload_input)
+        .toList();
+  }
+
+  @protected
+  List<EmployeeWorkloadSummary> dco_decode_list_employee_workload_summary(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_employee_workload_summary)
+        .toList();
+  }
+
+  @protected
+  List<FieldChange> dco_decode_list_field_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_change).toList();
+  }
+
+  @protected
+  List<FieldRankRule> dco_decode_list_field_rank_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_rank_rule).toList();
+  }
+
+  @protected
+  List<Form1DiffEntry> dco_decode_list_form_1_diff_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_form_1_diff_entry).toList();
+  }
+
+  @protected
+  List<Form1Finding> dco_decode_list_form_1_finding(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_form_1_finding).toList();
+  }
+
+  @protected
+  List<InputRawRow> dco_decode_list_input_raw_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_input_raw_row).toList();
+  }
+
+  @protected
+  List<LearningFormTotal> dco_decode_list_learning_form_total(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_learning_form_total).toList();
+  }
+
+  @protected
+  List<MainTableSection> dco_decode_list_main_table_section(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_main_table_section).toList();
+  }
+
+  @protected
+  List<NormFinding> dco_decode_list_norm_finding(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_norm_finding).toList();
+  }
+
+  @protected
+  List<NormRule> dco_decode_list_norm_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_norm_rule).toList();
+  }
+
+  @protected
+  List<OutputMainWorkerRow> dco_decode_list_output_main_worker_row(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_output_main_worker_row)
+        .toList();
+  }
+
+  @protected
+  List<OutputPersonalSemesterRow> dco_decode_list_output_personal_semester_row(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_output_personal_semester_row)
+        .toList();
+  }
+
+  @protected
+  List<OutputPersonalTable> dco_decode_list_output_personal_table(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_output_personal_table)
+        .toList();
+  }
+
+  @protected
+  List<OutputPersonalTables> dco_decode_list_output_personal_tables(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_output_personal_tables)
+        .toList();
+  }
+
+  @protected
+  List<PersonalSheetName> dco_decode_list_personal_sheet_name(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_personal_sheet_name).toList();
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<RateNorms> dco_decode_list_rate_norms(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rate_norms).toList();
+  }
+
+  @protected
+  List<ReconcileConflict> dco_decode_list_reconcile_conflict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_reconcile_conflict).toList();
+  }
+
+  @protected
+  List<(String, List<InputRawRow>)>
+  dco_decode_list_record_string_list_input_raw_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_list_input_raw_row)
+        .toList();
+  }
+
+  @protected
+  List<TotalsMismatch> dco_decode_list_totals_mismatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_totals_mismatch).toList();
+  }
+
+  @protected
+  List<UndistributedWorkload> dco_decode_list_undistributed_workload(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_undistributed_workload)
+        .toList();
+  }
+
+  @protected
+  List<UniversityForm1WorkloadItem>
+  dco_decode_list_university_form_1_workload_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_university_form_1_workload_item)
+        .toList();
+  }
+
+  @protected
+  List<UniversityForm3WorkloadItem>
+  dco_decode_list_university_form_3_workload_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_university_form_3_workload_item)
+        .toList();
+  }
+
+  @protected
+  List<WorkloadColumn> dco_decode_list_workload_column(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_workload_column).toList();
+  }
+
+  @protected
+  List<WorkloadField> dco_decode_list_workload_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_workload_field).toList();
+  }
+
+  @protected
+  List<WorkloadSectionInput> dco_decode_list_workload_section_input(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_workload_section_input)
+        .toList();
+  }
+
+  @protected
+  MainTableSection dco_decode_main_table_section(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MainTableSection(
+      title: dco_decode_String(arr[0]),
+      rows: dco_decode_list_output_main_worker_row(arr[1]),
+      total: dco_decode_output_main_rate_and_hours(arr[2]),
+    );
+  }
+
+  @protected
+  NormBasis dco_decode_norm_basis(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NormBasis.values[raw as int];
+  }
+
+  @protected
+  NormFinding dco_decode_norm_finding(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NormFinding(
+      row: dco_decode_u_32(arr[0]),
+      key: dco_decode_discipline_key(arr[1]),
+      field: dco_decode_workload_field(arr[2]),
+      fileValue: dco_decode_f_64(arr[3]),
+      normValue: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  NormRule dco_decode_norm_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return NormRule(
+      field: dco_decode_workload_field(arr[0]),
+      basis: dco_decode_norm_basis(arr[1]),
+      hoursPerUnit: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  NormsTable dco_decode_norms_table(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NormsTable(
+      rules: dco_decode_list_norm_rule(arr[0]),
+      tolerance: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AcademicCalendar? dco_decode_opt_box_autoadd_academic_calendar(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_academic_calendar(raw);
+  }
+
+  @protected
+  DisciplineKey? dco_decode_opt_box_autoadd_discipline_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_discipline_key(raw);
+  }
+
+  @protected
+  DistributionProject? dco_decode_opt_box_autoadd_distribution_project(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_distribution_project(raw);
+  }
+
+  @protected
+  ExportTheme? dco_decode_opt_box_autoadd_export_theme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_export_theme(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  NormsTable? dco_decode_opt_box_autoadd_norms_table(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_norms_table(raw);
+  }
+
+  @protected
+  PdfFonts? dco_decode_opt_box_autoadd_pdf_fonts(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pdf_fonts(raw);
+  }
+
+  @protected
+  WorkloadField? dco_decode_opt_box_autoadd_workload_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_workload_field(raw);
+  }
+
+  @protected
+  List<WorkloadColumn>? dco_decode_opt_list_workload_column(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_workload_column(raw);
+  }
+
+  @protected
+  OutputHoursRow dco_decode_output_hours_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return OutputHoursRow(
+      lectures: dco_decode_f_64(arr[0]),
+      practices: dco_decode_f_64(arr[1]),
+      labs: dco_decode_f_64(arr[2]),
+      exams: dco_decode_f_64(arr[3]),
+      examConsults: dco_decode_f_64(arr[4]),
+      tests: dco_decode_f_64(arr[5]),
+      qualWorks: dco_decode_f_64(arr[6]),
+      certificationExams: dco_decode_f_64(arr[7]),
+      workingPractice: dco_decode_f_64(arr[8]),
+      teachingPractice: dco_decode_f_64(arr[9]),
+      consults: dco_decode_f_64(arr[10]),
+      individualWorks: dco_decode_f_64(arr[11]),
+      courseWorks: dco_decode_f_64(arr[12]),
+      postgraduateExams: dco_decode_f_64(arr[13]),
+      supervising: dco_decode_f_64(arr[14]),
+      internship: dco_decode_f_64(arr[15]),
+      total: dco_decode_f_64(arr[16]),
+    );
+  }
+
+  @protected
+  OutputMainRateAndHours dco_decode_output_main_rate_and_hours(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return OutputMainRateAndHours(
+      rate: dco_decode_f_64(arr[0]),
+      semester1: dco_decode_output_hours_row(arr[1]),
+      semester2: dco_decode_output_hours_row(arr[2]),
+      year: dco_decode_output_hours_row(arr[3]),
+    );
+  }
+
+  @protected
+  OutputMainTable dco_decode_output_main_table(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OutputMainTable(
+      sections: dco_decode_list_main_table_section(arr[0]),
+      total: dco_decode_output_main_rate_and_hours(arr[1]),
+    );
+  }
+
+  @protected
+  OutputMainWorkerRow dco_decode_output_main_worker_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return OutputMainWorkerRow(
+      firstName: dco_decode_String(arr[0]),
+      middleName: dco_decode_String(arr[1]),
+      lastName: dco_decode_String(arr[2]),
+      rank: dco_decode_String(arr[3]),
+      rateAndHours: dco_decode_output_main_rate_and_hours(arr[4]),
+    );
+  }
+
+  @protected
+  OutputPersonalSemesterRow dco_decode_output_personal_semester_row(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return OutputPersonalSemesterRow(
+      name: dco_decode_String(arr[0]),
+      learningForm: dco_decode_String(arr[1]),
+      speciality: dco_decode_String(arr[2]),
+      group: dco_decode_String(arr[3]),
+      course: dco_decode_String(arr[4]),
+      studentsCount: dco_decode_u_32(arr[5]),
+      individualWorkTypes: dco_decode_String(arr[6]),
+      hours: dco_decode_output_hours_row(arr[7]),
+      mergeLecturesWithNext: dco_decode_bool(arr[8]),
+    );
+  }
+
+  @protected
+  OutputPersonalTable dco_decode_output_personal_table(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return OutputPersonalTable(
+      id: dco_decode_u_32(arr[0]),
+      firstName: dco_decode_String(arr[1]),
+      middleName: dco_decode_String(arr[2]),
+      lastName: dco_decode_String(arr[3]),
+      commentSemester1: dco_decode_String(arr[4]),
+      commentSemester2: dco_decode_String(arr[5]),
+      rank: dco_decode_String(arr[6]),
+      semester1: dco_decode_list_output_personal_semester_row(arr[7]),
+      semester1Rate: dco_decode_f_64(arr[8]),
+      semester1FormTotals: dco_decode_list_learning_form_total(arr[9]),
+      semester1Total: dco_decode_output_hours_row(arr[10]),
+      semester2: dco_decode_list_output_personal_semester_row(arr[11]),
+      semester2Rate: dco_decode_f_64(arr[12]),
+      semester2FormTotals: dco_decode_list_learning_form_total(arr[13]),
+      semester2Total: dco_decode_output_hours_row(arr[14]),
+      yearTotal: dco_decode_output_hours_row(arr[15]),
+    );
+  }
+
+  @protected
+  OutputPersonalTables dco_decode_output_personal_tables(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OutputPersonalTables(
+      workerLastName: dco_decode_String(arr[0]),
+      tables: dco_decode_list_output_personal_table(arr[1]),
+    );
+  }
+
+  @protected
+  ParsedExcelFile dco_decode_parsed_excel_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ParsedExcelFile(
+      data: dco_decode_Map_String_list_input_raw_row_None(arr[0]),
+    );
+  }
+
+  @protected
+  PdfFonts dco_decode_pdf_fonts(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PdfFonts(
+      regular: dco_decode_String(arr[0]),
+      bold: dco_decode_opt_String(arr[1]),
+      italic: dco_decode_opt_String(arr[2]),
+      boldItalic: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  PeriodNorm dco_decode_period_norm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PeriodNorm(
+      rateMonths: dco_decode_f_64(arr[0]),
+      supervisionHours: dco_decode_f_64(arr[1]),
+      minHours: dco_decode_f_64(arr[2]),
+      maxHours: dco_decode_f_64(arr[3]),
+      hours: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  PersonalSheetName dco_decode_personal_sheet_name(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PersonalSheetName(
+      workerLastName: dco_decode_String(arr[0]),
+      tableIds: dco_decode_list_prim_u_32_strict(arr[1]),
+      sheetName: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  RateNorms dco_decode_rate_norms(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RateNorms(
+      rateId: dco_decode_String(arr[0]),
+      rateValue: dco_decode_f_64(arr[1]),
+      months: dco_decode_f_64(arr[2]),
+      firstSemester: dco_decode_period_norm(arr[3]),
+      secondSemester: dco_decode_period_norm(arr[4]),
+      year: dco_decode_period_norm(arr[5]),
+    );
+  }
+
+  @protected
+  ReconcileConflict dco_decode_reconcile_conflict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ReconcileConflict(
+      kind: dco_decode_reconcile_conflict_kind(arr[0]),
+      key: dco_decode_discipline_key(arr[1]),
+      field: dco_decode_opt_box_autoadd_workload_field(arr[2]),
+      total: dco_decode_f_64(arr[3]),
+      distributed: dco_decode_f_64(arr[4]),
+      employees: dco_decode_list_String(arr[5]),
+      message: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  ReconcileConflictKind dco_decode_reconcile_conflict_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReconcileConflictKind.values[raw as int];
+  }
+
+  @protected
+  ReconcileResult dco_decode_reconcile_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReconcileResult(
+      project: dco_decode_distribution_project(arr[0]),
+      diff: dco_decode_form_1_diff(arr[1]),
+      conflicts: dco_decode_list_reconcile_conflict(arr[2]),
+    );
+  }
+
+  @protected
+  (String, List<InputRawRow>) dco_decode_record_string_list_input_raw_row(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_list_input_raw_row(arr[1]));
+  }
+
+  @protected
+  SolverOptions dco_decode_solver_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SolverOptions(
+      preferences: dco_decode_list_employee_preferences(arr[0]),
+      rankRules: dco_decode_list_field_rank_rule(arr[1]),
+    );
+  }
+
+  @protected
+  SolverResult dco_decode_solver_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SolverResult(
+      project: dco_decode_distribution_project(arr[0]),
+      unplaced: dco_decode_list_undistributed_workload(arr[1]),
+      splitItems: dco_decode_list_discipline_key(arr[2]),
+      employees: dco_decode_list_employee_workload_summary(arr[3]),
+    );
+  }
+
+  @protected
+  TotalsMismatch dco_decode_totals_mismatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TotalsMismatch(
+      location: dco_decode_String(arr[0]),
+      field: dco_decode_String(arr[1]),
+      expected: dco_decode_f_64(arr[2]),
+      actual: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  UndistributedWorkload dco_decode_undistributed_workload(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return UndistributedWorkload(
+      workloadKey: dco_decode_discipline_key(arr[0]),
+      field: dco_decode_workload_field(arr[1]),
+      total: dco_decode_f_64(arr[2]),
+      distributed: dco_decode_f_64(arr[3]),
+      undistributed: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UniversityForm1 dco_decode_university_form_1(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UniversityForm1(
+      id: dco_decode_String(arr[0]),
+      academicYear: dco_decode_u_32(arr[1]),
+      workloadItems: dco_decode_list_university_form_1_workload_item(arr[2]),
+    );
+  }
+
+  @protected
+  UniversityForm1WorkloadItem dco_decode_university_form_1_workload_item(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 24)
+      throw Exception('unexpected arr length: expect 24 but see ${arr.length}');
+    return UniversityForm1WorkloadItem(
+      id: dco_decode_String(arr[0]),
+      workloadKey: dco_decode_discipline_key(arr[1]),
+      weekCount: dco_decode_f_64(arr[2]),
+      studentCount: dco_decode_u_32(arr[3]),
+      flowCount: dco_decode_f_64(arr[4]),
+      groupCount: dco_decode_f_64(arr[5]),
+      subgroupCount: dco_decode_f_64(arr[6]),
+      lecturesPlanned: dco_decode_f_64(arr[7]),
+      lecturesTotal: dco_decode_f_64(arr[8]),
+      practicesPlanned: dco_decode_f_64(arr[9]),
+      practicesTotal: dco_decode_f_64(arr[10]),
+      labsPlanned: dco_decode_f_64(arr[11]),
+      labsTotal: dco_decode_f_64(arr[12]),
+      exams: dco_decode_f_64(arr[13]),
+      examConsults: dco_decode_f_64(arr[14]),
+      tests: dco_decode_f_64(arr[15]),
+      qualificationWorks: dco_decode_f_64(arr[16]),
+      certificationExams: dco_decode_f_64(arr[17]),
+      productionPractices: dco_decode_f_64(arr[18]),
+      teachingPractices: dco_decode_f_64(arr[19]),
+      currentConsults: dco_decode_f_64(arr[20]),
+      individualWorks: dco_decode_f_64(arr[21]),
+      courseWorks: dco_decode_f_64(arr[22]),
+      postgraduateExams: dco_decode_f_64(arr[23]),
+    );
+  }
+
+  @protected
+  UniversityForm3 dco_decode_university_form_3(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UniversityForm3(
+      id: dco_decode_String(arr[0]),
+      academicYear: dco_decode_u_32(arr[1]),
+      employees: dco_decode_list_employee(arr[2]),
+    );
+  }
+
+  @protected
+  UniversityForm3WorkloadItem dco_decode_university_form_3_workload_item(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 18)
+      throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+    return UniversityForm3WorkloadItem(
+      id: dco_decode_String(arr[0]),
+      workloadKey: dco_decode_discipline_key(arr[1]),
+      studentCount: dco_decode_u_32(arr[2]),
+      academicGroups: dco_decode_list_String(arr[3]),
+      lectures: dco_decode_f_64(arr[4]),
+      practices: dco_decode_f_64(arr[5]),
+      labs: dco_decode_f_64(arr[6]),
+      exams: dco_decode_f_64(arr[7]),
+      examConsults: dco_decode_f_64(arr[8]),
+      tests: dco_decode_f_64(arr[9]),
+      qualificationWorks: dco_decode_f_64(arr[10]),
+      certificationExams: dco_decode_f_64(arr[11]),
+      productionPractices: dco_decode_f_64(arr[12]),
+      teachingPractices: dco_decode_f_64(arr[13]),
+      currentConsults: dco_decode_f_64(arr[14]),
+      individualWorks: dco_decode_f_64(arr[15]),
+      courseWorks: dco_decode_f_64(arr[16]),
+      postgraduateExams: dco_decode_f_64(arr[17]),
+    );
+  }
+
+  @protected
+  WorkloadColumn dco_decode_workload_column(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WorkloadColumn(
+      key: dco_decode_workload_key(arr[0]),
+      caption: dco_decode_String(arr[1]),
+      width: dco_decode_f_64(arr[2]),
+      numberFormat: dco_decode_String(arr[3]),
+      includeInTotal: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  WorkloadField dco_decode_workload_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WorkloadField.values[raw as int];
+  }
+
+  @protected
+  WorkloadInput dco_decode_workload_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WorkloadInput(
+      year: dco_decode_u_32(arr[0]),
+      typeName: dco_decode_String(arr[1]),
+      sections: dco_decode_list_workload_section_input(arr[2]),
+    );
+  }
+
+  @protected
+  WorkloadKey dco_decode_workload_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WorkloadKey.values[raw as int];
+  }
+
+  @protected
+  WorkloadSectionInput dco_decode_workload_section_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WorkloadSectionInput(
+      title: dco_decode_String(arr[0]),
+      employees: dco_decode_list_employee_workload_input(arr[1]),
+    );
+  }
+
+  @protected
+  Map<String, List<InputRawRow>> sse_decode_Map_String_list_input_raw_row_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_list_input_raw_row(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AcademicCalendar sse_decode_academic_calendar(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_firstSemesterStart = sse_decode_String(deserializer);
+    var var_firstSemesterEnd = sse_decode_String(deserializer);
+    var var_secondSemesterStart = sse_decode_String(deserializer);
+    var var_secondSemesterEnd = sse_decode_String(deserializer);
+    return AcademicCalendar(
+      firstSemesterStart: var_firstSemesterStart,
+      firstSemesterEnd: var_firstSemesterEnd,
+      secondSemesterStart: var_secondSemesterStart,
+      secondSemesterEnd: var_secondSemesterEnd,
+    );
+  }
+
+  @protected
+  AcademicSemester sse_decode_academic_semester(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AcademicSemester.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AcademicCalendar sse_decode_box_autoadd_academic_calendar(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_academic_calendar(deserializer));
+  }
+
+  @protected
+  DisciplineKey sse_decode_box_autoadd_discipline_key(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_discipline_key(deserializer));
+  }
+
+  @protected
+  DistributionProject sse_decode_box_autoadd_distribution_project(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_distribution_project(deserializer));
+  }
+
+  @protected
+  ExportOptions sse_decode_box_autoadd_export_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_export_options(deserializer));
+  }
+
+  @protected
+  ExportTheme sse_decode_box_autoadd_export_theme(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_export_theme(deserializer));
+  }
+
+  @protected
+  ExportedData sse_decode_box_autoadd_exported_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_exported_data(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  NormsTable sse_decode_box_autoadd_norms_table(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_norms_table(deserializer));
+  }
+
+  @protected
+  ParsedExcelFile sse_decode_box_autoadd_parsed_excel_file(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_parsed_excel_file(deserializer));
+  }
+
+  @protected
+  PdfFonts sse_decode_box_autoadd_pdf_fonts(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pdf_fonts(deserializer));
+  }
+
+  @protected
+  SolverOptions sse_decode_box_autoadd_solver_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_solver_options(deserializer));
+  }
+
+  @protected
+  UniversityForm1 sse_decode_box_autoadd_university_form_1(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_university_form_1(deserializer));
+  }
+
+  @protected
+  UniversityForm3WorkloadItem
+  sse_decode_box_autoadd_university_form_3_workload_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_university_form_3_workload_item(deserializer));
+  }
+
+  @protected
+  WorkloadField sse_decode_box_autoadd_workload_field(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_workload_field(deserializer));
+  }
+
+  @protected
+  WorkloadInput sse_decode_box_autoadd_workload_input(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_workload_input(deserializer));
+  }
+
+  @protected
+  CarriedOverItem sse_decode_carried_over_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_discipline_key(deserializer);
+    var var_previousKey = sse_decode_discipline_key(deserializer);
+    var var_similarity = sse_decode_f_64(deserializer);
+    var var_changedFields = sse_decode_list_workload_field(deserializer);
+    return CarriedOverItem(
+      key: var_key,
+      previousKey: var_previousKey,
+      similarity: var_similarity,
+      changedFields: var_changedFields,
+    );
+  }
+
+  @protected
+  CarryOverResult sse_decode_carry_over_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_project = sse_decode_distribution_project(deserializer);
+    var var_newItems = sse_decode_list_discipline_key(deserializer);
+    var var_removedItems = sse_decode_list_discipline_key(deserializer);
+    var var_changedItems = sse_decode_list_carried_over_item(deserializer);
+    return CarryOverResult(
+      project: var_project,
+      newItems: var_newItems,
+      removedItems: var_removedItems,
+      changedItems: var_changedItems,
+    );
+  }
+
+  @protected
+  DisciplineKey sse_decode_discipline_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_learningForm = sse_decode_learning_form(deserializer);
+    var var_specialty = sse_decode_String(deserializer);
+    var var_disciplineName = sse_decode_String(deserializer);
+    var var_course = sse_decode_String(deserializer);
+    var var_semester = sse_decode_academic_semester(deserializer);
+    return DisciplineKey(
+      learningForm: var_learningForm,
+      specialty: var_specialty,
+      disciplineName: var_disciplineName,
+      course: var_course,
+      semester: var_semester,
+    );
+  }
+
+  @protected
+  DistributionIssue sse_decode_distribution_issue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_employeeId = sse_decode_opt_String(deserializer);
+    var var_itemId = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return DistributionIssue(
+      employeeId: var_employeeId,
+      itemId: var_itemId,
+      message: var_message,
+    );
+  }
+
+  @protected
+  DistributionProject sse_decode_distribution_project(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_universityForm1 = sse_decode_university_form_1(deserializer);
+    var var_universityForm3 = sse_decode_university_form_3(deserializer);
+    var var_createdAt = sse_decode_String(deserializer);
+    var var_updatedAt = sse_decode_String(deserializer);
+    return DistributionProject(
+      id: var_id,
+      title: var_title,
+      universityForm1: var_universityForm1,
+      universityForm3: var_universityForm3,
+      createdAt: var_createdAt,
+      updatedAt: var_updatedAt,
+    );
+  }
+
+  @protected
+  Employee sse_decode_employee(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_firstName = sse_decode_String(deserializer);
+    var var_lastName = sse_decode_String(deserializer);
+    var var_patronymic = sse_decode_String(deserializer);
+    var var_rank = sse_decode_employee_rank(deserializer);
+    var var_rates = sse_decode_list_employee_rate(deserializer);
+    return Employee(
+      id: var_id,
+      firstName: var_firstName,
+      lastName: var_lastName,
+      patronymic: var_patronymic,
+      rank: var_rank,
+      rates: var_rates,
+    );
+  }
+
+  @protected
+  EmployeeNorms sse_decode_employee_norms(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_employeeId = sse_decode_String(deserializer);
+    var var_fullName = sse_decode_String(deserializer);
+    var var_peakRate = sse_decode_f_64(deserializer);
+    var var_rates = sse_decode_list_rate_norms(deserializer);
+    var var_firstSemester = sse_decode_period_norm(deserializer);
+    var var_secondSemester = sse_decode_period_norm(deserializer);
+    var var_year = sse_decode_period_norm(deserializer);
+    return EmployeeNorms(
+      employeeId: var_employeeId,
+      fullName: var_fullName,
+      peakRate: var_peakRate,
+      rates: var_rates,
+      firstSemester: var_firstSemester,
+      secondSemester: var_secondSemester,
+      year: var_year,
+    );
+  }
+
+  @protected
+  EmployeePreferences sse_decode_employee_preferences(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_employeeId = sse_decode_String(deserializer);
+    var var_disciplines = sse_decode_list_String(deserializer);
+    return EmployeePreferences(
+      employeeId: var_employeeId,
+      disciplines: var_disciplines,
+    );
+  }
+
+  @protected
+  EmployeeRank sse_decode_employee_rank(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EmployeeRank.values[inner];
+  }
+
+  @protected
+  EmployeeRate sse_decode_employee_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_rateValue = sse_decode_f_64(deserializer);
+    var var_dateStart = sse_decode_String(deserializer);
+    var var_dateEnd = sse_decode_String(deserializer);
+    var var_postgraduateCount = sse_decode_u_32(deserializer);
+    var var_workloadItems = sse_decode_list_university_form_3_workload_item(
+      deserializer,
+    );
+    return EmployeeRate(
+      id: var_id,
+      rateValue: var_rateValue,
+      dateStart: var_dateStart,
+      dateEnd: var_dateEnd,
+      postgraduateCount: var_postgraduateCount,
+      workloadItems: var_workloadItems,
+    );
+  }
+
+  @protected
+  EmployeeWorkloadInput sse_decode_employee_workload_input(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_firstName = sse_decode_String(deserializer);
+    var var_middleName = sse_decode_String(deserializer);
+    var var_lastName = sse_decode_String(deserializer);
+    var var_rank = sse_decode_String(deserializer);
+    var var_commentSemester1 = sse_decode_String(deserializer);
+    var var_commentSemester2 = sse_decode_String(deserializer);
+    var var_semester1 = sse_decode_list_output_personal_semester_row(
+      deserializer,
+    );
+    var var_semester1Rate = sse_decode_f_64(deserializer);
+    var var_semester2 = sse_decode_list_output_personal_semester_row(
+      deserializer,
+    );
+    var var_semester2Rate = sse_decode_f_64(deserializer);
+    return EmployeeWorkloadInput(
+      id: var_id,
+      firstName: var_firstName,
+      middleName: var_middleName,
+      lastName: var_lastName,
+      rank: var_rank,
+      commentSemester1: var_commentSemester1,
+      commentSemester2: var_commentSemester2,
+      semester1: var_semester1,
+      semester1Rate: var_semester1Rate,
+      semester2: var_semester2,
+      semester2Rate: var_semester2Rate,
+    );
+  }
+
+  @protected
+  EmployeeWorkloadSummary sse_decode_employee_workload_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_employeeId = sse_decode_String(deserializer);
+    var var_fullName = sse_decode_String(deserializer);
+    var var_hours = sse_decode_f_64(deserializer);
+    var var_minPossibleHours = sse_decode_f_64(deserializer);
+    var var_maxPossibleHours = sse_decode_f_64(deserializer);
+    return EmployeeWorkloadSummary(
+      employeeId: var_employeeId,
+      fullName: var_fullName,
+      hours: var_hours,
+      minPossibleHours: var_minPossibleHours,
+      maxPossibleHours: var_maxPossibleHours,
+    );
+  }
+
+  @protected
+  ExportOptions sse_decode_export_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hideEmptyColumns = sse_decode_bool(deserializer);
+    var var_columns = sse_decode_opt_list_workload_column(deserializer);
+    var var_theme = sse_decode_opt_box_autoadd_export_theme(deserializer);
+    var var_highlightWorkloadNorms = sse_decode_bool(deserializer);
+    var var_includeAnalytics = sse_decode_bool(deserializer);
+    return ExportOptions(
+      hideEmptyColumns: var_hideEmptyColumns,
+      columns: var_columns,
+      theme: var_theme,
+      highlightWorkloadNorms: var_highlightWorkloadNorms,
+      includeAnalytics: var_includeAnalytics,
+    );
+  }
+
+  @protected
+  ExportTheme sse_decode_export_theme(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fontName = sse_decode_String(deserializer);
+    var var_fontSize = sse_decode_f_64(deserializer);
+    return ExportTheme(fontName: var_fontName, fontSize: var_fontSize);
+  }
+
+  @protected
+  ExportedData sse_decode_exported_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_year = sse_decode_u_32(deserializer);
+    var var_typeName = sse_decode_String(deserializer);
+    var var_mainTable = sse_decode_output_main_table(deserializer);
+    var var_personalTables = sse_decode_list_output_personal_tables(
+      deserializer,
+    );
+    return ExportedData(
+      year: var_year,
+      typeName: var_typeName,
+      mainTable: var_mainTable,
+      personalTables: var_personalTables,
+    );
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FieldChange sse_decode_field_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_workload_field(deserializer);
+    var var_oldValue = sse_decode_f_64(deserializer);
+    var var_newValue = sse_decode_f_64(deserializer);
+    return FieldChange(
+      field: var_field,
+      oldValue: var_oldValue,
+      newValue: var_newValue,
+    );
+  }
+
+  @protected
+  FieldRankRule sse_decode_field_rank_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_workload_field(deserializer);
+    var var_minRank = sse_decode_employee_rank(deserializer);
+    return FieldRankRule(field: var_field, minRank: var_minRank);
+  }
+
+  @protected
+  Form1Diff sse_decode_form_1_diff(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_added = sse_decode_list_form_1_diff_entry(deserializer);
+    var var_removed = sse_decode_list_form_1_diff_entry(deserializer);
+    var var_changed = sse_decode_list_form_1_diff_entry(deserializer);
+    return Form1Diff(
+      added: var_added,
+      removed: var_removed,
+      changed: var_changed,
+    );
+  }
+
+  @protected
+  Form1DiffEntry sse_decode_form_1_diff_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_discipline_key(deserializer);
+    var var_changes = sse_decode_list_field_change(deserializer);
+    var var_affectedEmployees = sse_decode_list_String(deserializer);
+    return Form1DiffEntry(
+      key: var_key,
+      changes: var_changes,
+      affectedEmployees: var_affectedEmployees,
+    );
+  }
+
+  @protected
+  Form1Finding sse_decode_form_1_finding(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_row = sse_decode_u_32(deserializer);
+    var var_kind = sse_decode_form_1_finding_kind(deserializer);
+    var var_key = sse_decode_opt_box_autoadd_discipline_key(deserializer);
+    var var_field = sse_decode_opt_box_autoadd_workload_field(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return Form1Finding(
+      row: var_row,
+      kind: var_kind,
+      key: var_key,
+      field: var_field,
+      message: var_message,
+    );
+  }
+
+  @protected
+  Form1FindingKind sse_decode_form_1_finding_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Form1FindingKind.values[inner];
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  InputRawRow sse_decode_input_raw_row(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_learningForm = sse_decode_String(deserializer);
+    var var_speciality = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_course = sse_decode_String(deserializer);
+    var var_semester = sse_decode_String(deserializer);
+    var var_weeksCount = sse_decode_String(deserializer);
+    var var_studentsCount = sse_decode_String(deserializer);
+    var var_flowsCount = sse_decode_String(deserializer);
+    var var_groupsCount = sse_decode_String(deserializer);
+    var var_subgroupsCount = sse_decode_String(deserializer);
+    var var_lecturesPlannedCount = sse_decode_String(deserializer);
+    var var_lecturesTotalCount = sse_decode_String(deserializer);
+    var var_practicesPlannedCount = sse_decode_String(deserializer);
+    var var_practicesTotalCount = sse_decode_String(deserializer);
+    var var_labsPlannedCount = sse_decode_String(deserializer);
+    var var_labsTotalCount = sse_decode_String(deserializer);
+    var var_exams = sse_decode_String(deserializer);
+    var var_examConsults = sse_decode_String(deserializer);
+    var var_tests = sse_decode_String(deserializer);
+    var var_qualWorks = sse_decode_String(deserializer);
+    var var_certificationExams = sse_decode_String(deserializer);
+    var var_workingPractice = sse_decode_String(deserializer);
+    var var_teachingPractice = sse_decode_String(deserializer);
+    var var_consults = sse_decode_String(deserializer);
+    var var_individualWorks = sse_decode_String(deserializer);
+    var var_individualWorkTypes = sse_decode_String(deserializer);
+    var var_courseWorks = sse_decode_String(deserializer);
+    var var_postgraduateExams = sse_decode_String(deserializer);
+    var var_supervising = sse_decode_String(deserializer);
+    var var_internship = sse_decode_String(deserializer);
+    return InputRawRow(
+      learningForm: var_learningForm,
+      speciality: var_speciality,
+      name: var_name,
+      course: var_course,
+      semester: var_semester,
+      weeksCount: var_weeksCount,
+      studentsCount: var_studentsCount,
+      flowsCount: var_flowsCount,
+      groupsCount: var_groupsCount,
+      subgroupsCount: var_subgroupsCount,
+      lecturesPlannedCount: var_lecturesPlannedCount,
+      lecturesTotalCount: var_lecturesTotalCount,
+      practicesPlannedCount: var_practicesPlannedCount,
+      practicesTotalCount: var_practicesTotalCount,
+      labsPlannedCount: var_labsPlannedCount,
+      labsTotalCount: var_labsTotalCount,
+      exams: var_exams,
+      examConsults: var_examConsults,
+      tests: var_tests,
+      qualWorks: var_qualWorks,
+      certificationExams: var_certificationExams,
+      workingPractice: var_workingPractice,
+      teachingPractice: var_teachingPractice,
+      consults: var_consults,
+      individualWorks: var_individualWorks,
+      individualWorkTypes: var_individualWorkTypes,
+      courseWorks: var_courseWorks,
+      postgraduateExams: var_postgraduateExams,
+      supervising: var_supervising,
+      internship: var_internship,
+    );
+  }
+
+  @protected
+  LearningForm sse_decode_learning_form(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LearningForm.values[inner];
+  }
+
+  @protected
+  LearningFormTotal sse_decode_learning_form_total(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_learningForm = sse_decode_String(deserializer);
+    var var_hours = sse_decode_output_hours_row(deserializer);
+    return LearningFormTotal(learningForm: var_learningForm, hours: var_hours);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CarriedOverItem> sse_decode_list_carried_over_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CarriedOverItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_carried_over_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DisciplineKey> sse_decode_list_discipline_key(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DisciplineKey>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_discipline_key(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DistributionIssue> sse_decode_list_distribution_issue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DistributionIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_distribution_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Employee> sse_decode_list_employee(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Employee>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_employee(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmployeeNorms> sse_decode_list_employee_norms(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EmployeeNorms>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_employee_norms(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmployeePreferences> sse_decode_list_employee_preferences(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EmployeePreferences>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_employee_preferences(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmployeeRate> sse_decode_list_employee_rate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EmployeeRate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_employee_rate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmployeeWorkloadInput> sse_decode_list_employee_workload_input(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EmployeeWorkloadInput>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_employee_workload_input(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmployeeWorkloadSummary> sse_decode_list_employee_workload_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EmployeeWorkloadSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_employee_workload_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldChange> sse_decode_list_field_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldChange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_change(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldRankRule> sse_decode_list_field_rank_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldRankRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_rank_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Form1DiffEntry> sse_decode_list_form_1_diff_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Form1DiffEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_form_1_diff_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Form1Finding> sse_decode_list_form_1_finding(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Form1Finding>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_form_1_finding(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InputRawRow> sse_decode_list_input_raw_row(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InputRawRow>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_input_raw_row(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LearningFormTotal> sse_decode_list_learning_form_total(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LearningFormTotal>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_learning_form_total(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MainTableSection> sse_decode_list_main_table_section(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MainTableSection>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_main_table_section(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NormFinding> sse_decode_list_norm_finding(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NormFinding>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_norm_finding(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NormRule> sse_decode_list_norm_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NormRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_norm_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OutputMainWorkerRow> sse_decode_list_output_main_worker_row(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OutputMainWorkerRow>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_output_main_worker_row(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OutputPersonalSemesterRow> sse_decode_list_output_personal_semester_row(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OutputPersonalSemesterRow>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_output_personal_semester_row(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OutputPersonalTable> sse_decode_list_output_personal_table(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OutputPersonalTable>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_output_personal_table(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OutputPersonalTables> sse_decode_list_output_personal_tables(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OutputPersonalTables>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_output_personal_tables(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PersonalSheetName> sse_decode_list_personal_sheet_name(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PersonalSheetName>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_personal_sheet_name(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<RateNorms> sse_decode_list_rate_norms(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RateNorms>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rate_norms(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReconcileConflict> sse_decode_list_reconcile_conflict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReconcileConflict>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_reconcile_conflict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, List<InputRawRow>)>
+  sse_decode_list_record_string_list_input_raw_row(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, List<InputRawRow>)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_list_input_raw_row(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TotalsMismatch> sse_decode_list_totals_mismatch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TotalsMismatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_totals_mismatch(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UndistributedWorkload> sse_decode_list_undistributed_workload(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UndistributedWorkload>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_undistributed_workload(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UniversityForm1WorkloadItem>
+  sse_decode_list_university_form_1_workload_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UniversityForm1WorkloadItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_university_form_1_workload_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UniversityForm3WorkloadItem>
+  sse_decode_list_university_form_3_workload_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UniversityForm3WorkloadItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_university_form_3_workload_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WorkloadColumn> sse_decode_list_workload_column(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WorkloadColumn>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_workload_column(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WorkloadField> sse_decode_list_workload_field(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WorkloadField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_workload_field(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WorkloadSectionInput> sse_decode_list_workload_section_input(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WorkloadSectionInput>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_workload_section_input(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MainTableSection sse_decode_main_table_section(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_rows = sse_decode_list_output_main_worker_row(deserializer);
+    var var_total = sse_decode_output_main_rate_and_hours(deserializer);
+    return MainTableSection(title: var_title, rows: var_rows, total: var_total);
+  }
+
+  @protected
+  NormBasis sse_decode_norm_basis(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NormBasis.values[inner];
+  }
+
+  @protected
+  NormFinding sse_decode_norm_finding(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_row = sse_decode_u_32(deserializer);
+    var var_key = sse_decode_discipline_key(deserializer);
+    var var_field = sse_decode_workload_field(deserializer);
+    var var_fileValue = sse_decode_f_64(deserializer);
+    var var_normValue = sse_decode_f_64(deserializer);
+    return NormFinding(
+      row: var_row,
+      key: var_key,
+      field: var_field,
+      fileValue: var_fileValue,
+      normValue: var_normValue,
+    );
+  }
+
+  @protected
+  NormRule sse_decode_norm_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_workload_field(deserializer);
+    var var_basis = sse_decode_norm_basis(deserializer);
+    var var_hoursPerUnit = sse_decode_f_64(deserializer);
+    return NormRule(
+      field: var_field,
+      basis: var_basis,
+      hoursPerUnit: var_hoursPerUnit,
+    );
+  }
+
+  @protected
+  NormsTable sse_decode_norms_table(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rules = sse_decode_list_norm_rule(deserializer);
+    var var_tolerance = sse_decode_f_64(deserializer);
+    return NormsTable(rules: var_rules, tolerance: var_tolerance);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AcademicCalendar? sse_decode_opt_box_autoadd_academic_calendar(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_academic_calendar(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DisciplineKey? sse_decode_opt_box_autoadd_discipline_key(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_discipline_key(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DistributionProject? sse_decode_opt_box_autoadd_distribution_project(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_distribution_project(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ExportTheme? sse_decode_opt_box_autoadd_export_theme(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_export_theme(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  NormsTable? sse_decode_opt_box_autoadd_norms_table(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_norms_table(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PdfFonts? sse_decode_opt_box_autoadd_pdf_fonts(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pdf_fonts(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WorkloadField? sse_decode_opt_box_autoadd_workload_field(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_workload_field(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<WorkloadColumn>? sse_decode_opt_list_workload_column(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_workload_column(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutputHoursRow sse_decode_output_hours_row(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_lectures = sse_decode_f_64(deserializer);
+    var var_practices = sse_decode_f_64(deserializer);
+    var var_labs = sse_decode_f_64(deserializer);
+    var var_exams = sse_decode_f_64(deserializer);
+    var var_examConsults = sse_decode_f_64(deserializer);
+    var var_tests = sse_decode_f_64(deserializer);
+    var var_qualWorks = sse_decode_f_64(deserializer);
+    var var_certificationExams = sse_decode_f_64(deserializer);
+    var var_workingPractice = sse_decode_f_64(deserializer);
+    var var_teachingPractice = sse_decode_f_64(deserializer);
+    var var_consults = sse_decode_f_64(deserializer);
+    var var_individualWorks = sse_decode_f_64(deserializer);
+    var var_courseWorks = sse_decode_f_64(deserializer);
+    var var_postgraduateExams = sse_decode_f_64(deserializer);
+    var var_supervising = sse_decode_f_64(deserializer);
+    var var_internship = sse_decode_f_64(deserializer);
+    var var_total = sse_decode_f_64(deserializer);
+    return OutputHoursRow(
+      lectures: var_lectures,
+      practices: var_practices,
+      labs: var_labs,
+      exams: var_exams,
+      examConsults: var_examConsults,
+      tests: var_tests,
+      qualWorks: var_qualWorks,
+      certificationExams: var_certificationExams,
+      workingPractice: var_workingPractice,
+      teachingPractice: var_teachingPractice,
+      consults: var_consults,
+      individualWorks: var_individualWorks,
+      courseWorks: var_courseWorks,
+      postgraduateExams: var_postgraduateExams,
+      supervising: var_supervising,
+      internship: var_internship,
+      total: var_total,
+    );
+  }
+
+  @protected
+  OutputMainRateAndHours sse_decode_output_main_rate_and_hours(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rate = sse_decode_f_64(deserializer);
+    var var_semester1 = sse_decode_output_hours_row(deserializer);
+    var var_semester2 = sse_decode_output_hours_row(deserializer);
+    var var_year = sse_decode_output_hours_row(deserializer);
+    return OutputMainRateAndHours(
+      rate: var_rate,
+      semester1: var_semester1,
+      semester2: var_semester2,
+      year: var_year,
+    );
+  }
+
+  @protected
+  OutputMainTable sse_decode_output_main_table(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sections = sse_decode_list_main_table_section(deserializer);
+    var var_total = sse_decode_output_main_rate_and_hours(deserializer);
+    return OutputMainTable(sections: var_sections, total: var_total);
+  }
+
+  @protected
+  OutputMainWorkerRow sse_decode_output_main_worker_row(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_firstName = sse_decode_String(deserializer);
+    var var_middleName = sse_decode_String(deserializer);
+    var var_lastName = sse_decode_String(deserializer);
+    var var_rank = sse_decode_String(deserializer);
+    var var_rateAndHours = sse_decode_output_main_rate_and_hours(deserializer);
+    return OutputMainWorkerRow(
+      firstName: var_firstName,
+      middleName: var_middleName,
+      lastName: var_lastName,
+      rank: var_rank,
+      rateAndHours: var_rateAndHours,
+    );
+  }
+
+  @protected
+  OutputPersonalSemesterRow sse_decode_output_personal_semester_row(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_learningForm = sse_decode_String(deserializer);
+    var var_speciality = sse_decode_String(deserializer);
+    var var_group = sse_decode_String(deserializer);
+    var var_course = sse_decode_String(deserializer);
+    var var_studentsCount = sse_decode_u_32(deserializer);
+    var var_individualWorkTypes = sse_decode_String(deserializer);
+    var var_hours = sse_decode_output_hours_row(deserializer);
+    var var_mergeLecturesWithNext = sse_decode_bool(deserializer);
+    return OutputPersonalSemesterRow(
+      name: var_name,
+      learningForm: var_learningForm,
+      speciality: var_speciality,
+      group: var_group,
+      course: var_course,
+      studentsCount: var_studentsCount,
+      individualWorkTypes: var_individualWorkTypes,
+      hours: var_hours,
+      mergeLecturesWithNext: var_mergeLecturesWithNext,
+    );
+  }
+
+  @protected
+  OutputPersonalTable sse_decode_output_personal_table(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_firstName = sse_decode_String(deserializer);
+    var var_middleName = sse_decode_String(deserializer);
+    var var_lastName = sse_decode_String(deserializer);
+    var var_commentSemester1 = sse_decode_String(deserializer);
+    var var_commentSemester2 = sse_decode_String(deserializer);
+    var var_rank = sse_decode_String(deserializer);
+    var var_semester1 = sse_decode_list_output_personal_semester_row(
+      deserializer,
+    );
+    var var_semester1Rate = sse_decode_f_64(deserializer);
+    var var_semester1FormTotals = sse_decode_list_learning_form_total(
+      deserializer,
+    );
+    var var_semester1Total = sse_decode_output_hours_row(deserializer);
+    var var_semester2 = sse_decode_list_output_personal_semester_row(
+      deserializer,
+    );
+    var var_semester2Rate = sse_decode_f_64(deserializer);
+    var var_semester2FormTotals = sse_decode_list_learning_form_total(
+      deserializer,
+    );
+    var var_semester2Total = sse_decode_output_hours_row(deserializer);
+    var var_yearTotal = sse_decode_output_hours_row(deserializer);
+    return OutputPersonalTable(
+      id: var_id,
+      firstName: var_firstName,
+      middleName: var_middleName,
+      lastName: var_lastName,
+      commentSemester1: var_commentSemester1,
+      commentSemester2: var_commentSemester2,
+      rank: var_rank,
+      semester1: var_semester1,
+      semester1Rate: var_semester1Rate,
+      semester1FormTotals: var_semester1FormTotals,
+      semester1Total: var_semester1Total,
+      semester2: var_semester2,
+      semester2Rate: var_semester2Rate,
+      semester2FormTotals: var_semester2FormTotals,
+      semester2Total: var_semester2Total,
+      yearTotal: var_yearTotal,
+    );
+  }
+
+  @protected
+  OutputPersonalTables sse_decode_output_personal_tables(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_workerLastName = sse_decode_String(deserializer);
+    var var_tables = sse_decode_list_output_personal_table(deserializer);
+    return OutputPersonalTables(
+      workerLastName: var_workerLastName,
+      tables: var_tables,
+    );
+  }
+
+  @protected
+  ParsedExcelFile sse_decode_parsed_excel_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_data = sse_decode_Map_String_list_input_raw_row_None(deserializer);
+    return ParsedExcelFile(data: var_data);
+  }
+
+  @protected
+  PdfFonts sse_decode_pdf_fonts(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_regular = sse_decode_String(deserializer);
+    var var_bold = sse_decode_opt_String(deserializer);
+    var var_italic = sse_decode_opt_String(deserializer);
+    var var_boldItalic = sse_decode_opt_String(deserializer);
+    return PdfFonts(
+      regular: var_regular,
+      bold: var_bold,
+      italic: var_italic,
+      boldItalic: var_boldItalic,
+    );
+  }
+
+  @protected
+  PeriodNorm sse_decode_period_norm(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rateMonths = sse_decode_f_64(deserializer);
+    var var_supervisionHours = sse_decode_f_64(deserializer);
+    var var_minHours = sse_decode_f_64(deserializer);
+    var var_maxHours = sse_decode_f_64(deserializer);
+    var var_hours = sse_decode_f_64(deserializer);
+    return PeriodNorm(
+      rateMonths: var_rateMonths,
+      supervisionHours: var_supervisionHours,
+      minHours: var_minHours,
+      maxHours: var_maxHours,
+      hours: var_hours,
+    );
+  }
+
+  @protected
+  PersonalSheetName sse_decode_personal_sheet_name(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_workerLastName = sse_decode_String(deserializer);
+    var var_tableIds = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_sheetName = sse_decode_String(deserializer);
+    return PersonalSheetName(
+      workerLastName: var_workerLastName,
+      tableIds: var_tableIds,
+      sheetName: var_sheetName,
+    );
+  }
+
+  @protected
+  RateNorms sse_decode_rate_norms(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rateId = sse_decode_String(deserializer);
+    var var_rateValue = sse_decode_f_64(deserializer);
+    var var_months = sse_decode_f_64(deserializer);
+    var var_firstSemester = sse_decode_period_norm(deserializer);
+    var var_secondSemester = sse_decode_period_norm(deserializer);
+    var var_year = sse_decode_period_norm(deserializer);
+    return RateNorms(
+      rateId: var_rateId,
+      rateValue: var_rateValue,
+      months: var_months,
+      firstSemester: var_firstSemester,
+      secondSemester: var_secondSemester,
+      year: var_year,
+    );
+  }
+
+  @protected
+  ReconcileConflict sse_decode_reconcile_conflict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_reconcile_conflict_kind(deserializer);
+    var var_key = sse_decode_discipline_key(deserializer);
+    var var_field = sse_decode_opt_box_autoadd_workload_field(deserializer);
+    var var_total = sse_decode_f_64(deserializer);
+    var var_distributed = sse_decode_f_64(deserializer);
+    var var_employees = sse_decode_list_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return ReconcileConflict(
+      kind: var_kind,
+      key: var_key,
+      field: var_field,
+      total: var_total,
+      distributed: var_distributed,
+      employees: var_employees,
+      message: var_message,
+    );
+  }
+
+  @protected
+  ReconcileConflictKind sse_decode_reconcile_conflict_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ReconcileConflictKind.values[inner];
+  }
+
+  @protected
+  ReconcileResult sse_decode_reconcile_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_project = sse_decode_distribution_project(deserializer);
+    var var_diff = sse_decode_form_1_diff(deserializer);
+    var var_conflicts = sse_decode_list_reconcile_conflict(deserializer);
+    return ReconcileResult(
+      project: var_project,
+      diff: var_diff,
+      conflicts: var_conflicts,
+    );
+  }
+
+  @protected
+  (String, List<InputRawRow>) sse_decode_record_string_list_input_raw_row(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_list_input_raw_row(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  SolverOptions sse_decode_solver_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_preferences = sse_decode_list_employee_preferences(deserializer);
+    var var_rankRules = sse_decode_list_field_rank_rule(deserializer);
+    return SolverOptions(
+      preferences: var_preferences,
+      rankRules: var_rankRules,
+    );
+  }
+
+  @protected
+  SolverResult sse_decode_solver_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_project = sse_decode_distribution_project(deserializer);
+    var var_unplaced = sse_decode_list_undistributed_workload(deserializer);
+    var var_splitItems = sse_decode_list_discipline_key(deserializer);
+    var var_employees = sse_decode_list_employee_workload_summary(deserializer);
+    return SolverResult(
+      project: var_project,
+      unplaced: var_unplaced,
+      splitItems: var_splitItems,
+      employees: var_employees,
+    );
+  }
+
+  @protected
+  TotalsMismatch sse_decode_totals_mismatch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_location = sse_decode_String(deserializer);
+    var var_field = sse_decode_String(deserializer);
+    var var_expected = sse_decode_f_64(deserializer);
+    var var_actual = sse_decode_f_64(deserializer);
+    return TotalsMismatch(
+      location: var_location,
+      field: var_field,
+      expected: var_expected,
+      actual: var_actual,
+    );
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  UndistributedWorkload sse_decode_undistributed_workload(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_workloadKey = sse_decode_discipline_key(deserializer);
+    var var_field = sse_decode_workload_field(deserializer);
+    var var_total = sse_decode_f_64(deserializer);
+    var var_distributed = sse_decode_f_64(deserializer);
+    var var_undistributed = sse_decode_f_64(deserializer);
+    return UndistributedWorkload(
+      workloadKey: var_workloadKey,
+      field: var_field,
+      total: var_total,
+      distributed: var_distributed,
+      undistributed: var_undistributed,
+    );
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  UniversityForm1 sse_decode_university_form_1(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_academicYear = sse_decode_u_32(deserializer);
+    var var_workloadItems = sse_decode_list_university_form_1_workload_item(
+      deserializer,
+    );
+    return UniversityForm1(
+      id: var_id,
+      academicYear: var_academicYear,
+      workloadItems: var_workloadItems,
+    );
+  }
+
+  @protected
+  UniversityForm1WorkloadItem sse_decode_university_form_1_workload_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_workloadKey = sse_decode_discipline_key(deserializer);
+    var var_weekCount = sse_decode_f_64(deserializer);
+    var var_studentCount = sse_decode_u_32(deserializer);
+    var var_flowCount = sse_decode_f_64(deserializer);
+    var var_groupCount = sse_decode_f_64(deserializer);
+    var var_subgroupCount = sse_decode_f_64(deserializer);
+    var var_lecturesPlanned = sse_decode_f_64(deserializer);
+    var var_lecturesTotal = sse_decode_f_64(deserializer);
+    var var_practicesPlanned = sse_decode_f_64(deserializer);
+    var var_practicesTotal = sse_decode_f_64(deserializer);
+    var var_labsPlanned = sse_decode_f_64(deserializer);
+    var var_labsTotal = sse_decode_f_64(deserializer);
+    var var_exams = sse_decode_f_64(deserializer);
+    var var_examConsults = sse_decode_f_64(deserializer);
+    var var_tests = sse_decode_f_64(deserializer);
+    var var_qualificationWorks = sse_decode_f_64(deserializer);
+    var var_certificationExams = sse_decode_f_64(deserializer);
+    var var_productionPractices = sse_decode_f_64(deserializer);
+    var var_teachingPractices = sse_decode_f_64(deserializer);
+    var var_currentConsults = sse_decode_f_64(deserializer);
+    var var_individualWorks = sse_decode_f_64(deserializer);
+    var var_courseWorks = sse_decode_f_64(deserializer);
+    var var_postgraduateExams = sse_decode_f_64(deserializer);
+    return UniversityForm1WorkloadItem(
+      id: var_id,
+      workloadKey: var_workloadKey,
+      weekCount: var_weekCount,
+      studentCount: var_studentCount,
+      flowCount: var_flowCount,
+      groupCount: var_groupCount,
+      subgroupCount: var_subgroupCount,
+      lecturesPlanned: var_lecturesPlanned,
+      lecturesTotal: var_lecturesTotal,
+      practicesPlanned: var_practicesPlanned,
+      practicesTotal: var_practicesTotal,
+      labsPlanned: var_labsPlanned,
+      labsTotal: var_labsTotal,
+      exams: var_exams,
+      examConsults: var_examConsults,
+      tests: var_tests,
+      qualificationWorks: var_qualificationWorks,
+      certificationExams: var_certificationExams,
+      productionPractices: var_productionPractices,
+      teachingPractices: var_teachingPractices,
+      currentConsults: var_currentConsults,
+      individualWorks: var_individualWorks,
+      courseWorks: var_courseWorks,
+      postgraduateExams: var_postgraduateExams,
+    );
+  }
+
+  @protected
+  UniversityForm3 sse_decode_university_form_3(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_academicYear = sse_decode_u_32(deserializer);
+    var var_employees = sse_decode_list_employee(deserializer);
+    return UniversityForm3(
+      id: var_id,
+      academicYear: var_academicYear,
+      employees: var_employees,
+    );
+  }
+
+  @protected
+  UniversityForm3WorkloadItem sse_decode_university_form_3_workload_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_workloadKey = sse_decode_discipline_key(deserializer);
+    var var_studentCount = sse_decode_u_32(deserializer);
+    var var_academicGroups = sse_decode_list_String(deserializer);
+    var var_lectures = sse_decode_f_64(deserializer);
+    var var_practices = sse_decode_f_64(deserializer);
+    var var_labs = sse_decode_f_64(deserializer);
+    var var_exams = sse_decode_f_64(deserializer);
+    var var_examConsults = sse_decode_f_64(deserializer);
+    var var_tests = sse_decode_f_64(deserializer);
+    var var_qualificationWorks = sse_decode_f_64(deserializer);
+    var var_certificationExams = sse_decode_f_64(deserializer);
+    var var_productionPractices = sse_decode_f_64(deserializer);
+    var var_teachingPractices = sse_decode_f_64(deserializer);
+    var var_currentConsults = sse_decode_f_64(deserializer);
+    var var_individualWorks = sse_decode_f_64(deserializer);
+    var var_courseWorks = sse_decode_f_64(deserializer);
+    var var_postgraduateExams = sse_decode_f_64(deserializer);
+    return UniversityForm3WorkloadItem(
+      id: var_id,
+      workloadKey: var_workloadKey,
+      studentCount: var_studentCount,
+      academicGroups: var_academicGroups,
+      lectures: var_lectures,
+      practices: var_practices,
+      labs: var_labs,
+      exams: var_exams,
+      examConsults: var_examConsults,
+      tests: var_tests,
+      qualificationWorks: var_qualificationWorks,
+      certificationExams: var_certificationExams,
+      productionPractices: var_productionPractices,
+      teachingPractices: var_teachingPractices,
+      currentConsults: var_currentConsults,
+      individualWorks: var_individualWorks,
+      courseWorks: var_courseWorks,
+      postgraduateExams: var_postgraduateExams,
+    );
+  }
+
+  @protected
+  WorkloadColumn sse_decode_workload_column(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_workload_key(deserializer);
+    var var_caption = sse_decode_String(deserializer);
+    var var_width = sse_decode_f_64(deserializer);
+    var var_numberFormat = sse_decode_String(deserializer);
+    var var_includeInTotal = sse_decode_bool(deserializer);
+    return WorkloadColumn(
+      key: var_key,
+      caption: var_caption,
+      width: var_width,
+      numberFormat: var_numberFormat,
+      includeInTotal: var_includeInTotal,
+    );
+  }
+
+  @protected
+  WorkloadField sse_decode_workload_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WorkloadField.values[inner];
+  }
+
+  @protected
+  WorkloadInput sse_decode_workload_input(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_year = sse_decode_u_32(deserializer);
+    var var_typeName = sse_decode_String(deserializer);
+    var var_sections = sse_decode_list_workload_section_input(deserializer);
+    return WorkloadInput(
+      year: var_year,
+      typeName: var_typeName,
+      sections: var_sections,
+    );
+  }
+
+  @protected
+  WorkloadKey sse_decode_workload_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WorkloadKey.values[inner];
+  }
+
+  @protected
+  WorkloadSectionInput sse_decode_workload_section_input(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_employees = sse_decode_list_employee_workload_input(deserializer);
+    return WorkloadSectionInput(title: var_title, employees: var_employees);
+  }
+
+  @protected
+  void sse_encode_Map_String_list_input_raw_row_None(
+    Map<String, List<InputRawRow>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_list_input_raw_row(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_academic_calendar(
+    AcademicCalendar self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.firstSemesterStart, serializer);
+    sse_encode_String(self.firstSemesterEnd, serializer);
+    sse_encode_String(self.secondSemesterStart, serializer);
+    sse_encode_String(self.secondSemesterEnd, serializer);
+  }
+
+  @protected
+  void sse_encode_academic_semester(
+    AcademicSemester self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_academic_calendar(
+    AcademicCalendar self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_academic_calendar(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_discipline_key(
+    DisciplineKey self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_discipline_key(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_distribution_project(
+    DistributionProject self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_distribution_project(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_export_options(
+    ExportOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_export_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_export_theme(
+    ExportTheme self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_export_theme(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_exported_data(
+    ExportedData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_exported_data(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_norms_table(
+    NormsTable self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_norms_table(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_parsed_excel_file(
+    ParsedExcelFile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_parsed_excel_file(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_pdf_fonts(
+    PdfFonts self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pdf_fonts(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_solver_options(
+    SolverOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_solver_options(self, serializer);
   }
 
   @protected
-  List<OutputMainWorkerRow> dco_decode_list_output_main_worker_row(
-    dynamic raw,
+  void sse_encode_box_autoadd_university_form_1(
+    UniversityForm1 self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_output_main_worker_row)
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_university_form_1(self, serializer);
   }
 
   @protected
-  List<OutputPersonalSemesterRow> dco_decode_list_output_personal_semester_row(
-    dynamic raw,
+  void sse_encode_box_autoadd_university_form_3_workload_item(
+    UniversityForm3WorkloadItem self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_output_personal_semester_row)
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_university_form_3_workload_item(self, serializer);
   }
 
   @protected
-  List<OutputPersonalTable> dco_decode_list_output_personal_table(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_output_personal_table)
-        .toList();
+  void sse_encode_box_autoadd_workload_field(
+    WorkloadField self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_workload_field(self, serializer);
   }
 
   @protected
-  List<OutputPersonalTables> dco_decode_list_output_personal_tables(
-    dynamic raw,
+  void sse_encode_box_autoadd_workload_input(
+    WorkloadInput self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_output_personal_tables)
-        .toList();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_workload_input(self, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_carried_over_item(
+    CarriedOverItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_discipline_key(self.key, serializer);
+    sse_encode_discipline_key(self.previousKey, serializer);
+    sse_encode_f_64(self.similarity, serializer);
+    sse_encode_list_workload_field(self.changedFields, serializer);
   }
 
   @protected
-  List<(String, List<InputRawRow>)>
-  dco_decode_list_record_string_list_input_raw_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_list_input_raw_row)
-        .toList();
+  void sse_encode_carry_over_result(
+    CarryOverResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_distribution_project(self.project, serializer);
+    sse_encode_list_discipline_key(self.newItems, serializer);
+    sse_encode_list_discipline_key(self.removedItems, serializer);
+    sse_encode_list_carried_over_item(self.changedItems, serializer);
   }
 
   @protected
-  OutputHoursRow dco_decode_output_hours_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 14)
-      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
-    return OutputHoursRow(
-      lectures: dco_decode_f_64(arr[0]),
-      practices: dco_decode_f_64(arr[1]),
-      labs: dco_decode_f_64(arr[2]),
-      exams: dco_decode_f_64(arr[3]),
-      examConsults: dco_decode_f_64(arr[4]),
-      tests: dco_decode_f_64(arr[5]),
-      qualWorks: dco_decode_f_64(arr[6]),
-      workingPractice: dco_decode_f_64(arr[7]),
-      teachingPractice: dco_decode_f_64(arr[8]),
-      consults: dco_decode_f_64(arr[9]),
-      individualWorks: dco_decode_f_64(arr[10]),
-      courseWorks: dco_decode_f_64(arr[11]),
-      supervising: dco_decode_f_64(arr[12]),
-      total: dco_decode_f_64(arr[13]),
-    );
+  void sse_encode_discipline_key(DisciplineKey self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_learning_form(self.learningForm, serializer);
+    sse_encode_String(self.specialty, serializer);
+    sse_encode_String(self.disciplineName, serializer);
+    sse_encode_String(self.course, serializer);
+    sse_encode_academic_semester(self.semester, serializer);
   }
 
   @protected
-  OutputMainRateAndHours dco_decode_output_main_rate_and_hours(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return OutputMainRateAndHours(
-      rate: dco_decode_f_64(arr[0]),
-      semester1: dco_decode_output_hours_row(arr[1]),
-      semester2: dco_decode_output_hours_row(arr[2]),
-      year: dco_decode_output_hours_row(arr[3]),
-    );
+  void sse_encode_distribution_issue(
+    DistributionIssue self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.employeeId, serializer);
+    sse_encode_opt_String(self.itemId, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  OutputMainTable dco_decode_output_main_table(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 13)
-      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
-    return OutputMainTable(
-      heads: dco_decode_list_output_main_worker_row(arr[0]),
-      headsTotal: dco_decode_output_main_rate_and_hours(arr[1]),
-      professors: dco_decode_list_output_main_worker_row(arr[2]),
-      professorsTotal: dco_decode_output_main_rate_and_hours(arr[3]),
-      associateProfessors: dco_decode_list_output_main_worker_row(arr[4]),
-      associateProfessorsTotal: dco_decode_output_main_rate_and_hours(arr[5]),
-      lecturers: dco_decode_list_output_main_worker_row(arr[6]),
-      lecturersTotal: dco_decode_output_main_rate_and_hours(arr[7]),
-      assistants: dco_decode_list_output_main_worker_row(arr[8]),
-      assistantsTotal: dco_decode_output_main_rate_and_hours(arr[9]),
-      partTimers: dco_decode_list_output_main_worker_row(arr[10]),
-      partTimersTotal: dco_decode_output_main_rate_and_hours(arr[11]),
-      total: dco_decode_output_main_rate_and_hours(arr[12]),
-    );
+  void sse_encode_distribution_project(
+    DistributionProject self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_university_form_1(self.universityForm1, serializer);
+    sse_encode_university_form_3(self.universityForm3, serializer);
+    sse_encode_String(self.createdAt, serializer);
+    sse_encode_String(self.updatedAt, serializer);
   }
 
   @protected
-  OutputMainWorkerRow dco_decode_output_main_worker_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return OutputMainWorkerRow(
-      firstName: dco_decode_String(arr[0]),
-      middleName: dco_decode_String(arr[1]),
-      lastName: dco_decode_String(arr[2]),
-      rank: dco_decode_String(arr[3]),
-      rateAndHours: dco_decode_output_main_rate_and_hours(arr[4]),
+  void sse_encode_employee(Employee self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.firstName, serializer);
+    sse_encode_String(self.lastName, serializer);
+    sse_encode_String(self.patronymic, serializer);
+    sse_encode_employee_rank(self.rank, serializer);
+    sse_encode_list_employee_rate(self.rates, serializer);
+  }
+
+  @protected
+  void sse_encode_employee_norms(EmployeeNorms self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.employeeId, serializer);
+    sse_encode_String(self.fullName, serializer);
+    sse_encode_f_64(self.peakRate, serializer);
+    sse_encode_list_rate_norms(self.rates, serializer);
+    sse_encode_period_norm(self.firstSemester, serializer);
+    sse_encode_period_norm(self.secondSemester, serializer);
+    sse_encode_period_norm(self.year, serializer);
+  }
+
+  @protected
+  void sse_encode_employee_preferences(
+    EmployeePreferences self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.employeeId, serializer);
+    sse_encode_list_String(self.disciplines, serializer);
+  }
+
+  @protected
+  void sse_encode_employee_rank(EmployeeRank self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_employee_rate(EmployeeRate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_f_64(self.rateValue, serializer);
+    sse_encode_String(self.dateStart, serializer);
+    sse_encode_String(self.dateEnd, serializer);
+    sse_encode_u_32(self.postgraduateCount, serializer);
+    sse_encode_list_university_form_3_workload_item(
+      self.workloadItems,
+      serializer,
     );
   }
 
   @protected
-  OutputPersonalSemesterRow dco_decode_output_personal_semester_row(
-    dynamic raw,
+  void sse_encode_employee_workload_input(
+    EmployeeWorkloadInput self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.id, serializer);
+    sse_encode_String(self.firstName, serializer);
+    sse_encode_String(self.middleName, serializer);
+    sse_encode_String(self.lastName, serializer);
+    sse_encode_String(self.rank, serializer);
+    sse_encode_String(self.commentSemester1, serializer);
+    sse_encode_String(self.commentSemester2, serializer);
+    sse_encode_list_output_personal_semester_row(self.semester1, serializer);
+    sse_encode_f_64(self.semester1Rate, serializer);
+    sse_encode_list_output_personal_semester_row(self.semester2, serializer);
+    sse_encode_f_64(self.semester2Rate, serializer);
+  }
+
+  @protected
+  void sse_encode_employee_workload_summary(
+    EmployeeWorkloadSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.employeeId, serializer);
+    sse_encode_String(self.fullName, serializer);
+    sse_encode_f_64(self.hours, serializer);
+    sse_encode_f_64(self.minPossibleHours, serializer);
+    sse_encode_f_64(self.maxPossibleHours, serializer);
+  }
+
+  @protected
+  void sse_encode_export_options(ExportOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.hideEmptyColumns, serializer);
+    sse_encode_opt_list_workload_column(self.columns, serializer);
+    sse_encode_opt_box_autoadd_export_theme(self.theme, serializer);
+    sse_encode_bool(self.highlightWorkloadNorms, serializer);
+    sse_encode_bool(self.includeAnalytics, serializer);
+  }
+
+  @protected
+  void sse_encode_export_theme(ExportTheme self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fontName, serializer);
+    sse_encode_f_64(self.fontSize, serializer);
+  }
+
+  @protected
+  void sse_encode_exported_data(ExportedData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.year, serializer);
+    sse_encode_String(self.typeName, serializer);
+    sse_encode_output_main_table(self.mainTable, serializer);
+    sse_encode_list_output_personal_tables(self.personalTables, serializer);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_field_change(FieldChange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_workload_field(self.field, serializer);
+    sse_encode_f_64(self.oldValue, serializer);
+    sse_encode_f_64(self.newValue, serializer);
+  }
+
+  @protected
+  void sse_encode_field_rank_rule(
+    FieldRankRule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_workload_field(self.field, serializer);
+    sse_encode_employee_rank(self.minRank, serializer);
+  }
+
+  @protected
+  void sse_encode_form_1_diff(Form1Diff self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_form_1_diff_entry(self.added, serializer);
+    sse_encode_list_form_1_diff_entry(self.removed, serializer);
+    sse_encode_list_form_1_diff_entry(self.changed, serializer);
+  }
+
+  @protected
+  void sse_encode_form_1_diff_entry(
+    Form1DiffEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_discipline_key(self.key, serializer);
+    sse_encode_list_field_change(self.changes, serializer);
+    sse_encode_list_String(self.affectedEmployees, serializer);
+  }
+
+  @protected
+  void sse_encode_form_1_finding(Form1Finding self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.row, serializer);
+    sse_encode_form_1_finding_kind(self.kind, serializer);
+    sse_encode_opt_box_autoadd_discipline_key(self.key, serializer);
+    sse_encode_opt_box_autoadd_workload_field(self.field, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_form_1_finding_kind(
+    Form1FindingKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_input_raw_row(InputRawRow self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.learningForm, serializer);
+    sse_encode_String(self.speciality, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.course, serializer);
+    sse_encode_String(self.semester, serializer);
+    sse_encode_String(self.weeksCount, serializer);
+    sse_encode_String(self.studentsCount, serializer);
+    sse_encode_String(self.flowsCount, serializer);
+    sse_encode_String(self.groupsCount, serializer);
+    sse_encode_String(self.subgroupsCount, serializer);
+    sse_encode_String(self.lecturesPlannedCount, serializer);
+    sse_encode_String(self.lecturesTotalCount, serializer);
+    sse_encode_String(self.practicesPlannedCount, serializer);
+    sse_encode_String(self.practicesTotalCount, serializer);
+    sse_encode_String(self.labsPlannedCount, serializer);
+    sse_encode_String(self.labsTotalCount, serializer);
+    sse_encode_String(self.exams, serializer);
+    sse_encode_String(self.examConsults, serializer);
+    sse_encode_String(self.tests, serializer);
+    sse_encode_String(self.qualWorks, serializer);
+    sse_encode_String(self.certificationExams, serializer);
+    sse_encode_String(self.workingPractice, serializer);
+    sse_encode_String(self.teachingPractice, serializer);
+    sse_encode_String(self.consults, serializer);
+    sse_encode_String(self.individualWorks, serializer);
+    sse_encode_String(self.individualWorkTypes, serializer);
+    sse_encode_String(self.courseWorks, serializer);
+    sse_encode_String(self.postgraduateExams, serializer);
+    sse_encode_String(self.supervising, serializer);
+    sse_encode_String(self.internship, serializer);
+  }
+
+  @protected
+  void sse_encode_learning_form(LearningForm self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_learning_form_total(
+    LearningFormTotal self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.learningForm, serializer);
+    sse_encode_output_hours_row(self.hours, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_carried_over_item(
+    List<CarriedOverItem> self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return OutputPersonalSemesterRow(
-      name: dco_decode_String(arr[0]),
-      learningForm: dco_decode_String(arr[1]),
-      speciality: dco_decode_String(arr[2]),
-      group: dco_decode_String(arr[3]),
-      course: dco_decode_String(arr[4]),
-      studentsCount: dco_decode_u_32(arr[5]),
-      hours: dco_decode_output_hours_row(arr[6]),
-      mergeLecturesWithNext: dco_decode_bool(arr[7]),
-    );
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_carried_over_item(item, serializer);
+    }
   }
 
   @protected
-  OutputPersonalTable dco_decode_output_personal_table(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 18)
-      throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
-    return OutputPersonalTable(
-      id: dco_decode_u_32(arr[0]),
-      firstName: dco_decode_String(arr[1]),
-      middleName: dco_decode_String(arr[2]),
-      lastName: dco_decode_String(arr[3]),
-      commentSemester1: dco_decode_String(arr[4]),
-      commentSemester2: dco_decode_String(arr[5]),
-      rank: dco_decode_String(arr[6]),
-      semester1: dco_decode_list_output_personal_semester_row(arr[7]),
-      semester1Rate: dco_decode_f_64(arr[8]),
-      semester1TotalDay: dco_decode_output_hours_row(arr[9]),
-      semester1TotalEvening: dco_decode_output_hours_row(arr[10]),
-      semester1Total: dco_decode_output_hours_row(arr[11]),
-      semester2: dco_decode_list_output_personal_semester_row(arr[12]),
-      semester2Rate: dco_decode_f_64(arr[13]),
-      semester2TotalDay: dco_decode_output_hours_row(arr[14]),
-      semester2TotalEvening: dco_decode_output_hours_row(arr[15]),
-      semester2Total: dco_decode_output_hours_row(arr[16]),
-      yearTotal: dco_decode_output_hours_row(arr[17]),
-    );
+  void sse_encode_list_discipline_key(
+    List<DisciplineKey> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_discipline_key(item, serializer);
+    }
   }
 
   @protected
-  OutputPersonalTables dco_decode_output_personal_tables(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return OutputPersonalTables(
-      workerLastName: dco_decode_String(arr[0]),
-      tables: dco_decode_list_output_personal_table(arr[1]),
-    );
+  void sse_encode_list_distribution_issue(
+    List<DistributionIssue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_distribution_issue(item, serializer);
+    }
   }
 
   @protected
-  ParsedExcelFile dco_decode_parsed_excel_file(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return ParsedExcelFile(
-      data: dco_decode_Map_String_list_input_raw_row_None(arr[0]),
-    );
+  void sse_encode_list_employee(List<Employee> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_employee(item, serializer);
+    }
   }
 
   @protected
-  (String, List<InputRawRow>) dco_decode_record_string_list_input_raw_row(
-    dynamic raw,
+  void sse_encode_list_employee_norms(
+    List<EmployeeNorms> self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_employee_norms(item, serializer);
     }
-    return (dco_decode_String(arr[0]), dco_decode_list_input_raw_row(arr[1]));
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_employee_preferences(
+    List<EmployeePreferences> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_employee_preferences(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_employee_rate(
+    List<EmployeeRate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_employee_rate(item, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_list_employee_workload_input(
+    List<EmployeeWorkloadInput> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_employee_workload_input(item, serializer);
+    }
   }
 
   @protected
-  Map<String, List<InputRawRow>> sse_decode_Map_String_list_input_raw_row_None(
-    SseDeserializer deserializer,
+  void sse_encode_list_employee_workload_summary(
+    List<EmployeeWorkloadSummary> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_list_input_raw_row(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_employee_workload_summary(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_field_change(
+    List<FieldChange> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_field_change(item, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_field_rank_rule(
+    List<FieldRankRule> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_field_rank_rule(item, serializer);
+    }
   }
 
   @protected
-  ExportedData sse_decode_box_autoadd_exported_data(
-    SseDeserializer deserializer,
+  void sse_encode_list_form_1_diff_entry(
+    List<Form1DiffEntry> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_exported_data(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_form_1_diff_entry(item, serializer);
+    }
   }
 
   @protected
-  ExportedData sse_decode_exported_data(SseDeserializer deserializer) {
+  void sse_encode_list_form_1_finding(
+    List<Form1Finding> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_year = sse_decode_u_32(deserializer);
-    var var_typeName = sse_decode_String(deserializer);
-    var var_mainTable = sse_decode_output_main_table(deserializer);
-    var var_personalTables = sse_decode_list_output_personal_tables(
-      deserializer,
-    );
-    return ExportedData(
-      year: var_year,
-      typeName: var_typeName,
-      mainTable: var_mainTable,
-      personalTables: var_personalTables,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_form_1_finding(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_input_raw_row(
+    List<InputRawRow> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_input_raw_row(item, serializer);
+    }
   }
 
   @protected
-  InputRawRow sse_decode_input_raw_row(SseDeserializer deserializer) {
+  void sse_encode_list_learning_form_total(
+    List<LearningFormTotal> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_learningForm = sse_decode_String(deserializer);
-    var var_speciality = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_course = sse_decode_String(deserializer);
-    var var_semester = sse_decode_String(deserializer);
-    var var_weeksCount = sse_decode_String(deserializer);
-    var var_studentsCount = sse_decode_String(deserializer);
-    var var_flowsCount = sse_decode_String(deserializer);
-    var var_groupsCount = sse_decode_String(deserializer);
-    var var_subgroupsCount = sse_decode_String(deserializer);
-    var var_lecturesPlannedCount = sse_decode_String(deserializer);
-    var var_lecturesTotalCount = sse_decode_String(deserializer);
-    var var_practicesPlannedCount = sse_decode_String(deserializer);
-    var var_practicesTotalCount = sse_decode_String(deserializer);
-    var var_labsPlannedCount = sse_decode_String(deserializer);
-    var var_labsTotalCount = sse_decode_String(deserializer);
-    var var_exams = sse_decode_String(deserializer);
-    var var_examConsults = sse_decode_String(deserializer);
-    var var_tests = sse_decode_String(deserializer);
-    var var_qualWorks = sse_decode_String(deserializer);
-    var var_certificationExams = sse_decode_String(deserializer);
-    var var_workingPractice = sse_decode_String(deserializer);
-    var var_teachingPractice = sse_decode_String(deserializer);
-    var var_consults = sse_decode_String(deserializer);
-    var var_individualWorks = sse_decode_String(deserializer);
-    var var_individualWorkTypes = sse_decode_String(deserializer);
-    var var_courseWorks = sse_decode_String(deserializer);
-    var var_postgraduateExams = sse_decode_String(deserializer);
-    var var_supervising = sse_decode_String(deserializer);
-    var var_internship = sse_decode_String(deserializer);
-    return InputRawRow(
-      learningForm: var_learningForm,
-      speciality: var_speciality,
-      name: var_name,
-      course: var_course,
-      semester: var_semester,
-      weeksCount: var_weeksCount,
-      studentsCount: var_studentsCount,
-      flowsCount: var_flowsCount,
-      groupsCount: var_groupsCount,
-      subgroupsCount: var_subgroupsCount,
-      lecturesPlannedCount: var_lecturesPlannedCount,
-      lecturesTotalCount: var_lecturesTotalCount,
-      practicesPlannedCount: var_practicesPlannedCount,
-      practicesTotalCount: var_practicesTotalCount,
-      labsPlannedCount: var_labsPlannedCount,
-      labsTotalCount: var_labsTotalCount,
-      exams: var_exams,
-      examConsults: var_examConsults,
-      tests: var_tests,
-      qualWorks: var_qualWorks,
-      certificationExams: var_certificationExams,
-      workingPractice: var_workingPractice,
-      teachingPractice: var_teachingPractice,
-      consults: var_consults,
-      individualWorks: var_individualWorks,
-      individualWorkTypes: var_individualWorkTypes,
-      courseWorks: var_courseWorks,
-      postgraduateExams: var_postgraduateExams,
-      supervising: var_supervising,
-      internship: var_internship,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_learning_form_total(item, serializer);
+    }
   }
 
   @protected
-  List<InputRawRow> sse_decode_list_input_raw_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_main_table_section(
+    List<MainTableSection> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <InputRawRow>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_input_raw_row(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_main_table_section(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<OutputMainWorkerRow> sse_decode_list_output_main_worker_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_norm_finding(
+    List<NormFinding> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OutputMainWorkerRow>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_output_main_worker_row(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_norm_finding(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<OutputPersonalSemesterRow> sse_decode_list_output_personal_semester_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_norm_rule(
+    List<NormRule> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OutputPersonalSemesterRow>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_output_personal_semester_row(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_norm_rule(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<OutputPersonalTable> sse_decode_list_output_personal_table(
-    SseDeserializer deserializer,
+  void sse_encode_list_output_main_worker_row(
+    List<OutputMainWorkerRow> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OutputPersonalTable>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_output_personal_table(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_output_main_worker_row(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<OutputPersonalTables> sse_decode_list_output_personal_tables(
-    SseDeserializer deserializer,
+  void sse_encode_list_output_personal_semester_row(
+    List<OutputPersonalSemesterRow> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OutputPersonalTables>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_output_personal_tables(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_output_personal_semester_row(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_output_personal_table(
+    List<OutputPersonalTable> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_output_personal_table(item, serializer);
+    }
   }
 
   @protected
-  List<(String, List<InputRawRow>)>
-  sse_decode_list_record_string_list_input_raw_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_output_personal_tables(
+    List<OutputPersonalTables> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, List<InputRawRow>)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_list_input_raw_row(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_output_personal_tables(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  OutputHoursRow sse_decode_output_hours_row(SseDeserializer deserializer) {
+  void sse_encode_list_personal_sheet_name(
+    List<PersonalSheetName> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_lectures = sse_decode_f_64(deserializer);
-    var var_practices = sse_decode_f_64(deserializer);
-    var var_labs = sse_decode_f_64(deserializer);
-    var var_exams = sse_decode_f_64(deserializer);
-    var var_examConsults = sse_decode_f_64(deserializer);
-    var var_tests = sse_decode_f_64(deserializer);
-    var var_qualWorks = sse_decode_f_64(deserializer);
-    var var_workingPractice = sse_decode_f_64(deserializer);
-    var var_teachingPractice = sse_decode_f_64(deserializer);
-    var var_consults = sse_decode_f_64(deserializer);
-    var var_individualWorks = sse_decode_f_64(deserializer);
-    var var_courseWorks = sse_decode_f_64(deserializer);
-    var var_supervising = sse_decode_f_64(deserializer);
-    var var_total = sse_decode_f_64(deserializer);
-    return OutputHoursRow(
-      lectures: var_lectures,
-      practices: var_practices,
-      labs: var_labs,
-      exams: var_exams,
-      examConsults: var_examConsults,
-      tests: var_tests,
-      qualWorks: var_qualWorks,
-      workingPractice: var_workingPractice,
-      teachingPractice: var_teachingPractice,
-      consults: var_consults,
-      individualWorks: var_individualWorks,
-      courseWorks: var_courseWorks,
-      supervising: var_supervising,
-      total: var_total,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_personal_sheet_name(item, serializer);
+    }
   }
 
   @protected
-  OutputMainRateAndHours sse_decode_output_main_rate_and_hours(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_rate = sse_decode_f_64(deserializer);
-    var var_semester1 = sse_decode_output_hours_row(deserializer);
-    var var_semester2 = sse_decode_output_hours_row(deserializer);
-    var var_year = sse_decode_output_hours_row(deserializer);
-    return OutputMainRateAndHours(
-      rate: var_rate,
-      semester1: var_semester1,
-      semester2: var_semester2,
-      year: var_year,
-    );
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  OutputMainTable sse_decode_output_main_table(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_heads = sse_decode_list_output_main_worker_row(deserializer);
-    var var_headsTotal = sse_decode_output_main_rate_and_hours(deserializer);
-    var var_professors = sse_decode_list_output_main_worker_row(deserializer);
-    var var_professorsTotal = sse_decode_output_main_rate_and_hours(
-      deserializer,
-    );
-    var var_associateProfessors = sse_decode_list_output_main_worker_row(
-      deserializer,
-    );
-    var var_associateProfessorsTotal = sse_decode_output_main_rate_and_hours(
-      deserializer,
-    );
-    var var_lecturers = sse_decode_list_output_main_worker_row(deserializer);
-    var var_lecturersTotal = sse_decode_output_main_rate_and_hours(
-      deserializer,
-    );
-    var var_assistants = sse_decode_list_output_main_worker_row(deserializer);
-    var var_assistantsTotal = sse_decode_output_main_rate_and_hours(
-      deserializer,
-    );
-    var var_partTimers = sse_decode_list_output_main_worker_row(deserializer);
-    var var_partTimersTotal = sse_decode_output_main_rate_and_hours(
-      deserializer,
-    );
-    var var_total = sse_decode_output_main_rate_and_hours(deserializer);
-    return OutputMainTable(
-      heads: var_heads,
-      headsTotal: var_headsTotal,
-      professors: var_professors,
-      professorsTotal: var_professorsTotal,
-      associateProfessors: var_associateProfessors,
-      associateProfessorsTotal: var_associateProfessorsTotal,
-      lecturers: var_lecturers,
-      lecturersTotal: var_lecturersTotal,
-      assistants: var_assistants,
-      assistantsTotal: var_assistantsTotal,
-      partTimers: var_partTimers,
-      partTimersTotal: var_partTimersTotal,
-      total: var_total,
-    );
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  OutputMainWorkerRow sse_decode_output_main_worker_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_rate_norms(
+    List<RateNorms> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_firstName = sse_decode_String(deserializer);
-    var var_middleName = sse_decode_String(deserializer);
-    var var_lastName = sse_decode_String(deserializer);
-    var var_rank = sse_decode_String(deserializer);
-    var var_rateAndHours = sse_decode_output_main_rate_and_hours(deserializer);
-    return OutputMainWorkerRow(
-      firstName: var_firstName,
-      middleName: var_middleName,
-      lastName: var_lastName,
-      rank: var_rank,
-      rateAndHours: var_rateAndHours,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rate_norms(item, serializer);
+    }
   }
 
   @protected
-  OutputPersonalSemesterRow sse_decode_output_personal_semester_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_reconcile_conflict(
+    List<ReconcileConflict> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_learningForm = sse_decode_String(deserializer);
-    var var_speciality = sse_decode_String(deserializer);
-    var var_group = sse_decode_String(deserializer);
-    var var_course = sse_decode_String(deserializer);
-    var var_studentsCount = sse_decode_u_32(deserializer);
-    var var_hours = sse_decode_output_hours_row(deserializer);
-    var var_mergeLecturesWithNext = sse_decode_bool(deserializer);
-    return OutputPersonalSemesterRow(
-      name: var_name,
-      learningForm: var_learningForm,
-      speciality: var_speciality,
-      group: var_group,
-      course: var_course,
-      studentsCount: var_studentsCount,
-      hours: var_hours,
-      mergeLecturesWithNext: var_mergeLecturesWithNext,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_reconcile_conflict(item, serializer);
+    }
   }
 
   @protected
-  OutputPersonalTable sse_decode_output_personal_table(
-    SseDeserializer deserializer,
+  void sse_encode_list_record_string_list_input_raw_row(
+    List<(String, List<InputRawRow>)> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_firstName = sse_decode_String(deserializer);
-    var var_middleName = sse_decode_String(deserializer);
-    var var_lastName = sse_decode_String(deserializer);
-    var var_commentSemester1 = sse_decode_String(deserializer);
-    var var_commentSemester2 = sse_decode_String(deserializer);
-    var var_rank = sse_decode_String(deserializer);
-    var var_semester1 = sse_decode_list_output_personal_semester_row(
-      deserializer,
-    );
-    var var_semester1Rate = sse_decode_f_64(deserializer);
-    var var_semester1TotalDay = sse_decode_output_hours_row(deserializer);
-    var var_semester1TotalEvening = sse_decode_output_hours_row(deserializer);
-    var var_semester1Total = sse_decode_output_hours_row(deserializer);
-    var var_semester2 = sse_decode_list_output_personal_semester_row(
-      deserializer,
-    );
-    var var_semester2Rate = sse_decode_f_64(deserializer);
-    var var_semester2TotalDay = sse_decode_output_hours_row(deserializer);
-    var var_semester2TotalEvening = sse_decode_output_hours_row(deserializer);
-    var var_semester2Total = sse_decode_output_hours_row(deserializer);
-    var var_yearTotal = sse_decode_output_hours_row(deserializer);
-    return OutputPersonalTable(
-      id: var_id,
-      firstName: var_firstName,
-      middleName: var_middleName,
-      lastName: var_lastName,
-      commentSemester1: var_commentSemester1,
-      commentSemester2: var_commentSemester2,
-      rank: var_rank,
-      semester1: var_semester1,
-      semester1Rate: var_semester1Rate,
-      semester1TotalDay: var_semester1TotalDay,
-      semester1TotalEvening: var_semester1TotalEvening,
-      semester1Total: var_semester1Total,
-      semester2: var_semester2,
-      semester2Rate: var_semester2Rate,
-      semester2TotalDay: var_semester2TotalDay,
-      semester2TotalEvening: var_semester2TotalEvening,
-      semester2Total: var_semester2Total,
-      yearTotal: var_yearTotal,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_list_input_raw_row(item, serializer);
+    }
   }
 
   @protected
-  OutputPersonalTables sse_decode_output_personal_tables(
-    SseDeserializer deserializer,
+  void sse_encode_list_totals_mismatch(
+    List<TotalsMismatch> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_workerLastName = sse_decode_String(deserializer);
-    var var_tables = sse_decode_list_output_personal_table(deserializer);
-    return OutputPersonalTables(
-      workerLastName: var_workerLastName,
-      tables: var_tables,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_totals_mismatch(item, serializer);
+    }
   }
 
   @protected
-  ParsedExcelFile sse_decode_parsed_excel_file(SseDeserializer deserializer) {
+  void sse_encode_list_undistributed_workload(
+    List<UndistributedWorkload> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_data = sse_decode_Map_String_list_input_raw_row_None(deserializer);
-    return ParsedExcelFile(data: var_data);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_undistributed_workload(item, serializer);
+    }
   }
 
   @protected
-  (String, List<InputRawRow>) sse_decode_record_string_list_input_raw_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_university_form_1_workload_item(
+    List<UniversityForm1WorkloadItem> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_list_input_raw_row(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_university_form_1_workload_item(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_university_form_3_workload_item(
+    List<UniversityForm3WorkloadItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_university_form_3_workload_item(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_workload_column(
+    List<WorkloadColumn> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_workload_column(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_workload_field(
+    List<WorkloadField> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_workload_field(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_workload_section_input(
+    List<WorkloadSectionInput> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_workload_section_input(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Map_String_list_input_raw_row_None(
-    Map<String, List<InputRawRow>> self,
+  void sse_encode_main_table_section(
+    MainTableSection self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_list_input_raw_row(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
+    sse_encode_String(self.title, serializer);
+    sse_encode_list_output_main_worker_row(self.rows, serializer);
+    sse_encode_output_main_rate_and_hours(self.total, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_norm_basis(NormBasis self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_norm_finding(NormFinding self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_u_32(self.row, serializer);
+    sse_encode_discipline_key(self.key, serializer);
+    sse_encode_workload_field(self.field, serializer);
+    sse_encode_f_64(self.fileValue, serializer);
+    sse_encode_f_64(self.normValue, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_exported_data(
-    ExportedData self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_norm_rule(NormRule self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_exported_data(self, serializer);
+    sse_encode_workload_field(self.field, serializer);
+    sse_encode_norm_basis(self.basis, serializer);
+    sse_encode_f_64(self.hoursPerUnit, serializer);
   }
 
   @protected
-  void sse_encode_exported_data(ExportedData self, SseSerializer serializer) {
+  void sse_encode_norms_table(NormsTable self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.year, serializer);
-    sse_encode_String(self.typeName, serializer);
-    sse_encode_output_main_table(self.mainTable, serializer);
-    sse_encode_list_output_personal_tables(self.personalTables, serializer);
+    sse_encode_list_norm_rule(self.rules, serializer);
+    sse_encode_f_64(self.tolerance, serializer);
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_input_raw_row(InputRawRow self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_academic_calendar(
+    AcademicCalendar? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.learningForm, serializer);
-    sse_encode_String(self.speciality, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.course, serializer);
-    sse_encode_String(self.semester, serializer);
-    sse_encode_String(self.weeksCount, serializer);
-    sse_encode_String(self.studentsCount, serializer);
-    sse_encode_String(self.flowsCount, serializer);
-    sse_encode_String(self.groupsCount, serializer);
-    sse_encode_String(self.subgroupsCount, serializer);
-    sse_encode_String(self.lecturesPlannedCount, serializer);
-    sse_encode_String(self.lecturesTotalCount, serializer);
-    sse_encode_String(self.practicesPlannedCount, serializer);
-    sse_encode_String(self.practicesTotalCount, serializer);
-    sse_encode_String(self.labsPlannedCount, serializer);
-    sse_encode_String(self.labsTotalCount, serializer);
-    sse_encode_String(self.exams, serializer);
-    sse_encode_String(self.examConsults, serializer);
-    sse_encode_String(self.tests, serializer);
-    sse_encode_String(self.qualWorks, serializer);
-    sse_encode_String(self.certificationExams, serializer);
-    sse_encode_String(self.workingPractice, serializer);
-    sse_encode_String(self.teachingPractice, serializer);
-    sse_encode_String(self.consults, serializer);
-    sse_encode_String(self.individualWorks, serializer);
-    sse_encode_String(self.individualWorkTypes, serializer);
-    sse_encode_String(self.courseWorks, serializer);
-    sse_encode_String(self.postgraduateExams, serializer);
-    sse_encode_String(self.supervising, serializer);
-    sse_encode_String(self.internship, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_academic_calendar(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_input_raw_row(
-    List<InputRawRow> self,
+  void sse_encode_opt_box_autoadd_discipline_key(
+    DisciplineKey? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_input_raw_row(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_discipline_key(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_output_main_worker_row(
-    List<OutputMainWorkerRow> self,
+  void sse_encode_opt_box_autoadd_distribution_project(
+    DistributionProject? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_output_main_worker_row(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_distribution_project(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_output_personal_semester_row(
-    List<OutputPersonalSemesterRow> self,
+  void sse_encode_opt_box_autoadd_export_theme(
+    ExportTheme? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_output_personal_semester_row(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_export_theme(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_output_personal_table(
-    List<OutputPersonalTable> self,
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_norms_table(
+    NormsTable? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_output_personal_table(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_norms_table(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_output_personal_tables(
-    List<OutputPersonalTables> self,
+  void sse_encode_opt_box_autoadd_pdf_fonts(
+    PdfFonts? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_output_personal_tables(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pdf_fonts(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_workload_field(
+    WorkloadField? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_workload_field(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_record_string_list_input_raw_row(
-    List<(String, List<InputRawRow>)> self,
+  void sse_encode_opt_list_workload_column(
+    List<WorkloadColumn>? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_list_input_raw_row(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_workload_column(self, serializer);
     }
   }
 
@@ -1152,12 +5727,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_f_64(self.examConsults, serializer);
     sse_encode_f_64(self.tests, serializer);
     sse_encode_f_64(self.qualWorks, serializer);
+    sse_encode_f_64(self.certificationExams, serializer);
     sse_encode_f_64(self.workingPractice, serializer);
     sse_encode_f_64(self.teachingPractice, serializer);
     sse_encode_f_64(self.consults, serializer);
     sse_encode_f_64(self.individualWorks, serializer);
     sse_encode_f_64(self.courseWorks, serializer);
+    sse_encode_f_64(self.postgraduateExams, serializer);
     sse_encode_f_64(self.supervising, serializer);
+    sse_encode_f_64(self.internship, serializer);
     sse_encode_f_64(self.total, serializer);
   }
 
@@ -1179,24 +5757,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_output_main_worker_row(self.heads, serializer);
-    sse_encode_output_main_rate_and_hours(self.headsTotal, serializer);
-    sse_encode_list_output_main_worker_row(self.professors, serializer);
-    sse_encode_output_main_rate_and_hours(self.professorsTotal, serializer);
-    sse_encode_list_output_main_worker_row(
-      self.associateProfessors,
-      serializer,
-    );
-    sse_encode_output_main_rate_and_hours(
-      self.associateProfessorsTotal,
-      serializer,
-    );
-    sse_encode_list_output_main_worker_row(self.lecturers, serializer);
-    sse_encode_output_main_rate_and_hours(self.lecturersTotal, serializer);
-    sse_encode_list_output_main_worker_row(self.assistants, serializer);
-    sse_encode_output_main_rate_and_hours(self.assistantsTotal, serializer);
-    sse_encode_list_output_main_worker_row(self.partTimers, serializer);
-    sse_encode_output_main_rate_and_hours(self.partTimersTotal, serializer);
+    sse_encode_list_main_table_section(self.sections, serializer);
     sse_encode_output_main_rate_and_hours(self.total, serializer);
   }
 
@@ -1225,6 +5786,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.group, serializer);
     sse_encode_String(self.course, serializer);
     sse_encode_u_32(self.studentsCount, serializer);
+    sse_encode_String(self.individualWorkTypes, serializer);
     sse_encode_output_hours_row(self.hours, serializer);
     sse_encode_bool(self.mergeLecturesWithNext, serializer);
   }
@@ -1244,13 +5806,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.rank, serializer);
     sse_encode_list_output_personal_semester_row(self.semester1, serializer);
     sse_encode_f_64(self.semester1Rate, serializer);
-    sse_encode_output_hours_row(self.semester1TotalDay, serializer);
-    sse_encode_output_hours_row(self.semester1TotalEvening, serializer);
+    sse_encode_list_learning_form_total(self.semester1FormTotals, serializer);
     sse_encode_output_hours_row(self.semester1Total, serializer);
     sse_encode_list_output_personal_semester_row(self.semester2, serializer);
     sse_encode_f_64(self.semester2Rate, serializer);
-    sse_encode_output_hours_row(self.semester2TotalDay, serializer);
-    sse_encode_output_hours_row(self.semester2TotalEvening, serializer);
+    sse_encode_list_learning_form_total(self.semester2FormTotals, serializer);
     sse_encode_output_hours_row(self.semester2Total, serializer);
     sse_encode_output_hours_row(self.yearTotal, serializer);
   }
@@ -1274,6 +5834,82 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_Map_String_list_input_raw_row_None(self.data, serializer);
   }
 
+  @protected
+  void sse_encode_pdf_fonts(PdfFonts self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.regular, serializer);
+    sse_encode_opt_String(self.bold, serializer);
+    sse_encode_opt_String(self.italic, serializer);
+    sse_encode_opt_String(self.boldItalic, serializer);
+  }
+
+  @protected
+  void sse_encode_period_norm(PeriodNorm self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.rateMonths, serializer);
+    sse_encode_f_64(self.supervisionHours, serializer);
+    sse_encode_f_64(self.minHours, serializer);
+    sse_encode_f_64(self.maxHours, serializer);
+    sse_encode_f_64(self.hours, serializer);
+  }
+
+  @protected
+  void sse_encode_personal_sheet_name(
+    PersonalSheetName self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.workerLastName, serializer);
+    sse_encode_list_prim_u_32_strict(self.tableIds, serializer);
+    sse_encode_String(self.sheetName, serializer);
+  }
+
+  @protected
+  void sse_encode_rate_norms(RateNorms self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.rateId, serializer);
+    sse_encode_f_64(self.rateValue, serializer);
+    sse_encode_f_64(self.months, serializer);
+    sse_encode_period_norm(self.firstSemester, serializer);
+    sse_encode_period_norm(self.secondSemester, serializer);
+    sse_encode_period_norm(self.year, serializer);
+  }
+
+  @protected
+  void sse_encode_reconcile_conflict(
+    ReconcileConflict self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_reconcile_conflict_kind(self.kind, serializer);
+    sse_encode_discipline_key(self.key, serializer);
+    sse_encode_opt_box_autoadd_workload_field(self.field, serializer);
+    sse_encode_f_64(self.total, serializer);
+    sse_encode_f_64(self.distributed, serializer);
+    sse_encode_list_String(self.employees, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_reconcile_conflict_kind(
+    ReconcileConflictKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_reconcile_result(
+    ReconcileResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_distribution_project(self.project, serializer);
+    sse_encode_form_1_diff(self.diff, serializer);
+    sse_encode_list_reconcile_conflict(self.conflicts, serializer);
+  }
+
   @protected
   void sse_encode_record_string_list_input_raw_row(
     (String, List<InputRawRow>) self,
@@ -1284,6 +5920,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_input_raw_row(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_solver_options(SolverOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_employee_preferences(self.preferences, serializer);
+    sse_encode_list_field_rank_rule(self.rankRules, serializer);
+  }
+
+  @protected
+  void sse_encode_solver_result(SolverResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_distribution_project(self.project, serializer);
+    sse_encode_list_undistributed_workload(self.unplaced, serializer);
+    sse_encode_list_discipline_key(self.splitItems, serializer);
+    sse_encode_list_employee_workload_summary(self.employees, serializer);
+  }
+
+  @protected
+  void sse_encode_totals_mismatch(
+    TotalsMismatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.location, serializer);
+    sse_encode_String(self.field, serializer);
+    sse_encode_f_64(self.expected, serializer);
+    sse_encode_f_64(self.actual, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1296,14 +5960,147 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8(self);
   }
 
+  @protected
+  void sse_encode_undistributed_workload(
+    UndistributedWorkload self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_discipline_key(self.workloadKey, serializer);
+    sse_encode_workload_field(self.field, serializer);
+    sse_encode_f_64(self.total, serializer);
+    sse_encode_f_64(self.distributed, serializer);
+    sse_encode_f_64(self.undistributed, serializer);
+  }
+
   @protected
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_university_form_1(
+    UniversityForm1 self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_String(self.id, serializer);
+    sse_encode_u_32(self.academicYear, serializer);
+    sse_encode_list_university_form_1_workload_item(
+      self.workloadItems,
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_university_form_1_workload_item(
+    UniversityForm1WorkloadItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_discipline_key(self.workloadKey, serializer);
+    sse_encode_f_64(self.weekCount, serializer);
+    sse_encode_u_32(self.studentCount, serializer);
+    sse_encode_f_64(self.flowCount, serializer);
+    sse_encode_f_64(self.groupCount, serializer);
+    sse_encode_f_64(self.subgroupCount, serializer);
+    sse_encode_f_64(self.lecturesPlanned, serializer);
+    sse_encode_f_64(self.lecturesTotal, serializer);
+    sse_encode_f_64(self.practicesPlanned, serializer);
+    sse_encode_f_64(self.practicesTotal, serializer);
+    sse_encode_f_64(self.labsPlanned, serializer);
+    sse_encode_f_64(self.labsTotal, serializer);
+    sse_encode_f_64(self.exams, serializer);
+    sse_encode_f_64(self.examConsults, serializer);
+    sse_encode_f_64(self.tests, serializer);
+    sse_encode_f_64(self.qualificationWorks, serializer);
+    sse_encode_f_64(self.certificationExams, serializer);
+    sse_encode_f_64(self.productionPractices, serializer);
+    sse_encode_f_64(self.teachingPractices, serializer);
+    sse_encode_f_64(self.currentConsults, serializer);
+    sse_encode_f_64(self.individualWorks, serializer);
+    sse_encode_f_64(self.courseWorks, serializer);
+    sse_encode_f_64(self.postgraduateExams, serializer);
+  }
+
+  @protected
+  void sse_encode_university_form_3(
+    UniversityForm3 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_u_32(self.academicYear, serializer);
+    sse_encode_list_employee(self.employees, serializer);
+  }
+
+  @protected
+  void sse_encode_university_form_3_workload_item(
+    UniversityForm3WorkloadItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_discipline_key(self.workloadKey, serializer);
+    sse_encode_u_32(self.studentCount, serializer);
+    sse_encode_list_String(self.academicGroups, serializer);
+    sse_encode_f_64(self.lectures, serializer);
+    sse_encode_f_64(self.practices, serializer);
+    sse_encode_f_64(self.labs, serializer);
+    sse_encode_f_64(self.exams, serializer);
+    sse_encode_f_64(self.examConsults, serializer);
+    sse_encode_f_64(self.tests, serializer);
+    sse_encode_f_64(self.qualificationWorks, serializer);
+    sse_encode_f_64(self.certificationExams, serializer);
+    sse_encode_f_64(self.productionPractices, serializer);
+    sse_encode_f_64(self.teachingPractices, serializer);
+    sse_encode_f_64(self.currentConsults, serializer);
+    sse_encode_f_64(self.individualWorks, serializer);
+    sse_encode_f_64(self.courseWorks, serializer);
+    sse_encode_f_64(self.postgraduateExams, serializer);
+  }
+
+  @protected
+  void sse_encode_workload_column(
+    WorkloadColumn self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_workload_key(self.key, serializer);
+    sse_encode_String(self.caption, serializer);
+    sse_encode_f_64(self.width, serializer);
+    sse_encode_String(self.numberFormat, serializer);
+    sse_encode_bool(self.includeInTotal, serializer);
+  }
+
+  @protected
+  void sse_encode_workload_field(WorkloadField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_workload_input(WorkloadInput self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.year, serializer);
+    sse_encode_String(self.typeName, serializer);
+    sse_encode_list_workload_section_input(self.sections, serializer);
+  }
+
+  @protected
+  void sse_encode_workload_key(WorkloadKey self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_workload_section_input(
+    WorkloadSectionInput self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_list_employee_workload_input(self.employees, serializer);
   }
 }
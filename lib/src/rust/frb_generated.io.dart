@@ -3,10 +3,12 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'package:aw_flutter/src/rust/api/distribution_interface.dart';
 import 'package:aw_flutter/src/rust/api/excel_interface.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
+import 'package:aw_flutter/src/rust/distribution/data.dart';
 import 'package:aw_flutter/src/rust/excel/data.dart';
 import 'package:aw_flutter/src/rust/frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
@@ -27,261 +29,1430 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AcademicCalendar dco_decode_academic_calendar(dynamic raw);
+
+  @protected
+  AcademicSemester dco_decode_academic_semester(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
   @protected
-  ExportedData dco_decode_box_autoadd_exported_data(dynamic raw);
+  AcademicCalendar dco_decode_box_autoadd_academic_calendar(dynamic raw);
+
+  @protected
+  DisciplineKey dco_decode_box_autoadd_discipline_key(dynamic raw);
+
+  @protected
+  DistributionProject dco_decode_box_autoadd_distribution_project(dynamic raw);
+
+  @protected
+  ExportOptions dco_decode_box_autoadd_export_options(dynamic raw);
+
+  @protected
+  ExportTheme dco_decode_box_autoadd_export_theme(dynamic raw);
+
+  @protected
+  ExportedData dco_decode_box_autoadd_exported_data(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  NormsTable dco_decode_box_autoadd_norms_table(dynamic raw);
+
+  @protected
+  ParsedExcelFile dco_decode_box_autoadd_parsed_excel_file(dynamic raw);
+
+  @protected
+  PdfFonts dco_decode_box_autoadd_pdf_fonts(dynamic raw);
+
+  @protected
+  SolverOptions dco_decode_box_autoadd_solver_options(dynamic raw);
+
+  @protected
+  UniversityForm1 dco_decode_box_autoadd_university_form_1(dynamic raw);
+
+  @protected
+  UniversityForm3WorkloadItem
+  dco_decode_box_autoadd_university_form_3_workload_item(dynamic raw);
+
+  @protected
+  WorkloadField dco_decode_box_autoadd_workload_field(dynamic raw);
+
+  @protected
+  WorkloadInput dco_decode_box_autoadd_workload_input(dynamic raw);
+
+  @protected
+  CarriedOverItem dco_decode_carried_over_item(dynamic raw);
+
+  @protected
+  CarryOverResult dco_decode_carry_over_result(dynamic raw);
+
+  @protected
+  DisciplineKey dco_decode_discipline_key(dynamic raw);
+
+  @protected
+  DistributionIssue dco_decode_distribution_issue(dynamic raw);
+
+  @protected
+  DistributionProject dco_decode_distribution_project(dynamic raw);
+
+  @protected
+  Employee dco_decode_employee(dynamic raw);
+
+  @protected
+  EmployeeNorms dco_decode_employee_norms(dynamic raw);
+
+  @protected
+  EmployeePreferences dco_decode_employee_preferences(dynamic raw);
+
+  @protected
+  EmployeeRank dco_decode_employee_rank(dynamic raw);
+
+  @protected
+  EmployeeRate dco_decode_employee_rate(dynamic raw);
+
+  @protected
+  EmployeeWorkloadInput dco_decode_employee_workload_input(dynamic raw);
+
+  @protected
+  EmployeeWorkloadSummary dco_decode_employee_workload_summary(dynamic raw);
+
+  @protected
+  ExportOptions dco_decode_export_options(dynamic raw);
+
+  @protected
+  ExportTheme dco_decode_export_theme(dynamic raw);
+
+  @protected
+  ExportedData dco_decode_exported_data(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FieldChange dco_decode_field_change(dynamic raw);
+
+  @protected
+  FieldRankRule dco_decode_field_rank_rule(dynamic raw);
+
+  @protected
+  Form1Diff dco_decode_form_1_diff(dynamic raw);
+
+  @protected
+  Form1DiffEntry dco_decode_form_1_diff_entry(dynamic raw);
+
+  @protected
+  Form1Finding dco_decode_form_1_finding(dynamic raw);
+
+  @protected
+  Form1FindingKind dco_decode_form_1_finding_kind(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  InputRawRow dco_decode_input_raw_row(dynamic raw);
+
+  @protected
+  LearningForm dco_decode_learning_form(dynamic raw);
+
+  @protected
+  LearningFormTotal dco_decode_learning_form_total(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<CarriedOverItem> dco_decode_list_carried_over_item(dynamic raw);
+
+  @protected
+  List<DisciplineKey> dco_decode_list_discipline_key(dynamic raw);
+
+  @protected
+  List<DistributionIssue> dco_decode_list_distribution_issue(dynamic raw);
+
+  @protected
+  List<Employee> dco_decode_list_employee(dynamic raw);
+
+  @protected
+  List<EmployeeNorms> dco_decode_list_employee_norms(dynamic raw);
+
+  @protected
+  List<EmployeePreferences> dco_decode_list_employee_preferences(dynamic raw);
+
+  @protected
+  List<EmployeeRate> dco_decode_list_employee_rate(dynamic raw);
+
+  @protected
+  List<EmployeeWorkloadInput> dco_decode_list_employee_workload_input(
+    dynamic raw,
+  );
+
+  @protected
+  List<EmployeeWorkloadSummary> dco_decode_list_employee_workload_summary(
+    dynamic raw,
+  );
+
+  @protected
+  List<FieldChange> dco_decode_list_field_change(dynamic raw);
+
+  @protected
+  List<FieldRankRule> dco_decode_list_field_rank_rule(dynamic raw);
+
+  @protected
+  List<Form1DiffEntry> dco_decode_list_form_1_diff_entry(dynamic raw);
+
+  @protected
+  List<Form1Finding> dco_decode_list_form_1_finding(dynamic raw);
+
+  @protected
+  List<InputRawRow> dco_decode_list_input_raw_row(dynamic raw);
+
+  @protected
+  List<LearningFormTotal> dco_decode_list_learning_form_total(dynamic raw);
+
+  @protected
+  List<MainTableSection> dco_decode_list_main_table_section(dynamic raw);
+
+  @protected
+  List<NormFinding> dco_decode_list_norm_finding(dynamic raw);
+
+  @protected
+  List<NormRule> dco_decode_list_norm_rule(dynamic raw);
+
+  @protected
+  List<OutputMainWorkerRow> dco_decode_list_output_main_worker_row(dynamic raw);
+
+  @protected
+  List<OutputPersonalSemesterRow> dco_decode_list_output_personal_semester_row(
+    dynamic raw,
+  );
+
+  @protected
+  List<OutputPersonalTable> dco_decode_list_output_personal_table(dynamic raw);
+
+  @protected
+  List<OutputPersonalTables> dco_decode_list_output_personal_tables(
+    dynamic raw,
+  );
+
+  @protected
+  List<PersonalSheetName> dco_decode_list_personal_sheet_name(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<RateNorms> dco_decode_list_rate_norms(dynamic raw);
+
+  @protected
+  List<ReconcileConflict> dco_decode_list_reconcile_conflict(dynamic raw);
+
+  @protected
+  List<(String, List<InputRawRow>)>
+  dco_decode_list_record_string_list_input_raw_row(dynamic raw);
+
+  @protected
+  List<TotalsMismatch> dco_decode_list_totals_mismatch(dynamic raw);
+
+  @protected
+  List<UndistributedWorkload> dco_decode_list_undistributed_workload(
+    dynamic raw,
+  );
+
+  @protected
+  List<UniversityForm1WorkloadItem>
+  dco_decode_list_university_form_1_workload_item(dynamic raw);
+
+  @protected
+  List<UniversityForm3WorkloadItem>
+  dco_decode_list_university_form_3_workload_item(dynamic raw);
+
+  @protected
+  List<WorkloadColumn> dco_decode_list_workload_column(dynamic raw);
+
+  @protected
+  List<WorkloadField> dco_decode_list_workload_field(dynamic raw);
+
+  @protected
+  List<WorkloadSectionInput> dco_decode_list_workload_section_input(
+    dynamic raw,
+  );
+
+  @protected
+  MainTableSection dco_decode_main_table_section(dynamic raw);
+
+  @protected
+  NormBasis dco_decode_norm_basis(dynamic raw);
+
+  @protected
+  NormFinding dco_decode_norm_finding(dynamic raw);
+
+  @protected
+  NormRule dco_decode_norm_rule(dynamic raw);
+
+  @protected
+  NormsTable dco_decode_norms_table(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  AcademicCalendar? dco_decode_opt_box_autoadd_academic_calendar(dynamic raw);
+
+  @protected
+  DisciplineKey? dco_decode_opt_box_autoadd_discipline_key(dynamic raw);
+
+  @protected
+  DistributionProject? dco_decode_opt_box_autoadd_distribution_project(
+    dynamic raw,
+  );
+
+  @protected
+  ExportTheme? dco_decode_opt_box_autoadd_export_theme(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  NormsTable? dco_decode_opt_box_autoadd_norms_table(dynamic raw);
+
+  @protected
+  PdfFonts? dco_decode_opt_box_autoadd_pdf_fonts(dynamic raw);
+
+  @protected
+  WorkloadField? dco_decode_opt_box_autoadd_workload_field(dynamic raw);
+
+  @protected
+  List<WorkloadColumn>? dco_decode_opt_list_workload_column(dynamic raw);
+
+  @protected
+  OutputHoursRow dco_decode_output_hours_row(dynamic raw);
+
+  @protected
+  OutputMainRateAndHours dco_decode_output_main_rate_and_hours(dynamic raw);
+
+  @protected
+  OutputMainTable dco_decode_output_main_table(dynamic raw);
+
+  @protected
+  OutputMainWorkerRow dco_decode_output_main_worker_row(dynamic raw);
+
+  @protected
+  OutputPersonalSemesterRow dco_decode_output_personal_semester_row(
+    dynamic raw,
+  );
+
+  @protected
+  OutputPersonalTable dco_decode_output_personal_table(dynamic raw);
+
+  @protected
+  OutputPersonalTables dco_decode_output_personal_tables(dynamic raw);
+
+  @protected
+  ParsedExcelFile dco_decode_parsed_excel_file(dynamic raw);
+
+  @protected
+  PdfFonts dco_decode_pdf_fonts(dynamic raw);
+
+  @protected
+  PeriodNorm dco_decode_period_norm(dynamic raw);
+
+  @protected
+  PersonalSheetName dco_decode_personal_sheet_name(dynamic raw);
+
+  @protected
+  RateNorms dco_decode_rate_norms(dynamic raw);
+
+  @protected
+  ReconcileConflict dco_decode_reconcile_conflict(dynamic raw);
+
+  @protected
+  ReconcileConflictKind dco_decode_reconcile_conflict_kind(dynamic raw);
+
+  @protected
+  ReconcileResult dco_decode_reconcile_result(dynamic raw);
+
+  @protected
+  (String, List<InputRawRow>) dco_decode_record_string_list_input_raw_row(
+    dynamic raw,
+  );
+
+  @protected
+  SolverOptions dco_decode_solver_options(dynamic raw);
+
+  @protected
+  SolverResult dco_decode_solver_result(dynamic raw);
+
+  @protected
+  TotalsMismatch dco_decode_totals_mismatch(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  UndistributedWorkload dco_decode_undistributed_workload(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  UniversityForm1 dco_decode_university_form_1(dynamic raw);
+
+  @protected
+  UniversityForm1WorkloadItem dco_decode_university_form_1_workload_item(
+    dynamic raw,
+  );
+
+  @protected
+  UniversityForm3 dco_decode_university_form_3(dynamic raw);
+
+  @protected
+  UniversityForm3WorkloadItem dco_decode_university_form_3_workload_item(
+    dynamic raw,
+  );
+
+  @protected
+  WorkloadColumn dco_decode_workload_column(dynamic raw);
+
+  @protected
+  WorkloadField dco_decode_workload_field(dynamic raw);
+
+  @protected
+  WorkloadInput dco_decode_workload_input(dynamic raw);
+
+  @protected
+  WorkloadKey dco_decode_workload_key(dynamic raw);
+
+  @protected
+  WorkloadSectionInput dco_decode_workload_section_input(dynamic raw);
+
+  @protected
+  Map<String, List<InputRawRow>> sse_decode_Map_String_list_input_raw_row_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AcademicCalendar sse_decode_academic_calendar(SseDeserializer deserializer);
+
+  @protected
+  AcademicSemester sse_decode_academic_semester(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AcademicCalendar sse_decode_box_autoadd_academic_calendar(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DisciplineKey sse_decode_box_autoadd_discipline_key(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DistributionProject sse_decode_box_autoadd_distribution_project(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExportOptions sse_decode_box_autoadd_export_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExportTheme sse_decode_box_autoadd_export_theme(SseDeserializer deserializer);
+
+  @protected
+  ExportedData sse_decode_box_autoadd_exported_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  NormsTable sse_decode_box_autoadd_norms_table(SseDeserializer deserializer);
+
+  @protected
+  ParsedExcelFile sse_decode_box_autoadd_parsed_excel_file(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PdfFonts sse_decode_box_autoadd_pdf_fonts(SseDeserializer deserializer);
+
+  @protected
+  SolverOptions sse_decode_box_autoadd_solver_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  UniversityForm1 sse_decode_box_autoadd_university_form_1(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  UniversityForm3WorkloadItem
+  sse_decode_box_autoadd_university_form_3_workload_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WorkloadField sse_decode_box_autoadd_workload_field(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WorkloadInput sse_decode_box_autoadd_workload_input(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CarriedOverItem sse_decode_carried_over_item(SseDeserializer deserializer);
+
+  @protected
+  CarryOverResult sse_decode_carry_over_result(SseDeserializer deserializer);
+
+  @protected
+  DisciplineKey sse_decode_discipline_key(SseDeserializer deserializer);
+
+  @protected
+  DistributionIssue sse_decode_distribution_issue(SseDeserializer deserializer);
+
+  @protected
+  DistributionProject sse_decode_distribution_project(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Employee sse_decode_employee(SseDeserializer deserializer);
+
+  @protected
+  EmployeeNorms sse_decode_employee_norms(SseDeserializer deserializer);
+
+  @protected
+  EmployeePreferences sse_decode_employee_preferences(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EmployeeRank sse_decode_employee_rank(SseDeserializer deserializer);
+
+  @protected
+  EmployeeRate sse_decode_employee_rate(SseDeserializer deserializer);
+
+  @protected
+  EmployeeWorkloadInput sse_decode_employee_workload_input(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EmployeeWorkloadSummary sse_decode_employee_workload_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExportOptions sse_decode_export_options(SseDeserializer deserializer);
+
+  @protected
+  ExportTheme sse_decode_export_theme(SseDeserializer deserializer);
+
+  @protected
+  ExportedData sse_decode_exported_data(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FieldChange sse_decode_field_change(SseDeserializer deserializer);
+
+  @protected
+  FieldRankRule sse_decode_field_rank_rule(SseDeserializer deserializer);
+
+  @protected
+  Form1Diff sse_decode_form_1_diff(SseDeserializer deserializer);
+
+  @protected
+  Form1DiffEntry sse_decode_form_1_diff_entry(SseDeserializer deserializer);
+
+  @protected
+  Form1Finding sse_decode_form_1_finding(SseDeserializer deserializer);
+
+  @protected
+  Form1FindingKind sse_decode_form_1_finding_kind(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  InputRawRow sse_decode_input_raw_row(SseDeserializer deserializer);
+
+  @protected
+  LearningForm sse_decode_learning_form(SseDeserializer deserializer);
+
+  @protected
+  LearningFormTotal sse_decode_learning_form_total(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<CarriedOverItem> sse_decode_list_carried_over_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DisciplineKey> sse_decode_list_discipline_key(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DistributionIssue> sse_decode_list_distribution_issue(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Employee> sse_decode_list_employee(SseDeserializer deserializer);
+
+  @protected
+  List<EmployeeNorms> sse_decode_list_employee_norms(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EmployeePreferences> sse_decode_list_employee_preferences(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EmployeeRate> sse_decode_list_employee_rate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EmployeeWorkloadInput> sse_decode_list_employee_workload_input(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EmployeeWorkloadSummary> sse_decode_list_employee_workload_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FieldChange> sse_decode_list_field_change(SseDeserializer deserializer);
+
+  @protected
+  List<FieldRankRule> sse_decode_list_field_rank_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Form1DiffEntry> sse_decode_list_form_1_diff_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Form1Finding> sse_decode_list_form_1_finding(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<InputRawRow> sse_decode_list_input_raw_row(SseDeserializer deserializer);
+
+  @protected
+  List<LearningFormTotal> sse_decode_list_learning_form_total(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MainTableSection> sse_decode_list_main_table_section(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NormFinding> sse_decode_list_norm_finding(SseDeserializer deserializer);
+
+  @protected
+  List<NormRule> sse_decode_list_norm_rule(SseDeserializer deserializer);
+
+  @protected
+  List<OutputMainWorkerRow> sse_decode_list_output_main_worker_row(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<OutputPersonalSemesterRow> sse_decode_list_output_personal_semester_row(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<OutputPersonalTable> sse_decode_list_output_personal_table(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<OutputPersonalTables> sse_decode_list_output_personal_tables(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PersonalSheetName> sse_decode_list_personal_sheet_name(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<RateNorms> sse_decode_list_rate_norms(SseDeserializer deserializer);
+
+  @protected
+  List<ReconcileConflict> sse_decode_list_reconcile_conflict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, List<InputRawRow>)>
+  sse_decode_list_record_string_list_input_raw_row(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TotalsMismatch> sse_decode_list_totals_mismatch(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<UndistributedWorkload> sse_decode_list_undistributed_workload(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<UniversityForm1WorkloadItem>
+  sse_decode_list_university_form_1_workload_item(SseDeserializer deserializer);
+
+  @protected
+  List<UniversityForm3WorkloadItem>
+  sse_decode_list_university_form_3_workload_item(SseDeserializer deserializer);
+
+  @protected
+  List<WorkloadColumn> sse_decode_list_workload_column(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WorkloadField> sse_decode_list_workload_field(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WorkloadSectionInput> sse_decode_list_workload_section_input(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MainTableSection sse_decode_main_table_section(SseDeserializer deserializer);
+
+  @protected
+  NormBasis sse_decode_norm_basis(SseDeserializer deserializer);
+
+  @protected
+  NormFinding sse_decode_norm_finding(SseDeserializer deserializer);
+
+  @protected
+  NormRule sse_decode_norm_rule(SseDeserializer deserializer);
+
+  @protected
+  NormsTable sse_decode_norms_table(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AcademicCalendar? sse_decode_opt_box_autoadd_academic_calendar(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DisciplineKey? sse_decode_opt_box_autoadd_discipline_key(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DistributionProject? sse_decode_opt_box_autoadd_distribution_project(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExportTheme? sse_decode_opt_box_autoadd_export_theme(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  NormsTable? sse_decode_opt_box_autoadd_norms_table(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PdfFonts? sse_decode_opt_box_autoadd_pdf_fonts(SseDeserializer deserializer);
+
+  @protected
+  WorkloadField? sse_decode_opt_box_autoadd_workload_field(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WorkloadColumn>? sse_decode_opt_list_workload_column(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputHoursRow sse_decode_output_hours_row(SseDeserializer deserializer);
+
+  @protected
+  OutputMainRateAndHours sse_decode_output_main_rate_and_hours(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputMainTable sse_decode_output_main_table(SseDeserializer deserializer);
+
+  @protected
+  OutputMainWorkerRow sse_decode_output_main_worker_row(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputPersonalSemesterRow sse_decode_output_personal_semester_row(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputPersonalTable sse_decode_output_personal_table(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputPersonalTables sse_decode_output_personal_tables(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ParsedExcelFile sse_decode_parsed_excel_file(SseDeserializer deserializer);
+
+  @protected
+  PdfFonts sse_decode_pdf_fonts(SseDeserializer deserializer);
+
+  @protected
+  PeriodNorm sse_decode_period_norm(SseDeserializer deserializer);
+
+  @protected
+  PersonalSheetName sse_decode_personal_sheet_name(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RateNorms sse_decode_rate_norms(SseDeserializer deserializer);
+
+  @protected
+  ReconcileConflict sse_decode_reconcile_conflict(SseDeserializer deserializer);
+
+  @protected
+  ReconcileConflictKind sse_decode_reconcile_conflict_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReconcileResult sse_decode_reconcile_result(SseDeserializer deserializer);
+
+  @protected
+  (String, List<InputRawRow>) sse_decode_record_string_list_input_raw_row(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SolverOptions sse_decode_solver_options(SseDeserializer deserializer);
+
+  @protected
+  SolverResult sse_decode_solver_result(SseDeserializer deserializer);
+
+  @protected
+  TotalsMismatch sse_decode_totals_mismatch(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  UndistributedWorkload sse_decode_undistributed_workload(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  UniversityForm1 sse_decode_university_form_1(SseDeserializer deserializer);
+
+  @protected
+  UniversityForm1WorkloadItem sse_decode_university_form_1_workload_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  UniversityForm3 sse_decode_university_form_3(SseDeserializer deserializer);
+
+  @protected
+  UniversityForm3WorkloadItem sse_decode_university_form_3_workload_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WorkloadColumn sse_decode_workload_column(SseDeserializer deserializer);
+
+  @protected
+  WorkloadField sse_decode_workload_field(SseDeserializer deserializer);
+
+  @protected
+  WorkloadInput sse_decode_workload_input(SseDeserializer deserializer);
+
+  @protected
+  WorkloadKey sse_decode_workload_key(SseDeserializer deserializer);
+
+  @protected
+  WorkloadSectionInput sse_decode_workload_section_input(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_list_input_raw_row_None(
+    Map<String, List<InputRawRow>> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_academic_calendar(
+    AcademicCalendar self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_academic_semester(
+    AcademicSemester self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_academic_calendar(
+    AcademicCalendar self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_discipline_key(
+    DisciplineKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_distribution_project(
+    DistributionProject self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_export_options(
+    ExportOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_export_theme(
+    ExportTheme self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_exported_data(
+    ExportedData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_norms_table(
+    NormsTable self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_parsed_excel_file(
+    ParsedExcelFile self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_pdf_fonts(
+    PdfFonts self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_solver_options(
+    SolverOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_university_form_1(
+    UniversityForm1 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_university_form_3_workload_item(
+    UniversityForm3WorkloadItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_workload_field(
+    WorkloadField self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_workload_input(
+    WorkloadInput self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_carried_over_item(
+    CarriedOverItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_carry_over_result(
+    CarryOverResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_discipline_key(DisciplineKey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_distribution_issue(
+    DistributionIssue self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_distribution_project(
+    DistributionProject self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_employee(Employee self, SseSerializer serializer);
 
   @protected
-  ExportedData dco_decode_exported_data(dynamic raw);
+  void sse_encode_employee_norms(EmployeeNorms self, SseSerializer serializer);
 
   @protected
-  double dco_decode_f_64(dynamic raw);
+  void sse_encode_employee_preferences(
+    EmployeePreferences self,
+    SseSerializer serializer,
+  );
 
   @protected
-  InputRawRow dco_decode_input_raw_row(dynamic raw);
+  void sse_encode_employee_rank(EmployeeRank self, SseSerializer serializer);
 
   @protected
-  List<InputRawRow> dco_decode_list_input_raw_row(dynamic raw);
+  void sse_encode_employee_rate(EmployeeRate self, SseSerializer serializer);
 
   @protected
-  List<OutputMainWorkerRow> dco_decode_list_output_main_worker_row(dynamic raw);
+  void sse_encode_employee_workload_input(
+    EmployeeWorkloadInput self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<OutputPersonalSemesterRow> dco_decode_list_output_personal_semester_row(
-    dynamic raw,
+  void sse_encode_employee_workload_summary(
+    EmployeeWorkloadSummary self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<OutputPersonalTable> dco_decode_list_output_personal_table(dynamic raw);
+  void sse_encode_export_options(ExportOptions self, SseSerializer serializer);
 
   @protected
-  List<OutputPersonalTables> dco_decode_list_output_personal_tables(
-    dynamic raw,
-  );
+  void sse_encode_export_theme(ExportTheme self, SseSerializer serializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_exported_data(ExportedData self, SseSerializer serializer);
 
   @protected
-  List<(String, List<InputRawRow>)>
-  dco_decode_list_record_string_list_input_raw_row(dynamic raw);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  OutputHoursRow dco_decode_output_hours_row(dynamic raw);
+  void sse_encode_field_change(FieldChange self, SseSerializer serializer);
 
   @protected
-  OutputMainRateAndHours dco_decode_output_main_rate_and_hours(dynamic raw);
+  void sse_encode_field_rank_rule(FieldRankRule self, SseSerializer serializer);
 
   @protected
-  OutputMainTable dco_decode_output_main_table(dynamic raw);
+  void sse_encode_form_1_diff(Form1Diff self, SseSerializer serializer);
 
   @protected
-  OutputMainWorkerRow dco_decode_output_main_worker_row(dynamic raw);
+  void sse_encode_form_1_diff_entry(
+    Form1DiffEntry self,
+    SseSerializer serializer,
+  );
 
   @protected
-  OutputPersonalSemesterRow dco_decode_output_personal_semester_row(
-    dynamic raw,
+  void sse_encode_form_1_finding(Form1Finding self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_form_1_finding_kind(
+    Form1FindingKind self,
+    SseSerializer serializer,
   );
 
   @protected
-  OutputPersonalTable dco_decode_output_personal_table(dynamic raw);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  OutputPersonalTables dco_decode_output_personal_tables(dynamic raw);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  ParsedExcelFile dco_decode_parsed_excel_file(dynamic raw);
+  void sse_encode_input_raw_row(InputRawRow self, SseSerializer serializer);
 
   @protected
-  (String, List<InputRawRow>) dco_decode_record_string_list_input_raw_row(
-    dynamic raw,
+  void sse_encode_learning_form(LearningForm self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_learning_form_total(
+    LearningFormTotal self,
+    SseSerializer serializer,
   );
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_list_carried_over_item(
+    List<CarriedOverItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_list_discipline_key(
+    List<DisciplineKey> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Map<String, List<InputRawRow>> sse_decode_Map_String_list_input_raw_row_None(
-    SseDeserializer deserializer,
+  void sse_encode_list_distribution_issue(
+    List<DistributionIssue> self,
+    SseSerializer serializer,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_list_employee(List<Employee> self, SseSerializer serializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_list_employee_norms(
+    List<EmployeeNorms> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ExportedData sse_decode_box_autoadd_exported_data(
-    SseDeserializer deserializer,
+  void sse_encode_list_employee_preferences(
+    List<EmployeePreferences> self,
+    SseSerializer serializer,
   );
 
   @protected
-  ExportedData sse_decode_exported_data(SseDeserializer deserializer);
+  void sse_encode_list_employee_rate(
+    List<EmployeeRate> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  void sse_encode_list_employee_workload_input(
+    List<EmployeeWorkloadInput> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  InputRawRow sse_decode_input_raw_row(SseDeserializer deserializer);
+  void sse_encode_list_employee_workload_summary(
+    List<EmployeeWorkloadSummary> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<InputRawRow> sse_decode_list_input_raw_row(SseDeserializer deserializer);
+  void sse_encode_list_field_change(
+    List<FieldChange> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<OutputMainWorkerRow> sse_decode_list_output_main_worker_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_field_rank_rule(
+    List<FieldRankRule> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<OutputPersonalSemesterRow> sse_decode_list_output_personal_semester_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_form_1_diff_entry(
+    List<Form1DiffEntry> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<OutputPersonalTable> sse_decode_list_output_personal_table(
-    SseDeserializer deserializer,
+  void sse_encode_list_form_1_finding(
+    List<Form1Finding> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<OutputPersonalTables> sse_decode_list_output_personal_tables(
-    SseDeserializer deserializer,
+  void sse_encode_list_input_raw_row(
+    List<InputRawRow> self,
+    SseSerializer serializer,
   );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_list_learning_form_total(
+    List<LearningFormTotal> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<(String, List<InputRawRow>)>
-  sse_decode_list_record_string_list_input_raw_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_main_table_section(
+    List<MainTableSection> self,
+    SseSerializer serializer,
   );
 
   @protected
-  OutputHoursRow sse_decode_output_hours_row(SseDeserializer deserializer);
+  void sse_encode_list_norm_finding(
+    List<NormFinding> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  OutputMainRateAndHours sse_decode_output_main_rate_and_hours(
-    SseDeserializer deserializer,
+  void sse_encode_list_norm_rule(List<NormRule> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_output_main_worker_row(
+    List<OutputMainWorkerRow> self,
+    SseSerializer serializer,
   );
 
   @protected
-  OutputMainTable sse_decode_output_main_table(SseDeserializer deserializer);
+  void sse_encode_list_output_personal_semester_row(
+    List<OutputPersonalSemesterRow> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  OutputMainWorkerRow sse_decode_output_main_worker_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_output_personal_table(
+    List<OutputPersonalTable> self,
+    SseSerializer serializer,
   );
 
   @protected
-  OutputPersonalSemesterRow sse_decode_output_personal_semester_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_output_personal_tables(
+    List<OutputPersonalTables> self,
+    SseSerializer serializer,
   );
 
   @protected
-  OutputPersonalTable sse_decode_output_personal_table(
-    SseDeserializer deserializer,
+  void sse_encode_list_personal_sheet_name(
+    List<PersonalSheetName> self,
+    SseSerializer serializer,
   );
 
   @protected
-  OutputPersonalTables sse_decode_output_personal_tables(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
   );
 
   @protected
-  ParsedExcelFile sse_decode_parsed_excel_file(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  (String, List<InputRawRow>) sse_decode_record_string_list_input_raw_row(
-    SseDeserializer deserializer,
+  void sse_encode_list_rate_norms(
+    List<RateNorms> self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_list_reconcile_conflict(
+    List<ReconcileConflict> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_list_record_string_list_input_raw_row(
+    List<(String, List<InputRawRow>)> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_list_totals_mismatch(
+    List<TotalsMismatch> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_list_undistributed_workload(
+    List<UndistributedWorkload> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_Map_String_list_input_raw_row_None(
-    Map<String, List<InputRawRow>> self,
+  void sse_encode_list_university_form_1_workload_item(
+    List<UniversityForm1WorkloadItem> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_list_university_form_3_workload_item(
+    List<UniversityForm3WorkloadItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_list_workload_column(
+    List<WorkloadColumn> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_exported_data(
-    ExportedData self,
+  void sse_encode_list_workload_field(
+    List<WorkloadField> self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_exported_data(ExportedData self, SseSerializer serializer);
+  void sse_encode_list_workload_section_input(
+    List<WorkloadSectionInput> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_main_table_section(
+    MainTableSection self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_input_raw_row(InputRawRow self, SseSerializer serializer);
+  void sse_encode_norm_basis(NormBasis self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_input_raw_row(
-    List<InputRawRow> self,
+  void sse_encode_norm_finding(NormFinding self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_norm_rule(NormRule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_norms_table(NormsTable self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_academic_calendar(
+    AcademicCalendar? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_output_main_worker_row(
-    List<OutputMainWorkerRow> self,
+  void sse_encode_opt_box_autoadd_discipline_key(
+    DisciplineKey? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_output_personal_semester_row(
-    List<OutputPersonalSemesterRow> self,
+  void sse_encode_opt_box_autoadd_distribution_project(
+    DistributionProject? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_output_personal_table(
-    List<OutputPersonalTable> self,
+  void sse_encode_opt_box_autoadd_export_theme(
+    ExportTheme? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_output_personal_tables(
-    List<OutputPersonalTables> self,
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_norms_table(
+    NormsTable? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_pdf_fonts(
+    PdfFonts? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_record_string_list_input_raw_row(
-    List<(String, List<InputRawRow>)> self,
+  void sse_encode_opt_box_autoadd_workload_field(
+    WorkloadField? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_workload_column(
+    List<WorkloadColumn>? self,
     SseSerializer serializer,
   );
 
@@ -333,23 +1504,116 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_pdf_fonts(PdfFonts self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_period_norm(PeriodNorm self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_personal_sheet_name(
+    PersonalSheetName self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rate_norms(RateNorms self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reconcile_conflict(
+    ReconcileConflict self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reconcile_conflict_kind(
+    ReconcileConflictKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reconcile_result(
+    ReconcileResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_record_string_list_input_raw_row(
     (String, List<InputRawRow>) self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_solver_options(SolverOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_solver_result(SolverResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_totals_mismatch(
+    TotalsMismatch self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_undistributed_workload(
+    UndistributedWorkload self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_university_form_1(
+    UniversityForm1 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_university_form_1_workload_item(
+    UniversityForm1WorkloadItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_university_form_3(
+    UniversityForm3 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_university_form_3_workload_item(
+    UniversityForm3WorkloadItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_workload_column(
+    WorkloadColumn self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_workload_field(WorkloadField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_workload_input(WorkloadInput self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_workload_key(WorkloadKey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_workload_section_input(
+    WorkloadSectionInput self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
@@ -0,0 +1,218 @@
+use crate::distribution::{
+    carry_over, data, export, form1, form1_check, form1_diff, form1_norms, project, rate_norms,
+    reconcile, solver,
+};
+use crate::excel::data::{ExportedData, ParsedExcelFile};
+
+pub fn read_distribution_project(file_path: String) -> Result<data::DistributionProject, String> {
+    project::read_project_file(&file_path)
+}
+
+pub fn write_distribution_project(
+    file_path: String,
+    project: data::DistributionProject,
+) -> Result<(), String> {
+    project::write_project_file(&file_path, &project)
+}
+
+pub fn form1_from_parsed_file(
+    file: ParsedExcelFile,
+    sheet_name: String,
+    academic_year: u32,
+) -> Result<data::UniversityForm1, String> {
+    form1::form1_from_parsed_file(&file, &sheet_name, academic_year)
+}
+
+pub fn get_undistributed_workload(
+    project: data::DistributionProject,
+    key: data::DisciplineKey,
+    field: data::WorkloadField,
+) -> Result<f64, String> {
+    project.undistributed_workload(&key, field)
+}
+
+pub fn list_undistributed_workload(
+    project: data::DistributionProject,
+) -> Vec<data::UndistributedWorkload> {
+    project::undistributed_workload(&project)
+}
+
+pub fn add_workload_item(
+    mut project: data::DistributionProject,
+    employee_id: String,
+    rate_id: String,
+    item: data::UniversityForm3WorkloadItem,
+) -> Result<data::DistributionProject, String> {
+    project.add_workload_item(&employee_id, &rate_id, item)?;
+    Ok(project)
+}
+
+pub fn update_workload_field(
+    mut project: data::DistributionProject,
+    employee_id: String,
+    rate_id: String,
+    item_id: String,
+    field: data::WorkloadField,
+    value: f64,
+) -> Result<data::DistributionProject, String> {
+    project.update_workload_field(&employee_id, &rate_id, &item_id, field, value)?;
+    Ok(project)
+}
+
+pub fn remove_workload_item(
+    mut project: data::DistributionProject,
+    employee_id: String,
+    rate_id: String,
+    item_id: String,
+) -> Result<data::DistributionProject, String> {
+    project.remove_workload_item(&employee_id, &rate_id, &item_id)?;
+    Ok(project)
+}
+
+/// `None` for the default calendar of the project's academic year.
+/// `supervision_hours_per_postgraduate` is the department's yearly allowance
+/// for supervising one postgraduate.
+pub fn get_employee_summaries(
+    project: data::DistributionProject,
+    calendar: Option<data::AcademicCalendar>,
+    supervision_hours_per_postgraduate: f64,
+) -> Result<Vec<data::EmployeeWorkloadSummary>, String> {
+    let calendar = calendar_or_default(&project, calendar);
+    project::employee_summaries(&project, &calendar, supervision_hours_per_postgraduate)
+}
+
+pub fn default_academic_calendar(academic_year: u32) -> data::AcademicCalendar {
+    rate_norms::default_academic_calendar(academic_year)
+}
+
+/// Norm hours of every employee per semester and for the year, counted by
+/// the exact months their rates last. `None` for the default calendar of
+/// the project's academic year. `supervision_hours_per_postgraduate` is the
+/// department's yearly allowance for supervising one postgraduate.
+pub fn get_employee_norms(
+    project: data::DistributionProject,
+    calendar: Option<data::AcademicCalendar>,
+    supervision_hours_per_postgraduate: f64,
+) -> Result<Vec<data::EmployeeNorms>, String> {
+    let calendar = calendar_or_default(&project, calendar);
+    rate_norms::employee_norms(&project, &calendar, supervision_hours_per_postgraduate)
+}
+
+/// Hours assigned in a semester the employee's rate does not last in.
+pub fn check_semester_coverage(
+    project: data::DistributionProject,
+    calendar: Option<data::AcademicCalendar>,
+) -> Result<Vec<data::DistributionIssue>, String> {
+    let calendar = calendar_or_default(&project, calendar);
+    rate_norms::check_semester_coverage(&project, &calendar)
+}
+
+pub fn validate_distribution(project: data::DistributionProject) -> Vec<data::DistributionIssue> {
+    project::validate_distribution(&project)
+}
+
+pub fn build_project_exported_data(
+    project: data::DistributionProject,
+    type_name: String,
+    calendar: Option<data::AcademicCalendar>,
+) -> Result<ExportedData, String> {
+    let calendar = calendar_or_default(&project, calendar);
+    export::build_project_exported_data(&project, &type_name, &calendar)
+}
+
+/// `None` for the default calendar of the project's academic year.
+/// `supervision_hours_per_postgraduate` is the department's yearly allowance
+/// for supervising one postgraduate.
+pub fn distribute_workload(
+    project: data::DistributionProject,
+    options: data::SolverOptions,
+    calendar: Option<data::AcademicCalendar>,
+    supervision_hours_per_postgraduate: f64,
+) -> Result<data::SolverResult, String> {
+    let calendar = calendar_or_default(&project, calendar);
+    solver::distribute_workload(
+        &project,
+        &options,
+        &calendar,
+        supervision_hours_per_postgraduate,
+    )
+}
+
+/// `min_similarity` of the discipline names from 0 to 1, `None` for 0.8.
+pub fn carry_over_distribution(
+    previous: data::DistributionProject,
+    form1: data::UniversityForm1,
+    min_similarity: Option<f64>,
+) -> Result<data::CarryOverResult, String> {
+    carry_over::carry_over_distribution(
+        &previous,
+        form1,
+        min_similarity.unwrap_or(carry_over::DEFAULT_MIN_SIMILARITY),
+    )
+}
+
+/// Rows of `sheet_name` in `old_file` against the same sheet of `new_file`.
+/// With a `project`, the entries list the employees they affect.
+pub fn diff_form1_files(
+    old_file: ParsedExcelFile,
+    new_file: ParsedExcelFile,
+    sheet_name: String,
+    project: Option<data::DistributionProject>,
+) -> Result<data::Form1Diff, String> {
+    let old_form1 = form1::form1_from_parsed_file(&old_file, &sheet_name, 0)?;
+    let new_form1 = form1::form1_from_parsed_file(&new_file, &sheet_name, 0)?;
+    Ok(form1_diff::diff_form1(
+        &old_form1.workload_items,
+        &new_form1.workload_items,
+        project.as_ref(),
+    ))
+}
+
+/// `project` moved onto the rows of `sheet_name` in a revised form 1.
+pub fn reconcile_form1_file(
+    project: data::DistributionProject,
+    file: ParsedExcelFile,
+    sheet_name: String,
+) -> Result<data::ReconcileResult, String> {
+    let academic_year = project.university_form1.academic_year;
+    let form1 = form1::form1_from_parsed_file(&file, &sheet_name, academic_year)?;
+    Ok(reconcile::reconcile_distribution(&project, form1))
+}
+
+/// Rows of `sheet_name` that contradict themselves or each other.
+pub fn check_form1_file(file: ParsedExcelFile, sheet_name: String) -> Vec<data::Form1Finding> {
+    let rows = file.data.get(&sheet_name).map_or(&[][..], Vec::as_slice);
+    form1_check::check_form1_rows(rows)
+}
+
+pub fn default_norms_table() -> data::NormsTable {
+    form1_norms::default_norms_table()
+}
+
+/// Rows of `sheet_name` whose hours disagree with `norms`, `None` for
+/// `default_norms_table`.
+pub fn check_form1_norms(
+    file: ParsedExcelFile,
+    sheet_name: String,
+    norms: Option<data::NormsTable>,
+) -> Result<Vec<data::NormFinding>, String> {
+    let form1 = form1::form1_from_parsed_file(&file, &sheet_name, 0)?;
+    let norms = norms.unwrap_or_else(form1_norms::default_norms_table);
+    Ok(form1_norms::check_form1_norms(&form1, &norms))
+}
+
+pub fn apply_form1_norms(
+    form1: data::UniversityForm1,
+    norms: data::NormsTable,
+) -> data::UniversityForm1 {
+    form1_norms::apply_form1_norms(form1, &norms)
+}
+
+fn calendar_or_default(
+    project: &data::DistributionProject,
+    calendar: Option<data::AcademicCalendar>,
+) -> data::AcademicCalendar {
+    calendar.unwrap_or_else(|| {
+        rate_norms::default_academic_calendar(project.university_form3.academic_year)
+    })
+}
@@ -1,12 +1,83 @@
-use crate::excel::{data, document_generator, parser};
+use crate::excel::{
+    columns, data, data_export, document_generator, html_generator, ods_generator, parser,
+    pdf_generator, personal_export, totals,
+};
 
 pub fn parse_excel_file(file_path: String) -> data::ParsedExcelFile {
     let map = parser::parse_file(&file_path);
     return data::ParsedExcelFile { data: map };
 }
 
-pub fn write_excel_file(file_path: String, exported_tables: data::ExportedData) {
-    document_generator::generate_output_file(&file_path, exported_tables);
+pub fn write_excel_file(
+    file_path: String,
+    exported_tables: data::ExportedData,
+    options: data::ExportOptions,
+) -> Result<Vec<data::PersonalSheetName>, String> {
+    let is_ods = std::path::Path::new(&file_path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("ods"));
+    if is_ods {
+        ods_generator::generate_ods_file(&file_path, exported_tables, options)
+    } else {
+        document_generator::generate_output_file(&file_path, exported_tables, options)
+    }
+}
+
+pub fn write_pdf_file(
+    file_path: String,
+    exported_tables: data::ExportedData,
+    options: data::ExportOptions,
+    fonts: data::PdfFonts,
+) -> Result<(), String> {
+    pdf_generator::generate_pdf_file(&file_path, exported_tables, options, fonts)
+}
+
+pub fn write_personal_files(
+    directory: String,
+    exported_tables: data::ExportedData,
+    options: data::ExportOptions,
+    pdf_fonts: Option<data::PdfFonts>,
+) -> Result<Vec<String>, String> {
+    personal_export::generate_personal_files(&directory, exported_tables, options, pdf_fonts)
+}
+
+pub fn write_html_file(
+    file_path: String,
+    exported_tables: data::ExportedData,
+    options: data::ExportOptions,
+) -> Result<(), String> {
+    html_generator::generate_html_file(&file_path, exported_tables, options)
+}
+
+pub fn write_json_file(
+    file_path: String,
+    exported_tables: data::ExportedData,
+) -> Result<(), String> {
+    data_export::write_json_file(&file_path, &exported_tables)
+}
+
+pub fn read_json_file(file_path: String) -> Result<data::ExportedData, String> {
+    data_export::read_json_file(&file_path)
+}
+
+pub fn write_csv_file(
+    file_path: String,
+    exported_tables: data::ExportedData,
+    options: data::ExportOptions,
+) -> Result<(), String> {
+    data_export::write_csv_file(&file_path, &exported_tables, &options)
+}
+
+pub fn default_workload_columns() -> Vec<data::WorkloadColumn> {
+    columns::default_workload_columns()
+}
+
+pub fn build_exported_data(input: data::WorkloadInput) -> data::ExportedData {
+    totals::build_exported_data(input)
+}
+
+pub fn validate_exported_data(exported_tables: data::ExportedData) -> Vec<data::TotalsMismatch> {
+    totals::validate_exported_data(&exported_tables)
 }
 
 #[flutter_rust_bridge::frb(init)]
@@ -1,2 +1,3 @@
+pub mod distribution_interface;
 pub mod excel_interface;
 pub use crate::excel::data;
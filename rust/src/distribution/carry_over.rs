@@ -0,0 +1,220 @@
+use crate::distribution::{
+    data::{
+        CarriedOverItem, CarryOverResult, DisciplineKey, DistributionProject, EmployeeRate,
+        UniversityForm1, UniversityForm1WorkloadItem, UniversityForm3, UniversityForm3WorkloadItem,
+    },
+    dates::Date,
+    project::{WORKLOAD_FIELDS, normalize_name},
+};
+
+/// Names at least this similar are taken for the same discipline when the
+/// caller does not choose a threshold.
+pub const DEFAULT_MIN_SIMILARITY: f64 = 0.8;
+
+/// Starts this year's distribution from last year's: rows of `form1` are
+/// matched to last year's rows with the same normalised key, or with a name
+/// at least `min_similarity` alike and the rest of the key the same, and
+/// each teacher keeps their share of every field of the matched row. Rates
+/// are moved by the difference of the academic years. The project keeps
+/// the id and timestamps of `previous`.
+pub fn carry_over_distribution(
+    previous: &DistributionProject,
+    form1: UniversityForm1,
+    min_similarity: f64,
+) -> Result<CarryOverResult, String> {
+    let previous_rows = &previous.university_form1.workload_items;
+    let matches = match_rows(previous_rows, &form1.workload_items, min_similarity);
+
+    let years = form1.academic_year as i32 - previous.university_form1.academic_year as i32;
+    let mut employees = previous.university_form3.employees.clone();
+    for rate in employees
+        .iter_mut()
+        .flat_map(|employee| &mut employee.rates)
+    {
+        carry_over_rate(rate, years, previous_rows, &form1.workload_items, &matches)?;
+    }
+
+    let mut new_items = Vec::new();
+    let mut changed_items = Vec::new();
+    for (index, row) in form1.workload_items.iter().enumerate() {
+        let Some((previous_index, similarity)) = matches[index] else {
+            new_items.push(row.workload_key.clone());
+            continue;
+        };
+        let previous_row = &previous_rows[previous_index];
+        let changed_fields: Vec<_> = WORKLOAD_FIELDS
+            .into_iter()
+            .filter(|field| row.field_value(*field) != previous_row.field_value(*field))
+            .collect();
+        if similarity < 1_f64 || !changed_fields.is_empty() {
+            changed_items.push(CarriedOverItem {
+                key: row.workload_key.clone(),
+                previous_key: previous_row.workload_key.clone(),
+                similarity,
+                changed_fields,
+            });
+        }
+    }
+    let removed_items = previous_rows
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            !matches
+                .iter()
+                .flatten()
+                .any(|(matched, _)| matched == index)
+        })
+        .map(|(_, row)| row.workload_key.clone())
+        .collect();
+
+    Ok(CarryOverResult {
+        project: DistributionProject {
+            id: previous.id,
+            title: previous.title.clone(),
+            university_form3: UniversityForm3 {
+                id: previous.university_form3.id.clone(),
+                academic_year: form1.academic_year,
+                employees,
+            },
+            university_form1: form1,
+            created_at: previous.created_at.clone(),
+            updated_at: previous.updated_at.clone(),
+        },
+        new_items,
+        removed_items,
+        changed_items,
+    })
+}
+
+/// For every row of `rows`, the index of last year's row it continues and
+/// the similarity of their names. Exact matches are taken first, then the
+/// most similar pairs.
+fn match_rows(
+    previous_rows: &[UniversityForm1WorkloadItem],
+    rows: &[UniversityForm1WorkloadItem],
+    min_similarity: f64,
+) -> Vec<Option<(usize, f64)>> {
+    let mut matches = vec![None; rows.len()];
+    let mut taken = vec![false; previous_rows.len()];
+    for (index, row) in rows.iter().enumerate() {
+        let exact = (0..previous_rows.len()).find(|previous_index| {
+            !taken[*previous_index]
+                && same_key(
+                    &previous_rows[*previous_index].workload_key,
+                    &row.workload_key,
+                )
+                && normalize_name(&previous_rows[*previous_index].workload_key.discipline_name)
+                    == normalize_name(&row.workload_key.discipline_name)
+        });
+        if let Some(previous_index) = exact {
+            matches[index] = Some((previous_index, 1_f64));
+            taken[previous_index] = true;
+        }
+    }
+
+    let mut pairs = Vec::new();
+    for (index, row) in rows.iter().enumerate() {
+        if matches[index].is_some() {
+            continue;
+        }
+        for (previous_index, previous_row) in previous_rows.iter().enumerate() {
+            if taken[previous_index] || !same_key(&previous_row.workload_key, &row.workload_key) {
+                continue;
+            }
+            let similarity = name_similarity(
+                &previous_row.workload_key.discipline_name,
+                &row.workload_key.discipline_name,
+            );
+            if similarity >= min_similarity {
+                pairs.push((index, previous_index, similarity));
+            }
+        }
+    }
+    pairs.sort_by(|a, b| b.2.total_cmp(&a.2));
+    for (index, previous_index, similarity) in pairs {
+        if matches[index].is_none() && !taken[previous_index] {
+            matches[index] = Some((previous_index, similarity));
+            taken[previous_index] = true;
+        }
+    }
+    matches
+}
+
+/// Whether the keys match apart from the discipline name.
+fn same_key(a: &DisciplineKey, b: &DisciplineKey) -> bool {
+    a.learning_form == b.learning_form
+        && a.semester == b.semester
+        && a.course.trim() == b.course.trim()
+        && normalize_name(&a.specialty) == normalize_name(&b.specialty)
+}
+
+/// One minus the edit distance of the normalised names relative to the
+/// longer one.
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = normalize_name(a).chars().collect();
+    let b: Vec<char> = normalize_name(b).chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1_f64;
+    }
+    let mut distances: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.iter().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != b_char);
+            diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(diagonal + 1);
+        }
+    }
+    1_f64 - distances[b.len()] as f64 / longest as f64
+}
+
+fn carry_over_rate(
+    rate: &mut EmployeeRate,
+    years: i32,
+    previous_rows: &[UniversityForm1WorkloadItem],
+    rows: &[UniversityForm1WorkloadItem],
+    matches: &[Option<(usize, f64)>],
+) -> Result<(), String> {
+    rate.date_start = Date::parse(&rate.date_start)?.add_years(years).to_string();
+    rate.date_end = Date::parse(&rate.date_end)?.add_years(years).to_string();
+    let mut items = Vec::new();
+    for item in &rate.workload_items {
+        let previous_index = previous_rows
+            .iter()
+            .position(|row| row.workload_key == item.workload_key);
+        let row = previous_index.and_then(|previous_index| {
+            matches
+                .iter()
+                .position(|matched| matched.is_some_and(|(matched, _)| matched == previous_index))
+        });
+        if let (Some(previous_index), Some(index)) = (previous_index, row) {
+            items.push(scaled_item(
+                item,
+                &previous_rows[previous_index],
+                &rows[index],
+            ));
+        }
+    }
+    rate.workload_items = items;
+    Ok(())
+}
+
+/// `item` moved to `row`, with the same share of each field of it as it had
+/// of `previous_row`. Groups are cleared, they are renamed every year.
+fn scaled_item(
+    item: &UniversityForm3WorkloadItem,
+    previous_row: &UniversityForm1WorkloadItem,
+    row: &UniversityForm1WorkloadItem,
+) -> UniversityForm3WorkloadItem {
+    let mut scaled = UniversityForm3WorkloadItem::empty(item.id.clone(), row.workload_key.clone());
+    for field in WORKLOAD_FIELDS {
+        let previous_total = previous_row.field_value(field);
+        if previous_total > 0_f64 {
+            let value = item.field_value(field) / previous_total * row.field_value(field);
+            scaled.set_field_share(field, value);
+        }
+    }
+    scaled
+}
@@ -0,0 +1,442 @@
+use serde::{Deserialize, Serialize};
+
+/// Workload distribution project as the app stores it: the form 1 workload
+/// of the department and the employees it is distributed among (form 3).
+/// Field names follow the JSON of the Dart `WorkloadDistributionProject`,
+/// the timestamps are kept as the ISO 8601 strings Dart writes.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionProject {
+    pub id: i64,
+    pub title: String,
+    pub university_form1: UniversityForm1,
+    pub university_form3: UniversityForm3,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversityForm1 {
+    pub id: String,
+    pub academic_year: u32,
+    pub workload_items: Vec<UniversityForm1WorkloadItem>,
+}
+
+/// A form 1 row, the hours planned for one discipline of one group of
+/// students. The `*_total` hours are the ones to distribute.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversityForm1WorkloadItem {
+    pub id: String,
+    pub workload_key: DisciplineKey,
+    pub week_count: f64,
+    pub student_count: u32,
+    pub flow_count: f64,
+    pub group_count: f64,
+    pub subgroup_count: f64,
+    pub lectures_planned: f64,
+    pub lectures_total: f64,
+    pub practices_planned: f64,
+    pub practices_total: f64,
+    pub labs_planned: f64,
+    pub labs_total: f64,
+    pub exams: f64,
+    pub exam_consults: f64,
+    pub tests: f64,
+    pub qualification_works: f64,
+    pub certification_exams: f64,
+    pub production_practices: f64,
+    pub teaching_practices: f64,
+    pub current_consults: f64,
+    pub individual_works: f64,
+    pub course_works: f64,
+    pub postgraduate_exams: f64,
+}
+
+/// Identifies a form 1 row and the form 3 items distributed from it.
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisciplineKey {
+    pub learning_form: LearningForm,
+    pub specialty: String,
+    pub discipline_name: String,
+    pub course: String,
+    pub semester: AcademicSemester,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LearningForm {
+    Daytime,
+    Evening,
+    Correspondence,
+}
+
+/// Stored as 1 and 2, like the Dart `AcademicSemester`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub enum AcademicSemester {
+    First,
+    Second,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversityForm3 {
+    pub id: String,
+    pub academic_year: u32,
+    pub employees: Vec<Employee>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Employee {
+    pub id: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub patronymic: String,
+    pub rank: EmployeeRank,
+    pub rates: Vec<EmployeeRate>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EmployeeRank {
+    Head,
+    Professor,
+    Associate,
+    SeniorLecturer,
+    Assistant,
+}
+
+/// A rate of an employee and the workload assigned under it. The dates are
+/// ISO 8601 strings as Dart writes them, only the date part is used.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeRate {
+    pub id: String,
+    pub rate_value: f64,
+    pub date_start: String,
+    pub date_end: String,
+    pub postgraduate_count: u32,
+    pub workload_items: Vec<UniversityForm3WorkloadItem>,
+}
+
+/// Part of a form 1 row assigned to an employee.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversityForm3WorkloadItem {
+    pub id: String,
+    pub workload_key: DisciplineKey,
+    pub student_count: u32,
+    pub academic_groups: Vec<String>,
+    pub lectures: f64,
+    pub practices: f64,
+    pub labs: f64,
+    pub exams: f64,
+    pub exam_consults: f64,
+    pub tests: f64,
+    pub qualification_works: f64,
+    pub certification_exams: f64,
+    pub production_practices: f64,
+    pub teaching_practices: f64,
+    pub current_consults: f64,
+    pub individual_works: f64,
+    pub course_works: f64,
+    pub postgraduate_exams: f64,
+}
+
+/// A distributable value of a form 1 row and of the items assigned from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WorkloadField {
+    StudentCount,
+    Lectures,
+    Practices,
+    Labs,
+    Exams,
+    ExamConsults,
+    Tests,
+    QualificationWorks,
+    CertificationExams,
+    ProductionPractices,
+    TeachingPractices,
+    CurrentConsults,
+    IndividualWorks,
+    CourseWorks,
+    PostgraduateExams,
+}
+
+/// Planned, distributed and remaining value of one field of a form 1 row.
+#[derive(Serialize, Deserialize)]
+pub struct UndistributedWorkload {
+    pub workload_key: DisciplineKey,
+    pub field: WorkloadField,
+    pub total: f64,
+    pub distributed: f64,
+    pub undistributed: f64,
+}
+
+/// Hours of an employee, supervision included, against the yearly norm of
+/// their rates.
+#[derive(Serialize, Deserialize)]
+pub struct EmployeeWorkloadSummary {
+    pub employee_id: String,
+    pub full_name: String,
+    pub hours: f64,
+    pub min_possible_hours: f64,
+    pub max_possible_hours: f64,
+}
+
+/// A problem of a distribution that the app would have refused to create,
+/// e.g. a project edited by hand or by an older version.
+#[derive(Serialize, Deserialize)]
+pub struct DistributionIssue {
+    pub employee_id: Option<String>,
+    pub item_id: Option<String>,
+    pub message: String,
+}
+
+/// What `distribute_workload` may assign to whom.
+#[derive(Serialize, Deserialize)]
+pub struct SolverOptions {
+    pub preferences: Vec<EmployeePreferences>,
+    pub rank_rules: Vec<FieldRankRule>,
+}
+
+/// Disciplines an employee can teach, by name. Employees without
+/// preferences can be given any discipline.
+#[derive(Serialize, Deserialize)]
+pub struct EmployeePreferences {
+    pub employee_id: String,
+    pub disciplines: Vec<String>,
+}
+
+/// `field` may only be assigned to employees of `min_rank` or above, e.g.
+/// lectures only to associate professors.
+#[derive(Serialize, Deserialize)]
+pub struct FieldRankRule {
+    pub field: WorkloadField,
+    pub min_rank: EmployeeRank,
+}
+
+/// The project with the proposed assignment added to it.
+#[derive(Serialize, Deserialize)]
+pub struct SolverResult {
+    pub project: DistributionProject,
+    /// Hours nobody could take within their norm and preferences.
+    pub unplaced: Vec<UndistributedWorkload>,
+    /// Form 1 rows whose hours went to more than one employee.
+    pub split_items: Vec<DisciplineKey>,
+    pub employees: Vec<EmployeeWorkloadSummary>,
+}
+
+/// This year's project started from last year's distribution.
+#[derive(Serialize, Deserialize)]
+pub struct CarryOverResult {
+    pub project: DistributionProject,
+    /// Rows of this year's form 1 nobody taught last year, left undistributed.
+    pub new_items: Vec<DisciplineKey>,
+    /// Rows of last year's form 1 gone this year, their assignments dropped.
+    pub removed_items: Vec<DisciplineKey>,
+    pub changed_items: Vec<CarriedOverItem>,
+}
+
+/// A row matched to last year's one under another name or with other hours.
+#[derive(Serialize, Deserialize)]
+pub struct CarriedOverItem {
+    pub key: DisciplineKey,
+    pub previous_key: DisciplineKey,
+    /// Similarity of the names from 0 to 1, 1 when they are the same.
+    pub similarity: f64,
+    pub changed_fields: Vec<WorkloadField>,
+}
+
+/// Differences between two versions of form 1.
+#[derive(Serialize, Deserialize)]
+pub struct Form1Diff {
+    pub added: Vec<Form1DiffEntry>,
+    pub removed: Vec<Form1DiffEntry>,
+    pub changed: Vec<Form1DiffEntry>,
+}
+
+/// A row of form 1 that appeared, disappeared or changed. Added rows list
+/// their values as changes from 0, removed ones as changes to 0.
+#[derive(Serialize, Deserialize)]
+pub struct Form1DiffEntry {
+    pub key: DisciplineKey,
+    pub changes: Vec<FieldChange>,
+    /// Full names of the employees the row is distributed to.
+    pub affected_employees: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: WorkloadField,
+    pub old_value: f64,
+    pub new_value: f64,
+}
+
+/// The distribution moved onto a revised form 1.
+#[derive(Serialize, Deserialize)]
+pub struct ReconcileResult {
+    pub project: DistributionProject,
+    pub diff: Form1Diff,
+    pub conflicts: Vec<ReconcileConflict>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReconcileConflictKind {
+    /// The row is gone from the revised form 1 but is still distributed.
+    VanishedDiscipline,
+    /// More of `field` is distributed than the revised form 1 plans.
+    OverDistributed,
+}
+
+/// Something the reconciliation could not decide and left to the user.
+#[derive(Serialize, Deserialize)]
+pub struct ReconcileConflict {
+    pub kind: ReconcileConflictKind,
+    pub key: DisciplineKey,
+    /// `None` for a vanished discipline, whose hours are summed.
+    pub field: Option<WorkloadField>,
+    pub total: f64,
+    pub distributed: f64,
+    pub employees: Vec<String>,
+    pub message: String,
+}
+
+/// Count of a form 1 row a norm is given per.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NormBasis {
+    Students,
+    Flows,
+    Groups,
+    Subgroups,
+    Weeks,
+}
+
+/// `field` takes `hours_per_unit` hours per unit of `basis`, e.g. exams
+/// 0.33 hours per student.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NormRule {
+    pub field: WorkloadField,
+    pub basis: NormBasis,
+    pub hours_per_unit: f64,
+}
+
+/// University norms form 1 hours are calculated by.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NormsTable {
+    pub rules: Vec<NormRule>,
+    /// Hours the file may differ from the norm by without being flagged.
+    pub tolerance: f64,
+}
+
+/// A form 1 value that disagrees with the norm.
+#[derive(Serialize, Deserialize)]
+pub struct NormFinding {
+    /// 1-based position of the row in the sheet.
+    pub row: u32,
+    pub key: DisciplineKey,
+    pub field: WorkloadField,
+    pub file_value: f64,
+    pub norm_value: f64,
+}
+
+/// What is wrong with a form 1 row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Form1FindingKind {
+    UnknownLearningForm,
+    UnreadableValue,
+    NegativeValue,
+    TotalMismatch,
+    SemesterMismatch,
+    DuplicateKey,
+}
+
+/// A form 1 row that contradicts itself or another row.
+#[derive(Serialize, Deserialize)]
+pub struct Form1Finding {
+    /// 1-based position of the row in the sheet.
+    pub row: u32,
+    pub kind: Form1FindingKind,
+    /// `None` when the learning form of the row is unknown.
+    pub key: Option<DisciplineKey>,
+    pub field: Option<WorkloadField>,
+    pub message: String,
+}
+
+/// First and last days of the semesters of an academic year, `YYYY-MM-DD`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcademicCalendar {
+    pub first_semester_start: String,
+    pub first_semester_end: String,
+    pub second_semester_start: String,
+    pub second_semester_end: String,
+}
+
+/// Norm hours of a rate, or of all the rates of an employee, over a
+/// semester or the academic year.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct PeriodNorm {
+    /// Months of the period the rates last, a month partly covered counting
+    /// as the covered share of its days, each rate weighted by its value.
+    pub rate_months: f64,
+    /// Hours of postgraduate supervision, counted towards the workload.
+    pub supervision_hours: f64,
+    pub min_hours: f64,
+    pub max_hours: f64,
+    /// Hours assigned, supervision included.
+    pub hours: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RateNorms {
+    pub rate_id: String,
+    pub rate_value: f64,
+    /// Calendar months of the academic year the rate lasts.
+    pub months: f64,
+    pub first_semester: PeriodNorm,
+    pub second_semester: PeriodNorm,
+    pub year: PeriodNorm,
+}
+
+/// Norm hours of an employee, the sum of those of their rates.
+#[derive(Serialize, Deserialize)]
+pub struct EmployeeNorms {
+    pub employee_id: String,
+    pub full_name: String,
+    /// The largest sum of the values of rates lasting on the same day.
+    pub peak_rate: f64,
+    pub rates: Vec<RateNorms>,
+    pub first_semester: PeriodNorm,
+    pub second_semester: PeriodNorm,
+    pub year: PeriodNorm,
+}
+
+impl TryFrom<u32> for AcademicSemester {
+    type Error = String;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::First),
+            2 => Ok(Self::Second),
+            _ => Err(format!("Невідомий семестр: {value}")),
+        }
+    }
+}
+
+impl From<AcademicSemester> for u32 {
+    fn from(semester: AcademicSemester) -> Self {
+        match semester {
+            AcademicSemester::First => 1,
+            AcademicSemester::Second => 2,
+        }
+    }
+}
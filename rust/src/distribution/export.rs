@@ -0,0 +1,143 @@
+use crate::distribution::{
+    data::{
+        AcademicCalendar, AcademicSemester, DistributionProject, Employee, EmployeeRank,
+        EmployeeRate, UniversityForm3WorkloadItem,
+    },
+    rate_norms::semester_rate,
+};
+use crate::excel::{
+    data::{
+        EmployeeWorkloadInput, ExportedData, OutputHoursRow, OutputPersonalSemesterRow,
+        WorkloadInput, WorkloadSectionInput,
+    },
+    totals::{EMPTY, build_exported_data},
+};
+
+/// Main table sections in the order they are written, with the caption of
+/// their subtotal row.
+const RANK_SECTIONS: [(EmployeeRank, &str); 5] = [
+    (EmployeeRank::Head, "Всього за зав. каф."),
+    (EmployeeRank::Professor, "Всього за професорами"),
+    (EmployeeRank::Associate, "Всього за доцентами"),
+    (EmployeeRank::SeniorLecturer, "Всього за викладачами"),
+    (EmployeeRank::Assistant, "Всього за асистентами"),
+];
+
+/// The distribution as `write_excel_file` expects it: employees grouped by
+/// rank, a personal table per rate, its items split by semester and
+/// ordered by learning form, and every total computed. The rate of each
+/// semester is the share of it the rate lasts by `calendar`.
+pub fn build_project_exported_data(
+    project: &DistributionProject,
+    type_name: &str,
+    calendar: &AcademicCalendar,
+) -> Result<ExportedData, String> {
+    Ok(build_exported_data(project_workload_input(
+        project, type_name, calendar,
+    )?))
+}
+
+pub fn project_workload_input(
+    project: &DistributionProject,
+    type_name: &str,
+    calendar: &AcademicCalendar,
+) -> Result<WorkloadInput, String> {
+    let mut next_id = 1;
+    let mut sections = Vec::new();
+    for (rank, title) in RANK_SECTIONS {
+        let mut employees = Vec::new();
+        for employee in &project.university_form3.employees {
+            if employee.rank != rank {
+                continue;
+            }
+            for rate in &employee.rates {
+                employees.push(employee_input(next_id, employee, rate, calendar)?);
+                next_id += 1;
+            }
+        }
+        if !employees.is_empty() {
+            sections.push(WorkloadSectionInput {
+                title: title.to_string(),
+                employees,
+            });
+        }
+    }
+    Ok(WorkloadInput {
+        year: project.university_form1.academic_year,
+        type_name: type_name.to_string(),
+        sections,
+    })
+}
+
+fn employee_input(
+    id: u32,
+    employee: &Employee,
+    rate: &EmployeeRate,
+    calendar: &AcademicCalendar,
+) -> Result<EmployeeWorkloadInput, String> {
+    Ok(EmployeeWorkloadInput {
+        id,
+        first_name: employee.first_name.clone(),
+        middle_name: employee.patronymic.clone(),
+        last_name: employee.last_name.clone(),
+        rank: employee.rank.display_name().to_string(),
+        comment_semester_1: String::new(),
+        comment_semester_2: String::new(),
+        semester_1: semester_rows(rate, AcademicSemester::First),
+        semester_1_rate: semester_rate(rate, calendar, AcademicSemester::First)?,
+        semester_2: semester_rows(rate, AcademicSemester::Second),
+        semester_2_rate: semester_rate(rate, calendar, AcademicSemester::Second)?,
+    })
+}
+
+fn semester_rows(
+    rate: &EmployeeRate,
+    semester: AcademicSemester,
+) -> Vec<OutputPersonalSemesterRow> {
+    let mut items: Vec<&UniversityForm3WorkloadItem> = rate
+        .workload_items
+        .iter()
+        .filter(|item| item.workload_key.semester == semester)
+        .collect();
+    // Stable, so items of the same form keep the order they were assigned in.
+    items.sort_by_key(|item| item.workload_key.learning_form as u8);
+    items.into_iter().map(semester_row).collect()
+}
+
+fn semester_row(item: &UniversityForm3WorkloadItem) -> OutputPersonalSemesterRow {
+    let key = &item.workload_key;
+    OutputPersonalSemesterRow {
+        name: key.discipline_name.clone(),
+        learning_form: key.learning_form.short_name().to_string(),
+        speciality: key.specialty.clone(),
+        group: item.academic_groups.join(", "),
+        course: key.course.clone(),
+        students_count: item.student_count,
+        individual_work_types: String::new(),
+        hours: OutputHoursRow::new(
+            hours(item.lectures),
+            hours(item.practices),
+            hours(item.labs),
+            hours(item.exams),
+            hours(item.exam_consults),
+            hours(item.tests),
+            hours(item.qualification_works),
+            hours(item.certification_exams),
+            hours(item.production_practices),
+            hours(item.teaching_practices),
+            hours(item.current_consults),
+            hours(item.individual_works),
+            hours(item.course_works),
+            hours(item.postgraduate_exams),
+            EMPTY,
+            EMPTY,
+            EMPTY,
+        ),
+        merge_lectures_with_next: false,
+    }
+}
+
+/// Unassigned hours are left empty, as in a hand-made export.
+fn hours(value: f64) -> f64 {
+    if value > 0_f64 { value } else { EMPTY }
+}
@@ -0,0 +1,98 @@
+use uuid::Uuid;
+
+use crate::distribution::data::{
+    AcademicSemester, DisciplineKey, LearningForm, UniversityForm1, UniversityForm1WorkloadItem,
+};
+use crate::excel::data::{InputRawRow, ParsedExcelFile};
+
+/// Form 1 of `sheet_name`, read the way `UniversityForm1.fromParsedExcelFile`
+/// reads it in Dart. The form gets a new id, item ids are the row numbers
+/// within the sheet.
+pub fn form1_from_parsed_file(
+    file: &ParsedExcelFile,
+    sheet_name: &str,
+    academic_year: u32,
+) -> Result<UniversityForm1, String> {
+    let rows = file.data.get(sheet_name).map_or(&[][..], Vec::as_slice);
+    let workload_items = rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| form1_item(format!("{sheet_name}-{}", index + 1), row))
+        .collect::<Result<_, _>>()?;
+    Ok(UniversityForm1 {
+        id: Uuid::new_v4().to_string(),
+        academic_year,
+        workload_items,
+    })
+}
+
+pub fn form1_item(id: String, row: &InputRawRow) -> Result<UniversityForm1WorkloadItem, String> {
+    Ok(UniversityForm1WorkloadItem {
+        id,
+        workload_key: row_key(row)?,
+        week_count: parse_number(&row.weeks_count),
+        student_count: parse_count(&row.students_count),
+        flow_count: parse_number(&row.flows_count),
+        group_count: parse_number(&row.groups_count),
+        subgroup_count: parse_number(&row.subgroups_count),
+        lectures_planned: parse_number(&row.lectures_planned_count),
+        lectures_total: parse_number(&row.lectures_total_count),
+        practices_planned: parse_number(&row.practices_planned_count),
+        practices_total: parse_number(&row.practices_total_count),
+        labs_planned: parse_number(&row.labs_planned_count),
+        labs_total: parse_number(&row.labs_total_count),
+        exams: parse_number(&row.exams),
+        exam_consults: parse_number(&row.exam_consults),
+        tests: parse_number(&row.tests),
+        qualification_works: parse_number(&row.qual_works),
+        certification_exams: parse_number(&row.certification_exams),
+        production_practices: parse_number(&row.working_practice),
+        teaching_practices: parse_number(&row.teaching_practice),
+        current_consults: parse_number(&row.consults),
+        individual_works: parse_number(&row.individual_works),
+        course_works: parse_number(&row.course_works),
+        postgraduate_exams: parse_number(&row.postgraduate_exams),
+    })
+}
+
+pub fn row_key(row: &InputRawRow) -> Result<DisciplineKey, String> {
+    let learning_form = LearningForm::from_short_name(&row.learning_form).ok_or_else(|| {
+        format!(
+            "Невідома форма навчання \"{}\" у дисципліні \"{}\"",
+            row.learning_form, row.name
+        )
+    })?;
+    Ok(DisciplineKey {
+        learning_form,
+        specialty: row.speciality.clone(),
+        discipline_name: row.name.clone(),
+        course: row.course.clone(),
+        semester: if row.semester.trim() == "2" {
+            AcademicSemester::Second
+        } else {
+            AcademicSemester::First
+        },
+    })
+}
+
+/// Hours as form 1 writes them: "12", "12,5" or a fraction like "1/3".
+/// Anything unreadable counts as zero.
+pub fn parse_number(value: &str) -> f64 {
+    let value = value.trim();
+    if let Some((numerator, denominator)) = value.split_once('/') {
+        let numerator = numerator.trim().parse().unwrap_or(0_f64);
+        let denominator = denominator.trim().parse().unwrap_or(1_f64);
+        return if denominator == 0_f64 {
+            0_f64
+        } else {
+            numerator / denominator
+        };
+    }
+    value.replace(',', ".").parse().unwrap_or(0_f64)
+}
+
+/// Student count with everything but the digits dropped, as Dart reads it.
+pub fn parse_count(value: &str) -> u32 {
+    let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+    digits.parse().unwrap_or(0)
+}
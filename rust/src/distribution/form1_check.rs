@@ -0,0 +1,232 @@
+use crate::distribution::{
+    data::{DisciplineKey, Form1Finding, Form1FindingKind, WorkloadField},
+    form1::{parse_number, row_key},
+    project::display_value,
+};
+use crate::excel::data::InputRawRow;
+
+/// Totals may differ from planned hours times the multiplier by this much.
+const TOLERANCE: f64 = 0.01;
+
+/// Checks parsed form 1 rows against themselves and each other: readable,
+/// non-negative numbers, lectures, practices and labs totals equal to the
+/// planned hours times flows, groups and subgroups, a semester of 1 or 2 and
+/// no two rows with the same key.
+pub fn check_form1_rows(rows: &[InputRawRow]) -> Vec<Form1Finding> {
+    let mut findings = Vec::new();
+    let mut keys: Vec<(u32, DisciplineKey)> = Vec::new();
+    for (row_number, row) in (1..).zip(rows) {
+        let key = match row_key(row) {
+            Ok(key) => Some(key),
+            Err(message) => {
+                findings.push(Form1Finding {
+                    row: row_number,
+                    kind: Form1FindingKind::UnknownLearningForm,
+                    key: None,
+                    field: None,
+                    message,
+                });
+                None
+            }
+        };
+        let mut finding = |kind, field, message| {
+            findings.push(Form1Finding {
+                row: row_number,
+                kind,
+                key: key.clone(),
+                field,
+                message,
+            })
+        };
+
+        for (column, value, field) in number_columns(row) {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            let number = parse_number(value);
+            if number == 0_f64 && !is_zero(value) {
+                finding(
+                    Form1FindingKind::UnreadableValue,
+                    field,
+                    format!("{column}: не вдалося прочитати число \"{value}\""),
+                );
+            } else if number < 0_f64 {
+                finding(
+                    Form1FindingKind::NegativeValue,
+                    field,
+                    format!("{column}: від'ємне значення {}", display_value(number)),
+                );
+            }
+        }
+
+        let totals = [
+            (
+                WorkloadField::Lectures,
+                &row.lectures_planned_count,
+                &row.lectures_total_count,
+                &row.flows_count,
+                "потоків",
+            ),
+            (
+                WorkloadField::Practices,
+                &row.practices_planned_count,
+                &row.practices_total_count,
+                &row.groups_count,
+                "груп",
+            ),
+            (
+                WorkloadField::Labs,
+                &row.labs_planned_count,
+                &row.labs_total_count,
+                &row.subgroups_count,
+                "підгруп",
+            ),
+        ];
+        for (field, planned, total, multiplier, multiplier_name) in totals {
+            let (planned, total, multiplier) = (
+                parse_number(planned),
+                parse_number(total),
+                parse_number(multiplier),
+            );
+            if (planned * multiplier - total).abs() > TOLERANCE {
+                finding(
+                    Form1FindingKind::TotalMismatch,
+                    Some(field),
+                    format!(
+                        "{}: всього {} год., а за планом {} год. на {} {multiplier_name} — {} год.",
+                        field.display_name(),
+                        display_value(total),
+                        display_value(planned),
+                        display_value(multiplier),
+                        display_value(planned * multiplier)
+                    ),
+                );
+            }
+        }
+
+        if let Some(message) = semester_mismatch(&row.semester) {
+            finding(Form1FindingKind::SemesterMismatch, None, message);
+        }
+
+        if let Some(key) = &key {
+            if let Some((first_row, _)) = keys.iter().find(|(_, other)| other == key) {
+                finding(
+                    Form1FindingKind::DuplicateKey,
+                    None,
+                    format!("{} уже є у рядку {first_row}", key.describe()),
+                );
+            }
+            keys.push((row_number, key.clone()));
+        }
+    }
+    findings
+}
+
+/// Semesters are read as `form1::row_key` reads them: "2" is the second
+/// semester and anything else the first, so a semester numbered from the
+/// start of studies, like "4" for the second course, is imported into the
+/// first one.
+fn semester_mismatch(semester: &str) -> Option<String> {
+    let semester = semester.trim();
+    if semester == "1" || semester == "2" {
+        return None;
+    }
+    Some(format!(
+        "Семестр \"{semester}\" буде прочитано як I семестр, вкажіть 1 або 2"
+    ))
+}
+
+fn is_zero(value: &str) -> bool {
+    value
+        .chars()
+        .all(|char| matches!(char, '0' | ',' | '.' | '/' | ' ' | '-'))
+}
+
+/// Numeric columns of `row`, named as in the file, with the field they feed.
+/// The student count is left out, it is read whatever else it contains.
+fn number_columns(row: &InputRawRow) -> [(&'static str, &str, Option<WorkloadField>); 21] {
+    [
+        ("Кількість тижнів", &row.weeks_count, None),
+        ("Кількість потоків", &row.flows_count, None),
+        ("Кількість груп", &row.groups_count, None),
+        ("Кількість підгруп", &row.subgroups_count, None),
+        (
+            "Лекції по плану",
+            &row.lectures_planned_count,
+            Some(WorkloadField::Lectures),
+        ),
+        (
+            "Лекції всього",
+            &row.lectures_total_count,
+            Some(WorkloadField::Lectures),
+        ),
+        (
+            "Практичні по плану",
+            &row.practices_planned_count,
+            Some(WorkloadField::Practices),
+        ),
+        (
+            "Практичні всього",
+            &row.practices_total_count,
+            Some(WorkloadField::Practices),
+        ),
+        (
+            "Лабораторні по плану",
+            &row.labs_planned_count,
+            Some(WorkloadField::Labs),
+        ),
+        (
+            "Лабораторні всього",
+            &row.labs_total_count,
+            Some(WorkloadField::Labs),
+        ),
+        ("Екзамени", &row.exams, Some(WorkloadField::Exams)),
+        (
+            "Консультації перед екзаменом",
+            &row.exam_consults,
+            Some(WorkloadField::ExamConsults),
+        ),
+        ("Заліки", &row.tests, Some(WorkloadField::Tests)),
+        (
+            "Кваліфікаційні роботи",
+            &row.qual_works,
+            Some(WorkloadField::QualificationWorks),
+        ),
+        (
+            "Атестаційні екзамени",
+            &row.certification_exams,
+            Some(WorkloadField::CertificationExams),
+        ),
+        (
+            "Виробнича практика",
+            &row.working_practice,
+            Some(WorkloadField::ProductionPractices),
+        ),
+        (
+            "Навчальна практика",
+            &row.teaching_practice,
+            Some(WorkloadField::TeachingPractices),
+        ),
+        (
+            "Поточні консультації",
+            &row.consults,
+            Some(WorkloadField::CurrentConsults),
+        ),
+        (
+            "Індивідуальні завдання",
+            &row.individual_works,
+            Some(WorkloadField::IndividualWorks),
+        ),
+        (
+            "Курсові роботи",
+            &row.course_works,
+            Some(WorkloadField::CourseWorks),
+        ),
+        (
+            "Аспірантські екзамени",
+            &row.postgraduate_exams,
+            Some(WorkloadField::PostgraduateExams),
+        ),
+    ]
+}
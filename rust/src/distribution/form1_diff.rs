@@ -0,0 +1,72 @@
+use crate::distribution::{
+    data::{
+        DisciplineKey, DistributionProject, FieldChange, Form1Diff, Form1DiffEntry,
+        UniversityForm1WorkloadItem,
+    },
+    project::WORKLOAD_FIELDS,
+};
+
+/// Compares the rows of two versions of form 1 by their key. Rows with the
+/// same key are paired in the order they appear. With a `project`, every
+/// entry names the employees whose plans it affects.
+pub fn diff_form1(
+    old_rows: &[UniversityForm1WorkloadItem],
+    new_rows: &[UniversityForm1WorkloadItem],
+    project: Option<&DistributionProject>,
+) -> Form1Diff {
+    let mut diff = Form1Diff {
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+    };
+    let mut paired = vec![false; old_rows.len()];
+    for new_row in new_rows {
+        let old_index = (0..old_rows.len())
+            .find(|index| !paired[*index] && old_rows[*index].workload_key == new_row.workload_key);
+        let Some(old_index) = old_index else {
+            diff.added
+                .push(entry(&new_row.workload_key, None, Some(new_row), project));
+            continue;
+        };
+        paired[old_index] = true;
+        let entry = entry(
+            &new_row.workload_key,
+            Some(&old_rows[old_index]),
+            Some(new_row),
+            project,
+        );
+        if !entry.changes.is_empty() {
+            diff.changed.push(entry);
+        }
+    }
+    for (old_row, _) in old_rows.iter().zip(paired).filter(|(_, paired)| !paired) {
+        diff.removed
+            .push(entry(&old_row.workload_key, Some(old_row), None, project));
+    }
+    diff
+}
+
+fn entry(
+    key: &DisciplineKey,
+    old_row: Option<&UniversityForm1WorkloadItem>,
+    new_row: Option<&UniversityForm1WorkloadItem>,
+    project: Option<&DistributionProject>,
+) -> Form1DiffEntry {
+    let changes = WORKLOAD_FIELDS
+        .into_iter()
+        .map(|field| FieldChange {
+            field,
+            old_value: old_row.map_or(0_f64, |row| row.field_value(field)),
+            new_value: new_row.map_or(0_f64, |row| row.field_value(field)),
+        })
+        .filter(|change| change.old_value != change.new_value)
+        .collect();
+    let affected_employees = project
+        .map(|project| project.employee_names(key))
+        .unwrap_or_default();
+    Form1DiffEntry {
+        key: key.clone(),
+        changes,
+        affected_employees,
+    }
+}
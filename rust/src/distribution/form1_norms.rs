@@ -0,0 +1,77 @@
+use crate::distribution::data::{
+    NormBasis, NormFinding, NormRule, NormsTable, UniversityForm1, UniversityForm1WorkloadItem,
+    WorkloadField,
+};
+use crate::excel::totals::round;
+
+/// The usual university norms: 0.33 hours per student for an exam, 2 hours
+/// of consultation per group before it, 0.25 hours per student for a test,
+/// 3 hours per course work and 20 hours per qualification work.
+pub fn default_norms_table() -> NormsTable {
+    let rule = |field, basis, hours_per_unit| NormRule {
+        field,
+        basis,
+        hours_per_unit,
+    };
+    NormsTable {
+        rules: vec![
+            rule(WorkloadField::Exams, NormBasis::Students, 0.33),
+            rule(WorkloadField::ExamConsults, NormBasis::Groups, 2.0),
+            rule(WorkloadField::Tests, NormBasis::Students, 0.25),
+            rule(WorkloadField::CourseWorks, NormBasis::Students, 3.0),
+            rule(WorkloadField::QualificationWorks, NormBasis::Students, 20.0),
+        ],
+        tolerance: 0.5,
+    }
+}
+
+/// Hours of `rule.field` for `row` by the norm, `None` when the row does
+/// not have that kind of work at all (e.g. a discipline without an exam).
+pub fn norm_value(row: &UniversityForm1WorkloadItem, rule: &NormRule) -> Option<f64> {
+    if row.field_value(rule.field) <= 0_f64 {
+        return None;
+    }
+    let units = match rule.basis {
+        NormBasis::Students => f64::from(row.student_count),
+        NormBasis::Flows => row.flow_count,
+        NormBasis::Groups => row.group_count,
+        NormBasis::Subgroups => row.subgroup_count,
+        NormBasis::Weeks => row.week_count,
+    };
+    Some(round(units * rule.hours_per_unit))
+}
+
+/// Rows whose hours differ from the norms by more than `table.tolerance`.
+pub fn check_form1_norms(form1: &UniversityForm1, table: &NormsTable) -> Vec<NormFinding> {
+    let mut findings = Vec::new();
+    for (row_number, row) in (1..).zip(&form1.workload_items) {
+        for rule in &table.rules {
+            let Some(norm_value) = norm_value(row, rule) else {
+                continue;
+            };
+            let file_value = row.field_value(rule.field);
+            if (file_value - norm_value).abs() > table.tolerance {
+                findings.push(NormFinding {
+                    row: row_number,
+                    key: row.workload_key.clone(),
+                    field: rule.field,
+                    file_value,
+                    norm_value,
+                });
+            }
+        }
+    }
+    findings
+}
+
+/// `form1` with the hours the norms cover replaced by the norm values.
+pub fn apply_form1_norms(mut form1: UniversityForm1, table: &NormsTable) -> UniversityForm1 {
+    for row in &mut form1.workload_items {
+        for rule in &table.rules {
+            if let Some(value) = norm_value(row, rule) {
+                row.set_field_value(rule.field, value);
+            }
+        }
+    }
+    form1
+}
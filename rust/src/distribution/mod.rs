@@ -0,0 +1,12 @@
+pub mod carry_over;
+pub mod data;
+pub mod dates;
+pub mod export;
+pub mod form1;
+pub mod form1_check;
+pub mod form1_diff;
+pub mod form1_norms;
+pub mod project;
+pub mod rate_norms;
+pub mod reconcile;
+pub mod solver;
@@ -0,0 +1,555 @@
+use std::collections::HashSet;
+
+use crate::distribution::{
+    data::{
+        AcademicCalendar, DisciplineKey, DistributionIssue, DistributionProject, Employee,
+        EmployeeRank, EmployeeRate, EmployeeWorkloadSummary, LearningForm, UndistributedWorkload,
+        UniversityForm1WorkloadItem, UniversityForm3WorkloadItem, WorkloadField,
+    },
+    dates::Date,
+    rate_norms::employee_norms,
+};
+
+/// Distributed values may exceed the planned ones by this much, to allow
+/// for rounding of fractional hours.
+const TOLERANCE: f64 = 0.0001;
+
+pub const WORKLOAD_FIELDS: [WorkloadField; 15] = [
+    WorkloadField::StudentCount,
+    WorkloadField::Lectures,
+    WorkloadField::Practices,
+    WorkloadField::Labs,
+    WorkloadField::Exams,
+    WorkloadField::ExamConsults,
+    WorkloadField::Tests,
+    WorkloadField::QualificationWorks,
+    WorkloadField::CertificationExams,
+    WorkloadField::ProductionPractices,
+    WorkloadField::TeachingPractices,
+    WorkloadField::CurrentConsults,
+    WorkloadField::IndividualWorks,
+    WorkloadField::CourseWorks,
+    WorkloadField::PostgraduateExams,
+];
+
+impl WorkloadField {
+    pub fn display_name(self) -> &'static str {
+        match self {
+            WorkloadField::StudentCount => "Кількість студентів",
+            WorkloadField::Lectures => "Лекції",
+            WorkloadField::Practices => "Практичні",
+            WorkloadField::Labs => "Лабораторні",
+            WorkloadField::Exams => "Екзамени",
+            WorkloadField::ExamConsults => "Консультації до екзаменів",
+            WorkloadField::Tests => "Заліки",
+            WorkloadField::QualificationWorks => "Кваліфікаційні роботи",
+            WorkloadField::CertificationExams => "Атестаційні екзамени",
+            WorkloadField::ProductionPractices => "Виробничі практики",
+            WorkloadField::TeachingPractices => "Навчальні практики",
+            WorkloadField::CurrentConsults => "Поточні консультації",
+            WorkloadField::IndividualWorks => "Індивідуальні роботи",
+            WorkloadField::CourseWorks => "Курсові роботи",
+            WorkloadField::PostgraduateExams => "Кандидатські екзамени",
+        }
+    }
+
+    /// Whether the field is hours, the student count is distributed too but
+    /// is not part of anyone's workload.
+    pub fn is_hours(self) -> bool {
+        self != WorkloadField::StudentCount
+    }
+}
+
+impl EmployeeRank {
+    pub fn display_name(self) -> &'static str {
+        match self {
+            EmployeeRank::Head => "Завідувач",
+            EmployeeRank::Professor => "Професор",
+            EmployeeRank::Associate => "Доцент",
+            EmployeeRank::SeniorLecturer => "Старший викладач",
+            EmployeeRank::Assistant => "Асистент",
+        }
+    }
+}
+
+impl LearningForm {
+    /// The code form 1 uses in its "Форма навчання" column.
+    pub fn short_name(self) -> &'static str {
+        match self {
+            LearningForm::Daytime => "Д",
+            LearningForm::Evening => "В",
+            LearningForm::Correspondence => "З",
+        }
+    }
+
+    pub fn from_short_name(value: &str) -> Option<Self> {
+        [
+            LearningForm::Daytime,
+            LearningForm::Evening,
+            LearningForm::Correspondence,
+        ]
+        .into_iter()
+        .find(|form| form.short_name() == value.trim())
+    }
+}
+
+impl DisciplineKey {
+    /// "Назва (Д, 121, 2 курс, 1 семестр)", for messages.
+    pub fn describe(&self) -> String {
+        format!(
+            "{} ({}, {}, {} курс, {} семестр)",
+            self.discipline_name,
+            self.learning_form.short_name(),
+            self.specialty,
+            self.course,
+            u32::from(self.semester)
+        )
+    }
+}
+
+impl UniversityForm1WorkloadItem {
+    /// The planned value, the total one for lectures, practices and labs.
+    pub fn field_value(&self, field: WorkloadField) -> f64 {
+        match field {
+            WorkloadField::StudentCount => f64::from(self.student_count),
+            WorkloadField::Lectures => self.lectures_total,
+            WorkloadField::Practices => self.practices_total,
+            WorkloadField::Labs => self.labs_total,
+            WorkloadField::Exams => self.exams,
+            WorkloadField::ExamConsults => self.exam_consults,
+            WorkloadField::Tests => self.tests,
+            WorkloadField::QualificationWorks => self.qualification_works,
+            WorkloadField::CertificationExams => self.certification_exams,
+            WorkloadField::ProductionPractices => self.production_practices,
+            WorkloadField::TeachingPractices => self.teaching_practices,
+            WorkloadField::CurrentConsults => self.current_consults,
+            WorkloadField::IndividualWorks => self.individual_works,
+            WorkloadField::CourseWorks => self.course_works,
+            WorkloadField::PostgraduateExams => self.postgraduate_exams,
+        }
+    }
+
+    /// Sets the planned value, the total one for lectures, practices and labs.
+    pub fn set_field_value(&mut self, field: WorkloadField, value: f64) {
+        let target = match field {
+            WorkloadField::StudentCount => {
+                self.student_count = value as u32;
+                return;
+            }
+            WorkloadField::Lectures => &mut self.lectures_total,
+            WorkloadField::Practices => &mut self.practices_total,
+            WorkloadField::Labs => &mut self.labs_total,
+            WorkloadField::Exams => &mut self.exams,
+            WorkloadField::ExamConsults => &mut self.exam_consults,
+            WorkloadField::Tests => &mut self.tests,
+            WorkloadField::QualificationWorks => &mut self.qualification_works,
+            WorkloadField::CertificationExams => &mut self.certification_exams,
+            WorkloadField::ProductionPractices => &mut self.production_practices,
+            WorkloadField::TeachingPractices => &mut self.teaching_practices,
+            WorkloadField::CurrentConsults => &mut self.current_consults,
+            WorkloadField::IndividualWorks => &mut self.individual_works,
+            WorkloadField::CourseWorks => &mut self.course_works,
+            WorkloadField::PostgraduateExams => &mut self.postgraduate_exams,
+        };
+        *target = value;
+    }
+}
+
+impl UniversityForm3WorkloadItem {
+    /// An item of `workload_key` with nothing assigned yet.
+    pub fn empty(id: String, workload_key: DisciplineKey) -> Self {
+        Self {
+            id,
+            workload_key,
+            student_count: 0,
+            academic_groups: Vec::new(),
+            lectures: 0_f64,
+            practices: 0_f64,
+            labs: 0_f64,
+            exams: 0_f64,
+            exam_consults: 0_f64,
+            tests: 0_f64,
+            qualification_works: 0_f64,
+            certification_exams: 0_f64,
+            production_practices: 0_f64,
+            teaching_practices: 0_f64,
+            current_consults: 0_f64,
+            individual_works: 0_f64,
+            course_works: 0_f64,
+            postgraduate_exams: 0_f64,
+        }
+    }
+
+    pub fn field_value(&self, field: WorkloadField) -> f64 {
+        match field {
+            WorkloadField::StudentCount => f64::from(self.student_count),
+            WorkloadField::Lectures => self.lectures,
+            WorkloadField::Practices => self.practices,
+            WorkloadField::Labs => self.labs,
+            WorkloadField::Exams => self.exams,
+            WorkloadField::ExamConsults => self.exam_consults,
+            WorkloadField::Tests => self.tests,
+            WorkloadField::QualificationWorks => self.qualification_works,
+            WorkloadField::CertificationExams => self.certification_exams,
+            WorkloadField::ProductionPractices => self.production_practices,
+            WorkloadField::TeachingPractices => self.teaching_practices,
+            WorkloadField::CurrentConsults => self.current_consults,
+            WorkloadField::IndividualWorks => self.individual_works,
+            WorkloadField::CourseWorks => self.course_works,
+            WorkloadField::PostgraduateExams => self.postgraduate_exams,
+        }
+    }
+
+    /// The student count is truncated, as the Dart model does.
+    pub fn set_field_value(&mut self, field: WorkloadField, value: f64) {
+        let target = match field {
+            WorkloadField::StudentCount => {
+                self.student_count = value as u32;
+                return;
+            }
+            WorkloadField::Lectures => &mut self.lectures,
+            WorkloadField::Practices => &mut self.practices,
+            WorkloadField::Labs => &mut self.labs,
+            WorkloadField::Exams => &mut self.exams,
+            WorkloadField::ExamConsults => &mut self.exam_consults,
+            WorkloadField::Tests => &mut self.tests,
+            WorkloadField::QualificationWorks => &mut self.qualification_works,
+            WorkloadField::CertificationExams => &mut self.certification_exams,
+            WorkloadField::ProductionPractices => &mut self.production_practices,
+            WorkloadField::TeachingPractices => &mut self.teaching_practices,
+            WorkloadField::CurrentConsults => &mut self.current_consults,
+            WorkloadField::IndividualWorks => &mut self.individual_works,
+            WorkloadField::CourseWorks => &mut self.course_works,
+            WorkloadField::PostgraduateExams => &mut self.postgraduate_exams,
+        };
+        *target = value;
+    }
+
+    /// Sets `field` to a share of a row computed by division. Student counts
+    /// are truncated, so a little is added against 11.999... from it.
+    pub fn set_field_share(&mut self, field: WorkloadField, value: f64) {
+        let value = if field.is_hours() {
+            value
+        } else {
+            value + 1e-6
+        };
+        self.set_field_value(field, value);
+    }
+
+    /// Sum of the hour fields.
+    pub fn hours(&self) -> f64 {
+        WORKLOAD_FIELDS
+            .into_iter()
+            .filter(|field| field.is_hours())
+            .map(|field| self.field_value(field))
+            .sum()
+    }
+}
+
+impl EmployeeRate {
+    pub fn hours(&self) -> f64 {
+        self.workload_items.iter().map(|item| item.hours()).sum()
+    }
+}
+
+impl Employee {
+    pub fn full_name(&self) -> String {
+        if self.patronymic.is_empty() {
+            format!("{} {}", self.first_name, self.last_name)
+        } else {
+            format!("{} {} {}", self.first_name, self.last_name, self.patronymic)
+        }
+    }
+}
+
+impl DistributionProject {
+    pub fn form1_item(&self, key: &DisciplineKey) -> Result<&UniversityForm1WorkloadItem, String> {
+        self.university_form1
+            .workload_items
+            .iter()
+            .find(|item| item.workload_key == *key)
+            .ok_or_else(|| format!("Дисципліну {} не знайдено у формі 1", key.describe()))
+    }
+
+    /// Every assigned item with the employee and rate it is assigned under.
+    pub fn assigned_items(
+        &self,
+    ) -> impl Iterator<Item = (&Employee, &EmployeeRate, &UniversityForm3WorkloadItem)> {
+        self.university_form3.employees.iter().flat_map(|employee| {
+            employee.rates.iter().flat_map(move |rate| {
+                rate.workload_items
+                    .iter()
+                    .map(move |item| (employee, rate, item))
+            })
+        })
+    }
+
+    /// Full names of the employees `key` is distributed to.
+    pub fn employee_names(&self, key: &DisciplineKey) -> Vec<String> {
+        let mut names = Vec::new();
+        for (employee, _, item) in self.assigned_items() {
+            let name = employee.full_name();
+            if item.workload_key == *key && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    pub fn total_workload(&self, key: &DisciplineKey, field: WorkloadField) -> Result<f64, String> {
+        Ok(self.form1_item(key)?.field_value(field))
+    }
+
+    pub fn distributed_workload(&self, key: &DisciplineKey, field: WorkloadField) -> f64 {
+        self.assigned_items()
+            .filter(|(_, _, item)| item.workload_key == *key)
+            .map(|(_, _, item)| item.field_value(field))
+            .sum()
+    }
+
+    pub fn undistributed_workload(
+        &self,
+        key: &DisciplineKey,
+        field: WorkloadField,
+    ) -> Result<f64, String> {
+        Ok(self.total_workload(key, field)? - self.distributed_workload(key, field))
+    }
+
+    /// Assigns `item` to a rate of an employee, failing when any of its
+    /// values exceeds what is left undistributed or its id is taken.
+    pub fn add_workload_item(
+        &mut self,
+        employee_id: &str,
+        rate_id: &str,
+        item: UniversityForm3WorkloadItem,
+    ) -> Result<(), String> {
+        if self
+            .assigned_items()
+            .any(|(_, _, other)| other.id == item.id)
+        {
+            return Err(format!("Навантаження з ідентифікатором {} вже є", item.id));
+        }
+        for field in WORKLOAD_FIELDS {
+            let value = item.field_value(field);
+            if value > 0_f64 {
+                self.check_workload_limit(&item.workload_key, field, value, 0_f64)?;
+            }
+        }
+        self.rate_mut(employee_id, rate_id)?
+            .workload_items
+            .push(item);
+        Ok(())
+    }
+
+    pub fn update_workload_field(
+        &mut self,
+        employee_id: &str,
+        rate_id: &str,
+        item_id: &str,
+        field: WorkloadField,
+        value: f64,
+    ) -> Result<(), String> {
+        let item = self.item_mut(employee_id, rate_id, item_id)?;
+        let key = item.workload_key.clone();
+        let old_value = item.field_value(field);
+        self.check_workload_limit(&key, field, value, old_value)?;
+        self.item_mut(employee_id, rate_id, item_id)?
+            .set_field_value(field, value);
+        Ok(())
+    }
+
+    pub fn remove_workload_item(
+        &mut self,
+        employee_id: &str,
+        rate_id: &str,
+        item_id: &str,
+    ) -> Result<(), String> {
+        self.rate_mut(employee_id, rate_id)?
+            .workload_items
+            .retain(|item| item.id != item_id);
+        Ok(())
+    }
+
+    /// Fails when replacing `old_value` with `new_value` would distribute
+    /// more of `field` than form 1 plans.
+    fn check_workload_limit(
+        &self,
+        key: &DisciplineKey,
+        field: WorkloadField,
+        new_value: f64,
+        old_value: f64,
+    ) -> Result<(), String> {
+        let total = self.total_workload(key, field)?;
+        let distributed = self.distributed_workload(key, field);
+        if total - distributed + old_value - new_value < -TOLERANCE {
+            return Err(format!(
+                "Перевищено ліміт по полю '{}': заплановано {}, вже розподілено {}, ви намагаєтесь встановити {}",
+                field.display_name(),
+                display_value(total),
+                display_value(distributed),
+                display_value(new_value)
+            ));
+        }
+        Ok(())
+    }
+
+    fn rate_mut(&mut self, employee_id: &str, rate_id: &str) -> Result<&mut EmployeeRate, String> {
+        self.university_form3
+            .employees
+            .iter_mut()
+            .find(|employee| employee.id == employee_id)
+            .ok_or_else(|| "Викладача не знайдено".to_string())?
+            .rates
+            .iter_mut()
+            .find(|rate| rate.id == rate_id)
+            .ok_or_else(|| "Ставку не знайдено".to_string())
+    }
+
+    fn item_mut(
+        &mut self,
+        employee_id: &str,
+        rate_id: &str,
+        item_id: &str,
+    ) -> Result<&mut UniversityForm3WorkloadItem, String> {
+        self.rate_mut(employee_id, rate_id)?
+            .workload_items
+            .iter_mut()
+            .find(|item| item.id == item_id)
+            .ok_or_else(|| "Навантаження не знайдено".to_string())
+    }
+}
+
+/// Every field of every form 1 row that is planned or distributed, with
+/// what is left of it.
+pub fn undistributed_workload(project: &DistributionProject) -> Vec<UndistributedWorkload> {
+    let mut rows = Vec::new();
+    for form1_item in &project.university_form1.workload_items {
+        let key = &form1_item.workload_key;
+        for field in WORKLOAD_FIELDS {
+            let total = form1_item.field_value(field);
+            let distributed = project.distributed_workload(key, field);
+            if total == 0_f64 && distributed == 0_f64 {
+                continue;
+            }
+            rows.push(UndistributedWorkload {
+                workload_key: key.clone(),
+                field,
+                total,
+                distributed,
+                undistributed: total - distributed,
+            });
+        }
+    }
+    rows
+}
+
+/// Hours of every employee against the yearly norm of their rates, as
+/// `rate_norms::employee_norms` counts it for `calendar`.
+pub fn employee_summaries(
+    project: &DistributionProject,
+    calendar: &AcademicCalendar,
+    supervision_hours_per_postgraduate: f64,
+) -> Result<Vec<EmployeeWorkloadSummary>, String> {
+    Ok(
+        employee_norms(project, calendar, supervision_hours_per_postgraduate)?
+            .into_iter()
+            .map(|norms| EmployeeWorkloadSummary {
+                employee_id: norms.employee_id,
+                full_name: norms.full_name,
+                hours: norms.year.hours,
+                min_possible_hours: norms.year.min_hours,
+                max_possible_hours: norms.year.max_hours,
+            })
+            .collect(),
+    )
+}
+
+/// Everything `add_workload_item` and the rate editor would have refused:
+/// items of disciplines missing from form 1 or sharing an id, which the
+/// edits by id would confuse, fields distributed beyond the plan, and rates
+/// with a non-positive value or unreadable dates.
+pub fn validate_distribution(project: &DistributionProject) -> Vec<DistributionIssue> {
+    let mut issues = Vec::new();
+    for employee in &project.university_form3.employees {
+        for rate in &employee.rates {
+            let problem = if rate.rate_value <= 0_f64 {
+                Some("значення ставки має бути більшим за 0".to_string())
+            } else {
+                Date::parse(&rate.date_start)
+                    .and(Date::parse(&rate.date_end))
+                    .err()
+            };
+            if let Some(problem) = problem {
+                issues.push(DistributionIssue {
+                    employee_id: Some(employee.id.clone()),
+                    item_id: None,
+                    message: format!("Ставка {}: {problem}", employee.full_name()),
+                });
+            }
+        }
+    }
+    let mut item_ids = HashSet::new();
+    for (employee, _, item) in project.assigned_items() {
+        if let Err(message) = project.form1_item(&item.workload_key) {
+            issues.push(DistributionIssue {
+                employee_id: Some(employee.id.clone()),
+                item_id: Some(item.id.clone()),
+                message,
+            });
+        }
+        if !item_ids.insert(item.id.as_str()) {
+            issues.push(DistributionIssue {
+                employee_id: Some(employee.id.clone()),
+                item_id: Some(item.id.clone()),
+                message: format!(
+                    "{}: ідентифікатор навантаження {} повторюється",
+                    employee.full_name(),
+                    item.id
+                ),
+            });
+        }
+    }
+    for row in undistributed_workload(project) {
+        if row.undistributed < -TOLERANCE {
+            issues.push(DistributionIssue {
+                employee_id: None,
+                item_id: None,
+                message: format!(
+                    "{}: поле '{}' розподілено понад план ({} із {})",
+                    row.workload_key.describe(),
+                    row.field.display_name(),
+                    display_value(row.distributed),
+                    display_value(row.total)
+                ),
+            });
+        }
+    }
+    issues
+}
+
+pub fn read_project_file(file_path: &str) -> Result<DistributionProject, String> {
+    let json = std::fs::read_to_string(file_path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+pub fn write_project_file(file_path: &str, project: &DistributionProject) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(project).map_err(|e| e.to_string())?;
+    std::fs::write(file_path, json).map_err(|e| e.to_string())
+}
+
+/// Discipline names compared case- and whitespace-insensitively.
+pub fn normalize_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Whole numbers without decimals, the rest with two.
+pub fn display_value(value: f64) -> String {
+    if value.fract() == 0_f64 {
+        format!("{value}")
+    } else {
+        format!("{value:.2}")
+    }
+}
@@ -0,0 +1,281 @@
+use crate::distribution::{
+    data::{
+        AcademicCalendar, AcademicSemester, DistributionIssue, DistributionProject, Employee,
+        EmployeeNorms, EmployeeRate, PeriodNorm, RateNorms,
+    },
+    dates::{Date, days_in_month},
+    project::display_value,
+};
+use crate::excel::workload_norms::{MAX_HOURS_PER_RATE, MIN_HOURS_PER_RATE};
+
+/// The usual calendar of the academic year starting in September of
+/// `academic_year`: September to January and February to June.
+pub fn default_academic_calendar(academic_year: u32) -> AcademicCalendar {
+    let next_year = academic_year + 1;
+    AcademicCalendar {
+        first_semester_start: format!("{academic_year}-09-01"),
+        first_semester_end: format!("{next_year}-01-31"),
+        second_semester_start: format!("{next_year}-02-01"),
+        second_semester_end: format!("{next_year}-06-30"),
+    }
+}
+
+/// First and last day of `semester`, an error when it ends before it starts.
+pub fn semester_period(
+    calendar: &AcademicCalendar,
+    semester: AcademicSemester,
+) -> Result<(Date, Date), String> {
+    let (start, end) = match semester {
+        AcademicSemester::First => (&calendar.first_semester_start, &calendar.first_semester_end),
+        AcademicSemester::Second => (
+            &calendar.second_semester_start,
+            &calendar.second_semester_end,
+        ),
+    };
+    let (start, end) = (Date::parse(start)?, Date::parse(end)?);
+    if end < start {
+        return Err(format!(
+            "{} семестр закінчується {end} раніше, ніж починається {start}",
+            u32::from(semester)
+        ));
+    }
+    Ok((start, end))
+}
+
+/// Value of `rate` over `semester`: its value times the share of the
+/// semester it lasts, so a rate starting in February is 0 in the first
+/// semester.
+pub fn semester_rate(
+    rate: &EmployeeRate,
+    calendar: &AcademicCalendar,
+    semester: AcademicSemester,
+) -> Result<f64, String> {
+    let (period_start, period_end) = semester_period(calendar, semester)?;
+    let semester_months = months_within(period_start, period_end, period_start, period_end);
+    if semester_months == 0_f64 {
+        return Ok(0_f64);
+    }
+    let months = months_within(
+        Date::parse(&rate.date_start)?,
+        Date::parse(&rate.date_end)?,
+        period_start,
+        period_end,
+    );
+    Ok(rate.rate_value * months / semester_months)
+}
+
+/// Months from `start` to `end`, both included, that fall within
+/// `period_start..=period_end`. A month partly covered counts as the share
+/// of its days covered, so 15 to 30 September is half a month.
+pub fn months_within(start: Date, end: Date, period_start: Date, period_end: Date) -> f64 {
+    let start = start.max(period_start);
+    let end = end.min(period_end);
+    if start > end {
+        return 0_f64;
+    }
+    let mut months = 0_f64;
+    let (mut year, mut month) = (start.year, start.month);
+    while (year, month) <= (end.year, end.month) {
+        let days = days_in_month(year, month);
+        let first = if (year, month) == (start.year, start.month) {
+            start.day
+        } else {
+            1
+        };
+        let last = if (year, month) == (end.year, end.month) {
+            end.day
+        } else {
+            days
+        };
+        months += f64::from(last - first + 1) / f64::from(days);
+        (year, month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+    }
+    months
+}
+
+/// Norm hours of every employee of `project` per semester and for the
+/// year. Rates are counted by the exact months they last within the
+/// semesters of `calendar`, a rate may overlap others of the same employee.
+/// Every postgraduate supervised adds `supervision_hours_per_postgraduate`
+/// a year, in proportion to the months of the rate.
+pub fn employee_norms(
+    project: &DistributionProject,
+    calendar: &AcademicCalendar,
+    supervision_hours_per_postgraduate: f64,
+) -> Result<Vec<EmployeeNorms>, String> {
+    let (first_start, first_end) = semester_period(calendar, AcademicSemester::First)?;
+    let (second_start, second_end) = semester_period(calendar, AcademicSemester::Second)?;
+    let year_months = months_within(first_start, first_end, first_start, first_end)
+        + months_within(second_start, second_end, second_start, second_end);
+    project
+        .university_form3
+        .employees
+        .iter()
+        .map(|employee| {
+            employee_norms_of(
+                employee,
+                calendar,
+                year_months,
+                supervision_hours_per_postgraduate,
+            )
+        })
+        .collect()
+}
+
+/// Hours assigned in a semester none of the rate lasts in, they would be
+/// exported against a zero rate.
+pub fn check_semester_coverage(
+    project: &DistributionProject,
+    calendar: &AcademicCalendar,
+) -> Result<Vec<DistributionIssue>, String> {
+    let mut issues = Vec::new();
+    for (employee, rate, item) in project.assigned_items() {
+        let semester = item.workload_key.semester;
+        if item.hours() <= 0_f64 || semester_rate(rate, calendar, semester)? > 0_f64 {
+            continue;
+        }
+        issues.push(DistributionIssue {
+            employee_id: Some(employee.id.clone()),
+            item_id: Some(item.id.clone()),
+            message: format!(
+                "{}: {} год. дисципліни {} у {} семестрі, а ставка діє з {} по {}",
+                employee.full_name(),
+                display_value(item.hours()),
+                item.workload_key.describe(),
+                u32::from(semester),
+                Date::parse(&rate.date_start)?,
+                Date::parse(&rate.date_end)?
+            ),
+        });
+    }
+    Ok(issues)
+}
+
+fn employee_norms_of(
+    employee: &Employee,
+    calendar: &AcademicCalendar,
+    year_months: f64,
+    supervision_hours_per_postgraduate: f64,
+) -> Result<EmployeeNorms, String> {
+    let mut rates = Vec::new();
+    let mut periods = Vec::new();
+    for rate in &employee.rates {
+        let start = Date::parse(&rate.date_start)?;
+        let end = Date::parse(&rate.date_end)?;
+        periods.push((start, end, rate.rate_value));
+
+        let semester_months = |semester| -> Result<_, String> {
+            let (period_start, period_end) = semester_period(calendar, semester)?;
+            Ok(months_within(start, end, period_start, period_end))
+        };
+        let semester_hours = |semester| -> f64 {
+            rate.workload_items
+                .iter()
+                .filter(|item| item.workload_key.semester == semester)
+                .map(|item| item.hours())
+                .sum()
+        };
+        let first_months = semester_months(AcademicSemester::First)?;
+        let second_months = semester_months(AcademicSemester::Second)?;
+        let months = first_months + second_months;
+        // Rounded once for the year and split by the months, so the
+        // semesters add up to it.
+        let supervision_hours =
+            (supervision_hours_per_postgraduate * f64::from(rate.postgraduate_count) * months
+                / year_months)
+                .round();
+        let first_supervision_hours = if months > 0_f64 {
+            (supervision_hours * first_months / months).round()
+        } else {
+            0_f64
+        };
+        let norm = |months, supervision_hours, hours| {
+            period_norm(
+                rate.rate_value,
+                months / year_months,
+                supervision_hours,
+                hours,
+            )
+        };
+        rates.push(RateNorms {
+            rate_id: rate.id.clone(),
+            rate_value: rate.rate_value,
+            months,
+            first_semester: PeriodNorm {
+                rate_months: rate.rate_value * first_months,
+                ..norm(
+                    first_months,
+                    first_supervision_hours,
+                    semester_hours(AcademicSemester::First),
+                )
+            },
+            second_semester: PeriodNorm {
+                rate_months: rate.rate_value * second_months,
+                ..norm(
+                    second_months,
+                    supervision_hours - first_supervision_hours,
+                    semester_hours(AcademicSemester::Second),
+                )
+            },
+            year: PeriodNorm {
+                rate_months: rate.rate_value * months,
+                ..norm(months, supervision_hours, rate.hours())
+            },
+        });
+    }
+
+    let peak_rate = periods
+        .iter()
+        .map(|(day, _, _)| {
+            periods
+                .iter()
+                .filter(|(start, end, _)| start <= day && day <= end)
+                .map(|(_, _, rate_value)| rate_value)
+                .sum()
+        })
+        .fold(0_f64, f64::max);
+    Ok(EmployeeNorms {
+        employee_id: employee.id.clone(),
+        full_name: employee.full_name(),
+        peak_rate,
+        first_semester: sum(rates.iter().map(|rate| rate.first_semester)),
+        second_semester: sum(rates.iter().map(|rate| rate.second_semester)),
+        year: sum(rates.iter().map(|rate| rate.year)),
+        rates,
+    })
+}
+
+/// 580 to 600 hours per rate for the academic year, in proportion to the
+/// `share` of the year the rate lasts.
+fn period_norm(rate_value: f64, share: f64, supervision_hours: f64, hours: f64) -> PeriodNorm {
+    PeriodNorm {
+        rate_months: 0_f64,
+        supervision_hours,
+        min_hours: (MIN_HOURS_PER_RATE * rate_value * share).round(),
+        max_hours: (MAX_HOURS_PER_RATE * rate_value * share).round(),
+        hours: hours + supervision_hours,
+    }
+}
+
+fn sum(norms: impl Iterator<Item = PeriodNorm>) -> PeriodNorm {
+    norms.fold(
+        PeriodNorm {
+            rate_months: 0_f64,
+            supervision_hours: 0_f64,
+            min_hours: 0_f64,
+            max_hours: 0_f64,
+            hours: 0_f64,
+        },
+        |total, norm| PeriodNorm {
+            rate_months: total.rate_months + norm.rate_months,
+            supervision_hours: total.supervision_hours + norm.supervision_hours,
+            min_hours: total.min_hours + norm.min_hours,
+            max_hours: total.max_hours + norm.max_hours,
+            hours: total.hours + norm.hours,
+        },
+    )
+}
@@ -0,0 +1,104 @@
+use crate::distribution::{
+    data::{
+        DisciplineKey, DistributionProject, ReconcileConflict, ReconcileConflictKind,
+        ReconcileResult, UniversityForm1,
+    },
+    form1_diff::diff_form1,
+    project::{display_value, undistributed_workload},
+};
+
+/// Distributed values may exceed the planned ones by this much, as in
+/// `DistributionProject`.
+const TOLERANCE: f64 = 0.0001;
+
+/// Moves `project` onto the revised `form1`. Assignments of unchanged rows
+/// are kept, those of changed rows are scaled with every changed field, so
+/// each teacher keeps their share. Assignments of vanished rows are kept as
+/// they are and reported, as are fields distributed beyond the new plan.
+pub fn reconcile_distribution(
+    project: &DistributionProject,
+    form1: UniversityForm1,
+) -> ReconcileResult {
+    let diff = diff_form1(
+        &project.university_form1.workload_items,
+        &form1.workload_items,
+        Some(project),
+    );
+    let mut reconciled = project.clone();
+    for entry in &diff.changed {
+        for change in entry
+            .changes
+            .iter()
+            .filter(|change| change.old_value > 0_f64)
+        {
+            let factor = change.new_value / change.old_value;
+            let items = reconciled
+                .university_form3
+                .employees
+                .iter_mut()
+                .flat_map(|employee| &mut employee.rates)
+                .flat_map(|rate| &mut rate.workload_items)
+                .filter(|item| item.workload_key == entry.key);
+            for item in items {
+                let value = item.field_value(change.field) * factor;
+                item.set_field_share(change.field, value);
+            }
+        }
+    }
+    reconciled.university_form1 = form1;
+
+    let mut conflicts = Vec::new();
+    for entry in diff
+        .removed
+        .iter()
+        .filter(|entry| !entry.affected_employees.is_empty())
+    {
+        let distributed = distributed_hours(&reconciled, &entry.key);
+        conflicts.push(ReconcileConflict {
+            kind: ReconcileConflictKind::VanishedDiscipline,
+            key: entry.key.clone(),
+            field: None,
+            total: 0_f64,
+            distributed,
+            employees: entry.affected_employees.clone(),
+            message: format!(
+                "{} відсутня у новій формі 1, але розподілена: {} год.",
+                entry.key.describe(),
+                display_value(distributed)
+            ),
+        });
+    }
+    for row in undistributed_workload(&reconciled) {
+        if row.undistributed >= -TOLERANCE {
+            continue;
+        }
+        conflicts.push(ReconcileConflict {
+            kind: ReconcileConflictKind::OverDistributed,
+            employees: reconciled.employee_names(&row.workload_key),
+            message: format!(
+                "{}: поле '{}' розподілено понад план ({} із {})",
+                row.workload_key.describe(),
+                row.field.display_name(),
+                display_value(row.distributed),
+                display_value(row.total)
+            ),
+            key: row.workload_key,
+            field: Some(row.field),
+            total: row.total,
+            distributed: row.distributed,
+        });
+    }
+    ReconcileResult {
+        project: reconciled,
+        diff,
+        conflicts,
+    }
+}
+
+fn distributed_hours(project: &DistributionProject, key: &DisciplineKey) -> f64 {
+    project
+        .assigned_items()
+        .filter(|(_, _, item)| item.workload_key == *key)
+        .map(|(_, _, item)| item.hours())
+        .sum()
+}
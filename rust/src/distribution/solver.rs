@@ -0,0 +1,336 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::distribution::{
+    data::{
+        AcademicCalendar, AcademicSemester, DisciplineKey, DistributionProject, EmployeeRank,
+        PeriodNorm, SolverOptions, SolverResult, UniversityForm1WorkloadItem,
+        UniversityForm3WorkloadItem, WorkloadField,
+    },
+    project::{WORKLOAD_FIELDS, employee_summaries, normalize_name, undistributed_workload},
+    rate_norms::{employee_norms, semester_rate},
+};
+
+/// Hours below this are rounding leftovers and are not assigned.
+const TOLERANCE: f64 = 0.005;
+
+/// A rate the solver fills, with the hours assigned to it so far.
+struct RateSlot {
+    employee_index: usize,
+    rate_index: usize,
+    rank: EmployeeRank,
+    /// Normalised discipline names, `None` for any discipline.
+    disciplines: Option<HashSet<String>>,
+    taught: HashSet<String>,
+    /// First and second semester.
+    semesters: [SemesterSlot; 2],
+}
+
+/// A rate over a semester: its value there and its norm hours.
+struct SemesterSlot {
+    rate: f64,
+    hours: f64,
+    min_hours: f64,
+    max_hours: f64,
+}
+
+impl RateSlot {
+    fn semester(&self, semester: AcademicSemester) -> &SemesterSlot {
+        &self.semesters[semester_index(semester)]
+    }
+
+    fn capacity(&self, semester: AcademicSemester) -> f64 {
+        let slot = self.semester(semester);
+        slot.max_hours - slot.hours
+    }
+
+    fn deficit(&self, semester: AcademicSemester) -> f64 {
+        let slot = self.semester(semester);
+        slot.min_hours - slot.hours
+    }
+
+    /// Hours of a semester the rate does not last in would be exported
+    /// against a zero rate.
+    fn can_teach(&self, key: &DisciplineKey, min_rank: Option<EmployeeRank>) -> bool {
+        let rank_allowed =
+            min_rank.is_none_or(|min_rank| seniority(self.rank) >= seniority(min_rank));
+        let discipline_allowed = self
+            .disciplines
+            .as_ref()
+            .is_none_or(|disciplines| disciplines.contains(&normalize_name(&key.discipline_name)));
+        rank_allowed && discipline_allowed && self.semester(key.semester).rate > 0_f64
+    }
+}
+
+/// Proposes who teaches the undistributed hours of `project`, keeping what
+/// is already assigned. Rows go from the largest, each one preferably to a
+/// single rate: one that already teaches it or the same discipline, then the
+/// one furthest below its 580 hours per rate, never above 600, both counted
+/// for the row's semester. The norms are those of
+/// `rate_norms::employee_norms` for `calendar` and
+/// `supervision_hours_per_postgraduate`. Rows nobody
+/// can take whole are split field by field, hours nobody can take are left
+/// in `unplaced`.
+pub fn distribute_workload(
+    project: &DistributionProject,
+    options: &SolverOptions,
+    calendar: &AcademicCalendar,
+    supervision_hours_per_postgraduate: f64,
+) -> Result<SolverResult, String> {
+    let mut project = project.clone();
+    let mut slots = rate_slots(
+        &project,
+        options,
+        calendar,
+        supervision_hours_per_postgraduate,
+    )?;
+    let mut form1_items = project.university_form1.workload_items.clone();
+    form1_items.sort_by(|a, b| row_hours(b).total_cmp(&row_hours(a)));
+
+    let mut split_items = Vec::new();
+    for form1_item in &form1_items {
+        let key = &form1_item.workload_key;
+        let mut assignees: HashSet<usize> = slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| rate_items(&project, slot).any(|item| item.workload_key == *key))
+            .map(|(index, _)| index)
+            .collect();
+
+        for (min_rank, fields) in field_groups(options) {
+            let mut remaining = Vec::new();
+            for field in fields {
+                let value = project.undistributed_workload(key, field)?;
+                if value > TOLERANCE {
+                    remaining.push((field, value));
+                }
+            }
+            if remaining.is_empty() {
+                continue;
+            }
+            let candidates: Vec<usize> = (0..slots.len())
+                .filter(|index| slots[*index].can_teach(key, min_rank))
+                .collect();
+
+            let total: f64 = remaining.iter().map(|(_, value)| value).sum();
+            if let Some(best) = best_slot(&slots, &candidates, &assignees, key, total) {
+                for (field, value) in remaining {
+                    assign(&mut project, &mut slots[best], key, field, value)?;
+                }
+                assignees.insert(best);
+                continue;
+            }
+            for (field, mut value) in remaining {
+                while value > TOLERANCE {
+                    let Some(best) = best_slot(&slots, &candidates, &assignees, key, TOLERANCE)
+                    else {
+                        break;
+                    };
+                    let part = value.min(slots[best].capacity(key.semester));
+                    assign(&mut project, &mut slots[best], key, field, part)?;
+                    assignees.insert(best);
+                    value -= part;
+                }
+            }
+        }
+        if assignees.len() > 1 {
+            split_items.push(key.clone());
+        }
+    }
+
+    let unplaced = undistributed_workload(&project)
+        .into_iter()
+        .filter(|row| row.field.is_hours() && row.undistributed > TOLERANCE)
+        .collect();
+    let employees = employee_summaries(&project, calendar, supervision_hours_per_postgraduate)?;
+    Ok(SolverResult {
+        project,
+        unplaced,
+        split_items,
+        employees,
+    })
+}
+
+fn rate_slots(
+    project: &DistributionProject,
+    options: &SolverOptions,
+    calendar: &AcademicCalendar,
+    supervision_hours_per_postgraduate: f64,
+) -> Result<Vec<RateSlot>, String> {
+    let mut slots = Vec::new();
+    let employees = project.university_form3.employees.iter();
+    for (employee_index, (employee, employee_norms)) in employees
+        .zip(employee_norms(
+            project,
+            calendar,
+            supervision_hours_per_postgraduate,
+        )?)
+        .enumerate()
+    {
+        let disciplines = options
+            .preferences
+            .iter()
+            .find(|preferences| preferences.employee_id == employee.id)
+            .map(|preferences| {
+                preferences
+                    .disciplines
+                    .iter()
+                    .map(|name| normalize_name(name))
+                    .collect()
+            });
+        for (rate_index, (rate, norms)) in
+            employee.rates.iter().zip(employee_norms.rates).enumerate()
+        {
+            let semester = |semester, norms: PeriodNorm| -> Result<_, String> {
+                Ok(SemesterSlot {
+                    rate: semester_rate(rate, calendar, semester)?,
+                    hours: norms.hours,
+                    min_hours: norms.min_hours,
+                    max_hours: norms.max_hours,
+                })
+            };
+            slots.push(RateSlot {
+                employee_index,
+                rate_index,
+                rank: employee.rank,
+                disciplines: disciplines.clone(),
+                taught: rate
+                    .workload_items
+                    .iter()
+                    .map(|item| normalize_name(&item.workload_key.discipline_name))
+                    .collect(),
+                semesters: [
+                    semester(AcademicSemester::First, norms.first_semester)?,
+                    semester(AcademicSemester::Second, norms.second_semester)?,
+                ],
+            });
+        }
+    }
+    Ok(slots)
+}
+
+/// Hour fields grouped by the lowest rank allowed to take them, the
+/// restricted groups first so the senior staff is not filled up by the rest.
+fn field_groups(options: &SolverOptions) -> Vec<(Option<EmployeeRank>, Vec<WorkloadField>)> {
+    let mut groups: Vec<(Option<EmployeeRank>, Vec<WorkloadField>)> = Vec::new();
+    for field in WORKLOAD_FIELDS.into_iter().filter(|field| field.is_hours()) {
+        let min_rank = options
+            .rank_rules
+            .iter()
+            .filter(|rule| rule.field == field)
+            .map(|rule| rule.min_rank)
+            .max_by_key(|rank| seniority(*rank));
+        match groups.iter_mut().find(|(rank, _)| *rank == min_rank) {
+            Some((_, fields)) => fields.push(field),
+            None => groups.push((min_rank, vec![field])),
+        }
+    }
+    groups.sort_by_key(|(rank, _)| std::cmp::Reverse(rank.map_or(0, |rank| seniority(rank) + 1)));
+    groups
+}
+
+/// The candidate that can take `hours` and keeps the row with as few
+/// teachers as possible.
+fn best_slot(
+    slots: &[RateSlot],
+    candidates: &[usize],
+    assignees: &HashSet<usize>,
+    key: &DisciplineKey,
+    hours: f64,
+) -> Option<usize> {
+    let discipline = normalize_name(&key.discipline_name);
+    candidates
+        .iter()
+        .copied()
+        .filter(|index| {
+            let capacity = slots[*index].capacity(key.semester);
+            capacity > TOLERANCE && capacity >= hours - TOLERANCE
+        })
+        .max_by(|a, b| {
+            let (a_slot, b_slot) = (&slots[*a], &slots[*b]);
+            assignees
+                .contains(a)
+                .cmp(&assignees.contains(b))
+                .then(
+                    a_slot
+                        .taught
+                        .contains(&discipline)
+                        .cmp(&b_slot.taught.contains(&discipline)),
+                )
+                .then(
+                    a_slot
+                        .deficit(key.semester)
+                        .total_cmp(&b_slot.deficit(key.semester)),
+                )
+                // Earlier employees win ties.
+                .then(b.cmp(a))
+        })
+}
+
+fn assign(
+    project: &mut DistributionProject,
+    slot: &mut RateSlot,
+    key: &DisciplineKey,
+    field: WorkloadField,
+    hours: f64,
+) -> Result<(), String> {
+    // The first employee given a row takes its students.
+    let students = project
+        .undistributed_workload(key, WorkloadField::StudentCount)?
+        .max(0_f64);
+    let existing = project.university_form3.employees[slot.employee_index].rates[slot.rate_index]
+        .workload_items
+        .iter()
+        .position(|item| item.workload_key == *key);
+    let new_item = existing.is_none().then(|| {
+        let mut item = UniversityForm3WorkloadItem::empty(Uuid::new_v4().to_string(), key.clone());
+        item.student_count = students as u32;
+        item
+    });
+    let rate = &mut project.university_form3.employees[slot.employee_index].rates[slot.rate_index];
+    let index = existing.unwrap_or_else(|| {
+        rate.workload_items.extend(new_item);
+        rate.workload_items.len() - 1
+    });
+    let item = &mut rate.workload_items[index];
+    item.set_field_value(field, item.field_value(field) + hours);
+    slot.semesters[semester_index(key.semester)].hours += hours;
+    slot.taught.insert(normalize_name(&key.discipline_name));
+    Ok(())
+}
+
+fn rate_items<'a>(
+    project: &'a DistributionProject,
+    slot: &RateSlot,
+) -> impl Iterator<Item = &'a UniversityForm3WorkloadItem> {
+    project.university_form3.employees[slot.employee_index].rates[slot.rate_index]
+        .workload_items
+        .iter()
+}
+
+fn row_hours(item: &UniversityForm1WorkloadItem) -> f64 {
+    WORKLOAD_FIELDS
+        .into_iter()
+        .filter(|field| field.is_hours())
+        .map(|field| item.field_value(field))
+        .sum()
+}
+
+fn semester_index(semester: AcademicSemester) -> usize {
+    match semester {
+        AcademicSemester::First => 0,
+        AcademicSemester::Second => 1,
+    }
+}
+
+/// Higher is more senior.
+fn seniority(rank: EmployeeRank) -> u8 {
+    match rank {
+        EmployeeRank::Assistant => 0,
+        EmployeeRank::SeniorLecturer => 1,
+        EmployeeRank::Associate => 2,
+        EmployeeRank::Professor => 3,
+        EmployeeRank::Head => 4,
+    }
+}
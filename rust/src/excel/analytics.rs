@@ -0,0 +1,262 @@
+use umya_spreadsheet::{
+    Chart, ChartType, Spreadsheet, Worksheet,
+    drawing::{
+        charts::{CategoryAxisData, StringReference},
+        spreadsheet::MarkerType,
+    },
+};
+
+use crate::excel::{
+    columns::total_of,
+    data::{ExportedData, WorkloadColumn, WorkloadKey},
+    excel_functions::{column_letter, column_set_width, merge_cells},
+    sheet_names::ANALYTICS_SHEET_NAME,
+    styles::StyleRegistry,
+    totals::{personal_tables_of_main_rows, round},
+};
+
+/// Rows a chart takes next to its table, tables shorter than that are
+/// spaced out so the charts do not overlap.
+const CHART_ROWS: u32 = 16;
+const CHART_FIRST_X: u32 = 5;
+const CHART_LAST_X: u32 = 13;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AnalyticsChart {
+    Bar,
+    Pie,
+}
+
+/// Hours of the whole department grouped by one property, largest first.
+pub struct AnalyticsTable {
+    pub title: &'static str,
+    pub group_caption: &'static str,
+    pub rows: Vec<(String, f64)>,
+    pub chart: AnalyticsChart,
+}
+
+impl AnalyticsTable {
+    pub fn total(&self) -> f64 {
+        round(self.rows.iter().map(|(_, hours)| hours).sum())
+    }
+}
+
+/// The pivots the head of department builds by hand: yearly hours per
+/// discipline, speciality, learning form and semester, and lecture hours
+/// per rank. Hours are the totals of `columns`. Ranks are the main table
+/// sections the teachers are listed in, their free-text ranks with degrees
+/// and titles would give every teacher a slice of their own.
+pub fn workload_analytics(data: &ExportedData, columns: &[WorkloadColumn]) -> Vec<AnalyticsTable> {
+    let mut disciplines = Vec::new();
+    let mut specialities = Vec::new();
+    let mut learning_forms = Vec::new();
+    let mut semesters = Vec::new();
+    let mut lectures_by_rank = Vec::new();
+
+    let tables = data
+        .personal_tables
+        .iter()
+        .flat_map(|tables| &tables.tables);
+    for table in tables {
+        let semester_rows = [
+            ("I семестр", &table.semester_1),
+            ("II семестр", &table.semester_2),
+        ];
+        for (semester, rows) in semester_rows {
+            for row in rows {
+                let Some(hours) = total_of(&row.hours, columns) else {
+                    continue;
+                };
+                add_hours(&mut disciplines, &row.name, hours);
+                add_hours(&mut specialities, &row.speciality, hours);
+                add_hours(&mut learning_forms, &row.learning_form, hours);
+                add_hours(&mut semesters, semester, hours);
+            }
+        }
+    }
+
+    let section_titles = data
+        .main_table
+        .sections
+        .iter()
+        .flat_map(|section| section.rows.iter().map(|_| section.title.as_str()));
+    for (title, table) in section_titles.zip(personal_tables_of_main_rows(data)) {
+        let Some(table) = table else {
+            continue;
+        };
+        for row in table.semester_1.iter().chain(&table.semester_2) {
+            let lectures = row.hours.value(WorkloadKey::Lectures);
+            if lectures > 0_f64 {
+                add_hours(&mut lectures_by_rank, title, lectures);
+            }
+        }
+    }
+
+    // Semesters keep their order, the rest go from the largest.
+    for rows in [
+        &mut disciplines,
+        &mut specialities,
+        &mut learning_forms,
+        &mut lectures_by_rank,
+    ] {
+        rows.sort_by(|a, b| b.1.total_cmp(&a.1));
+    }
+    vec![
+        AnalyticsTable {
+            title: "Години за дисциплінами",
+            group_caption: "Дисципліна",
+            rows: disciplines,
+            chart: AnalyticsChart::Bar,
+        },
+        AnalyticsTable {
+            title: "Години за спеціальностями",
+            group_caption: "Спеціальність",
+            rows: specialities,
+            chart: AnalyticsChart::Bar,
+        },
+        AnalyticsTable {
+            title: "Години за формами навчання",
+            group_caption: "Форма навчання",
+            rows: learning_forms,
+            chart: AnalyticsChart::Pie,
+        },
+        AnalyticsTable {
+            title: "Години за семестрами",
+            group_caption: "Семестр",
+            rows: semesters,
+            chart: AnalyticsChart::Bar,
+        },
+        AnalyticsTable {
+            title: "Частка лекцій за посадами",
+            group_caption: "Посада",
+            rows: lectures_by_rank,
+            chart: AnalyticsChart::Pie,
+        },
+    ]
+}
+
+/// "Аналітика" sheet with a table of `workload_analytics` and a chart of it
+/// per pivot.
+pub fn write_analytics_sheet(
+    book: &mut Spreadsheet,
+    data: &ExportedData,
+    columns: &[WorkloadColumn],
+    styles: &StyleRegistry,
+) {
+    let sheet = book.new_sheet(ANALYTICS_SHEET_NAME).unwrap();
+    column_set_width(sheet, 1, 40.0);
+    column_set_width(sheet, 2, 12.0);
+    column_set_width(sheet, 3, 12.0);
+
+    merge_cells(sheet, (1, 1), (CHART_LAST_X, 1));
+    sheet.set_cell(styles.cell(
+        "title",
+        &format!(
+            "Аналітика навчального навантаження кафедри {} на {}-{} навчальний рік",
+            data.type_name,
+            data.year,
+            data.year + 1
+        ),
+        (1, 1),
+    ));
+
+    let mut position_y = 3;
+    for table in workload_analytics(data, columns) {
+        let table_height = write_analytics_table(sheet, position_y, &table, styles);
+        position_y += table_height.max(CHART_ROWS) + 2;
+    }
+}
+
+/// Returns the number of rows written.
+fn write_analytics_table(
+    sheet: &mut Worksheet,
+    position_y: u32,
+    table: &AnalyticsTable,
+    styles: &StyleRegistry,
+) -> u32 {
+    merge_cells(sheet, (1, position_y), (3, position_y));
+    sheet.set_cell(styles.cell("semester-title", table.title, (1, position_y)));
+    for x in 2..=3 {
+        styles.apply(sheet.get_cell_mut((x, position_y)), "header-blank");
+    }
+    let headers = [table.group_caption, "Години", "Частка"];
+    for (x, header) in (1..).zip(headers) {
+        sheet.set_cell(styles.cell("header", header, (x, position_y + 1)));
+    }
+
+    let total = table.total();
+    let first_row_y = position_y + 2;
+    let mut y = first_row_y;
+    for (group, hours) in &table.rows {
+        let group = if group.is_empty() { "—" } else { group };
+        sheet.set_cell(styles.cell("analytics-group", group, (1, y)));
+        sheet.set_cell(styles.number_cell("analytics-hours", *hours, (2, y)));
+        sheet.set_cell(styles.number_cell("analytics-share", share(*hours, total), (3, y)));
+        y += 1;
+    }
+    sheet.set_cell(styles.cell("analytics-total-group", "Разом", (1, y)));
+    sheet.set_cell(styles.number_cell("analytics-total-hours", total, (2, y)));
+    sheet.set_cell(styles.number_cell(
+        "analytics-total-share",
+        if total > 0_f64 { 1_f64 } else { 0_f64 },
+        (3, y),
+    ));
+
+    if !table.rows.is_empty() {
+        add_chart(sheet, position_y, first_row_y, y - 1, table);
+    }
+    y - position_y + 1
+}
+
+fn add_chart(
+    sheet: &mut Worksheet,
+    position_y: u32,
+    first_y: u32,
+    last_y: u32,
+    table: &AnalyticsTable,
+) {
+    let range = |x: u32| {
+        let letter = column_letter(x);
+        format!("{ANALYTICS_SHEET_NAME}!${letter}${first_y}:${letter}${last_y}")
+    };
+    // Markers are 0-based.
+    let mut from = MarkerType::default();
+    from.set_col(CHART_FIRST_X - 1).set_row(position_y - 1);
+    let mut to = MarkerType::default();
+    to.set_col(CHART_LAST_X)
+        .set_row(position_y - 1 + CHART_ROWS);
+
+    let chart_type = match table.chart {
+        AnalyticsChart::Bar => ChartType::BarChart,
+        AnalyticsChart::Pie => ChartType::PieChart,
+    };
+    let values = range(2);
+    let mut chart = Chart::default();
+    chart
+        .new_chart(chart_type, from, to, vec![values.as_str()])
+        .set_title(table.title)
+        .set_series_title(vec!["Години"]);
+
+    let mut categories = StringReference::default();
+    categories.get_formula_mut().set_address_str(range(1));
+    let mut category_axis_data = CategoryAxisData::default();
+    category_axis_data.set_string_reference(categories);
+    for series in chart
+        .get_area_chart_series_list_mut()
+        .get_area_chart_series_mut()
+    {
+        series.set_category_axis_data(category_axis_data.clone());
+    }
+    sheet.add_chart(chart);
+}
+
+fn add_hours(rows: &mut Vec<(String, f64)>, group: &str, hours: f64) {
+    match rows.iter_mut().find(|(name, _)| name == group) {
+        Some((_, sum)) => *sum = round(*sum + hours),
+        None => rows.push((group.to_string(), round(hours))),
+    }
+}
+
+fn share(hours: f64, total: f64) -> f64 {
+    if total > 0_f64 { hours / total } else { 0_f64 }
+}
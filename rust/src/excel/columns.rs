@@ -0,0 +1,58 @@
+use crate::excel::data::{OutputHoursRow, WorkloadColumn, WorkloadKey};
+
+const DEFAULT_WIDTH: f64 = 8.0;
+const DEFAULT_NUMBER_FORMAT: &str = "0.00";
+
+const DEFAULT_COLUMNS: [(WorkloadKey, &str); 17] = [
+    (WorkloadKey::Lectures, "Лекції"),
+    (WorkloadKey::Practices, "Практичні (семінарські) заняття"),
+    (WorkloadKey::Labs, "Лабораторні роботи"),
+    (WorkloadKey::Exams, "Екзамени"),
+    (WorkloadKey::ExamConsults, "Консультації перед екзаменами"),
+    (WorkloadKey::Tests, "Заліки"),
+    (WorkloadKey::QualWorks, "Випускні кваліфікаційні роботи"),
+    (WorkloadKey::CertificationExams, "Атестаційні екзамени"),
+    (WorkloadKey::WorkingPractice, "Виробнича практика"),
+    (WorkloadKey::TeachingPractice, "Навчальна практика"),
+    (WorkloadKey::Consults, "Поточні консультації"),
+    (WorkloadKey::IndividualWorks, "Індивідуальні"),
+    (WorkloadKey::CourseWorks, "Курсові роботи"),
+    (
+        WorkloadKey::PostgraduateExams,
+        "Проведення аспірантських екзаменів",
+    ),
+    (WorkloadKey::Supervising, "Керівництво аспірантами"),
+    (WorkloadKey::Internship, "Стажування"),
+    (WorkloadKey::Total, "Всього"),
+];
+
+/// Every workload type in `OutputHoursRow` order, all of them summed into
+/// the total.
+pub fn default_workload_columns() -> Vec<WorkloadColumn> {
+    DEFAULT_COLUMNS
+        .iter()
+        .map(|(key, caption)| WorkloadColumn {
+            key: *key,
+            caption: caption.to_string(),
+            width: DEFAULT_WIDTH,
+            number_format: DEFAULT_NUMBER_FORMAT.to_string(),
+            include_in_total: *key != WorkloadKey::Total,
+        })
+        .collect()
+}
+
+/// Sum of the non-empty hours of `columns` marked `include_in_total`, rounded
+/// to hundredths. `None` when all of them are empty.
+pub fn total_of(hours: &OutputHoursRow, columns: &[WorkloadColumn]) -> Option<f64> {
+    let values: Vec<f64> = columns
+        .iter()
+        .filter(|column| column.include_in_total && column.key != WorkloadKey::Total)
+        .map(|column| hours.value(column.key))
+        .filter(|value| *value >= 0_f64)
+        .collect();
+    if values.is_empty() {
+        None
+    } else {
+        Some((values.iter().sum::<f64>() * 100_f64).round() / 100_f64)
+    }
+}
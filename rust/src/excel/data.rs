@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-#[derive(Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InputRawRow {
     pub learning_form: String,           // Форма навчання
     pub speciality: String,              // Спеціальність
@@ -34,10 +36,12 @@ pub struct InputRawRow {
     pub internship: String,  // Стажування
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct ParsedExcelFile {
     pub data: HashMap<String, Vec<InputRawRow>>,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct ExportedData {
     pub year: u32,
     pub type_name: String,
@@ -45,27 +49,28 @@ pub struct ExportedData {
     pub personal_tables: Vec<OutputPersonalTables>,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct OutputMainTable {
-    pub heads: Vec<OutputMainWorkerRow>,
-    pub heads_total: OutputMainRateAndHours,
-    pub professors: Vec<OutputMainWorkerRow>,
-    pub professors_total: OutputMainRateAndHours,
-    pub associate_professors: Vec<OutputMainWorkerRow>,
-    pub associate_professors_total: OutputMainRateAndHours,
-    pub lecturers: Vec<OutputMainWorkerRow>,
-    pub lecturers_total: OutputMainRateAndHours,
-    pub assistants: Vec<OutputMainWorkerRow>,
-    pub assistants_total: OutputMainRateAndHours,
-    pub part_timers: Vec<OutputMainWorkerRow>,
-    pub part_timers_total: OutputMainRateAndHours,
+    pub sections: Vec<MainTableSection>,
     pub total: OutputMainRateAndHours,
 }
 
+/// A group of workers in the main table (e.g. professors) followed by its
+/// subtotal row. `title` is the caption of that subtotal row.
+#[derive(Serialize, Deserialize)]
+pub struct MainTableSection {
+    pub title: String,
+    pub rows: Vec<OutputMainWorkerRow>,
+    pub total: OutputMainRateAndHours,
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct OutputPersonalTables {
     pub worker_last_name: String,
     pub tables: Vec<OutputPersonalTable>,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct OutputPersonalTable {
     pub id: u32,
     pub first_name: String,
@@ -76,17 +81,24 @@ pub struct OutputPersonalTable {
     pub rank: String,
     pub semester_1: Vec<OutputPersonalSemesterRow>,
     pub semester_1_rate: f64,
-    pub semester_1_total_day: OutputHoursRow,
-    pub semester_1_total_evening: OutputHoursRow,
+    pub semester_1_form_totals: Vec<LearningFormTotal>,
     pub semester_1_total: OutputHoursRow,
     pub semester_2: Vec<OutputPersonalSemesterRow>,
     pub semester_2_rate: f64,
-    pub semester_2_total_day: OutputHoursRow,
-    pub semester_2_total_evening: OutputHoursRow,
+    pub semester_2_form_totals: Vec<LearningFormTotal>,
     pub semester_2_total: OutputHoursRow,
     pub year_total: OutputHoursRow,
 }
 
+/// Subtotal of a semester for one learning form, rendered as a
+/// "Разом (<form> форма)" row. `learning_form` is the adjective, e.g. "денна".
+#[derive(Serialize, Deserialize)]
+pub struct LearningFormTotal {
+    pub learning_form: String,
+    pub hours: OutputHoursRow,
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct OutputPersonalSemesterRow {
     pub name: String,
     pub learning_form: String,
@@ -94,10 +106,12 @@ pub struct OutputPersonalSemesterRow {
     pub group: String,
     pub course: String,
     pub students_count: u32,
+    pub individual_work_types: String,
     pub hours: OutputHoursRow,
     pub merge_lectures_with_next: bool,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct OutputMainWorkerRow {
     pub first_name: String,
     pub middle_name: String,
@@ -106,6 +120,7 @@ pub struct OutputMainWorkerRow {
     pub rate_and_hours: OutputMainRateAndHours,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct OutputMainRateAndHours {
     pub rate: f64,
     pub semester_1: OutputHoursRow,
@@ -113,6 +128,7 @@ pub struct OutputMainRateAndHours {
     pub year: OutputHoursRow,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
 pub struct OutputHoursRow {
     pub lectures: f64,
     pub practices: f64,
@@ -121,16 +137,23 @@ pub struct OutputHoursRow {
     pub exam_consults: f64,
     pub tests: f64,
     pub qual_works: f64,
+    pub certification_exams: f64,
     pub working_practice: f64,
     pub teaching_practice: f64,
     pub consults: f64,
     pub individual_works: f64,
     pub course_works: f64,
+    pub postgraduate_exams: f64,
     pub supervising: f64,
+    pub internship: f64,
     pub total: f64,
 }
 
+/// Number of values in `OutputHoursRow`, the total being the last one.
+pub(crate) const WORKLOAD_VALUES_COUNT: usize = 17;
+
 impl OutputHoursRow {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         lectures: f64,
         practices: f64,
@@ -139,12 +162,15 @@ impl OutputHoursRow {
         exam_consults: f64,
         tests: f64,
         qual_works: f64,
+        certification_exams: f64,
         working_practice: f64,
         teaching_practice: f64,
         consults: f64,
         individual_works: f64,
         course_works: f64,
+        postgraduate_exams: f64,
         supervising: f64,
+        internship: f64,
         total: f64,
     ) -> Self {
         Self {
@@ -155,13 +181,204 @@ impl OutputHoursRow {
             exam_consults,
             tests,
             qual_works,
+            certification_exams,
             working_practice,
             teaching_practice,
             consults,
             individual_works,
             course_works,
+            postgraduate_exams,
             supervising,
+            internship,
             total,
         }
     }
+
+    pub(crate) fn value(&self, key: WorkloadKey) -> f64 {
+        self.values()[key as usize]
+    }
+
+    /// Values in the order of `WorkloadKey`.
+    pub(crate) fn values(&self) -> [f64; WORKLOAD_VALUES_COUNT] {
+        [
+            self.lectures,
+            self.practices,
+            self.labs,
+            self.exams,
+            self.exam_consults,
+            self.tests,
+            self.qual_works,
+            self.certification_exams,
+            self.working_practice,
+            self.teaching_practice,
+            self.consults,
+            self.individual_works,
+            self.course_works,
+            self.postgraduate_exams,
+            self.supervising,
+            self.internship,
+            self.total,
+        ]
+    }
+
+    pub(crate) fn from_values(values: [f64; WORKLOAD_VALUES_COUNT]) -> Self {
+        let [
+            lectures,
+            practices,
+            labs,
+            exams,
+            exam_consults,
+            tests,
+            qual_works,
+            certification_exams,
+            working_practice,
+            teaching_practice,
+            consults,
+            individual_works,
+            course_works,
+            postgraduate_exams,
+            supervising,
+            internship,
+            total,
+        ] = values;
+        Self::new(
+            lectures,
+            practices,
+            labs,
+            exams,
+            exam_consults,
+            tests,
+            qual_works,
+            certification_exams,
+            working_practice,
+            teaching_practice,
+            consults,
+            individual_works,
+            course_works,
+            postgraduate_exams,
+            supervising,
+            internship,
+            total,
+        )
+    }
+}
+
+/// Sheet the personal tables of one `OutputPersonalTables` entry were
+/// written to, `table_ids` being the ids of its tables.
+#[derive(Serialize, Deserialize)]
+pub struct PersonalSheetName {
+    pub worker_last_name: String,
+    pub table_ids: Vec<u32>,
+    pub sheet_name: String,
+}
+
+/// Options of `write_excel_file`.
+#[derive(Serialize, Deserialize)]
+pub struct ExportOptions {
+    /// Drop the workload columns that have no hours anywhere in the export.
+    pub hide_empty_columns: bool,
+    /// Workload columns in the order they are written, `None` for
+    /// `default_workload_columns`.
+    pub columns: Option<Vec<WorkloadColumn>>,
+    /// Font of the written cells, `None` for Times New Roman 10pt.
+    pub theme: Option<ExportTheme>,
+    /// Colour the yearly total of the teachers whose hours are outside the
+    /// 580–600 hours per rate norm and comment it with the norm. The rate is
+    /// the mean of the semester rates, so a semester without one halves it.
+    pub highlight_workload_norms: bool,
+    /// Add the "Аналітика" sheet with hours per discipline, speciality,
+    /// learning form and semester and lectures per rank, with charts.
+    pub include_analytics: bool,
+}
+
+/// Font every cell of an export is written with; the named styles of
+/// `StyleRegistry` only add weight, alignment and borders on top of it.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ExportTheme {
+    pub font_name: String,
+    pub font_size: f64,
+}
+
+/// TrueType files embedded into `write_pdf_file` output. They must cover
+/// Cyrillic; a missing style falls back to `regular`.
+#[derive(Serialize, Deserialize)]
+pub struct PdfFonts {
+    pub regular: String,
+    pub bold: Option<String>,
+    pub italic: Option<String>,
+    pub bold_italic: Option<String>,
+}
+
+/// Workload type of an `OutputHoursRow` value, in the order of its fields.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkloadKey {
+    Lectures,
+    Practices,
+    Labs,
+    Exams,
+    ExamConsults,
+    Tests,
+    QualWorks,
+    CertificationExams,
+    WorkingPractice,
+    TeachingPractice,
+    Consults,
+    IndividualWorks,
+    CourseWorks,
+    PostgraduateExams,
+    Supervising,
+    Internship,
+    Total,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct WorkloadColumn {
+    pub key: WorkloadKey,
+    pub caption: String,
+    pub width: f64,
+    /// Excel number format of the hours, e.g. "0.00".
+    pub number_format: String,
+    /// Whether the hours are summed into the `WorkloadKey::Total` column.
+    pub include_in_total: bool,
+}
+
+/// Workload without any totals. `totals::build_exported_data` derives the
+/// per-form, semester, year, section and department totals from it.
+#[derive(Serialize, Deserialize)]
+pub struct WorkloadInput {
+    pub year: u32,
+    pub type_name: String,
+    pub sections: Vec<WorkloadSectionInput>,
+}
+
+/// Employees of one main table section, `title` is its subtotal caption.
+#[derive(Serialize, Deserialize)]
+pub struct WorkloadSectionInput {
+    pub title: String,
+    pub employees: Vec<EmployeeWorkloadInput>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EmployeeWorkloadInput {
+    pub id: u32,
+    pub first_name: String,
+    pub middle_name: String,
+    pub last_name: String,
+    pub rank: String,
+    pub comment_semester_1: String,
+    pub comment_semester_2: String,
+    pub semester_1: Vec<OutputPersonalSemesterRow>,
+    pub semester_1_rate: f64,
+    pub semester_2: Vec<OutputPersonalSemesterRow>,
+    pub semester_2_rate: f64,
+}
+
+/// A supplied total that differs from the one computed from its rows.
+#[derive(Serialize, Deserialize)]
+pub struct TotalsMismatch {
+    pub location: String,
+    pub field: String,
+    pub expected: f64,
+    pub actual: f64,
 }
@@ -0,0 +1,127 @@
+use std::fmt::Write as _;
+
+use crate::excel::{
+    columns::default_workload_columns,
+    data::{
+        ExportOptions, ExportedData, OutputPersonalSemesterRow, OutputPersonalTable, WorkloadKey,
+    },
+};
+
+const CSV_HEADER: [&str; 15] = [
+    "teacher_id",
+    "last_name",
+    "first_name",
+    "middle_name",
+    "rank",
+    "semester",
+    "discipline",
+    "learning_form",
+    "speciality",
+    "group",
+    "course",
+    "students_count",
+    "workload_key",
+    "workload_type",
+    "hours",
+];
+
+pub fn write_json_file(file_path: &str, data: &ExportedData) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(data).map_err(|e| e.to_string())?;
+    std::fs::write(file_path, json).map_err(|e| e.to_string())
+}
+
+pub fn read_json_file(file_path: &str) -> Result<ExportedData, String> {
+    let json = std::fs::read_to_string(file_path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+/// One line per teacher, discipline, semester and workload type with hours,
+/// in the order and with the captions of `ExportOptions::columns`. Totals
+/// are left out, they can be summed from the lines.
+pub fn generate_csv(data: &ExportedData, options: &ExportOptions) -> String {
+    let columns = options
+        .columns
+        .clone()
+        .unwrap_or_else(default_workload_columns);
+    let mut csv = String::new();
+    write_csv_line(&mut csv, CSV_HEADER.map(String::from));
+
+    let tables = data
+        .personal_tables
+        .iter()
+        .flat_map(|tables| &tables.tables);
+    for table in tables {
+        let semesters = [(1, &table.semester_1), (2, &table.semester_2)];
+        for (semester, rows) in semesters {
+            for row in rows {
+                for column in columns
+                    .iter()
+                    .filter(|column| column.key != WorkloadKey::Total)
+                {
+                    let hours = row.hours.value(column.key);
+                    if hours <= 0_f64 {
+                        continue;
+                    }
+                    let key = serde_json::to_value(column.key)
+                        .ok()
+                        .and_then(|key| key.as_str().map(String::from))
+                        .unwrap_or_default();
+                    let line = csv_fields(table, semester, row, key, &column.caption, hours);
+                    write_csv_line(&mut csv, line);
+                }
+            }
+        }
+    }
+    csv
+}
+
+pub fn write_csv_file(
+    file_path: &str,
+    data: &ExportedData,
+    options: &ExportOptions,
+) -> Result<(), String> {
+    std::fs::write(file_path, generate_csv(data, options)).map_err(|e| e.to_string())
+}
+
+fn csv_fields(
+    table: &OutputPersonalTable,
+    semester: u32,
+    row: &OutputPersonalSemesterRow,
+    workload_key: String,
+    workload_type: &str,
+    hours: f64,
+) -> [String; 15] {
+    [
+        table.id.to_string(),
+        table.last_name.clone(),
+        table.first_name.clone(),
+        table.middle_name.clone(),
+        table.rank.clone(),
+        semester.to_string(),
+        row.name.clone(),
+        row.learning_form.clone(),
+        row.speciality.clone(),
+        row.group.clone(),
+        row.course.clone(),
+        row.students_count.to_string(),
+        workload_key,
+        workload_type.to_string(),
+        hours.to_string(),
+    ]
+}
+
+/// RFC 4180: fields with separators, quotes or line breaks are quoted.
+/// Semicolons too, spreadsheets in locales with a decimal comma split on them.
+fn write_csv_line(csv: &mut String, fields: [String; 15]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            csv.push(',');
+        }
+        if field.contains([',', ';', '"', '\n', '\r']) {
+            let _ = write!(csv, "\"{}\"", field.replace('"', "\"\""));
+        } else {
+            csv.push_str(field);
+        }
+    }
+    csv.push_str("\r\n");
+}
@@ -1,27 +1,238 @@
 use umya_spreadsheet::{Spreadsheet, Worksheet, new_file_empty_worksheet, writer};
 
 use crate::{
+    excel::analytics::write_analytics_sheet,
+    excel::columns::{default_workload_columns, total_of},
     excel::data::{
-        ExportedData, OutputHoursRow, OutputMainRateAndHours, OutputMainTable, OutputMainWorkerRow,
-        OutputPersonalSemesterRow, OutputPersonalTable, OutputPersonalTables,
+        ExportOptions, ExportedData, LearningFormTotal, MainTableSection, OutputHoursRow,
+        OutputMainRateAndHours, OutputMainTable, OutputMainWorkerRow, OutputPersonalSemesterRow,
+        OutputPersonalTable, OutputPersonalTables, PersonalSheetName, WorkloadColumn, WorkloadKey,
     },
     excel::excel_functions::{
-        BorderThickness, TextAlignment, TextOrientation, TextStyle, cell_set_all_borders,
-        cell_set_bottom_border, cell_set_color, cell_set_left_border, cell_set_right_border,
-        cell_set_text_alignment, cell_set_text_orientation, cell_set_text_style,
-        cell_set_top_border, column_set_width, create_cell, create_cell_number, freeze_rows,
+        BorderThickness, TextAlignment, add_comment, cell_set_all_borders, cell_set_bottom_border,
+        cell_set_left_border, cell_set_number_format, cell_set_right_border,
+        cell_set_text_alignment, cell_set_top_border, column_set_width, freeze_rows, merge_cells,
         row_set_height,
     },
+    excel::sheet_names::{MAIN_SHEET_NAME, personal_sheet_names, sanitize_sheet_name},
+    excel::styles::{StyleRegistry, default_theme},
+    excel::totals::personal_tables_of_main_rows,
+    excel::workload_norms::{NormDeviation, NormViolation, check_workload_norm, yearly_norm_rate},
 };
 
-pub fn generate_output_file(file_path: &str, data: ExportedData) {
-    let mut book = new_file_empty_worksheet();
+const MAIN_TABLE_HOURS_X: u32 = 6;
+const PERSONAL_TABLE_HOURS_X: u32 = 11;
+const INDIVIDUAL_WORK_TYPES_WIDTH: f64 = 16.0;
+/// Rows a semester of a personal table takes at least, so the three lines
+/// of the name and the comment under them fit beside it.
+const MIN_SEMESTER_ROWS: u32 = 4;
+
+enum PersonalColumn {
+    /// Index into `WorkloadColumns::hours`.
+    Hours(usize),
+    IndividualWorkTypes,
+}
+
+/// Workload columns written to the tables, taken from `ExportOptions::columns`.
+/// With `hide_empty_columns` the ones without any hours in the whole export
+/// are left out, the total always stays.
+struct WorkloadColumns {
+    configured: Vec<WorkloadColumn>,
+    hours: Vec<WorkloadColumn>,
+    personal: Vec<PersonalColumn>,
+}
+
+/// Three rows of the main table: a worker with their name on three lines,
+/// or a subtotal with its caption in the first line and bold hours.
+struct MainRow<'a> {
+    /// Ordinal of the worker, 0 for none.
+    number: u32,
+    name: [&'a str; 3],
+    rank: &'a str,
+    rate_and_hours: &'a OutputMainRateAndHours,
+    is_subtotal: bool,
+    /// Rate the yearly total is checked against the workload norm for.
+    norm_rate: Option<f64>,
+}
+
+/// A row of a personal table. `details` are the learning form, speciality,
+/// group, course and students count; a subtotal has none and its caption
+/// spans their columns.
+struct PersonalRow<'a> {
+    name: &'a str,
+    details: Option<[&'a str; 5]>,
+    hours: &'a OutputHoursRow,
+    individual_work_types: &'a str,
+    merge_lectures: bool,
+    /// Rate the total is checked against the workload norm for.
+    norm_rate: Option<f64>,
+}
+
+impl WorkloadColumns {
+    fn new(data: &ExportedData, options: &ExportOptions) -> Self {
+        let configured = options
+            .columns
+            .clone()
+            .unwrap_or_else(default_workload_columns);
+
+        let mut all_hours: Vec<&OutputHoursRow> = Vec::new();
+        for section in &data.main_table.sections {
+            let rates_and_hours = section
+                .rows
+                .iter()
+                .map(|row| &row.rate_and_hours)
+                .chain([&section.total]);
+            for rate_and_hours in rates_and_hours {
+                all_hours.push(&rate_and_hours.semester_1);
+                all_hours.push(&rate_and_hours.semester_2);
+                all_hours.push(&rate_and_hours.year);
+            }
+        }
+        let semester_rows: Vec<&OutputPersonalSemesterRow> = data
+            .personal_tables
+            .iter()
+            .flat_map(|tables| &tables.tables)
+            .flat_map(|table| table.semester_1.iter().chain(&table.semester_2))
+            .collect();
+        all_hours.extend(semester_rows.iter().map(|row| &row.hours));
+
+        let hours: Vec<WorkloadColumn> = configured
+            .iter()
+            .filter(|column| {
+                !options.hide_empty_columns
+                    || column.key == WorkloadKey::Total
+                    || all_hours
+                        .iter()
+                        .any(|hours| hours.value(column.key) > 0_f64)
+            })
+            .cloned()
+            .collect();
+        let has_individual_work_types = !options.hide_empty_columns
+            || semester_rows
+                .iter()
+                .any(|row| !row.individual_work_types.is_empty());
+        let mut personal = Vec::new();
+        for (i, column) in hours.iter().enumerate() {
+            personal.push(PersonalColumn::Hours(i));
+            if column.key == WorkloadKey::IndividualWorks && has_individual_work_types {
+                personal.push(PersonalColumn::IndividualWorkTypes);
+            }
+        }
+        Self {
+            configured,
+            hours,
+            personal,
+        }
+    }
+
+    /// `None` for an empty cell. The total is summed from the configured
+    /// columns rather than taken from `OutputHoursRow::total`.
+    fn value(&self, hours: &OutputHoursRow, column: &WorkloadColumn) -> Option<f64> {
+        if column.key == WorkloadKey::Total {
+            return total_of(hours, &self.configured);
+        }
+        let value = hours.value(column.key);
+        if value < 0_f64 { None } else { Some(value) }
+    }
 
-    write_main_table(&mut book, data.year, &data.type_name, &data.main_table);
-    write_additional_tables(&mut book, data.year, &data.type_name, &data.personal_tables);
+    fn main_last_x(&self) -> u32 {
+        MAIN_TABLE_HOURS_X + self.hours.len() as u32 - 1
+    }
 
+    fn personal_last_x(&self) -> u32 {
+        PERSONAL_TABLE_HOURS_X + self.personal.len() as u32 - 1
+    }
+}
+
+/// Returns the sheet each teacher's tables went to.
+pub fn generate_output_file(
+    file_path: &str,
+    data: ExportedData,
+    options: ExportOptions,
+) -> Result<Vec<PersonalSheetName>, String> {
+    let book = build_workbook(&data, &options);
     let path = std::path::Path::new(file_path);
-    let _ = writer::xlsx::write(&book, path);
+    writer::xlsx::write(&book, path).map_err(|e| format!("{file_path}: {e}"))?;
+    Ok(personal_sheet_names(&data.personal_tables))
+}
+
+/// The distribution document as a workbook, the layout every export format
+/// is rendered from.
+pub fn build_workbook(data: &ExportedData, options: &ExportOptions) -> Spreadsheet {
+    let mut book = new_file_empty_worksheet();
+    let columns = WorkloadColumns::new(data, options);
+    let styles = export_styles(options);
+    let main_norm_rates = options
+        .highlight_workload_norms
+        .then(|| main_norm_rates(data));
+
+    write_main_table(
+        &mut book,
+        data.year,
+        &data.type_name,
+        &data.main_table,
+        &columns,
+        &styles,
+        main_norm_rates.as_deref(),
+    );
+    write_additional_tables(
+        &mut book,
+        data.year,
+        &data.type_name,
+        &data.personal_tables,
+        &columns,
+        &styles,
+        options.highlight_workload_norms,
+    );
+    if options.include_analytics {
+        write_analytics_sheet(&mut book, data, &columns.configured, &styles);
+    }
+    book
+}
+
+/// Workbook with only the personal sheet of `tables`. The columns are chosen
+/// over the whole `data`, so every teacher's workbook has the same layout.
+pub fn build_personal_workbook(
+    data: &ExportedData,
+    options: &ExportOptions,
+    tables: &OutputPersonalTables,
+) -> Spreadsheet {
+    let mut book = new_file_empty_worksheet();
+    let columns = WorkloadColumns::new(data, options);
+    let styles = export_styles(options);
+    let sheet_name = sanitize_sheet_name(&tables.worker_last_name);
+    write_personal_sheet(
+        &mut book,
+        data.year,
+        &data.type_name,
+        tables,
+        &sheet_name,
+        &columns,
+        &styles,
+        options.highlight_workload_norms,
+    );
+    book
+}
+
+fn export_styles(options: &ExportOptions) -> StyleRegistry {
+    StyleRegistry::new(options.theme.clone().unwrap_or_else(default_theme))
+}
+
+/// Rate the yearly total of every main table worker is checked against, in
+/// row order. It comes from the semester rates of the worker's personal
+/// table, or from the row's own rate for a worker without one.
+fn main_norm_rates(data: &ExportedData) -> Vec<f64> {
+    data.main_table
+        .sections
+        .iter()
+        .flat_map(|section| &section.rows)
+        .zip(personal_tables_of_main_rows(data))
+        .map(|(row, table)| {
+            table.map_or(row.rate_and_hours.rate, |table| {
+                yearly_norm_rate(table.semester_1_rate, table.semester_2_rate)
+            })
+        })
+        .collect()
 }
 
 fn write_main_table(
@@ -29,30 +240,83 @@ fn write_main_table(
     year: u32,
     type_name: &str,
     main_table: &OutputMainTable,
+    columns: &WorkloadColumns,
+    styles: &StyleRegistry,
+    norm_rates: Option<&[f64]>,
 ) {
-    let sheet = book.new_sheet("Загальна").unwrap();
-    write_table_title(sheet, year, type_name, 1, true, 18);
-    write_main_table_header(sheet);
-    write_main_table_content(sheet, main_table);
+    let sheet = book.new_sheet(MAIN_SHEET_NAME).unwrap();
+    write_table_title(
+        sheet,
+        year,
+        type_name,
+        1,
+        true,
+        columns.main_last_x(),
+        styles,
+    );
+    write_main_table_header(sheet, columns, styles);
+    write_main_table_content(sheet, main_table, columns, styles, norm_rates);
 }
 
 fn write_additional_tables(
     book: &mut Spreadsheet,
     year: u32,
     type_name: &str,
-    tables_list: &Vec<OutputPersonalTables>,
+    tables_list: &[OutputPersonalTables],
+    columns: &WorkloadColumns,
+    styles: &StyleRegistry,
+    highlight_norms: bool,
 ) {
-    for tables in tables_list {
-        let sheet = book.new_sheet(&tables.worker_last_name).unwrap();
-        let mut height_offset = 1;
-        for table in &tables.tables {
-            write_table_title(sheet, year, type_name, height_offset, false, 23);
-            height_offset += 4;
-            write_additional_table_header(sheet, height_offset);
-            height_offset += 2;
-            let table_height = write_additional_table_content(sheet, height_offset, table);
-            height_offset += table_height + 8;
-        }
+    let sheet_names = personal_sheet_names(tables_list);
+    for (tables, sheet_name) in tables_list.iter().zip(&sheet_names) {
+        write_personal_sheet(
+            book,
+            year,
+            type_name,
+            tables,
+            &sheet_name.sheet_name,
+            columns,
+            styles,
+            highlight_norms,
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn write_personal_sheet(
+    book: &mut Spreadsheet,
+    year: u32,
+    type_name: &str,
+    tables: &OutputPersonalTables,
+    sheet_name: &str,
+    columns: &WorkloadColumns,
+    styles: &StyleRegistry,
+    highlight_norms: bool,
+) {
+    let sheet = book.new_sheet(sheet_name).unwrap();
+    let mut height_offset = 1;
+    for table in &tables.tables {
+        write_table_title(
+            sheet,
+            year,
+            type_name,
+            height_offset,
+            false,
+            columns.personal_last_x(),
+            styles,
+        );
+        height_offset += 4;
+        write_additional_table_header(sheet, height_offset, columns, styles);
+        height_offset += 2;
+        let table_height = write_additional_table_content(
+            sheet,
+            height_offset,
+            table,
+            columns,
+            styles,
+            highlight_norms,
+        );
+        height_offset += table_height + 8;
     }
 }
 
@@ -62,55 +326,47 @@ fn write_table_title(
     header_name: &str,
     position_y: u32,
     do_freeze_rows: bool,
-    length: u32,
+    last_x: u32,
+    styles: &StyleRegistry,
 ) {
-    let letters = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-    let letter = letters.chars().nth((length % 26) as usize).unwrap();
-    sheet.add_merge_cells(&format!("A{}:{}{}", position_y, letter, position_y));
-    sheet.add_merge_cells(&format!("A{}:{}{}", position_y + 1, letter, position_y + 1));
-    sheet.add_merge_cells(&format!("A{}:{}{}", position_y + 2, letter, position_y + 2));
-    sheet.add_merge_cells(&format!("A{}:{}{}", position_y + 3, letter, position_y + 3));
+    for y in position_y..position_y + 4 {
+        merge_cells(sheet, (1, y), (last_x, y));
+    }
 
     if do_freeze_rows {
         freeze_rows(sheet, 7);
     }
 
-    let mut header_1_cell = create_cell(
+    sheet.set_cell(styles.cell(
+        "title",
         "ДНІПРОВСЬКИЙ НАЦІОНАЛЬНИЙ УНІВЕРСИТЕТ ІМЕНІ ОЛЕСЯ ГОНЧАРА",
         (1, position_y),
-    );
-    let mut header_2_cell = create_cell(
+    ));
+    sheet.set_cell(styles.cell(
+        "title",
         &format!(
             "Розподіл навчального навантаження між викладачами кафедри {}",
             header_name
         ),
         (1, position_y + 1),
-    );
-    let mut header_3_cell = create_cell(
+    ));
+    sheet.set_cell(styles.cell(
+        "title",
         &format!("на {}-{} навчальний рік", year, year + 1),
         (1, position_y + 2),
-    );
-
-    cell_set_text_alignment(&mut header_1_cell, &TextAlignment::HorizontalCenter);
-    cell_set_text_alignment(&mut header_2_cell, &TextAlignment::HorizontalCenter);
-    cell_set_text_alignment(&mut header_3_cell, &TextAlignment::HorizontalCenter);
-
-    cell_set_text_style(&mut header_1_cell, &TextStyle::Bold);
-    cell_set_text_style(&mut header_2_cell, &TextStyle::Bold);
-    cell_set_text_style(&mut header_3_cell, &TextStyle::Bold);
-
-    sheet.set_cell(header_1_cell);
-    sheet.set_cell(header_2_cell);
-    sheet.set_cell(header_3_cell);
+    ));
 }
 
-fn write_main_table_header(sheet: &mut Worksheet) {
-    sheet.add_merge_cells("A5:A6");
-    sheet.add_merge_cells("B5:B6");
-    sheet.add_merge_cells("C5:C6");
-    sheet.add_merge_cells("D5:D6");
-    sheet.add_merge_cells("E5:E6");
-    sheet.add_merge_cells("F5:S5");
+fn write_main_table_header(
+    sheet: &mut Worksheet,
+    columns: &WorkloadColumns,
+    styles: &StyleRegistry,
+) {
+    let last_x = columns.main_last_x();
+    for x in 1..MAIN_TABLE_HOURS_X {
+        merge_cells(sheet, (x, 5), (x, 6));
+    }
+    merge_cells(sheet, (MAIN_TABLE_HOURS_X, 5), (last_x, 5));
 
     row_set_height(sheet, 6, 74.0);
     column_set_width(sheet, 1, 4.0);
@@ -119,421 +375,195 @@ fn write_main_table_header(sheet: &mut Worksheet) {
     column_set_width(sheet, 4, 6.0);
     column_set_width(sheet, 5, 16.0);
 
-    for i in 1..=19 {
-        let mut cell = create_cell(&format!("{}", i), (i, 7));
-        cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
-        cell_set_text_style(&mut cell, &TextStyle::BoldItalic);
-        cell_set_all_borders(&mut cell, &BorderThickness::Medium);
-        sheet.set_cell(cell);
+    for i in 1..=last_x {
+        sheet.set_cell(styles.cell("column-number", &format!("{}", i), (i, 7)));
     }
 
-    let mut cell_n = create_cell("№ з/п", (1, 5));
-    cell_set_text_orientation(&mut cell_n, &TextOrientation::Vertical);
-    cell_set_text_style(&mut cell_n, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_n, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell_n, &TextAlignment::HorizontalCenter);
-    cell_set_all_borders(&mut cell_n, &BorderThickness::Medium);
-    cell_set_all_borders(sheet.get_cell_mut((1, 6)), &BorderThickness::Medium);
-    sheet.set_cell(cell_n);
-
-    let mut cell_name = create_cell("Прізвище, ім'я та по батькові (повністю)", (2, 5));
-    cell_set_text_style(&mut cell_name, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_name, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell_name, &TextAlignment::HorizontalCenter);
-    cell_set_all_borders(&mut cell_name, &BorderThickness::Medium);
-    cell_set_all_borders(sheet.get_cell_mut((2, 6)), &BorderThickness::Medium);
-    sheet.set_cell(cell_name);
-
-    let mut cell_rank = create_cell("Посада, вчене звання, вчена ступінь", (3, 5));
-    cell_set_text_style(&mut cell_rank, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_rank, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell_rank, &TextAlignment::HorizontalCenter);
-    cell_set_all_borders(&mut cell_rank, &BorderThickness::Medium);
-    cell_set_all_borders(sheet.get_cell_mut((3, 6)), &BorderThickness::Medium);
-    sheet.set_cell(cell_rank);
-
-    let mut cell_rate = create_cell("Ставка", (4, 5));
-    cell_set_text_orientation(&mut cell_rate, &TextOrientation::Vertical);
-    cell_set_text_style(&mut cell_rate, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_rate, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell_rate, &TextAlignment::HorizontalCenter);
-    cell_set_all_borders(&mut cell_rate, &BorderThickness::Medium);
-    cell_set_all_borders(sheet.get_cell_mut((4, 6)), &BorderThickness::Medium);
-    sheet.set_cell(cell_rate);
-
-    let mut cell_semester_empty = create_cell("", (5, 5));
-    cell_set_all_borders(&mut cell_semester_empty, &BorderThickness::Medium);
-    cell_set_all_borders(sheet.get_cell_mut((5, 6)), &BorderThickness::Medium);
-    sheet.set_cell(cell_semester_empty);
+    let headers = [
+        ("№ з/п", "header-vertical"),
+        ("Прізвище, ім'я та по батькові (повністю)", "header"),
+        ("Посада, вчене звання, вчена ступінь", "header"),
+        ("Ставка", "header-vertical"),
+        ("", "header-blank"),
+    ];
+    for (x, (text, style)) in (1..).zip(headers) {
+        sheet.set_cell(styles.cell(style, text, (x, 5)));
+        cell_set_all_borders(sheet.get_cell_mut((x, 6)), &BorderThickness::Medium);
+    }
 
-    let mut cell_types_of_workload = create_cell("ВИДИ НАВЧАЛЬНОГО НАВАНТАЖЕННЯ", (6, 5));
-    cell_set_text_style(&mut cell_types_of_workload, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_types_of_workload, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(
-        &mut cell_types_of_workload,
-        &TextAlignment::HorizontalCenter,
-    );
-    cell_set_all_borders(&mut cell_types_of_workload, &BorderThickness::Medium);
-    for i in 7..=19 {
+    for i in MAIN_TABLE_HOURS_X + 1..=last_x {
         cell_set_all_borders(sheet.get_cell_mut((i, 5)), &BorderThickness::Medium);
     }
-    sheet.set_cell(cell_types_of_workload);
-
-    let workload_types = vec![
-        "Лекції",
-        "Практичні (семінарські) заняття",
-        "Лабораторні роботи",
-        "Екзамени",
-        "Консультації перед екзаменами",
-        "Заліки",
-        "Випускні кваліфікаційні роботи",
-        "Виробнича практика",
-        "Навчальна практика",
-        "Поточні консультації",
-        "Індивідуальні",
-        "Курсові роботи",
-        "Керівництво аспірантами",
-        "Всього",
-    ];
-
-    let mut x = 6;
-    for wl_type in workload_types {
-        let mut cell_workload_type = create_cell(wl_type, (x, 6));
-        cell_set_text_orientation(&mut cell_workload_type, &TextOrientation::Vertical);
-        cell_set_text_style(&mut cell_workload_type, &TextStyle::Bold);
-        cell_set_text_alignment(&mut cell_workload_type, &TextAlignment::VerticalCenter);
-        cell_set_text_alignment(&mut cell_workload_type, &TextAlignment::HorizontalCenter);
-        cell_set_all_borders(&mut cell_workload_type, &BorderThickness::Medium);
-        sheet.set_cell(cell_workload_type);
+    sheet.set_cell(styles.cell(
+        "header",
+        "ВИДИ НАВЧАЛЬНОГО НАВАНТАЖЕННЯ",
+        (MAIN_TABLE_HOURS_X, 5),
+    ));
+
+    let mut x = MAIN_TABLE_HOURS_X;
+    for column in &columns.hours {
+        column_set_width(sheet, x, column.width);
+        sheet.set_cell(styles.cell("header-vertical", &column.caption, (x, 6)));
         x += 1;
     }
 }
 
-fn write_main_table_content(sheet: &mut Worksheet, main_table: &OutputMainTable) {
+fn write_main_table_content(
+    sheet: &mut Worksheet,
+    main_table: &OutputMainTable,
+    columns: &WorkloadColumns,
+    styles: &StyleRegistry,
+    norm_rates: Option<&[f64]>,
+) {
     let mut position_y = 8;
     let mut number = 1;
-    for head in &main_table.heads {
-        write_main_table_content_row(
-            sheet,
-            position_y,
-            number,
-            &head.last_name,
-            &head.middle_name,
-            &head.first_name,
-            &head.rank,
-            &head.rate_and_hours,
-            false,
-        );
-        position_y += 3;
-        number += 1;
-    }
-    write_main_table_content_row(
-        sheet,
-        position_y,
-        0,
-        "Всього за зав. каф.",
-        "",
-        "",
-        "",
-        &main_table.heads_total,
-        true,
-    );
-    position_y += 3;
-    for professor in &main_table.professors {
-        write_main_table_content_row(
-            sheet,
-            position_y,
-            number,
-            &professor.last_name,
-            &professor.middle_name,
-            &professor.first_name,
-            &professor.rank,
-            &professor.rate_and_hours,
-            false,
-        );
-        position_y += 3;
-        number += 1;
-    }
-    write_main_table_content_row(
-        sheet,
-        position_y,
-        0,
-        "Всього за професорами",
-        "",
-        "",
-        "",
-        &main_table.professors_total,
-        true,
-    );
-    position_y += 3;
-    for a_professor in &main_table.associate_professors {
-        write_main_table_content_row(
-            sheet,
-            position_y,
-            number,
-            &a_professor.last_name,
-            &a_professor.middle_name,
-            &a_professor.first_name,
-            &a_professor.rank,
-            &a_professor.rate_and_hours,
-            false,
-        );
-        position_y += 3;
-        number += 1;
-    }
-    write_main_table_content_row(
-        sheet,
-        position_y,
-        0,
-        "Всього за доцентами",
-        "",
-        "",
-        "",
-        &main_table.associate_professors_total,
-        true,
-    );
-    position_y += 3;
-    for lecturer in &main_table.lecturers {
-        write_main_table_content_row(
-            sheet,
-            position_y,
-            number,
-            &lecturer.last_name,
-            &lecturer.middle_name,
-            &lecturer.first_name,
-            &lecturer.rank,
-            &lecturer.rate_and_hours,
-            false,
-        );
-        position_y += 3;
-        number += 1;
-    }
-    write_main_table_content_row(
-        sheet,
-        position_y,
-        0,
-        "Всього за викладачами",
-        "",
-        "",
-        "",
-        &main_table.lecturers_total,
-        true,
-    );
-    position_y += 3;
-    for assistant in &main_table.assistants {
-        write_main_table_content_row(
-            sheet,
-            position_y,
-            number,
-            &assistant.last_name,
-            &assistant.middle_name,
-            &assistant.first_name,
-            &assistant.rank,
-            &assistant.rate_and_hours,
-            false,
-        );
+    for section in &main_table.sections {
+        for worker in &section.rows {
+            let row = MainRow {
+                number,
+                name: [&worker.last_name, &worker.middle_name, &worker.first_name],
+                rank: &worker.rank,
+                rate_and_hours: &worker.rate_and_hours,
+                is_subtotal: false,
+                norm_rate: norm_rates.map(|rates| rates[number as usize - 1]),
+            };
+            write_main_table_content_row(sheet, position_y, &row, columns, styles);
+            position_y += 3;
+            number += 1;
+        }
+        let row = subtotal_main_row(&section.title, &section.total);
+        write_main_table_content_row(sheet, position_y, &row, columns, styles);
         position_y += 3;
-        number += 1;
     }
-    write_main_table_content_row(
-        sheet,
-        position_y,
-        0,
-        "Всього за асистентами",
-        "",
-        "",
-        "",
-        &main_table.assistants_total,
-        true,
-    );
-    position_y += 3;
-    for part_timer in &main_table.part_timers {
-        write_main_table_content_row(
-            sheet,
-            position_y,
-            number,
-            &part_timer.last_name,
-            &part_timer.middle_name,
-            &part_timer.first_name,
-            &part_timer.rank,
-            &part_timer.rate_and_hours,
-            false,
-        );
-        position_y += 3;
-        number += 1;
+    let row = subtotal_main_row("Разом по кафедрі", &main_table.total);
+    write_main_table_content_row(sheet, position_y, &row, columns, styles);
+}
+
+fn subtotal_main_row<'a>(title: &'a str, total: &'a OutputMainRateAndHours) -> MainRow<'a> {
+    MainRow {
+        number: 0,
+        name: [title, "", ""],
+        rank: "",
+        rate_and_hours: total,
+        is_subtotal: true,
+        norm_rate: None,
     }
-    write_main_table_content_row(
-        sheet,
-        position_y,
-        0,
-        "Всього за сумісниками",
-        "",
-        "",
-        "",
-        &main_table.part_timers_total,
-        true,
-    );
-    position_y += 3;
-    write_main_table_content_row(
-        sheet,
-        position_y,
-        0,
-        "Разом по кафедрі",
-        "",
-        "",
-        "",
-        &main_table.total,
-        true,
-    );
 }
 
 fn write_main_table_content_row(
     sheet: &mut Worksheet,
     position_y: u32,
-    number: u32,
-    first_row_text: &str,
-    second_row_text: &str,
-    third_row_text: &str,
-    rank_text: &str,
-    content_row: &OutputMainRateAndHours,
-    make_bold: bool,
+    row: &MainRow,
+    columns: &WorkloadColumns,
+    styles: &StyleRegistry,
 ) {
+    let [first_row_text, second_row_text, third_row_text] = row.name;
+    let content_row = row.rate_and_hours;
+    let make_bold = row.is_subtotal;
     let is_name_merged = second_row_text.is_empty() && third_row_text.is_empty();
-    sheet.add_merge_cells(&format!("A{}:A{}", position_y, position_y + 2));
+    merge_cells(sheet, (1, position_y), (1, position_y + 2));
     if is_name_merged {
-        sheet.add_merge_cells(&format!("B{}:B{}", position_y, position_y + 2));
+        merge_cells(sheet, (2, position_y), (2, position_y + 2));
     }
-    sheet.add_merge_cells(&format!("C{}:C{}", position_y, position_y + 2));
-    sheet.add_merge_cells(&format!("D{}:D{}", position_y, position_y + 2));
+    merge_cells(sheet, (3, position_y), (3, position_y + 2));
+    merge_cells(sheet, (4, position_y), (4, position_y + 2));
 
-    let mut cell = create_cell(
-        if number > 0 {
-            format!("{}", number)
-        } else {
-            String::new()
-        }
-        .as_str(),
-        (1, position_y),
-    );
-    cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
-    sheet.set_cell(cell);
+    let text_style = if make_bold { "subtotal" } else { "text" };
+
+    let number_text = if row.number > 0 {
+        format!("{}", row.number)
+    } else {
+        String::new()
+    };
+    sheet.set_cell(styles.cell("centered", &number_text, (1, position_y)));
     cell_set_hv_borders(sheet, 1, position_y, 3);
 
     if is_name_merged {
-        let mut cell = create_cell(first_row_text, (2, position_y));
-        cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-        if make_bold {
-            cell_set_text_style(&mut cell, &TextStyle::Bold);
-        }
-        sheet.set_cell(cell);
-        cell_set_hv_borders(sheet, 2, position_y, 3);
+        sheet.set_cell(styles.cell(text_style, first_row_text, (2, position_y)));
     } else {
-        let mut cell = create_cell(first_row_text, (2, position_y));
-        cell_set_text_style(&mut cell, &TextStyle::BoldItalic);
-        sheet.set_cell(cell);
-        let mut cell = create_cell(second_row_text, (2, position_y + 1));
-        cell_set_text_style(&mut cell, &TextStyle::Italic);
-        sheet.set_cell(cell);
-        let mut cell = create_cell(third_row_text, (2, position_y + 2));
-        cell_set_text_style(&mut cell, &TextStyle::Italic);
-        sheet.set_cell(cell);
-        cell_set_hv_borders(sheet, 2, position_y, 3);
+        sheet.set_cell(styles.cell("last-name", first_row_text, (2, position_y)));
+        sheet.set_cell(styles.cell("first-name", second_row_text, (2, position_y + 1)));
+        sheet.set_cell(styles.cell("first-name", third_row_text, (2, position_y + 2)));
     }
+    cell_set_hv_borders(sheet, 2, position_y, 3);
 
-    let mut cell = create_cell(rank_text, (3, position_y));
-    cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-    sheet.set_cell(cell);
+    sheet.set_cell(styles.cell("text", row.rank, (3, position_y)));
     cell_set_hv_borders(sheet, 3, position_y, 3);
 
-    let mut cell = create_cell_number(content_row.rate, (4, position_y), true);
-    cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
-    if make_bold {
-        cell_set_text_style(&mut cell, &TextStyle::Bold);
-    }
-    sheet.set_cell(cell);
+    sheet.set_cell(styles.number_cell(
+        if make_bold {
+            "subtotal-number-2dp"
+        } else {
+            "number-2dp"
+        },
+        content_row.rate,
+        (4, position_y),
+    ));
     cell_set_hv_borders(sheet, 4, position_y, 3);
 
-    let mut cell = create_cell("I семестр", (5, position_y));
-    cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-    if make_bold {
-        cell_set_text_style(&mut cell, &TextStyle::Bold);
+    for (i, text) in ["I семестр", "II семестр", "Рік"].iter().enumerate() {
+        sheet.set_cell(styles.cell(text_style, text, (5, position_y + i as u32)));
     }
-    sheet.set_cell(cell);
-    let mut cell = create_cell("II семестр", (5, position_y + 1));
-    cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-    if make_bold {
-        cell_set_text_style(&mut cell, &TextStyle::Bold);
-    }
-    sheet.set_cell(cell);
-    let mut cell = create_cell("Рік", (5, position_y + 2));
-    cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-    if make_bold {
-        cell_set_text_style(&mut cell, &TextStyle::Bold);
-    }
-    sheet.set_cell(cell);
     cell_set_hv_borders(sheet, 5, position_y, 3);
 
-    for i in 0..14 {
-        let value_1 = workload_hours_by_index(&content_row.semester_1, i);
-        if let Some(value_1) = value_1 {
-            let mut cell = create_cell_number(value_1, (6 + i, position_y), true);
-            cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-            cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
-            if make_bold {
-                cell_set_text_style(&mut cell, &TextStyle::Bold);
-            }
-            sheet.set_cell(cell);
-        }
-        let value_2 = workload_hours_by_index(&content_row.semester_2, i);
-        if let Some(value_2) = value_2 {
-            let mut cell = create_cell_number(value_2, (6 + i, position_y + 1), true);
-            cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-            cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
-            if make_bold {
-                cell_set_text_style(&mut cell, &TextStyle::Bold);
-            }
-            sheet.set_cell(cell);
-        }
-        let value_3 = workload_hours_by_index(&content_row.year, i);
-        if let Some(value_3) = value_3 {
-            let mut cell = create_cell_number(value_3, (6 + i, position_y + 2), true);
-            cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-            cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
-            if i == 13 {
-                cell_set_text_style(&mut cell, &TextStyle::Bold);
-                cell_set_color(&mut cell, "FFFF0000");
+    let rows = [
+        &content_row.semester_1,
+        &content_row.semester_2,
+        &content_row.year,
+    ];
+    let mut x = MAIN_TABLE_HOURS_X;
+    for column in &columns.hours {
+        for (i, hours) in rows.iter().enumerate() {
+            let Some(value) = columns.value(hours, column) else {
+                continue;
+            };
+            let is_year_total = i == 2 && column.key == WorkloadKey::Total;
+            let violation = row
+                .norm_rate
+                .filter(|_| is_year_total)
+                .and_then(|rate| check_workload_norm(rate, value));
+            let style = if is_year_total {
+                norm_style("total-hours", violation)
             } else if make_bold {
-                cell_set_text_style(&mut cell, &TextStyle::Bold);
-            }
+                "subtotal-hours".to_string()
+            } else {
+                "hours".to_string()
+            };
+            let mut cell = styles.number_cell(&style, value, (x, position_y + i as u32));
+            cell_set_number_format(&mut cell, &column.number_format);
             sheet.set_cell(cell);
+            if let Some(violation) = violation {
+                add_comment(sheet, (x, position_y + i as u32), &violation.comment());
+            }
         }
 
-        cell_set_hv_borders(sheet, 6 + i, position_y, 3);
+        cell_set_hv_borders(sheet, x, position_y, 3);
+        x += 1;
     }
 
-    cell_set_right_border(
-        sheet.get_cell_mut((19, position_y)),
-        &BorderThickness::Medium,
-    );
-
-    cell_set_right_border(
-        sheet.get_cell_mut((19, position_y + 1)),
-        &BorderThickness::Medium,
-    );
-
-    cell_set_right_border(
-        sheet.get_cell_mut((19, position_y + 2)),
-        &BorderThickness::Medium,
-    );
+    for i in 0..3 {
+        cell_set_right_border(
+            sheet.get_cell_mut((columns.main_last_x(), position_y + i)),
+            &BorderThickness::Medium,
+        );
+    }
 }
 
-fn write_additional_table_header(sheet: &mut Worksheet, position_y: u32) {
-    for c in "ABCDEFGHIJ".chars() {
-        sheet.add_merge_cells(&format!("{c}{position_y}:{c}{}", position_y + 1));
+fn write_additional_table_header(
+    sheet: &mut Worksheet,
+    position_y: u32,
+    columns: &WorkloadColumns,
+    styles: &StyleRegistry,
+) {
+    let last_x = columns.personal_last_x();
+    for x in 1..PERSONAL_TABLE_HOURS_X {
+        merge_cells(sheet, (x, position_y), (x, position_y + 1));
     }
-    sheet.add_merge_cells(&format!("K{position_y}:X{position_y}"));
+    merge_cells(
+        sheet,
+        (PERSONAL_TABLE_HOURS_X, position_y),
+        (last_x, position_y),
+    );
 
     row_set_height(sheet, position_y + 1, 240.0);
     column_set_width(sheet, 1, 4.0);
@@ -545,133 +575,53 @@ fn write_additional_table_header(sheet: &mut Worksheet, position_y: u32) {
     column_set_width(sheet, 7, 10.0);
     column_set_width(sheet, 8, 14.0);
     let narrow_row_width = 8.0;
-    for i in 9..=24 {
-        column_set_width(sheet, i, narrow_row_width);
-    }
-
-    let mut cell_n = create_cell("№ з/п", (1, position_y));
-    cell_set_text_orientation(&mut cell_n, &TextOrientation::Vertical);
-    cell_set_text_style(&mut cell_n, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_n, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell_n, &TextAlignment::HorizontalCenter);
-    cell_set_all_borders(&mut cell_n, &BorderThickness::Medium);
-    cell_set_all_borders(
-        sheet.get_cell_mut((1, position_y + 1)),
-        &BorderThickness::Medium,
-    );
-    sheet.set_cell(cell_n);
-
-    let mut cell_name = create_cell("Прізвище, ім'я та по батькові (повністю)", (2, position_y));
-    cell_set_text_style(&mut cell_name, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_name, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell_name, &TextAlignment::HorizontalCenter);
-    cell_set_all_borders(&mut cell_name, &BorderThickness::Medium);
-    cell_set_all_borders(
-        sheet.get_cell_mut((2, position_y + 1)),
-        &BorderThickness::Medium,
-    );
-    sheet.set_cell(cell_name);
-
-    let mut cell_rank = create_cell("Посада, вчене звання, вчена ступінь", (3, position_y));
-    cell_set_text_style(&mut cell_rank, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_rank, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell_rank, &TextAlignment::HorizontalCenter);
-    cell_set_all_borders(&mut cell_rank, &BorderThickness::Medium);
-    cell_set_all_borders(
-        sheet.get_cell_mut((3, position_y + 1)),
-        &BorderThickness::Medium,
-    );
-    sheet.set_cell(cell_rank);
-
-    let mut cell_rate = create_cell("Ставка", (4, position_y));
-    cell_set_text_orientation(&mut cell_rate, &TextOrientation::Vertical);
-    cell_set_text_style(&mut cell_rate, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_rate, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell_rate, &TextAlignment::HorizontalCenter);
-    cell_set_all_borders(&mut cell_rate, &BorderThickness::Medium);
-    cell_set_all_borders(
-        sheet.get_cell_mut((4, position_y + 1)),
-        &BorderThickness::Medium,
-    );
-    sheet.set_cell(cell_rate);
-
-    let mut cell_discipline_name = create_cell("Назва дисципліни", (5, position_y));
-    cell_set_text_style(&mut cell_discipline_name, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_discipline_name, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(&mut cell_discipline_name, &TextAlignment::HorizontalCenter);
-    cell_set_all_borders(&mut cell_discipline_name, &BorderThickness::Medium);
-    cell_set_all_borders(
-        sheet.get_cell_mut((5, position_y + 1)),
-        &BorderThickness::Medium,
-    );
-    sheet.set_cell(cell_discipline_name);
-
-    let some_header_texts = vec![
-        "Форма навчання",
-        "Спеціальність",
-        "Група",
-        "Курс",
-        "Контингент",
+    column_set_width(sheet, 9, narrow_row_width);
+    column_set_width(sheet, 10, narrow_row_width);
+
+    let headers = [
+        ("№ з/п", "header-vertical"),
+        ("Прізвище, ім'я та по батькові (повністю)", "header"),
+        ("Посада, вчене звання, вчена ступінь", "header"),
+        ("Ставка", "header-vertical"),
+        ("Назва дисципліни", "header"),
+        ("Форма навчання", "header-vertical"),
+        ("Спеціальність", "header-vertical"),
+        ("Група", "header-vertical"),
+        ("Курс", "header-vertical"),
+        ("Контингент", "header-vertical"),
     ];
-
-    let mut x = 6;
-    for header_text in some_header_texts {
-        let mut cell_workload_type = create_cell(header_text, (x, position_y));
-        cell_set_text_orientation(&mut cell_workload_type, &TextOrientation::Vertical);
-        cell_set_text_style(&mut cell_workload_type, &TextStyle::Bold);
-        cell_set_text_alignment(&mut cell_workload_type, &TextAlignment::VerticalCenter);
-        cell_set_text_alignment(&mut cell_workload_type, &TextAlignment::HorizontalCenter);
-        cell_set_all_borders(&mut cell_workload_type, &BorderThickness::Medium);
+    for (x, (text, style)) in (1..).zip(headers) {
+        sheet.set_cell(styles.cell(style, text, (x, position_y)));
         cell_set_all_borders(
             sheet.get_cell_mut((x, position_y + 1)),
             &BorderThickness::Medium,
         );
-        sheet.set_cell(cell_workload_type);
-        x += 1;
     }
 
-    let mut cell_types_of_workload = create_cell("ВИДИ НАВЧАЛЬНОГО НАВАНТАЖЕННЯ", (11, position_y));
-    cell_set_text_style(&mut cell_types_of_workload, &TextStyle::Bold);
-    cell_set_text_alignment(&mut cell_types_of_workload, &TextAlignment::VerticalCenter);
-    cell_set_text_alignment(
-        &mut cell_types_of_workload,
-        &TextAlignment::HorizontalCenter,
-    );
-    cell_set_all_borders(&mut cell_types_of_workload, &BorderThickness::Medium);
-    for i in 7..=24 {
+    for i in 7..=last_x {
         cell_set_all_borders(
             sheet.get_cell_mut((i, position_y)),
             &BorderThickness::Medium,
         );
     }
-    sheet.set_cell(cell_types_of_workload);
-
-    let workload_types = vec![
-        "Лекції",
-        "Практичні (семінарські) заняття",
-        "Лабораторні роботи",
-        "Екзамени",
-        "Консультації перед екзаменами",
-        "Заліки",
-        "Випускні кваліфікаційні роботи",
-        "Виробнича практика",
-        "Навчальна практика",
-        "Поточні консультації",
-        "Індивідуальні",
-        "Курсові роботи",
-        "Керівництво аспірантами",
-        "Всього",
-    ];
-
-    let mut x = 11;
-    for wl_type in workload_types {
-        let mut cell_workload_type = create_cell(wl_type, (x, position_y + 1));
-        cell_set_text_orientation(&mut cell_workload_type, &TextOrientation::Vertical);
-        cell_set_text_style(&mut cell_workload_type, &TextStyle::Bold);
-        cell_set_text_alignment(&mut cell_workload_type, &TextAlignment::VerticalCenter);
-        cell_set_text_alignment(&mut cell_workload_type, &TextAlignment::HorizontalCenter);
-        cell_set_all_borders(&mut cell_workload_type, &BorderThickness::Medium);
-        sheet.set_cell(cell_workload_type);
+    sheet.set_cell(styles.cell(
+        "header",
+        "ВИДИ НАВЧАЛЬНОГО НАВАНТАЖЕННЯ",
+        (PERSONAL_TABLE_HOURS_X, position_y),
+    ));
+
+    let mut x = PERSONAL_TABLE_HOURS_X;
+    for column in &columns.personal {
+        let (caption, width) = match column {
+            PersonalColumn::Hours(i) => {
+                (columns.hours[*i].caption.as_str(), columns.hours[*i].width)
+            }
+            PersonalColumn::IndividualWorkTypes => {
+                ("Види індивідуальних завдань", INDIVIDUAL_WORK_TYPES_WIDTH)
+            }
+        };
+        column_set_width(sheet, x, width);
+        sheet.set_cell(styles.cell("header-vertical", caption, (x, position_y + 1)));
         x += 1;
     }
 }
@@ -680,7 +630,11 @@ fn write_additional_table_content(
     sheet: &mut Worksheet,
     position_y: u32,
     table: &OutputPersonalTable,
+    columns: &WorkloadColumns,
+    styles: &StyleRegistry,
+    highlight_norms: bool,
 ) -> u32 {
+    let last_x = columns.personal_last_x();
     let mut table_start_y = position_y + 1;
     let mut table_height: u32 = 2;
     for i in 0..2 {
@@ -699,15 +653,10 @@ fn write_additional_table_content(
         } else {
             &table.semester_2_rate
         };
-        let semester_total_day = if i == 0 {
-            &table.semester_1_total_day
-        } else {
-            &table.semester_2_total_day
-        };
-        let semester_total_evening = if i == 0 {
-            &table.semester_1_total_evening
+        let form_totals = if i == 0 {
+            &table.semester_1_form_totals
         } else {
-            &table.semester_2_total_evening
+            &table.semester_2_form_totals
         };
         let semester_total = if i == 0 {
             &table.semester_1_total
@@ -715,147 +664,85 @@ fn write_additional_table_content(
             &table.semester_2_total
         };
 
-        sheet.add_merge_cells(&format!("A{}:X{}", table_start_y - 1, table_start_y - 1));
-        let mut cell = create_cell(
-            &format!("{} семестр", if i == 0 { "I" } else { "II" }),
-            (1, table_start_y - 1),
-        );
-        cell_set_text_style(&mut cell, &TextStyle::Bold);
-        cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
-        cell_set_all_borders(&mut cell, &BorderThickness::Medium);
-        for i in 1..=24 {
+        merge_cells(sheet, (1, table_start_y - 1), (last_x, table_start_y - 1));
+        for i in 1..=last_x {
             cell_set_all_borders(
                 sheet.get_cell_mut((i, table_start_y - 1)),
                 &BorderThickness::Medium,
             );
         }
-        sheet.set_cell(cell);
-
-        let table_half_height = semester.len() as u32 + if i == 0 { 4 } else { 5 };
-
-        sheet.add_merge_cells(&format!(
-            "A{table_start_y}:A{}",
-            table_start_y + table_half_height
-        ));
-        sheet.add_merge_cells(&format!(
-            "B{}:B{}",
-            table_start_y + 3,
-            table_start_y + table_half_height
-        ));
-        sheet.add_merge_cells(&format!(
-            "C{table_start_y}:C{}",
-            table_start_y + table_half_height
-        ));
-        sheet.add_merge_cells(&format!(
-            "D{table_start_y}:D{}",
-            table_start_y + table_half_height
+        sheet.set_cell(styles.cell(
+            "semester-title",
+            &format!("{} семестр", if i == 0 { "I" } else { "II" }),
+            (1, table_start_y - 1),
         ));
 
-        let mut cell = create_cell(&format!("{}", table.id), (1, table_start_y));
-        cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
-        cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-        sheet.set_cell(cell);
-
-        let mut cell = create_cell(&table.last_name, (2, table_start_y));
-        cell_set_text_style(&mut cell, &TextStyle::BoldItalic);
-        sheet.set_cell(cell);
-
-        let mut cell = create_cell(&table.middle_name, (2, table_start_y + 1));
-        cell_set_text_style(&mut cell, &TextStyle::Italic);
-        sheet.set_cell(cell);
+        let written_rows =
+            semester.len() as u32 + 2 * form_totals.len() as u32 + if i == 0 { 1 } else { 2 };
+        let empty_rows = MIN_SEMESTER_ROWS.saturating_sub(written_rows);
+        let table_half_height = written_rows + empty_rows - 1;
 
-        let mut cell = create_cell(&table.first_name, (2, table_start_y + 2));
-        cell_set_text_style(&mut cell, &TextStyle::Italic);
-        sheet.set_cell(cell);
-
-        let mut cell = create_cell(&comment, (2, table_start_y + 3));
-        cell_set_text_style(&mut cell, &TextStyle::BoldItalic);
-        cell_set_text_alignment(&mut cell, &TextAlignment::Top);
-        sheet.set_cell(cell);
-
-        let mut cell = create_cell(&table.rank, (3, table_start_y));
-        cell_set_text_alignment(&mut cell, &TextAlignment::Top);
-        sheet.set_cell(cell);
-
-        let mut cell = create_cell_number(*semester_rate, (4, table_start_y), true);
-        cell_set_text_orientation(&mut cell, &TextOrientation::Vertical);
-        cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
-        cell_set_text_alignment(&mut cell, &TextAlignment::VerticalCenter);
-        sheet.set_cell(cell);
+        let table_end_y = table_start_y + table_half_height;
+        merge_cells(sheet, (1, table_start_y), (1, table_end_y));
+        if table_end_y > table_start_y + 3 {
+            merge_cells(sheet, (2, table_start_y + 3), (2, table_end_y));
+        }
+        merge_cells(sheet, (3, table_start_y), (3, table_end_y));
+        merge_cells(sheet, (4, table_start_y), (4, table_end_y));
+
+        sheet.set_cell(styles.cell("centered", &format!("{}", table.id), (1, table_start_y)));
+        sheet.set_cell(styles.cell("last-name", &table.last_name, (2, table_start_y)));
+        sheet.set_cell(styles.cell("first-name", &table.middle_name, (2, table_start_y + 1)));
+        sheet.set_cell(styles.cell("first-name", &table.first_name, (2, table_start_y + 2)));
+        sheet.set_cell(styles.cell("comment", comment, (2, table_start_y + 3)));
+        sheet.set_cell(styles.cell("rank", &table.rank, (3, table_start_y)));
+        sheet.set_cell(styles.number_cell(
+            "number-2dp-vertical",
+            *semester_rate,
+            (4, table_start_y),
+        ));
 
         let mut temp_y = table_start_y;
         for row in semester {
             let students_count = format!("{}", row.students_count);
-            write_additional_content_row(
-                sheet,
-                temp_y,
-                &row.hours,
-                &row.name,
-                Some(&row.learning_form),
-                Some(&row.speciality),
-                Some(&row.group),
-                Some(&row.course),
-                Some(&students_count),
-                row.merge_lectures_with_next,
-            );
+            let personal_row = PersonalRow {
+                name: &row.name,
+                details: Some([
+                    &row.learning_form,
+                    &row.speciality,
+                    &row.group,
+                    &row.course,
+                    &students_count,
+                ]),
+                hours: &row.hours,
+                individual_work_types: &row.individual_work_types,
+                merge_lectures: row.merge_lectures_with_next,
+                norm_rate: None,
+            };
+            write_additional_content_row(sheet, temp_y, &personal_row, columns, styles);
             temp_y += 1;
         }
-        additional_content_fill_empty_borders(sheet, temp_y);
-        temp_y += 1;
-        write_additional_content_row(
-            sheet,
-            temp_y,
-            semester_total_day,
-            "Разом (денна форма)",
-            None,
-            None,
-            None,
-            None,
-            None,
-            false,
-        );
-        temp_y += 1;
-        additional_content_fill_empty_borders(sheet, temp_y);
-        temp_y += 1;
-        write_additional_content_row(
-            sheet,
-            temp_y,
-            semester_total_evening,
-            "Разом (вечірня форма)",
-            None,
-            None,
-            None,
-            None,
-            None,
-            false,
-        );
-        temp_y += 1;
-        write_additional_content_row(
-            sheet,
-            temp_y,
-            semester_total,
-            &format!("Усього за {} семестр", if i == 0 { 1 } else { 2 }),
-            None,
-            None,
-            None,
-            None,
-            None,
-            false,
-        );
+        for _ in 0..empty_rows {
+            additional_content_fill_empty_borders(sheet, temp_y, columns);
+            temp_y += 1;
+        }
+        for form_total in form_totals {
+            additional_content_fill_empty_borders(sheet, temp_y, columns);
+            temp_y += 1;
+            let name = format!("Разом ({} форма)", form_total.learning_form);
+            let row = subtotal_personal_row(&name, &form_total.hours, None);
+            write_additional_content_row(sheet, temp_y, &row, columns, styles);
+            temp_y += 1;
+        }
+        let name = format!("Усього за {} семестр", if i == 0 { 1 } else { 2 });
+        let row = subtotal_personal_row(&name, semester_total, None);
+        write_additional_content_row(sheet, temp_y, &row, columns, styles);
         temp_y += 1;
         if i == 1 {
-            write_additional_content_row(
-                sheet,
-                temp_y,
-                &table.year_total,
-                "Усього за рік",
-                None,
-                None,
-                None,
-                None,
-                None,
-                false,
-            );
+            let norm_rate = highlight_norms
+                .then(|| yearly_norm_rate(table.semester_1_rate, table.semester_2_rate));
+            let row = subtotal_personal_row("Усього за рік", &table.year_total, norm_rate);
+            write_additional_content_row(sheet, temp_y, &row, columns, styles);
         }
 
         for i in 1..=4 {
@@ -863,7 +750,7 @@ fn write_additional_table_content(
         }
 
         for i in 0..=table_half_height {
-            let cell = sheet.get_cell_mut((24, table_start_y + i));
+            let cell = sheet.get_cell_mut((last_x, table_start_y + i));
             cell_set_right_border(cell, &BorderThickness::Medium);
         }
         table_start_y += table_half_height + 2;
@@ -872,34 +759,51 @@ fn write_additional_table_content(
     table_height
 }
 
+fn subtotal_personal_row<'a>(
+    name: &'a str,
+    hours: &'a OutputHoursRow,
+    norm_rate: Option<f64>,
+) -> PersonalRow<'a> {
+    PersonalRow {
+        name,
+        details: None,
+        hours,
+        individual_work_types: "",
+        merge_lectures: false,
+        norm_rate,
+    }
+}
+
 fn write_additional_content_row(
     sheet: &mut Worksheet,
     y: u32,
-    hours: &OutputHoursRow,
-    name: &str,
-    learning_form: Option<&str>,
-    speciality: Option<&str>,
-    group: Option<&str>,
-    course: Option<&str>,
-    students_count: Option<&str>,
-    merge_lectures: bool,
+    row: &PersonalRow,
+    columns: &WorkloadColumns,
+    styles: &StyleRegistry,
 ) {
-    let name_overriden = learning_form.is_none()
-        || speciality.is_none()
-        || group.is_none()
-        || course.is_none()
-        || students_count.is_none();
+    let PersonalRow {
+        name,
+        hours,
+        individual_work_types,
+        merge_lectures,
+        norm_rate,
+        ..
+    } = *row;
+    let name_overriden = row.details.is_none();
     let border_t = if name_overriden {
         BorderThickness::Medium
     } else {
         BorderThickness::Thin
     };
     if merge_lectures {
-        sheet.add_merge_cells(&format!("K{}:K{}", y, y + 1));
+        merge_cells(
+            sheet,
+            (PERSONAL_TABLE_HOURS_X, y),
+            (PERSONAL_TABLE_HOURS_X, y + 1),
+        );
     }
     if name_overriden {
-        let mut cell = create_cell(name, (5, y));
-        cell_set_text_style(&mut cell, &TextStyle::BoldItalic);
+        let mut cell = styles.cell("personal-subtotal", name, (5, y));
         cell_set_top_border(&mut cell, &BorderThickness::Medium);
         cell_set_bottom_border(&mut cell, &BorderThickness::Medium);
         cell_set_left_border(&mut cell, &BorderThickness::Medium);
@@ -911,67 +815,97 @@ fn write_additional_content_row(
             cell_set_left_border(cell, &BorderThickness::Thin);
             cell_set_right_border(cell, &BorderThickness::Thin);
         }
-    } else {
-        for i in 0..=5 {
-            let value = match i {
-                0 => name,
-                1 => learning_form.unwrap_or(""),
-                2 => speciality.unwrap_or(""),
-                3 => group.unwrap_or(""),
-                4 => course.unwrap_or(""),
-                5 => students_count.unwrap_or(""),
-                _ => "",
+    } else if let Some(details) = row.details {
+        for (i, value) in (0..).zip([name].into_iter().chain(details)) {
+            let style = if i == 0 {
+                "default"
+            } else {
+                "centered-horizontal"
             };
-            let mut cell = create_cell(value, (5 + i, y));
+            let mut cell = styles.cell(style, value, (5 + i, y));
             cell_set_top_border(&mut cell, &border_t);
             cell_set_bottom_border(&mut cell, &border_t);
             cell_set_left_border(&mut cell, &BorderThickness::Thin);
             cell_set_right_border(&mut cell, &BorderThickness::Thin);
             if i == 0 {
                 cell_set_left_border(&mut cell, &border_t);
-            } else {
-                cell_set_text_alignment(&mut cell, &TextAlignment::HorizontalCenter);
             }
             sheet.set_cell(cell);
         }
     }
-    for i in 0..=13 {
-        let value = workload_hours_by_index(hours, i);
-        if let Some(value) = value {
-            let cell = create_cell_number(value, (11 + i, y), true);
-            sheet.set_cell(cell);
+    let mut x = PERSONAL_TABLE_HOURS_X;
+    for column in &columns.personal {
+        let key = match column {
+            PersonalColumn::Hours(i) => Some(columns.hours[*i].key),
+            PersonalColumn::IndividualWorkTypes => None,
         };
-        let cell = sheet.get_cell_mut((11 + i, y));
-        if name_overriden {
-            cell_set_text_style(cell, &TextStyle::BoldItalic);
+        let violation = match column {
+            PersonalColumn::Hours(i) if key == Some(WorkloadKey::Total) => norm_rate
+                .zip(columns.value(hours, &columns.hours[*i]))
+                .and_then(|(rate, value)| check_workload_norm(rate, value)),
+            _ => None,
+        };
+        let style = if key == Some(WorkloadKey::Total) {
+            norm_style("personal-total-hours", violation)
+        } else if name_overriden {
+            "personal-subtotal-hours".to_string()
+        } else {
+            "personal-hours".to_string()
+        };
+        match column {
+            PersonalColumn::Hours(i) => {
+                let column = &columns.hours[*i];
+                if let Some(value) = columns.value(hours, column) {
+                    let mut cell = styles.number_cell(&style, value, (x, y));
+                    cell_set_number_format(&mut cell, &column.number_format);
+                    sheet.set_cell(cell);
+                } else {
+                    styles.apply(sheet.get_cell_mut((x, y)), &style);
+                }
+            }
+            PersonalColumn::IndividualWorkTypes => {
+                sheet.set_cell(styles.cell(&style, individual_work_types, (x, y)));
+            }
         }
-        cell_set_text_alignment(cell, &TextAlignment::HorizontalCenter);
+        let cell = sheet.get_cell_mut((x, y));
         cell_set_top_border(cell, &border_t);
         cell_set_bottom_border(cell, &border_t);
         cell_set_left_border(
             cell,
-            if i == 0 {
+            if x == PERSONAL_TABLE_HOURS_X {
                 &BorderThickness::Medium
             } else {
                 &BorderThickness::Thin
             },
         );
         cell_set_right_border(cell, &BorderThickness::Thin);
-        if i == 13 {
+        if key == Some(WorkloadKey::Total) {
             cell_set_all_borders(cell, &BorderThickness::Medium);
-            cell_set_text_style(cell, &TextStyle::Bold);
         }
-        if i == 0 && merge_lectures {
+        if merge_lectures && key == Some(WorkloadKey::Lectures) {
             cell_set_text_alignment(cell, &TextAlignment::VerticalCenter);
         }
+        if let Some(violation) = violation {
+            add_comment(sheet, (x, y), &violation.comment());
+        }
+        x += 1;
+    }
+}
+
+/// `base` or its "-underload" / "-overload" variant.
+fn norm_style(base: &str, violation: Option<NormViolation>) -> String {
+    match violation.map(|violation| violation.deviation) {
+        Some(NormDeviation::Underload) => format!("{base}-underload"),
+        Some(NormDeviation::Overload) => format!("{base}-overload"),
+        None => base.to_string(),
     }
 }
 
-fn additional_content_fill_empty_borders(sheet: &mut Worksheet, y: u32) {
-    for i in 5..=24 {
+fn additional_content_fill_empty_borders(sheet: &mut Worksheet, y: u32, columns: &WorkloadColumns) {
+    for i in 5..=columns.personal_last_x() {
         let cell = sheet.get_cell_mut((i, y));
         cell_set_all_borders(cell, &BorderThickness::Thin);
-        if i == 11 {
+        if i == PERSONAL_TABLE_HOURS_X {
             cell_set_left_border(cell, &BorderThickness::Medium);
         }
     }
@@ -991,463 +925,520 @@ fn cell_set_hv_borders(sheet: &mut Worksheet, x: u32, y: u32, height: u32) {
     }
 }
 
-fn workload_hours_by_index(hours: &OutputHoursRow, index: u32) -> Option<f64> {
-    let value = match index {
-        0 => hours.lectures,
-        1 => hours.practices,
-        2 => hours.labs,
-        3 => hours.exams,
-        4 => hours.exam_consults,
-        5 => hours.tests,
-        6 => hours.qual_works,
-        7 => hours.working_practice,
-        8 => hours.teaching_practice,
-        9 => hours.consults,
-        10 => hours.individual_works,
-        11 => hours.course_works,
-        12 => hours.supervising,
-        13 => hours.total,
-        _ => -1_f64,
-    };
-    if value < 0_f64 { None } else { Some(value) }
-}
-
 pub fn get_test_output_data() -> ExportedData {
     ExportedData {
         year: 2024,
         type_name: "електронних обчислювальних машин (КЕО)".to_string(),
         main_table: OutputMainTable {
-            heads: vec![OutputMainWorkerRow {
-                first_name: "Володимир".to_string(),
-                middle_name: "Сергійович".to_string(),
-                last_name: "Хандецький".to_string(),
-                rank: "зав. каф., д.т.н., професор, Гарант ОПП PhD".to_string(),
-                rate_and_hours: OutputMainRateAndHours {
-                    rate: 1.00_f64,
-                    semester_1: OutputHoursRow::new(
-                        56.00_f64, -1_f64, 96.00_f64, 13.00_f64, 4.00_f64, -1_f64, 30.00_f64,
-                        6.67_f64, -1_f64, 11.00_f64, -1_f64, 176.00_f64, -1_f64, 392.67_f64,
-                    ),
-                    semester_2: OutputHoursRow::new(
-                        60.00_f64, -1_f64, 36.00_f64, -1_f64, -1_f64, -1_f64, 45.00_f64, 12.00_f64,
-                        -1_f64, 3.00_f64, -1_f64, 30.00_f64, -1_f64, 196.00_f64,
-                    ),
-                    year: OutputHoursRow::new(
-                        116.00_f64, -1_f64, 132.00_f64, 13.00_f64, 4.00_f64, -1_f64, 75.00_f64,
-                        18.67_f64, -1_f64, 14.00_f64, -1_f64, 206.00_f64, -1_f64, 578.67_f64,
-                    ),
-                },
-            }],
-            heads_total: OutputMainRateAndHours {
-                rate: 1.00_f64,
-                semester_1: OutputHoursRow::new(
-                    56.00_f64, -1_f64, 96.00_f64, 13.00_f64, 4.00_f64, -1_f64, 30.00_f64, 6.67_f64,
-                    -1_f64, 11.00_f64, -1_f64, 176.00_f64, -1_f64, 392.67_f64,
-                ),
-                semester_2: OutputHoursRow::new(
-                    60.00_f64, -1_f64, 36.00_f64, -1_f64, -1_f64, -1_f64, 45.00_f64, 12.00_f64,
-                    -1_f64, 3.00_f64, -1_f64, 30.00_f64, -1_f64, 196.00_f64,
-                ),
-                year: OutputHoursRow::new(
-                    116.00_f64, -1_f64, 132.00_f64, 13.00_f64, 4.00_f64, -1_f64, 75.00_f64,
-                    18.67_f64, -1_f64, 14.00_f64, -1_f64, 206.00_f64, -1_f64, 578.67_f64,
-                ),
-            },
-            professors: vec![OutputMainWorkerRow {
-                first_name: "Олександр".to_string(),
-                middle_name: "Сергійович".to_string(),
-                last_name: "Тонкошкур".to_string(),
-                rank: "Професор професор д.ф.м.н.".to_string(),
-                rate_and_hours: OutputMainRateAndHours {
-                    rate: 1.00_f64,
-                    semester_1: OutputHoursRow::new(
-                        48.00_f64, -1_f64, 112.00_f64, 23.90_f64, 6.00_f64, -1_f64, 30.00_f64,
-                        6.67_f64, -1_f64, 10.00_f64, -1_f64, -1_f64, -1_f64, 236.57_f64,
-                    ),
-                    semester_2: OutputHoursRow::new(
-                        110.00_f64, -1_f64, 194.00_f64, 10.00_f64, 2.00_f64, -1_f64, 15.00_f64,
-                        10.00_f64, -1_f64, 13.00_f64, -1_f64, -1_f64, -1_f64, 354.00_f64,
-                    ),
-                    year: OutputHoursRow::new(
-                        158.00_f64, -1_f64, 306.00_f64, 33.90_f64, 8.00_f64, -1_f64, 45.00_f64,
-                        16.67_f64, -1_f64, 23.00_f64, -1_f64, -1_f64, -1_f64, 590.57_f64,
-                    ),
-                },
-            }],
-            professors_total: OutputMainRateAndHours {
-                rate: 1.00_f64,
-                semester_1: OutputHoursRow::new(
-                    48.00_f64, -1_f64, 112.00_f64, 23.90_f64, 6.00_f64, -1_f64, 30.00_f64,
-                    6.67_f64, -1_f64, 10.00_f64, -1_f64, -1_f64, -1_f64, 236.57_f64,
-                ),
-                semester_2: OutputHoursRow::new(
-                    110.00_f64, -1_f64, 194.00_f64, 10.00_f64, 2.00_f64, -1_f64, 15.00_f64,
-                    10.00_f64, -1_f64, 13.00_f64, -1_f64, -1_f64, -1_f64, 354.00_f64,
-                ),
-                year: OutputHoursRow::new(
-                    158.00_f64, -1_f64, 306.00_f64, 33.90_f64, 8.00_f64, -1_f64, 45.00_f64,
-                    16.67_f64, -1_f64, 23.00_f64, -1_f64, -1_f64, -1_f64, 590.57_f64,
-                ),
-            },
-            associate_professors: vec![
-                OutputMainWorkerRow {
-                    first_name: "Олексій".to_string(),
-                    middle_name: "Борисович".to_string(),
-                    last_name: "Гниленко".to_string(),
-                    rank: "Доцент доцент к.ф.м.н.".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
-                        rate: 1.00_f64,
-                        semester_1: OutputHoursRow::new(
-                            84.00_f64, 80.00_f64, 50.00_f64, 13.00_f64, 4.00_f64, -1_f64,
-                            21.00_f64, 6.67_f64, -1_f64, 13.00_f64, -1_f64, -1_f64, -1_f64,
-                            271.67_f64,
-                        ),
-                        semester_2: OutputHoursRow::new(
-                            76.00_f64, 16.00_f64, 164.00_f64, 26.00_f64, 7.00_f64, -1_f64,
-                            9.00_f64, 6.00_f64, -1_f64, 19.00_f64, -1_f64, -1_f64, -1_f64,
-                            323.00_f64,
-                        ),
-                        year: OutputHoursRow::new(
-                            160.00_f64, 96.00_f64, 214.00_f64, 39.00_f64, 11.00_f64, -1_f64,
-                            30.00_f64, 12.67_f64, -1_f64, 32.00_f64, -1_f64, -1_f64, -1_f64,
-                            594.67_f64,
-                        ),
-                    },
-                },
-                OutputMainWorkerRow {
-                    first_name: "Надія".to_string(),
-                    middle_name: "Валеріївна".to_string(),
-                    last_name: "Карпенко".to_string(),
-                    rank: "Доцент к.ф.м.н.".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
-                        rate: 1.00_f64,
-                        semester_1: OutputHoursRow::new(
-                            82.00_f64, -1_f64, 142.00_f64, 10.00_f64, 3.00_f64, -1_f64, 21.00_f64,
-                            6.67_f64, -1_f64, 12.00_f64, -1_f64, 8.00_f64, -1_f64, 284.67_f64,
-                        ),
-                        semester_2: OutputHoursRow::new(
-                            60.00_f64, -1_f64, 180.00_f64, 7.00_f64, 2.00_f64, -1_f64, 18.00_f64,
-                            12.00_f64, -1_f64, 16.00_f64, -1_f64, 15.00_f64, -1_f64, 310.00_f64,
-                        ),
-                        year: OutputHoursRow::new(
-                            142.00_f64, -1_f64, 322.00_f64, 17.00_f64, 5.00_f64, -1_f64, 39.00_f64,
-                            18.67_f64, -1_f64, 28.00_f64, -1_f64, 23.00_f64, -1_f64, 594.67_f64,
-                        ),
-                    },
-                },
-                OutputMainWorkerRow {
-                    first_name: "Олександр".to_string(),
-                    middle_name: "Анатолійович".to_string(),
-                    last_name: "Литвинов".to_string(),
-                    rank: "Доцент доцент к.т.н. Гарант ОПП бакалавр".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
-                        rate: 1.00_f64,
-                        semester_1: OutputHoursRow::new(
-                            24.00_f64, -1_f64, 32.00_f64, 1.20_f64, -1_f64, -1_f64, 30.00_f64,
-                            6.67_f64, -1_f64, 4.00_f64, -1_f64, 16.00_f64, -1_f64, 113.87_f64,
-                        ),
-                        semester_2: OutputHoursRow::new(
-                            120.00_f64, -1_f64, 136.00_f64, 19.00_f64, 6.00_f64, -1_f64, 45.00_f64,
-                            12.00_f64, -1_f64, 15.00_f64, -1_f64, 124.00_f64, -1_f64, 477.00_f64,
-                        ),
-                        year: OutputHoursRow::new(
-                            144.00_f64, -1_f64, 168.00_f64, 20.20_f64, 6.00_f64, -1_f64, 75.00_f64,
-                            18.67_f64, -1_f64, 19.00_f64, -1_f64, 140.00_f64, -1_f64, 590.87_f64,
-                        ),
-                    },
-                },
-                OutputMainWorkerRow {
-                    first_name: "Наталія".to_string(),
-                    middle_name: "Олександрівна".to_string(),
-                    last_name: "Матвеєва".to_string(),
-                    rank: "Доцент доцент к.т.н.".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
+            sections: vec![
+                MainTableSection {
+                    title: "Всього за зав. каф.".to_string(),
+                    rows: vec![OutputMainWorkerRow {
+                        first_name: "Володимир".to_string(),
+                        middle_name: "Сергійович".to_string(),
+                        last_name: "Хандецький".to_string(),
+                        rank: "зав. каф., д.т.н., професор, Гарант ОПП PhD".to_string(),
+                        rate_and_hours: OutputMainRateAndHours {
+                            rate: 1.00_f64,
+                            semester_1: OutputHoursRow::new(
+                                56.00_f64, -1_f64, 96.00_f64, 13.00_f64, 4.00_f64, -1_f64,
+                                30.00_f64, -1_f64, 6.67_f64, -1_f64, 11.00_f64, -1_f64, 176.00_f64,
+                                -1_f64, -1_f64, -1_f64, 392.67_f64,
+                            ),
+                            semester_2: OutputHoursRow::new(
+                                60.00_f64, -1_f64, 36.00_f64, -1_f64, -1_f64, -1_f64, 45.00_f64,
+                                -1_f64, 12.00_f64, -1_f64, 3.00_f64, -1_f64, 30.00_f64, -1_f64,
+                                -1_f64, -1_f64, 196.00_f64,
+                            ),
+                            year: OutputHoursRow::new(
+                                116.00_f64, -1_f64, 132.00_f64, 13.00_f64, 4.00_f64, -1_f64,
+                                75.00_f64, -1_f64, 18.67_f64, -1_f64, 14.00_f64, -1_f64,
+                                206.00_f64, -1_f64, -1_f64, -1_f64, 578.67_f64,
+                            ),
+                        },
+                    }],
+                    total: OutputMainRateAndHours {
                         rate: 1.00_f64,
                         semester_1: OutputHoursRow::new(
-                            60.00_f64, -1_f64, 104.00_f64, 0.90_f64, -1_f64, -1_f64, 21.00_f64,
-                            6.67_f64, -1_f64, 10.00_f64, -1_f64, 28.00_f64, -1_f64, 230.57_f64,
+                            56.00_f64, -1_f64, 96.00_f64, 13.00_f64, 4.00_f64, -1_f64, 30.00_f64,
+                            -1_f64, 6.67_f64, -1_f64, 11.00_f64, -1_f64, 176.00_f64, -1_f64,
+                            -1_f64, -1_f64, 392.67_f64,
                         ),
                         semester_2: OutputHoursRow::new(
-                            44.00_f64, -1_f64, 188.00_f64, 7.00_f64, 2.00_f64, -1_f64, 44.00_f64,
-                            12.00_f64, -1_f64, 16.00_f64, -1_f64, 54.00_f64, -1_f64, 367.00_f64,
+                            60.00_f64, -1_f64, 36.00_f64, -1_f64, -1_f64, -1_f64, 45.00_f64,
+                            -1_f64, 12.00_f64, -1_f64, 3.00_f64, -1_f64, 30.00_f64, -1_f64, -1_f64,
+                            -1_f64, 196.00_f64,
                         ),
                         year: OutputHoursRow::new(
-                            104.00_f64, -1_f64, 292.00_f64, 7.90_f64, 2.00_f64, -1_f64, 65.00_f64,
-                            18.67_f64, -1_f64, 26.00_f64, -1_f64, 82.00_f64, -1_f64, 597.57_f64,
+                            116.00_f64, -1_f64, 132.00_f64, 13.00_f64, 4.00_f64, -1_f64, 75.00_f64,
+                            -1_f64, 18.67_f64, -1_f64, 14.00_f64, -1_f64, 206.00_f64, -1_f64,
+                            -1_f64, -1_f64, 578.67_f64,
                         ),
                     },
                 },
-                OutputMainWorkerRow {
-                    first_name: "Ігор".to_string(),
-                    middle_name: "Володимирович".to_string(),
-                    last_name: "Пономарьов".to_string(),
-                    rank: "Доцент доцент к.т.н.".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
+                MainTableSection {
+                    title: "Всього за професорами".to_string(),
+                    rows: vec![OutputMainWorkerRow {
+                        first_name: "Олександр".to_string(),
+                        middle_name: "Сергійович".to_string(),
+                        last_name: "Тонкошкур".to_string(),
+                        rank: "Професор професор д.ф.м.н.".to_string(),
+                        rate_and_hours: OutputMainRateAndHours {
+                            rate: 1.00_f64,
+                            semester_1: OutputHoursRow::new(
+                                48.00_f64, -1_f64, 112.00_f64, 23.90_f64, 6.00_f64, -1_f64,
+                                30.00_f64, -1_f64, 6.67_f64, -1_f64, 10.00_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, 236.57_f64,
+                            ),
+                            semester_2: OutputHoursRow::new(
+                                110.00_f64, -1_f64, 194.00_f64, 10.00_f64, 2.00_f64, -1_f64,
+                                15.00_f64, -1_f64, 10.00_f64, -1_f64, 13.00_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, 354.00_f64,
+                            ),
+                            year: OutputHoursRow::new(
+                                158.00_f64, -1_f64, 306.00_f64, 33.90_f64, 8.00_f64, -1_f64,
+                                45.00_f64, -1_f64, 16.67_f64, -1_f64, 23.00_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, 590.57_f64,
+                            ),
+                        },
+                    }],
+                    total: OutputMainRateAndHours {
                         rate: 1.00_f64,
                         semester_1: OutputHoursRow::new(
-                            60.00_f64, -1_f64, 180.00_f64, 13.00_f64, 3.00_f64, -1_f64, 21.00_f64,
-                            6.67_f64, -1_f64, 15.00_f64, -1_f64, 20.00_f64, -1_f64, 318.67_f64,
+                            48.00_f64, -1_f64, 112.00_f64, 23.90_f64, 6.00_f64, -1_f64, 30.00_f64,
+                            -1_f64, 6.67_f64, -1_f64, 10.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, 236.57_f64,
                         ),
                         semester_2: OutputHoursRow::new(
-                            60.00_f64, 58.00_f64, 100.00_f64, -1_f64, -1_f64, -1_f64, 15.00_f64,
-                            10.00_f64, -1_f64, 5.00_f64, -1_f64, 30.00_f64, -1_f64, 278.00_f64,
+                            110.00_f64, -1_f64, 194.00_f64, 10.00_f64, 2.00_f64, -1_f64, 15.00_f64,
+                            -1_f64, 10.00_f64, -1_f64, 13.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, 354.00_f64,
                         ),
                         year: OutputHoursRow::new(
-                            120.00_f64, 58.00_f64, 280.00_f64, 13.00_f64, 3.00_f64, -1_f64,
-                            36.00_f64, 16.67_f64, -1_f64, 20.00_f64, -1_f64, 50.00_f64, -1_f64,
-                            596.67_f64,
+                            158.00_f64, -1_f64, 306.00_f64, 33.90_f64, 8.00_f64, -1_f64, 45.00_f64,
+                            -1_f64, 16.67_f64, -1_f64, 23.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, 590.57_f64,
                         ),
                     },
                 },
-                OutputMainWorkerRow {
-                    first_name: "Ігор".to_string(),
-                    middle_name: "Анатолійович".to_string(),
-                    last_name: "Скуратовський".to_string(),
-                    rank: "Доцент доцент к.ф.м.н.".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
-                        rate: 1.00_f64,
+                MainTableSection {
+                    title: "Всього за доцентами".to_string(),
+                    rows: vec![
+                        OutputMainWorkerRow {
+                            first_name: "Олексій".to_string(),
+                            middle_name: "Борисович".to_string(),
+                            last_name: "Гниленко".to_string(),
+                            rank: "Доцент доцент к.ф.м.н.".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 1.00_f64,
+                                semester_1: OutputHoursRow::new(
+                                    84.00_f64, 80.00_f64, 50.00_f64, 13.00_f64, 4.00_f64, -1_f64,
+                                    21.00_f64, -1_f64, 6.67_f64, -1_f64, 13.00_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, -1_f64, 271.67_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    76.00_f64, 16.00_f64, 164.00_f64, 26.00_f64, 7.00_f64, -1_f64,
+                                    9.00_f64, -1_f64, 6.00_f64, -1_f64, 19.00_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, -1_f64, 323.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    160.00_f64, 96.00_f64, 214.00_f64, 39.00_f64, 11.00_f64,
+                                    -1_f64, 30.00_f64, -1_f64, 12.67_f64, -1_f64, 32.00_f64,
+                                    -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 594.67_f64,
+                                ),
+                            },
+                        },
+                        OutputMainWorkerRow {
+                            first_name: "Надія".to_string(),
+                            middle_name: "Валеріївна".to_string(),
+                            last_name: "Карпенко".to_string(),
+                            rank: "Доцент к.ф.м.н.".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 1.00_f64,
+                                semester_1: OutputHoursRow::new(
+                                    82.00_f64, -1_f64, 142.00_f64, 10.00_f64, 3.00_f64, -1_f64,
+                                    21.00_f64, -1_f64, 6.67_f64, -1_f64, 12.00_f64, -1_f64,
+                                    8.00_f64, -1_f64, -1_f64, -1_f64, 284.67_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    60.00_f64, -1_f64, 180.00_f64, 7.00_f64, 2.00_f64, -1_f64,
+                                    18.00_f64, -1_f64, 12.00_f64, -1_f64, 16.00_f64, -1_f64,
+                                    15.00_f64, -1_f64, -1_f64, -1_f64, 310.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    142.00_f64, -1_f64, 322.00_f64, 17.00_f64, 5.00_f64, -1_f64,
+                                    39.00_f64, -1_f64, 18.67_f64, -1_f64, 28.00_f64, -1_f64,
+                                    23.00_f64, -1_f64, -1_f64, -1_f64, 594.67_f64,
+                                ),
+                            },
+                        },
+                        OutputMainWorkerRow {
+                            first_name: "Олександр".to_string(),
+                            middle_name: "Анатолійович".to_string(),
+                            last_name: "Литвинов".to_string(),
+                            rank: "Доцент доцент к.т.н. Гарант ОПП бакалавр".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 1.00_f64,
+                                semester_1: OutputHoursRow::new(
+                                    24.00_f64, -1_f64, 32.00_f64, 1.20_f64, -1_f64, -1_f64,
+                                    30.00_f64, -1_f64, 6.67_f64, -1_f64, 4.00_f64, -1_f64,
+                                    16.00_f64, -1_f64, -1_f64, -1_f64, 113.87_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    120.00_f64, -1_f64, 136.00_f64, 19.00_f64, 6.00_f64, -1_f64,
+                                    45.00_f64, -1_f64, 12.00_f64, -1_f64, 15.00_f64, -1_f64,
+                                    124.00_f64, -1_f64, -1_f64, -1_f64, 477.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    144.00_f64, -1_f64, 168.00_f64, 20.20_f64, 6.00_f64, -1_f64,
+                                    75.00_f64, -1_f64, 18.67_f64, -1_f64, 19.00_f64, -1_f64,
+                                    140.00_f64, -1_f64, -1_f64, -1_f64, 590.87_f64,
+                                ),
+                            },
+                        },
+                        OutputMainWorkerRow {
+                            first_name: "Наталія".to_string(),
+                            middle_name: "Олександрівна".to_string(),
+                            last_name: "Матвеєва".to_string(),
+                            rank: "Доцент доцент к.т.н.".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 1.00_f64,
+                                semester_1: OutputHoursRow::new(
+                                    60.00_f64, -1_f64, 104.00_f64, 0.90_f64, -1_f64, -1_f64,
+                                    21.00_f64, -1_f64, 6.67_f64, -1_f64, 10.00_f64, -1_f64,
+                                    28.00_f64, -1_f64, -1_f64, -1_f64, 230.57_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    44.00_f64, -1_f64, 188.00_f64, 7.00_f64, 2.00_f64, -1_f64,
+                                    44.00_f64, -1_f64, 12.00_f64, -1_f64, 16.00_f64, -1_f64,
+                                    54.00_f64, -1_f64, -1_f64, -1_f64, 367.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    104.00_f64, -1_f64, 292.00_f64, 7.90_f64, 2.00_f64, -1_f64,
+                                    65.00_f64, -1_f64, 18.67_f64, -1_f64, 26.00_f64, -1_f64,
+                                    82.00_f64, -1_f64, -1_f64, -1_f64, 597.57_f64,
+                                ),
+                            },
+                        },
+                        OutputMainWorkerRow {
+                            first_name: "Ігор".to_string(),
+                            middle_name: "Володимирович".to_string(),
+                            last_name: "Пономарьов".to_string(),
+                            rank: "Доцент доцент к.т.н.".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 1.00_f64,
+                                semester_1: OutputHoursRow::new(
+                                    60.00_f64, -1_f64, 180.00_f64, 13.00_f64, 3.00_f64, -1_f64,
+                                    21.00_f64, -1_f64, 6.67_f64, -1_f64, 15.00_f64, -1_f64,
+                                    20.00_f64, -1_f64, -1_f64, -1_f64, 318.67_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    60.00_f64, 58.00_f64, 100.00_f64, -1_f64, -1_f64, -1_f64,
+                                    15.00_f64, -1_f64, 10.00_f64, -1_f64, 5.00_f64, -1_f64,
+                                    30.00_f64, -1_f64, -1_f64, -1_f64, 278.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    120.00_f64, 58.00_f64, 280.00_f64, 13.00_f64, 3.00_f64, -1_f64,
+                                    36.00_f64, -1_f64, 16.67_f64, -1_f64, 20.00_f64, -1_f64,
+                                    50.00_f64, -1_f64, -1_f64, -1_f64, 596.67_f64,
+                                ),
+                            },
+                        },
+                        OutputMainWorkerRow {
+                            first_name: "Ігор".to_string(),
+                            middle_name: "Анатолійович".to_string(),
+                            last_name: "Скуратовський".to_string(),
+                            rank: "Доцент доцент к.ф.м.н.".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 1.00_f64,
+                                semester_1: OutputHoursRow::new(
+                                    112.00_f64, -1_f64, 196.00_f64, -1_f64, -1_f64, -1_f64,
+                                    21.00_f64, -1_f64, 6.67_f64, -1_f64, 11.00_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, -1_f64, 346.67_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    88.00_f64, -1_f64, 120.00_f64, -1_f64, -1_f64, -1_f64,
+                                    15.00_f64, -1_f64, 10.00_f64, -1_f64, 5.00_f64, -1_f64,
+                                    12.00_f64, -1_f64, -1_f64, -1_f64, 250.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    200.00_f64, -1_f64, 316.00_f64, -1_f64, -1_f64, -1_f64,
+                                    36.00_f64, -1_f64, 16.67_f64, -1_f64, 16.00_f64, -1_f64,
+                                    12.00_f64, -1_f64, -1_f64, -1_f64, 596.67_f64,
+                                ),
+                            },
+                        },
+                        OutputMainWorkerRow {
+                            first_name: "Ольга".to_string(),
+                            middle_name: "Володимирівна".to_string(),
+                            last_name: "Спірінцева".to_string(),
+                            rank: "Доцент к.т.н.".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 1.00_f64,
+                                semester_1: OutputHoursRow::new(
+                                    82.00_f64, 40.00_f64, 128.00_f64, 21.00_f64, 6.00_f64, -1_f64,
+                                    21.00_f64, -1_f64, 6.67_f64, -1_f64, 16.00_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, -1_f64, 320.67_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    44.00_f64, -1_f64, 120.00_f64, 13.00_f64, 4.00_f64, -1_f64,
+                                    15.00_f64, -1_f64, 10.00_f64, 40.00_f64, 5.00_f64, -1_f64,
+                                    24.00_f64, -1_f64, -1_f64, -1_f64, 275.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    126.00_f64, 40.00_f64, 248.00_f64, 34.00_f64, 10.00_f64,
+                                    -1_f64, 36.00_f64, -1_f64, 16.67_f64, 40.00_f64, 21.00_f64,
+                                    -1_f64, 24.00_f64, -1_f64, -1_f64, -1_f64, 595.67_f64,
+                                ),
+                            },
+                        },
+                        OutputMainWorkerRow {
+                            first_name: "Микола".to_string(),
+                            middle_name: "Іванович".to_string(),
+                            last_name: "Твердоступ".to_string(),
+                            rank: "Доцент доцент к.т.н.".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 0.90_f64,
+                                semester_1: OutputHoursRow::new(
+                                    68.00_f64, 80.00_f64, -1_f64, 23.00_f64, 5.00_f64, -1_f64,
+                                    -1_f64, -1_f64, 3.33_f64, -1_f64, 9.00_f64, -1_f64, 196.00_f64,
+                                    -1_f64, -1_f64, -1_f64, 384.33_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    52.00_f64, -1_f64, 64.00_f64, 20.00_f64, 5.00_f64, -1_f64,
+                                    6.00_f64, -1_f64, 4.00_f64, -1_f64, 4.00_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, -1_f64, 155.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    120.00_f64, 80.00_f64, 64.00_f64, 43.00_f64, 10.00_f64, -1_f64,
+                                    6.00_f64, -1_f64, 7.33_f64, -1_f64, 13.00_f64, -1_f64,
+                                    196.00_f64, -1_f64, -1_f64, -1_f64, 539.33_f64,
+                                ),
+                            },
+                        },
+                    ],
+                    total: OutputMainRateAndHours {
+                        rate: 7.90_f64,
                         semester_1: OutputHoursRow::new(
-                            112.00_f64, -1_f64, 196.00_f64, -1_f64, -1_f64, -1_f64, 21.00_f64,
-                            6.67_f64, -1_f64, 11.00_f64, -1_f64, -1_f64, -1_f64, 346.67_f64,
+                            572.00_f64,
+                            200.00_f64,
+                            832.00_f64,
+                            82.10_f64,
+                            21.00_f64,
+                            -1_f64,
+                            156.00_f64,
+                            -1_f64,
+                            50.02_f64,
+                            -1_f64,
+                            90.00_f64,
+                            -1_f64,
+                            268.00_f64,
+                            -1_f64,
+                            -1_f64,
+                            -1_f64,
+                            2271.12_f64,
                         ),
                         semester_2: OutputHoursRow::new(
-                            88.00_f64, -1_f64, 120.00_f64, -1_f64, -1_f64, -1_f64, 15.00_f64,
-                            10.00_f64, -1_f64, 5.00_f64, -1_f64, 12.00_f64, -1_f64, 250.00_f64,
+                            544.00_f64,
+                            74.00_f64,
+                            1072.00_f64,
+                            92.00_f64,
+                            26.00_f64,
+                            -1_f64,
+                            167.00_f64,
+                            -1_f64,
+                            76.00_f64,
+                            40.00_f64,
+                            85.00_f64,
+                            -1_f64,
+                            527.00_f64,
+                            -1_f64,
+                            -1_f64,
+                            -1_f64,
+                            4706.12_f64,
                         ),
                         year: OutputHoursRow::new(
-                            200.00_f64, -1_f64, 316.00_f64, -1_f64, -1_f64, -1_f64, 36.00_f64,
-                            16.67_f64, -1_f64, 16.00_f64, -1_f64, 12.00_f64, -1_f64, 596.67_f64,
+                            1116.00_f64,
+                            274.00_f64,
+                            1904.00_f64,
+                            174.10_f64,
+                            47.00_f64,
+                            -1_f64,
+                            323.00_f64,
+                            -1_f64,
+                            126.02_f64,
+                            40.00_f64,
+                            175.00_f64,
+                            -1_f64,
+                            527.00_f64,
+                            -1_f64,
+                            -1_f64,
+                            -1_f64,
+                            4706.12_f64,
                         ),
                     },
                 },
-                OutputMainWorkerRow {
-                    first_name: "Ольга".to_string(),
-                    middle_name: "Володимирівна".to_string(),
-                    last_name: "Спірінцева".to_string(),
-                    rank: "Доцент к.т.н.".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
+                MainTableSection {
+                    title: "Всього за викладачами".to_string(),
+                    rows: vec![OutputMainWorkerRow {
+                        first_name: "Геннадій".to_string(),
+                        middle_name: "Володимирович".to_string(),
+                        last_name: "Полухін".to_string(),
+                        rank: "з 07.10.24 Старший викладач".to_string(),
+                        rate_and_hours: OutputMainRateAndHours {
+                            rate: 1.00_f64,
+                            semester_1: OutputHoursRow::new(
+                                28.00_f64, -1_f64, 184.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, 4.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, 216.00_f64,
+                            ),
+                            semester_2: OutputHoursRow::new(
+                                60.00_f64, -1_f64, 184.00_f64, 7.00_f64, 2.00_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, 10.00_f64, -1_f64, 44.00_f64, -1_f64,
+                                -1_f64, -1_f64, 307.00_f64,
+                            ),
+                            year: OutputHoursRow::new(
+                                88.00_f64, -1_f64, 368.00_f64, 7.00_f64, 2.00_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, 14.00_f64, -1_f64, 44.00_f64, -1_f64,
+                                -1_f64, -1_f64, 523.00_f64,
+                            ),
+                        },
+                    }],
+                    total: OutputMainRateAndHours {
                         rate: 1.00_f64,
                         semester_1: OutputHoursRow::new(
-                            82.00_f64, 40.00_f64, 128.00_f64, 21.00_f64, 6.00_f64, -1_f64,
-                            21.00_f64, 6.67_f64, -1_f64, 16.00_f64, -1_f64, -1_f64, -1_f64,
-                            320.67_f64,
-                        ),
-                        semester_2: OutputHoursRow::new(
-                            44.00_f64, -1_f64, 120.00_f64, 13.00_f64, 4.00_f64, -1_f64, 15.00_f64,
-                            10.00_f64, 40.00_f64, 5.00_f64, -1_f64, 24.00_f64, -1_f64, 275.00_f64,
-                        ),
-                        year: OutputHoursRow::new(
-                            126.00_f64, 40.00_f64, 248.00_f64, 34.00_f64, 10.00_f64, -1_f64,
-                            36.00_f64, 16.67_f64, 40.00_f64, 21.00_f64, -1_f64, 24.00_f64, -1_f64,
-                            595.67_f64,
-                        ),
-                    },
-                },
-                OutputMainWorkerRow {
-                    first_name: "Микола".to_string(),
-                    middle_name: "Іванович".to_string(),
-                    last_name: "Твердоступ".to_string(),
-                    rank: "Доцент доцент к.т.н.".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
-                        rate: 0.90_f64,
-                        semester_1: OutputHoursRow::new(
-                            68.00_f64, 80.00_f64, -1_f64, 23.00_f64, 5.00_f64, -1_f64, -1_f64,
-                            3.33_f64, -1_f64, 9.00_f64, -1_f64, 196.00_f64, -1_f64, 384.33_f64,
+                            28.00_f64, -1_f64, 184.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, -1_f64, 4.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            216.00_f64,
                         ),
                         semester_2: OutputHoursRow::new(
-                            52.00_f64, -1_f64, 64.00_f64, 20.00_f64, 5.00_f64, -1_f64, 6.00_f64,
-                            4.00_f64, -1_f64, 4.00_f64, -1_f64, -1_f64, -1_f64, 155.00_f64,
+                            60.00_f64, -1_f64, 184.00_f64, 7.00_f64, 2.00_f64, -1_f64, -1_f64,
+                            -1_f64, -1_f64, -1_f64, 10.00_f64, -1_f64, 44.00_f64, -1_f64, -1_f64,
+                            -1_f64, 307.00_f64,
                         ),
                         year: OutputHoursRow::new(
-                            120.00_f64, 80.00_f64, 64.00_f64, 43.00_f64, 10.00_f64, -1_f64,
-                            6.00_f64, 7.33_f64, -1_f64, 13.00_f64, -1_f64, 196.00_f64, -1_f64,
-                            539.33_f64,
+                            88.00_f64, -1_f64, 368.00_f64, 7.00_f64, 2.00_f64, -1_f64, -1_f64,
+                            -1_f64, -1_f64, -1_f64, 14.00_f64, -1_f64, 44.00_f64, -1_f64, -1_f64,
+                            -1_f64, 523.00_f64,
                         ),
                     },
                 },
-            ],
-            associate_professors_total: OutputMainRateAndHours {
-                rate: 7.90_f64,
-                semester_1: OutputHoursRow::new(
-                    572.00_f64,
-                    200.00_f64,
-                    832.00_f64,
-                    82.10_f64,
-                    21.00_f64,
-                    -1_f64,
-                    156.00_f64,
-                    50.02_f64,
-                    -1_f64,
-                    90.00_f64,
-                    -1_f64,
-                    268.00_f64,
-                    -1_f64,
-                    2271.12_f64,
-                ),
-                semester_2: OutputHoursRow::new(
-                    544.00_f64,
-                    74.00_f64,
-                    1072.00_f64,
-                    92.00_f64,
-                    26.00_f64,
-                    -1_f64,
-                    167.00_f64,
-                    76.00_f64,
-                    40.00_f64,
-                    85.00_f64,
-                    -1_f64,
-                    527.00_f64,
-                    -1_f64,
-                    4706.12_f64,
-                ),
-                year: OutputHoursRow::new(
-                    1116.00_f64,
-                    274.00_f64,
-                    1904.00_f64,
-                    174.10_f64,
-                    47.00_f64,
-                    -1_f64,
-                    323.00_f64,
-                    126.02_f64,
-                    40.00_f64,
-                    175.00_f64,
-                    -1_f64,
-                    527.00_f64,
-                    -1_f64,
-                    4706.12_f64,
-                ),
-            },
-            lecturers: vec![OutputMainWorkerRow {
-                first_name: "Геннадій".to_string(),
-                middle_name: "Володимирович".to_string(),
-                last_name: "Полухін".to_string(),
-                rank: "з 07.10.24 Старший викладач".to_string(),
-                rate_and_hours: OutputMainRateAndHours {
-                    rate: 1.00_f64,
-                    semester_1: OutputHoursRow::new(
-                        28.00_f64, -1_f64, 184.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, 4.00_f64, -1_f64, -1_f64, -1_f64, 216.00_f64,
-                    ),
-                    semester_2: OutputHoursRow::new(
-                        60.00_f64, -1_f64, 184.00_f64, 7.00_f64, 2.00_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, 10.00_f64, -1_f64, 44.00_f64, -1_f64, 307.00_f64,
-                    ),
-                    year: OutputHoursRow::new(
-                        88.00_f64, -1_f64, 368.00_f64, 7.00_f64, 2.00_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, 14.00_f64, -1_f64, 44.00_f64, -1_f64, 523.00_f64,
-                    ),
-                },
-            }],
-            lecturers_total: OutputMainRateAndHours {
-                rate: 1.00_f64,
-                semester_1: OutputHoursRow::new(
-                    28.00_f64, -1_f64, 184.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    4.00_f64, -1_f64, -1_f64, -1_f64, 216.00_f64,
-                ),
-                semester_2: OutputHoursRow::new(
-                    60.00_f64, -1_f64, 184.00_f64, 7.00_f64, 2.00_f64, -1_f64, -1_f64, -1_f64,
-                    -1_f64, 10.00_f64, -1_f64, 44.00_f64, -1_f64, 307.00_f64,
-                ),
-                year: OutputHoursRow::new(
-                    88.00_f64, -1_f64, 368.00_f64, 7.00_f64, 2.00_f64, -1_f64, -1_f64, -1_f64,
-                    -1_f64, 14.00_f64, -1_f64, 44.00_f64, -1_f64, 523.00_f64,
-                ),
-            },
-            assistants: vec![
-                OutputMainWorkerRow {
-                    first_name: "Станіслав".to_string(),
-                    middle_name: "Васильович".to_string(),
-                    last_name: "Мазурик".to_string(),
-                    rank: "Асистент".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
-                        rate: 0.90_f64,
+                MainTableSection {
+                    title: "Всього за асистентами".to_string(),
+                    rows: vec![
+                        OutputMainWorkerRow {
+                            first_name: "Станіслав".to_string(),
+                            middle_name: "Васильович".to_string(),
+                            last_name: "Мазурик".to_string(),
+                            rank: "Асистент".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 0.90_f64,
+                                semester_1: OutputHoursRow::new(
+                                    -1_f64, -1_f64, 344.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, -1_f64, 17.00_f64, -1_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, 361.00_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    -1_f64, -1_f64, 116.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, 40.00_f64, 4.00_f64, -1_f64, 16.00_f64, -1_f64,
+                                    -1_f64, -1_f64, 176.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    -1_f64, -1_f64, 460.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, 40.00_f64, 21.00_f64, -1_f64, 16.00_f64,
+                                    -1_f64, -1_f64, -1_f64, 537.00_f64,
+                                ),
+                            },
+                        },
+                        OutputMainWorkerRow {
+                            first_name: "Михайло".to_string(),
+                            middle_name: "Олександрович".to_string(),
+                            last_name: "Литвинов".to_string(),
+                            rank: "Асистент з 30.09".to_string(),
+                            rate_and_hours: OutputMainRateAndHours {
+                                rate: 0.60_f64,
+                                semester_1: OutputHoursRow::new(
+                                    -1_f64, -1_f64, 94.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 48.00_f64, -1_f64,
+                                    -1_f64, -1_f64, 142.00_f64,
+                                ),
+                                semester_2: OutputHoursRow::new(
+                                    -1_f64, 48.00_f64, 108.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, 18.00_f64, 4.00_f64, -1_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, 178.00_f64,
+                                ),
+                                year: OutputHoursRow::new(
+                                    -1_f64, 48.00_f64, 202.00_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                    -1_f64, -1_f64, 18.00_f64, 4.00_f64, -1_f64, 48.00_f64, -1_f64,
+                                    -1_f64, -1_f64, 857.00_f64,
+                                ),
+                            },
+                        },
+                    ],
+                    total: OutputMainRateAndHours {
+                        rate: 1.50_f64,
                         semester_1: OutputHoursRow::new(
-                            -1_f64, -1_f64, 344.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                            -1_f64, 17.00_f64, -1_f64, -1_f64, -1_f64, 361.00_f64,
+                            -1_f64, -1_f64, 438.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, -1_f64, 17.00_f64, -1_f64, 48.00_f64, -1_f64, -1_f64, -1_f64,
+                            503.00_f64,
                         ),
                         semester_2: OutputHoursRow::new(
-                            -1_f64, -1_f64, 116.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                            40.00_f64, 4.00_f64, -1_f64, 16.00_f64, -1_f64, 176.00_f64,
+                            -1_f64, 48.00_f64, 224.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, 58.00_f64, 8.00_f64, -1_f64, 16.00_f64, -1_f64, -1_f64, -1_f64,
+                            354.00_f64,
                         ),
                         year: OutputHoursRow::new(
-                            -1_f64, -1_f64, 460.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                            40.00_f64, 21.00_f64, -1_f64, 16.00_f64, -1_f64, 537.00_f64,
+                            -1_f64, 48.00_f64, 224.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, 58.00_f64, 25.00_f64, -1_f64, 64.00_f64, -1_f64, -1_f64,
+                            -1_f64, 857.00_f64,
                         ),
                     },
                 },
-                OutputMainWorkerRow {
-                    first_name: "Михайло".to_string(),
-                    middle_name: "Олександрович".to_string(),
-                    last_name: "Литвинов".to_string(),
-                    rank: "Асистент з 30.09".to_string(),
-                    rate_and_hours: OutputMainRateAndHours {
-                        rate: 0.60_f64,
+                MainTableSection {
+                    title: "Всього за сумісниками".to_string(),
+                    rows: vec![OutputMainWorkerRow {
+                        first_name: "Станіслав".to_string(),
+                        middle_name: "Васильович".to_string(),
+                        last_name: "Мазурик".to_string(),
+                        rank: "Асистент з 07.10.24".to_string(),
+                        rate_and_hours: OutputMainRateAndHours {
+                            rate: 0.10_f64,
+                            semester_1: OutputHoursRow::new(
+                                -1_f64, -1_f64, 12.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                12.00_f64,
+                            ),
+                            semester_2: OutputHoursRow::new(
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, 40.00_f64, -1_f64, -1_f64, -1_f64,
+                                40.00_f64,
+                            ),
+                            year: OutputHoursRow::new(
+                                -1_f64, -1_f64, 12.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, 40.00_f64, -1_f64, -1_f64, -1_f64,
+                                52.00_f64,
+                            ),
+                        },
+                    }],
+                    total: OutputMainRateAndHours {
+                        rate: 0.10_f64,
                         semester_1: OutputHoursRow::new(
-                            -1_f64, -1_f64, 94.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                            -1_f64, -1_f64, -1_f64, 48.00_f64, -1_f64, 142.00_f64,
+                            -1_f64, -1_f64, 12.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            12.00_f64,
                         ),
                         semester_2: OutputHoursRow::new(
-                            -1_f64, 48.00_f64, 108.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                            18.00_f64, 4.00_f64, -1_f64, -1_f64, -1_f64, 178.00_f64,
+                            -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, -1_f64, -1_f64, 40.00_f64, -1_f64, -1_f64, -1_f64, 40.00_f64,
                         ),
                         year: OutputHoursRow::new(
-                            -1_f64, 48.00_f64, 202.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                            18.00_f64, 4.00_f64, -1_f64, 48.00_f64, -1_f64, 857.00_f64,
+                            -1_f64, -1_f64, 12.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                            -1_f64, -1_f64, -1_f64, -1_f64, 40.00_f64, -1_f64, -1_f64, -1_f64,
+                            52.00_f64,
                         ),
                     },
                 },
             ],
-            assistants_total: OutputMainRateAndHours {
-                rate: 1.50_f64,
-                semester_1: OutputHoursRow::new(
-                    -1_f64, -1_f64, 438.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    17.00_f64, -1_f64, 48.00_f64, -1_f64, 503.00_f64,
-                ),
-                semester_2: OutputHoursRow::new(
-                    -1_f64, 48.00_f64, 224.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    58.00_f64, 8.00_f64, -1_f64, 16.00_f64, -1_f64, 354.00_f64,
-                ),
-                year: OutputHoursRow::new(
-                    -1_f64, 48.00_f64, 224.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    58.00_f64, 25.00_f64, -1_f64, 64.00_f64, -1_f64, 857.00_f64,
-                ),
-            },
-            part_timers: vec![OutputMainWorkerRow {
-                first_name: "Станіслав".to_string(),
-                middle_name: "Васильович".to_string(),
-                last_name: "Мазурик".to_string(),
-                rank: "Асистент з 07.10.24".to_string(),
-                rate_and_hours: OutputMainRateAndHours {
-                    rate: 0.10_f64,
-                    semester_1: OutputHoursRow::new(
-                        -1_f64, -1_f64, 12.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, -1_f64, -1_f64, -1_f64, 12.00_f64,
-                    ),
-                    semester_2: OutputHoursRow::new(
-                        -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, -1_f64, 40.00_f64, -1_f64, 40.00_f64,
-                    ),
-                    year: OutputHoursRow::new(
-                        -1_f64, -1_f64, 12.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, -1_f64, 40.00_f64, -1_f64, 52.00_f64,
-                    ),
-                },
-            }],
-            part_timers_total: OutputMainRateAndHours {
-                rate: 0.10_f64,
-                semester_1: OutputHoursRow::new(
-                    -1_f64, -1_f64, 12.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    -1_f64, -1_f64, -1_f64, -1_f64, 12.00_f64,
-                ),
-                semester_2: OutputHoursRow::new(
-                    -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    -1_f64, 40.00_f64, -1_f64, 40.00_f64,
-                ),
-                year: OutputHoursRow::new(
-                    -1_f64, -1_f64, 12.00_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    -1_f64, -1_f64, 40.00_f64, -1_f64, 52.00_f64,
-                ),
-            },
             total: OutputMainRateAndHours {
                 rate: 15.75_f64,
                 semester_1: OutputHoursRow::new(
@@ -1458,12 +1449,15 @@ pub fn get_test_output_data() -> ExportedData {
                     42.00_f64,
                     -1_f64,
                     216.00_f64,
+                    -1_f64,
                     70.03_f64,
                     -1_f64,
                     146.00_f64,
                     -1_f64,
                     644.00_f64,
                     -1_f64,
+                    -1_f64,
+                    -1_f64,
                     4345.03_f64,
                 ),
                 semester_2: OutputHoursRow::new(
@@ -1474,12 +1468,15 @@ pub fn get_test_output_data() -> ExportedData {
                     43.00_f64,
                     -1_f64,
                     239.00_f64,
+                    -1_f64,
                     106.00_f64,
                     128.00_f64,
                     147.00_f64,
                     -1_f64,
                     543.00_f64,
                     -1_f64,
+                    -1_f64,
+                    -1_f64,
                     4554.00_f64,
                 ),
                 year: OutputHoursRow::new(
@@ -1490,12 +1487,15 @@ pub fn get_test_output_data() -> ExportedData {
                     85.00_f64,
                     -1_f64,
                     455.00_f64,
+                    -1_f64,
                     176.03_f64,
                     128.00_f64,
                     293.00_f64,
                     -1_f64,
                     1187.00_f64,
                     -1_f64,
+                    -1_f64,
+                    -1_f64,
                     8899.03_f64,
                 ),
             },
@@ -1519,9 +1519,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-23у-1".to_string(),
                             course: "2".to_string(),
                             students_count: 21,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 32_f64, -1_f64, 32_f64, 5_f64, 2_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, 3_f64, -1_f64, -1_f64, -1_f64, 74_f64,
+                                -1_f64, -1_f64, 3_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                74_f64,
                             ),
                             merge_lectures_with_next: true,
                         },
@@ -1532,9 +1534,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-21-1,2".to_string(),
                             course: "4".to_string(),
                             students_count: 32,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, 32_f64, 8_f64, 2_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, 4_f64, -1_f64, -1_f64, -1_f64, 46_f64,
+                                -1_f64, -1_f64, 4_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                46_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1545,9 +1549,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-23у-1".to_string(),
                             course: "2".to_string(),
                             students_count: 21,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, 40_f64, -1_f64, 40_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, 40_f64, -1_f64, -1_f64, -1_f64,
+                                40_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1558,9 +1564,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-21-1,2".to_string(),
                             course: "4".to_string(),
                             students_count: 32,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, 128_f64, -1_f64, 128_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, 128_f64, -1_f64, -1_f64, -1_f64,
+                                128_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1571,9 +1579,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "".to_string(),
                             course: "2м".to_string(),
                             students_count: 2,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1584,9 +1594,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-24м-1".to_string(),
                             course: "1м".to_string(),
                             students_count: 31,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 24_f64, -1_f64, 32_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, 4_f64, -1_f64, -1_f64, -1_f64, 60_f64,
+                                -1_f64, -1_f64, 4_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                60_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1597,9 +1609,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-23м-1".to_string(),
                             course: "2м".to_string(),
                             students_count: 18,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 9_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 9_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                9_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1610,9 +1624,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-23м-1".to_string(),
                             course: "2м".to_string(),
                             students_count: 2,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 21_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 21_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                21_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1623,9 +1639,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-23м-1".to_string(),
                             course: "2м".to_string(),
                             students_count: 2,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 6.7_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 6.7_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                6.7_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                6.7_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1636,25 +1654,37 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-23-1,2".to_string(),
                             course: "2".to_string(),
                             students_count: 2,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, 8_f64, -1_f64, 8_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, 8_f64, -1_f64, -1_f64, -1_f64,
+                                8_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
                     ],
                     semester_1_rate: 1.0_f64,
-                    semester_1_total_day: OutputHoursRow::new(
-                        56_f64, -1_f64, 96_f64, 13_f64, 4_f64, -1_f64, 30_f64, 6.7_f64, -1_f64,
-                        11_f64, -1_f64, 176_f64, -1_f64, 392.7_f64,
-                    ),
-                    semester_1_total_evening: OutputHoursRow::new(
-                        -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    ),
+                    semester_1_form_totals: vec![
+                        LearningFormTotal {
+                            learning_form: "денна".to_string(),
+                            hours: OutputHoursRow::new(
+                                56_f64, -1_f64, 96_f64, 13_f64, 4_f64, -1_f64, 30_f64, -1_f64,
+                                6.7_f64, -1_f64, 11_f64, -1_f64, 176_f64, -1_f64, -1_f64, -1_f64,
+                                392.7_f64,
+                            ),
+                        },
+                        LearningFormTotal {
+                            learning_form: "вечірня".to_string(),
+                            hours: OutputHoursRow::new(
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64,
+                            ),
+                        },
+                    ],
                     semester_1_total: OutputHoursRow::new(
-                        56_f64, -1_f64, 96_f64, 13_f64, 4_f64, -1_f64, 30_f64, 6.7_f64, -1_f64,
-                        11_f64, -1_f64, 176_f64, -1_f64, 392.7_f64,
+                        56_f64, -1_f64, 96_f64, 13_f64, 4_f64, -1_f64, 30_f64, -1_f64, 6.7_f64,
+                        -1_f64, 11_f64, -1_f64, 176_f64, -1_f64, -1_f64, -1_f64, 392.7_f64,
                     ),
                     semester_2: vec![
                         OutputPersonalSemesterRow {
@@ -1664,9 +1694,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-21, КІ-23у".to_string(),
                             course: "4, 2".to_string(),
                             students_count: 6,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 18_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 18_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                18_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1677,9 +1709,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-21, КІ-23у".to_string(),
                             course: "4, 2".to_string(),
                             students_count: 6,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 12_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 12_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                12_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                12_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1690,9 +1724,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КС-21".to_string(),
                             course: "4".to_string(),
                             students_count: 3,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1703,9 +1739,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "ВД".to_string(),
                             course: "1".to_string(),
                             students_count: 24,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 36_f64, -1_f64, 36_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, 3_f64, -1_f64, -1_f64, -1_f64, 75_f64,
+                                -1_f64, -1_f64, 3_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                75_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1716,9 +1754,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-24-1".to_string(),
                             course: "1".to_string(),
                             students_count: 10,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, 30_f64, -1_f64, 30_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, 30_f64, -1_f64, -1_f64, -1_f64,
+                                30_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1729,9 +1769,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-21, КІ-23у".to_string(),
                             course: "".to_string(),
                             students_count: 0,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 27_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 27_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                27_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1742,9 +1784,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-23у-1".to_string(),
                             course: "2".to_string(),
                             students_count: 21,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 24_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 24_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                24_f64,
                             ),
                             merge_lectures_with_next: true,
                         },
@@ -1755,29 +1799,41 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-21-1,2".to_string(),
                             course: "4".to_string(),
                             students_count: 32,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
                     ],
                     semester_2_rate: 1.0_f64,
-                    semester_2_total_day: OutputHoursRow::new(
-                        60_f64, -1_f64, 36_f64, -1_f64, -1_f64, -1_f64, 45_f64, 12_f64, -1_f64,
-                        3_f64, -1_f64, 30_f64, -1_f64, 186_f64,
-                    ),
-                    semester_2_total_evening: OutputHoursRow::new(
-                        -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    ),
+                    semester_2_form_totals: vec![
+                        LearningFormTotal {
+                            learning_form: "денна".to_string(),
+                            hours: OutputHoursRow::new(
+                                60_f64, -1_f64, 36_f64, -1_f64, -1_f64, -1_f64, 45_f64, -1_f64,
+                                12_f64, -1_f64, 3_f64, -1_f64, 30_f64, -1_f64, -1_f64, -1_f64,
+                                186_f64,
+                            ),
+                        },
+                        LearningFormTotal {
+                            learning_form: "вечірня".to_string(),
+                            hours: OutputHoursRow::new(
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64,
+                            ),
+                        },
+                    ],
                     semester_2_total: OutputHoursRow::new(
-                        60_f64, -1_f64, 36_f64, -1_f64, -1_f64, -1_f64, 45_f64, 12_f64, -1_f64,
-                        3_f64, -1_f64, 30_f64, -1_f64, 186_f64,
+                        60_f64, -1_f64, 36_f64, -1_f64, -1_f64, -1_f64, 45_f64, -1_f64, 12_f64,
+                        -1_f64, 3_f64, -1_f64, 30_f64, -1_f64, -1_f64, -1_f64, 186_f64,
                     ),
                     year_total: OutputHoursRow::new(
-                        116_f64, -1_f64, 132_f64, 13_f64, 4_f64, -1_f64, 75_f64, 18.7_f64, -1_f64,
-                        14_f64, -1_f64, 206_f64, -1_f64, 578.7_f64,
+                        116_f64, -1_f64, 132_f64, 13_f64, 4_f64, -1_f64, 75_f64, -1_f64, 18.7_f64,
+                        -1_f64, 14_f64, -1_f64, 206_f64, -1_f64, -1_f64, -1_f64, 578.7_f64,
                     ),
                 },
                 OutputPersonalTable {
@@ -1796,9 +1852,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КС-22".to_string(),
                             course: "3".to_string(),
                             students_count: 53,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 16_f64, -1_f64, -1_f64, 13_f64, 2_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, 4_f64, -1_f64, -1_f64, -1_f64, 35_f64,
+                                -1_f64, -1_f64, 4_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                35_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1810,9 +1868,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "PhD".to_string(),
                             course: "2А".to_string(),
                             students_count: 4,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 30_f64, -1_f64, 16_f64, 1_f64, 1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, 1_f64, -1_f64, -1_f64, -1_f64, 49_f64,
+                                -1_f64, -1_f64, 1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                49_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1823,25 +1883,37 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-23у-1".to_string(),
                             course: "2".to_string(),
                             students_count: 21,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, -1_f64, -1_f64, 44_f64, -1_f64, 44_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, 44_f64, -1_f64, -1_f64, -1_f64,
+                                44_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
                     ],
                     semester_1_rate: 0.5_f64,
-                    semester_1_total_day: OutputHoursRow::new(
-                        46_f64, -1_f64, 16_f64, 14_f64, 3_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        5_f64, -1_f64, 44_f64, -1_f64, 128_f64,
-                    ),
-                    semester_1_total_evening: OutputHoursRow::new(
-                        -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    ),
+                    semester_1_form_totals: vec![
+                        LearningFormTotal {
+                            learning_form: "денна".to_string(),
+                            hours: OutputHoursRow::new(
+                                46_f64, -1_f64, 16_f64, 14_f64, 3_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, 5_f64, -1_f64, 44_f64, -1_f64, -1_f64, -1_f64,
+                                128_f64,
+                            ),
+                        },
+                        LearningFormTotal {
+                            learning_form: "вечірня".to_string(),
+                            hours: OutputHoursRow::new(
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64,
+                            ),
+                        },
+                    ],
                     semester_1_total: OutputHoursRow::new(
                         46_f64, -1_f64, 16_f64, 14_f64, 3_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        5_f64, -1_f64, 44_f64, -1_f64, 128_f64,
+                        -1_f64, 5_f64, -1_f64, 44_f64, -1_f64, -1_f64, -1_f64, 128_f64,
                     ),
                     semester_2: vec![
                         OutputPersonalSemesterRow {
@@ -1851,9 +1923,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-23у-1".to_string(),
                             course: "2".to_string(),
                             students_count: 21,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, 16_f64, 5_f64, 2_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, 3_f64, -1_f64, -1_f64, -1_f64, 26_f64,
+                                -1_f64, -1_f64, 3_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                26_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1864,9 +1938,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-21-1,2".to_string(),
                             course: "4".to_string(),
                             students_count: 32,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, 16_f64, 8_f64, 2_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, 3_f64, -1_f64, -1_f64, -1_f64, 30_f64,
+                                -1_f64, -1_f64, 3_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                30_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1877,9 +1953,11 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "КІ-24м-1".to_string(),
                             course: "1м".to_string(),
                             students_count: 31,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 14_f64, -1_f64, 32_f64, 7_f64, 2_f64, -1_f64, -1_f64, -1_f64,
-                                -1_f64, 4_f64, -1_f64, -1_f64, -1_f64, 59_f64,
+                                -1_f64, -1_f64, 4_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                59_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
@@ -1890,29 +1968,41 @@ pub fn get_test_output_data() -> ExportedData {
                             group: "PhD".to_string(),
                             course: "2А".to_string(),
                             students_count: 5,
+                            individual_work_types: String::new(),
                             hours: OutputHoursRow::new(
                                 -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                                30_f64, -1_f64, -1_f64, -1_f64, -1_f64, 30_f64,
+                                -1_f64, 30_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                30_f64,
                             ),
                             merge_lectures_with_next: false,
                         },
                     ],
                     semester_2_rate: 0.5_f64,
-                    semester_2_total_day: OutputHoursRow::new(
-                        14_f64, -1_f64, 64_f64, 20_f64, 6_f64, -1_f64, -1_f64, -1_f64, 30_f64,
-                        11_f64, -1_f64, -1_f64, -1_f64, 145_f64,
-                    ),
-                    semester_2_total_evening: OutputHoursRow::new(
-                        -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                        -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
-                    ),
+                    semester_2_form_totals: vec![
+                        LearningFormTotal {
+                            learning_form: "денна".to_string(),
+                            hours: OutputHoursRow::new(
+                                14_f64, -1_f64, 64_f64, 20_f64, 6_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, 30_f64, 11_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                145_f64,
+                            ),
+                        },
+                        LearningFormTotal {
+                            learning_form: "вечірня".to_string(),
+                            hours: OutputHoursRow::new(
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                                -1_f64,
+                            ),
+                        },
+                    ],
                     semester_2_total: OutputHoursRow::new(
-                        14_f64, -1_f64, 64_f64, 20_f64, 6_f64, -1_f64, -1_f64, -1_f64, 30_f64,
-                        11_f64, -1_f64, -1_f64, -1_f64, 145_f64,
+                        14_f64, -1_f64, 64_f64, 20_f64, 6_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                        30_f64, 11_f64, -1_f64, -1_f64, -1_f64, -1_f64, -1_f64, 145_f64,
                     ),
                     year_total: OutputHoursRow::new(
-                        60_f64, -1_f64, 80_f64, 34_f64, 9_f64, -1_f64, -1_f64, -1_f64, 30_f64,
-                        16_f64, -1_f64, 44_f64, -1_f64, 273_f64,
+                        60_f64, -1_f64, 80_f64, 34_f64, 9_f64, -1_f64, -1_f64, -1_f64, -1_f64,
+                        30_f64, 16_f64, -1_f64, 44_f64, -1_f64, -1_f64, -1_f64, 273_f64,
                     ),
                 },
             ],
@@ -1,5 +1,5 @@
 use umya_spreadsheet::{
-    Border, Cell, Color, Coordinate, Pane, PaneStateValues, Worksheet,
+    Border, Cell, Color, Comment, Coordinate, Pane, PaneStateValues, Worksheet,
     helper::coordinate::CellCoordinates,
 };
 
@@ -55,31 +55,16 @@ where
     let mut cell = Cell::default();
     cell.set_value(val);
     cell.set_coordinate(coordinate);
-    cell.get_style_mut().get_alignment_mut().set_wrap_text(true);
-    cell.get_style_mut()
-        .get_font_mut()
-        .set_name("Times New Roman")
-        .set_size(10.0);
     cell
 }
 
-pub fn create_cell_number<T>(val: f64, coordinate: T, trailing_zeros: bool) -> Cell
+pub fn create_cell_number<T>(val: f64, coordinate: T) -> Cell
 where
     T: Into<CellCoordinates>,
 {
     let mut cell = Cell::default();
     cell.set_value(format!("{val}"));
-    if trailing_zeros {
-        cell.get_style_mut()
-            .get_number_format_mut()
-            .set_format_code("0.00");
-    }
     cell.set_coordinate(coordinate);
-    cell.get_style_mut().get_alignment_mut().set_wrap_text(true);
-    cell.get_style_mut()
-        .get_font_mut()
-        .set_name("Times New Roman")
-        .set_size(10.0);
     cell
 }
 
@@ -181,6 +166,49 @@ pub fn cell_set_color(cell: &mut Cell, argb_color: &str) {
     font.get_color_mut().set_argb(argb_color);
 }
 
+pub fn cell_set_fill(cell: &mut Cell, argb_color: &str) {
+    cell.get_style_mut().set_background_color(argb_color);
+}
+
+pub fn cell_set_number_format(cell: &mut Cell, format_code: &str) {
+    cell.get_style_mut()
+        .get_number_format_mut()
+        .set_format_code(format_code);
+}
+
+pub fn add_comment<T>(worksheet: &mut Worksheet, coordinate: T, text: &str)
+where
+    T: Into<CellCoordinates>,
+{
+    let mut comment = Comment::default();
+    comment.new_comment(coordinate).set_text_string(text);
+    worksheet.add_comments(comment);
+}
+
+/// Letters of a 1-based column index: 1 is "A", 26 is "Z", 27 is "AA".
+pub fn column_letter(x: u32) -> String {
+    let mut letters = Vec::new();
+    let mut x = x;
+    while x > 0 {
+        letters.push(b'A' + ((x - 1) % 26) as u8);
+        x = (x - 1) / 26;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap()
+}
+
+/// Merges the cells from `from` to `to` inclusive, both given as 1-based
+/// `(x, y)` like the cell coordinates.
+pub fn merge_cells(worksheet: &mut Worksheet, from: (u32, u32), to: (u32, u32)) {
+    worksheet.add_merge_cells(format!(
+        "{}{}:{}{}",
+        column_letter(from.0),
+        from.1,
+        column_letter(to.0),
+        to.1
+    ));
+}
+
 pub fn column_toggle_auto_width(worksheet: &mut Worksheet, x: u32, enable: bool) {
     worksheet
         .get_column_dimension_by_number_mut(&x)
@@ -0,0 +1,246 @@
+use std::{collections::HashMap, fmt::Write as _};
+
+use quick_xml::escape::escape;
+
+use crate::excel::{
+    data::{ExportOptions, ExportedData},
+    document_generator::build_workbook,
+    sheet_layout::{CellBorders, HorizontalAlign, LayoutCell, SheetLayout, VerticalAlign},
+    sheet_names::{ANALYTICS_SHEET_NAME, MAIN_SHEET_NAME},
+    styles::default_theme,
+    teacher_names::full_teacher_name,
+};
+
+const STYLESHEET: &str = r#"
+body { margin: 16px; }
+nav ul { columns: 3; list-style: none; padding: 0; }
+section { margin-bottom: 32px; }
+table { border-collapse: collapse; table-layout: fixed; }
+td { padding: 1px 3px; overflow: hidden; }
+.vertical { writing-mode: vertical-rl; transform: rotate(180deg); }
+@page { size: A4 landscape; margin: 10mm; }
+@media print {
+  body { margin: 0; }
+  nav { display: none; }
+  section { break-after: page; margin: 0; }
+  section:last-of-type { break-after: auto; }
+  tr { break-inside: avoid; }
+}
+"#;
+
+/// Renders the same sheets as `generate_output_file` to a standalone HTML
+/// page: the `#main` table, a `#teacher-<n>` section per teacher and the
+/// optional `#analytics` one, a list of links to them on top and print styles
+/// for A4 landscape.
+pub fn generate_html(data: ExportedData, options: ExportOptions) -> String {
+    let book = build_workbook(&data, &options);
+    let layouts: Vec<SheetLayout> = book
+        .get_sheet_collection()
+        .iter()
+        .map(SheetLayout::from_worksheet)
+        .collect();
+
+    let mut classes = CellClasses::default();
+    let mut body = String::new();
+    let _ = write!(
+        body,
+        r##"<nav><ul><li><a href="#main">{MAIN_SHEET_NAME}</a></li>"##
+    );
+    for (i, tables) in data.personal_tables.iter().enumerate() {
+        let _ = write!(
+            body,
+            r##"<li><a href="#teacher-{}">{}</a></li>"##,
+            i + 1,
+            escape(full_teacher_name(tables))
+        );
+    }
+    if options.include_analytics {
+        let _ = write!(
+            body,
+            r##"<li><a href="#analytics">{ANALYTICS_SHEET_NAME}</a></li>"##
+        );
+    }
+    body.push_str("</ul></nav>");
+    for (i, layout) in layouts.iter().enumerate() {
+        let id = if i == 0 {
+            "main".to_string()
+        } else if i <= data.personal_tables.len() {
+            format!("teacher-{i}")
+        } else {
+            "analytics".to_string()
+        };
+        let _ = write!(body, r#"<section id="{id}">"#);
+        write_table(&mut body, &mut classes, layout);
+        body.push_str("</section>");
+    }
+
+    let title = format!(
+        "Розподіл навчального навантаження {} на {}-{} навчальний рік",
+        data.type_name,
+        data.year,
+        data.year + 1
+    );
+    let theme = options.theme.unwrap_or_else(default_theme);
+    format!(
+        concat!(
+            r#"<!DOCTYPE html><html lang="uk"><head><meta charset="utf-8">"#,
+            "<title>{}</title><style>body {{ font-family: {}, serif; }}{}{}</style>",
+            "</head><body>{}</body></html>",
+        ),
+        escape(title.as_str()),
+        css_string(&theme.font_name),
+        STYLESHEET,
+        classes.css,
+        body
+    )
+}
+
+/// `value` as a quoted CSS string that cannot close the `<style>` element.
+fn css_string(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for char in value.chars() {
+        match char {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(char);
+            }
+            '<' => quoted.push_str("\\3c "),
+            _ => quoted.push(char),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+pub fn generate_html_file(
+    file_path: &str,
+    data: ExportedData,
+    options: ExportOptions,
+) -> Result<(), String> {
+    std::fs::write(file_path, generate_html(data, options)).map_err(|e| e.to_string())
+}
+
+/// CSS classes of the cells, one per distinct set of properties.
+#[derive(Default)]
+struct CellClasses {
+    names: HashMap<String, String>,
+    css: String,
+}
+
+impl CellClasses {
+    fn of(&mut self, cell: &LayoutCell, borders: &CellBorders) -> String {
+        let [r, g, b] = cell.color;
+        let mut rules = format!(
+            "font-size:{}pt;font-weight:{};font-style:{};color:#{r:02x}{g:02x}{b:02x};text-align:{};vertical-align:{};white-space:{};border-top:{};border-right:{};border-bottom:{};border-left:{};",
+            cell.font_size,
+            if cell.bold { "bold" } else { "normal" },
+            if cell.italic { "italic" } else { "normal" },
+            match cell.horizontal {
+                HorizontalAlign::Left => "left",
+                HorizontalAlign::Center => "center",
+                HorizontalAlign::Right => "right",
+            },
+            match cell.vertical {
+                VerticalAlign::Top => "top",
+                VerticalAlign::Center => "middle",
+                VerticalAlign::Bottom => "bottom",
+            },
+            if cell.wrap { "normal" } else { "nowrap" },
+            border(borders.top),
+            border(borders.right),
+            border(borders.bottom),
+            border(borders.left),
+        );
+        if let Some([r, g, b]) = cell.fill {
+            let _ = write!(rules, "background-color:#{r:02x}{g:02x}{b:02x};");
+        }
+        if let Some(name) = self.names.get(&rules) {
+            return name.clone();
+        }
+        let name = format!("c{}", self.names.len() + 1);
+        let _ = writeln!(self.css, ".{name} {{ {rules} }}");
+        self.names.insert(rules, name.clone());
+        name
+    }
+}
+
+fn border(width: f64) -> String {
+    if width > 0_f64 {
+        format!("{width}pt solid #000")
+    } else {
+        "none".to_string()
+    }
+}
+
+fn write_table(html: &mut String, classes: &mut CellClasses, layout: &SheetLayout) {
+    let width: f64 = layout.column_widths.iter().sum();
+    let _ = write!(
+        html,
+        r#"<table style="width:{width:.2}pt" aria-label="{}"><colgroup>"#,
+        escape(&layout.name)
+    );
+    for width in &layout.column_widths {
+        let _ = write!(html, r#"<col style="width:{width:.2}pt">"#);
+    }
+    html.push_str("</colgroup>");
+    for row in 1..=layout.row_count() {
+        match layout.row_heights[row as usize - 1] {
+            Some(height) => {
+                let _ = write!(html, r#"<tr style="height:{height:.2}pt">"#);
+            }
+            None => html.push_str("<tr>"),
+        }
+        for column in 1..=layout.column_count() {
+            write_cell(html, classes, layout, row, column);
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</table>");
+}
+
+fn write_cell(
+    html: &mut String,
+    classes: &mut CellClasses,
+    layout: &SheetLayout,
+    row: u32,
+    column: u32,
+) {
+    let area = layout.merged_area_at(row, column);
+    if area.is_some_and(|area| area.first_row != row || area.first_column != column) {
+        return;
+    }
+    let mut attributes = String::new();
+    if let Some(area) = area {
+        let columns = area.last_column - area.first_column + 1;
+        let rows = area.last_row - area.first_row + 1;
+        if columns > 1 {
+            let _ = write!(attributes, r#" colspan="{columns}""#);
+        }
+        if rows > 1 {
+            let _ = write!(attributes, r#" rowspan="{rows}""#);
+        }
+    }
+    let Some(cell) = layout.cell(row, column) else {
+        let _ = write!(html, "<td{attributes}></td>");
+        return;
+    };
+
+    let borders = area.map_or(cell.borders, |area| layout.merged_borders(area));
+    let class = classes.of(cell, &borders);
+    if let Some(comment) = &cell.comment {
+        let _ = write!(attributes, r#" title="{}""#, escape(comment.as_str()));
+    }
+    let _ = write!(html, r#"<td class="{class}"{attributes}>"#);
+    let text = cell
+        .text
+        .split('\n')
+        .map(|line| escape(line).into_owned())
+        .collect::<Vec<_>>()
+        .join("<br>");
+    if cell.vertical_text {
+        let _ = write!(html, r#"<div class="vertical">{text}</div>"#);
+    } else {
+        html.push_str(&text);
+    }
+    html.push_str("</td>");
+}
@@ -1,4 +1,18 @@
+pub mod analytics;
+pub mod columns;
 pub mod data;
+pub mod data_export;
 pub mod document_generator;
-mod excel_functions;
+pub mod excel_functions;
+pub mod html_generator;
+pub mod ods_generator;
 pub mod parser;
+pub mod pdf_generator;
+pub mod personal_export;
+pub mod sheet_layout;
+pub mod sheet_names;
+pub mod styles;
+pub mod teacher_names;
+pub mod totals;
+pub mod transliteration;
+pub mod workload_norms;
@@ -0,0 +1,282 @@
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    fs::File,
+    io::{self, Write},
+};
+
+use quick_xml::escape::escape;
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+use crate::excel::{
+    data::{ExportOptions, ExportedData, PersonalSheetName},
+    document_generator::build_workbook,
+    sheet_layout::{CellBorders, HorizontalAlign, LayoutCell, SheetLayout, VerticalAlign},
+    sheet_names::personal_sheet_names,
+};
+
+const MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.3">
+ <manifest:file-entry manifest:full-path="/" manifest:media-type="application/vnd.oasis.opendocument.spreadsheet"/>
+ <manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>
+"#;
+
+const CONTENT_NAMESPACES: &str = concat!(
+    r#"xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" "#,
+    r#"xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" "#,
+    r#"xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" "#,
+    r#"xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" "#,
+    r#"xmlns:number="urn:oasis:names:tc:opendocument:xmlns:datastyle:1.0" "#,
+    r#"xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0" "#,
+    r#"office:version="1.3""#,
+);
+
+/// Writes the same sheets as `generate_output_file` as an OpenDocument
+/// spreadsheet, with merges, rotated headers and borders expressed in ODF
+/// terms instead of going through an XLSX import.
+pub fn generate_ods_file(
+    file_path: &str,
+    data: ExportedData,
+    options: ExportOptions,
+) -> Result<Vec<PersonalSheetName>, String> {
+    let book = build_workbook(&data, &options);
+    let layouts: Vec<SheetLayout> = book
+        .get_sheet_collection()
+        .iter()
+        .map(SheetLayout::from_worksheet)
+        .collect();
+    write_ods_file(file_path, &layouts).map_err(|e| format!("{file_path}: {e}"))?;
+    Ok(personal_sheet_names(&data.personal_tables))
+}
+
+pub(crate) fn write_ods_file(file_path: &str, layouts: &[SheetLayout]) -> io::Result<()> {
+    let mut zip = ZipWriter::new(File::create(file_path)?);
+    // The mimetype has to be the first entry and stored uncompressed.
+    zip.start_file(
+        "mimetype",
+        SimpleFileOptions::default().compression_method(CompressionMethod::Stored),
+    )?;
+    zip.write_all(MIMETYPE.as_bytes())?;
+    zip.start_file("META-INF/manifest.xml", SimpleFileOptions::default())?;
+    zip.write_all(MANIFEST.as_bytes())?;
+    zip.start_file("content.xml", SimpleFileOptions::default())?;
+    zip.write_all(content_xml(layouts).as_bytes())?;
+    zip.finish()?;
+    Ok(())
+}
+
+/// Automatic styles of `content.xml`, one per distinct set of properties.
+#[derive(Default)]
+struct Styles {
+    names: HashMap<String, String>,
+    xml: String,
+}
+
+impl Styles {
+    /// `style` is the XML of the style with `{name}` in place of its name.
+    fn add(&mut self, prefix: &str, style: String) -> String {
+        if let Some(name) = self.names.get(&style) {
+            return name.clone();
+        }
+        let name = format!("{prefix}{}", self.names.len() + 1);
+        self.xml.push_str(&style.replace("{name}", &name));
+        self.names.insert(style, name.clone());
+        name
+    }
+
+    fn column(&mut self, width: f64) -> String {
+        self.add(
+            "co",
+            format!(
+                r#"<style:style style:name="{{name}}" style:family="table-column"><style:table-column-properties style:column-width="{width:.2}pt"/></style:style>"#
+            ),
+        )
+    }
+
+    fn row(&mut self, height: Option<f64>) -> String {
+        let properties = match height {
+            Some(height) => {
+                format!(r#"style:row-height="{height:.2}pt" style:use-optimal-row-height="false""#)
+            }
+            None => r#"style:use-optimal-row-height="true""#.to_string(),
+        };
+        self.add(
+            "ro",
+            format!(
+                r#"<style:style style:name="{{name}}" style:family="table-row"><style:table-row-properties {properties}/></style:style>"#
+            ),
+        )
+    }
+
+    fn number(&mut self, format_code: &str) -> Option<String> {
+        let decimals = match format_code.split_once('.') {
+            Some((_, fraction)) => fraction
+                .chars()
+                .take_while(|c| *c == '0' || *c == '#')
+                .count(),
+            None if format_code.contains('0') => 0,
+            None => return None,
+        };
+        let grouping = format_code.contains(',');
+        Some(self.add(
+            "N",
+            format!(
+                r#"<number:number-style style:name="{{name}}"><number:number number:decimal-places="{decimals}" number:min-decimal-places="{decimals}" number:min-integer-digits="1" number:grouping="{grouping}"/></number:number-style>"#
+            ),
+        ))
+    }
+
+    fn cell(&mut self, cell: &LayoutCell, borders: &CellBorders) -> String {
+        let data_style = cell
+            .number
+            .and_then(|_| self.number(&cell.number_format))
+            .map(|name| format!(r#" style:data-style-name="{name}""#))
+            .unwrap_or_default();
+        let [r, g, b] = cell.color;
+        let mut cell_properties = format!(
+            r#"style:vertical-align="{}" fo:border-top="{}" fo:border-right="{}" fo:border-bottom="{}" fo:border-left="{}""#,
+            match cell.vertical {
+                VerticalAlign::Top => "top",
+                VerticalAlign::Center => "middle",
+                VerticalAlign::Bottom => "bottom",
+            },
+            border(borders.top),
+            border(borders.right),
+            border(borders.bottom),
+            border(borders.left),
+        );
+        if cell.wrap {
+            cell_properties.push_str(r#" fo:wrap-option="wrap""#);
+        }
+        if let Some([r, g, b]) = cell.fill {
+            let _ = write!(
+                cell_properties,
+                r##" fo:background-color="#{r:02x}{g:02x}{b:02x}""##
+            );
+        }
+        if cell.vertical_text {
+            cell_properties.push_str(r#" style:rotation-angle="90" style:rotation-align="none""#);
+        }
+        let text_align = match cell.horizontal {
+            HorizontalAlign::Left => "start",
+            HorizontalAlign::Center => "center",
+            HorizontalAlign::Right => "end",
+        };
+        self.add(
+            "ce",
+            format!(
+                concat!(
+                    r#"<style:style style:name="{{name}}" style:family="table-cell"{data_style}>"#,
+                    r#"<style:table-cell-properties {cell_properties}/>"#,
+                    r#"<style:paragraph-properties fo:text-align="{text_align}"/>"#,
+                    r##"<style:text-properties fo:font-size="{size}pt" fo:font-weight="{weight}" fo:font-style="{font_style}" fo:color="#{r:02x}{g:02x}{b:02x}"/>"##,
+                    r#"</style:style>"#,
+                ),
+                data_style = data_style,
+                cell_properties = cell_properties,
+                text_align = text_align,
+                size = cell.font_size,
+                weight = if cell.bold { "bold" } else { "normal" },
+                font_style = if cell.italic { "italic" } else { "normal" },
+                r = r,
+                g = g,
+                b = b,
+            ),
+        )
+    }
+}
+
+fn border(width: f64) -> String {
+    if width > 0_f64 {
+        format!("{width}pt solid #000000")
+    } else {
+        "none".to_string()
+    }
+}
+
+fn content_xml(layouts: &[SheetLayout]) -> String {
+    let mut styles = Styles::default();
+    let mut body = String::new();
+    for layout in layouts {
+        write_table(&mut body, &mut styles, layout);
+    }
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><office:document-content {CONTENT_NAMESPACES}><office:automatic-styles>{}</office:automatic-styles><office:body><office:spreadsheet>{body}</office:spreadsheet></office:body></office:document-content>"#,
+        styles.xml
+    )
+}
+
+fn write_table(xml: &mut String, styles: &mut Styles, layout: &SheetLayout) {
+    let _ = write!(
+        xml,
+        r#"<table:table table:name="{}">"#,
+        escape(&layout.name)
+    );
+    for width in &layout.column_widths {
+        let style = styles.column(*width);
+        let _ = write!(xml, r#"<table:table-column table:style-name="{style}"/>"#);
+    }
+    for row in 1..=layout.row_count() {
+        let style = styles.row(layout.row_heights[row as usize - 1]);
+        let _ = write!(xml, r#"<table:table-row table:style-name="{style}">"#);
+        for column in 1..=layout.column_count() {
+            write_cell(xml, styles, layout, row, column);
+        }
+        xml.push_str("</table:table-row>");
+    }
+    xml.push_str("</table:table>");
+}
+
+fn write_cell(xml: &mut String, styles: &mut Styles, layout: &SheetLayout, row: u32, column: u32) {
+    let area = layout.merged_area_at(row, column);
+    if area.is_some_and(|area| area.first_row != row || area.first_column != column) {
+        xml.push_str("<table:covered-table-cell/>");
+        return;
+    }
+    // The spans go on the top-left cell even when it is empty, or the
+    // covered cells after it would have no area to belong to.
+    let spans = area
+        .map(|area| {
+            format!(
+                r#" table:number-columns-spanned="{}" table:number-rows-spanned="{}""#,
+                area.last_column - area.first_column + 1,
+                area.last_row - area.first_row + 1
+            )
+        })
+        .unwrap_or_default();
+    let Some(cell) = layout.cell(row, column) else {
+        let _ = write!(xml, "<table:table-cell{spans}/>");
+        return;
+    };
+
+    let mut attributes = spans;
+    let borders = area.map_or(cell.borders, |area| layout.merged_borders(area));
+    let style = styles.cell(cell, &borders);
+    let _ = write!(attributes, r#" table:style-name="{style}""#);
+    if let Some(number) = cell.number {
+        let _ = write!(
+            attributes,
+            r#" office:value-type="float" office:value="{number}""#
+        );
+    } else if !cell.text.is_empty() {
+        attributes.push_str(r#" office:value-type="string""#);
+    }
+
+    let _ = write!(xml, "<table:table-cell{attributes}>");
+    if let Some(comment) = &cell.comment {
+        xml.push_str("<office:annotation>");
+        for line in comment.split('\n') {
+            let _ = write!(xml, "<text:p>{}</text:p>", escape(line));
+        }
+        xml.push_str("</office:annotation>");
+    }
+    if !cell.text.is_empty() {
+        for line in cell.text.split('\n') {
+            let _ = write!(xml, "<text:p>{}</text:p>", escape(line));
+        }
+    }
+    xml.push_str("</table:table-cell>");
+}
@@ -0,0 +1,465 @@
+use std::{fs::File, io::BufWriter};
+
+use printpdf::{
+    Color, CustomPdfConformance, IndirectFontRef, Line, Mm, PdfConformance, PdfDocument,
+    PdfDocumentReference, PdfLayerReference, Point, Polygon, Pt, Rgb, TextMatrix,
+    path::{PaintMode, WindingOrder},
+};
+use ttf_parser::Face;
+use umya_spreadsheet::Spreadsheet;
+
+use crate::excel::{
+    data::{ExportOptions, ExportedData, PdfFonts},
+    document_generator::build_workbook,
+    sheet_layout::{HorizontalAlign, LayoutCell, SheetLayout, VerticalAlign},
+};
+
+/// A4 landscape, in millimetres.
+const PAGE_WIDTH: f64 = 297.0;
+const PAGE_HEIGHT: f64 = 210.0;
+const PAGE_MARGIN: f64 = 10.0;
+const PT_PER_MM: f64 = 72.0 / 25.4;
+
+const DEFAULT_ROW_HEIGHT: f64 = 12.75;
+const CELL_PADDING: f64 = 2.0;
+const LINE_SPACING: f64 = 1.2;
+
+#[derive(Clone)]
+struct PdfFont<'a> {
+    face: Face<'a>,
+    font: IndirectFontRef,
+}
+
+impl PdfFont<'_> {
+    fn text_width(&self, text: &str, size: f64) -> f64 {
+        let units_per_em = self.face.units_per_em() as f64;
+        let advance: f64 = text
+            .chars()
+            .map(|c| {
+                self.face
+                    .glyph_index(c)
+                    .and_then(|glyph| self.face.glyph_hor_advance(glyph))
+                    .map_or(units_per_em / 2_f64, |advance| advance as f64)
+            })
+            .sum();
+        advance / units_per_em * size
+    }
+}
+
+struct PdfFontSet<'a> {
+    regular: PdfFont<'a>,
+    bold: PdfFont<'a>,
+    italic: PdfFont<'a>,
+    bold_italic: PdfFont<'a>,
+}
+
+impl PdfFontSet<'_> {
+    fn of(&self, cell: &LayoutCell) -> &PdfFont<'_> {
+        match (cell.bold, cell.italic) {
+            (true, true) => &self.bold_italic,
+            (true, false) => &self.bold,
+            (false, true) => &self.italic,
+            (false, false) => &self.regular,
+        }
+    }
+}
+
+/// Renders the same sheets as `generate_output_file` to A4 landscape pages,
+/// each sheet scaled down to the page width when it does not fit.
+pub fn generate_pdf_file(
+    file_path: &str,
+    data: ExportedData,
+    options: ExportOptions,
+    fonts: PdfFonts,
+) -> Result<(), String> {
+    let book = build_workbook(&data, &options);
+    let title = format!("{} {}-{}", data.type_name, data.year, data.year + 1);
+    write_workbook_pdf(file_path, &title, &book, &fonts)
+}
+
+pub(crate) fn write_workbook_pdf(
+    file_path: &str,
+    title: &str,
+    book: &Spreadsheet,
+    fonts: &PdfFonts,
+) -> Result<(), String> {
+    let layouts: Vec<SheetLayout> = book
+        .get_sheet_collection()
+        .iter()
+        .map(SheetLayout::from_worksheet)
+        .collect();
+
+    let regular = read_font(&fonts.regular)?;
+    let bold = fonts.bold.as_deref().map(read_font).transpose()?;
+    let italic = fonts.italic.as_deref().map(read_font).transpose()?;
+    let bold_italic = fonts.bold_italic.as_deref().map(read_font).transpose()?;
+
+    // Plain PDF rather than the default PDF/A, which embeds an ICC profile
+    // several times the size of the tables.
+    let doc = PdfDocument::empty(title)
+        .with_conformance(PdfConformance::Custom(CustomPdfConformance::default()));
+    let regular = embed_font(&doc, &regular)?;
+    let bold = embed_optional_font(&doc, bold.as_deref(), &regular)?;
+    let fonts = PdfFontSet {
+        italic: embed_optional_font(&doc, italic.as_deref(), &regular)?,
+        bold_italic: embed_optional_font(&doc, bold_italic.as_deref(), &bold)?,
+        regular,
+        bold,
+    };
+
+    for layout in &layouts {
+        let row_heights = fit_row_heights(layout, &fonts);
+        let sheet_width: f64 = layout.column_widths.iter().sum();
+        let content_width = (PAGE_WIDTH - 2_f64 * PAGE_MARGIN) * PT_PER_MM;
+        let content_height = (PAGE_HEIGHT - 2_f64 * PAGE_MARGIN) * PT_PER_MM;
+        let scale = (content_width / sheet_width).min(1_f64);
+
+        for (first_row, last_row) in split_pages(layout, &row_heights, content_height / scale) {
+            let (page, layer) = doc.add_page(
+                Mm(PAGE_WIDTH as f32),
+                Mm(PAGE_HEIGHT as f32),
+                layout.name.as_str(),
+            );
+            let page = SheetPage {
+                layer: doc.get_page(page).get_layer(layer),
+                layout,
+                fonts: &fonts,
+                row_heights: &row_heights,
+                first_row,
+                last_row,
+                scale,
+            };
+            page.draw();
+        }
+    }
+
+    let file = File::create(file_path).map_err(|e| e.to_string())?;
+    doc.save(&mut BufWriter::new(file))
+        .map_err(|e| e.to_string())
+}
+
+fn read_font(path: &str) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|e| format!("{path}: {e}"))
+}
+
+fn embed_font<'a>(doc: &PdfDocumentReference, font_data: &'a [u8]) -> Result<PdfFont<'a>, String> {
+    let face = Face::parse(font_data, 0).map_err(|e| e.to_string())?;
+    let font = doc
+        .add_external_font_with_subsetting(font_data, true)
+        .map_err(|e| e.to_string())?;
+    Ok(PdfFont { face, font })
+}
+
+fn embed_optional_font<'a>(
+    doc: &PdfDocumentReference,
+    font_data: Option<&'a [u8]>,
+    fallback: &PdfFont<'a>,
+) -> Result<PdfFont<'a>, String> {
+    match font_data {
+        Some(font_data) => embed_font(doc, font_data),
+        None => Ok(fallback.clone()),
+    }
+}
+
+/// Heights of all rows in points. Rows without a height set in the workbook
+/// grow to fit their wrapped text, as they would in a spreadsheet program.
+fn fit_row_heights(layout: &SheetLayout, fonts: &PdfFontSet) -> Vec<f64> {
+    (1..=layout.row_count())
+        .map(|row| {
+            if let Some(height) = layout.row_heights[row as usize - 1] {
+                return height;
+            }
+            let mut height = DEFAULT_ROW_HEIGHT;
+            for column in 1..=layout.column_count() {
+                let Some(cell) = layout.cell(row, column) else {
+                    continue;
+                };
+                if cell.text.is_empty() || cell.vertical_text {
+                    continue;
+                }
+                let width = match layout.merged_area_at(row, column) {
+                    Some(area) if area.first_row != area.last_row => continue,
+                    Some(area) if area.first_column != column => continue,
+                    Some(area) => (area.first_column..=area.last_column)
+                        .map(|x| layout.column_widths[x as usize - 1])
+                        .sum(),
+                    None => layout.column_widths[column as usize - 1],
+                };
+                let lines = wrap_text(cell, fonts.of(cell), width - 2_f64 * CELL_PADDING).len();
+                height = height.max(lines as f64 * cell.font_size * LINE_SPACING + CELL_PADDING);
+            }
+            height
+        })
+        .collect()
+}
+
+/// First and last row of every page. A page only ends where no merged area
+/// continues onto the next row, unless a single merged area is taller than
+/// the page. An empty sheet still gets a blank page of its own.
+fn split_pages(layout: &SheetLayout, row_heights: &[f64], page_height: f64) -> Vec<(u32, u32)> {
+    let mut pages = Vec::new();
+    let mut first_row = 1;
+    while first_row <= layout.row_count() {
+        let mut height = 0_f64;
+        let mut last_row = first_row;
+        let mut last_break = None;
+        for row in first_row..=layout.row_count() {
+            height += row_heights[row as usize - 1];
+            if height > page_height && row > first_row {
+                break;
+            }
+            last_row = row;
+            if layout.can_break_after(row) {
+                last_break = Some(row);
+            }
+        }
+        if last_row < layout.row_count() {
+            last_row = last_break.unwrap_or(last_row);
+        }
+        pages.push((first_row, last_row));
+        first_row = last_row + 1;
+    }
+    if pages.is_empty() {
+        pages.push((1, 0));
+    }
+    pages
+}
+
+/// Lines of the cell text wrapped by words to `width`. A word longer than
+/// the width is left on its own line.
+fn wrap_text(cell: &LayoutCell, font: &PdfFont, width: f64) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in cell.text.split('\n') {
+        if !cell.wrap {
+            lines.push(paragraph.to_string());
+            continue;
+        }
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{line} {word}")
+            };
+            if line.is_empty() || font.text_width(&candidate, cell.font_size) <= width {
+                line = candidate;
+            } else {
+                lines.push(std::mem::replace(&mut line, word.to_string()));
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+struct SheetPage<'a> {
+    layer: PdfLayerReference,
+    layout: &'a SheetLayout,
+    fonts: &'a PdfFontSet<'a>,
+    row_heights: &'a [f64],
+    first_row: u32,
+    last_row: u32,
+    scale: f64,
+}
+
+/// Cell rectangle on the page in points, `y` of its top edge counted from
+/// the bottom of the page like PDF coordinates.
+struct Rect {
+    x: f64,
+    top: f64,
+    width: f64,
+    height: f64,
+}
+
+impl SheetPage<'_> {
+    fn draw(&self) {
+        self.draw_fills();
+        for row in self.first_row..=self.last_row {
+            for column in 1..=self.layout.column_count() {
+                let Some(cell) = self.layout.cell(row, column) else {
+                    continue;
+                };
+                self.draw_borders(row, column, cell);
+                let area = self.layout.merged_area_at(row, column);
+                if area.is_some_and(|area| area.first_row != row || area.first_column != column) {
+                    continue;
+                }
+                let (last_row, last_column) =
+                    area.map_or((row, column), |area| (area.last_row, area.last_column));
+                let rect = self.rect(row, column, last_row, last_column);
+                if !cell.text.is_empty() {
+                    self.draw_text(cell, &rect);
+                }
+            }
+        }
+    }
+
+    /// Backgrounds go first so the borders of neighbouring cells stay on top.
+    fn draw_fills(&self) {
+        for row in self.first_row..=self.last_row {
+            for column in 1..=self.layout.column_count() {
+                let Some(fill) = self.layout.cell(row, column).and_then(|cell| cell.fill) else {
+                    continue;
+                };
+                let area = self.layout.merged_area_at(row, column);
+                if area.is_some_and(|area| area.first_row != row || area.first_column != column) {
+                    continue;
+                }
+                let (last_row, last_column) =
+                    area.map_or((row, column), |area| (area.last_row, area.last_column));
+                let rect = self.rect(row, column, last_row, last_column);
+                let (left, right) = (rect.x, rect.x + rect.width);
+                let bottom = rect.top - rect.height;
+                self.layer.set_fill_color(rgb_color(fill));
+                self.layer.add_polygon(Polygon {
+                    rings: vec![vec![
+                        (point((left, bottom)), false),
+                        (point((right, bottom)), false),
+                        (point((right, rect.top)), false),
+                        (point((left, rect.top)), false),
+                    ]],
+                    mode: PaintMode::Fill,
+                    winding_order: WindingOrder::NonZero,
+                });
+            }
+        }
+    }
+
+    fn rect(&self, first_row: u32, first_column: u32, last_row: u32, last_column: u32) -> Rect {
+        let x: f64 = self.layout.column_widths[..first_column as usize - 1]
+            .iter()
+            .sum();
+        let y: f64 = self.row_heights[self.first_row as usize - 1..first_row as usize - 1]
+            .iter()
+            .sum();
+        let width: f64 = self.layout.column_widths[first_column as usize - 1..last_column as usize]
+            .iter()
+            .sum();
+        let height: f64 = self.row_heights[first_row as usize - 1..last_row as usize]
+            .iter()
+            .sum();
+        Rect {
+            x: PAGE_MARGIN * PT_PER_MM + x * self.scale,
+            top: (PAGE_HEIGHT - PAGE_MARGIN) * PT_PER_MM - y * self.scale,
+            width: width * self.scale,
+            height: height * self.scale,
+        }
+    }
+
+    /// Edges inside a merged area are skipped, the rest are drawn from the
+    /// cell that owns them like in the workbook.
+    fn draw_borders(&self, row: u32, column: u32, cell: &LayoutCell) {
+        let rect = self.rect(row, column, row, column);
+        let area = self.layout.merged_area_at(row, column);
+        let (left, right, bottom) = (rect.x, rect.x + rect.width, rect.top - rect.height);
+        let edges = [
+            (cell.borders.top, (left, rect.top), (right, rect.top), 0, -1),
+            (cell.borders.bottom, (left, bottom), (right, bottom), 0, 1),
+            (cell.borders.left, (left, bottom), (left, rect.top), -1, 0),
+            (cell.borders.right, (right, bottom), (right, rect.top), 1, 0),
+        ];
+        for (width, from, to, dx, dy) in edges {
+            if width <= 0_f64 {
+                continue;
+            }
+            let inside_area = area.is_some_and(|area| {
+                let neighbour_row = row as i64 + dy;
+                let neighbour_column = column as i64 + dx;
+                (area.first_row as i64..=area.last_row as i64).contains(&neighbour_row)
+                    && (area.first_column as i64..=area.last_column as i64)
+                        .contains(&neighbour_column)
+            });
+            if inside_area {
+                continue;
+            }
+            self.layer
+                .set_outline_thickness((width * self.scale) as f32);
+            self.layer.add_line(Line {
+                points: vec![(point(from), false), (point(to), false)],
+                is_closed: false,
+            });
+        }
+    }
+
+    /// Rotated text runs bottom to top with its lines stacked left to right,
+    /// as a 90 degree rotation does in the workbook.
+    fn draw_text(&self, cell: &LayoutCell, rect: &Rect) {
+        let font = self.fonts.of(cell);
+        let size = cell.font_size * self.scale;
+        let line_height = size * LINE_SPACING;
+        let padding = CELL_PADDING * self.scale;
+        let (along, across) = if cell.vertical_text {
+            (rect.height, rect.width)
+        } else {
+            (rect.width, rect.height)
+        };
+        let lines = wrap_text(cell, font, (along - 2_f64 * padding) / self.scale);
+        let block = lines.len() as f64 * line_height;
+        let (across_alignment, along_alignment) = if cell.vertical_text {
+            let across_alignment = match cell.horizontal {
+                HorizontalAlign::Left => VerticalAlign::Top,
+                HorizontalAlign::Center => VerticalAlign::Center,
+                HorizontalAlign::Right => VerticalAlign::Bottom,
+            };
+            let along_alignment = match cell.vertical {
+                VerticalAlign::Bottom => HorizontalAlign::Left,
+                VerticalAlign::Center => HorizontalAlign::Center,
+                VerticalAlign::Top => HorizontalAlign::Right,
+            };
+            (across_alignment, along_alignment)
+        } else {
+            (cell.vertical, cell.horizontal)
+        };
+        // Offset of the first line from the top edge, or from the left edge
+        // for rotated text.
+        let block_start = match across_alignment {
+            VerticalAlign::Top => padding,
+            VerticalAlign::Center => (across - block) / 2_f64,
+            VerticalAlign::Bottom => across - padding - block,
+        };
+
+        self.layer.set_fill_color(rgb_color(cell.color));
+        for (i, line) in lines.iter().enumerate() {
+            let line_width = font.text_width(line, cell.font_size) * self.scale;
+            let offset = match along_alignment {
+                HorizontalAlign::Left => padding,
+                HorizontalAlign::Center => (along - line_width) / 2_f64,
+                HorizontalAlign::Right => along - padding - line_width,
+            };
+            let baseline = block_start + (i as f64 + 0.5) * line_height + size * 0.35;
+            let matrix = if cell.vertical_text {
+                TextMatrix::TranslateRotate(
+                    Pt((rect.x + baseline) as f32),
+                    Pt((rect.top - rect.height + offset) as f32),
+                    90.0,
+                )
+            } else {
+                TextMatrix::Translate(
+                    Pt((rect.x + offset) as f32),
+                    Pt((rect.top - baseline) as f32),
+                )
+            };
+            self.layer.begin_text_section();
+            self.layer.set_font(&font.font, size as f32);
+            self.layer.set_text_matrix(matrix);
+            self.layer.write_text(line.as_str(), &font.font);
+            self.layer.end_text_section();
+        }
+    }
+}
+
+fn rgb_color([r, g, b]: [u8; 3]) -> Color {
+    Color::Rgb(Rgb::new(
+        r as f32 / 255.0,
+        g as f32 / 255.0,
+        b as f32 / 255.0,
+        None,
+    ))
+}
+
+fn point((x, y): (f64, f64)) -> Point {
+    Point {
+        x: Pt(x as f32),
+        y: Pt(y as f32),
+    }
+}
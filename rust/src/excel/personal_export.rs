@@ -0,0 +1,56 @@
+use std::{collections::HashSet, path::Path};
+
+use umya_spreadsheet::writer;
+
+use crate::excel::{
+    data::{ExportOptions, ExportedData, PdfFonts},
+    document_generator::build_personal_workbook,
+    pdf_generator::write_workbook_pdf,
+    teacher_names::short_teacher_name,
+    transliteration::safe_file_name,
+};
+
+/// Writes a workbook per `OutputPersonalTables` entry into `directory`, and a
+/// PDF next to it when `pdf_fonts` is given. Files are named after the
+/// transliterated teacher name, with `_2`, `_3`... for namesakes. Returns the
+/// paths written.
+pub fn generate_personal_files(
+    directory: &str,
+    data: ExportedData,
+    options: ExportOptions,
+    pdf_fonts: Option<PdfFonts>,
+) -> Result<Vec<String>, String> {
+    let directory = Path::new(directory);
+    std::fs::create_dir_all(directory).map_err(|e| e.to_string())?;
+
+    let mut used_names = HashSet::new();
+    let mut paths = Vec::new();
+    for tables in &data.personal_tables {
+        let base_name = safe_file_name(&short_teacher_name(tables));
+        let mut name = base_name.clone();
+        let mut n = 1;
+        while !used_names.insert(name.to_lowercase()) {
+            n += 1;
+            name = format!("{base_name}_{n}");
+        }
+
+        let book = build_personal_workbook(&data, &options, tables);
+        let xlsx_path = directory.join(format!("{name}.xlsx"));
+        writer::xlsx::write(&book, &xlsx_path).map_err(|e| e.to_string())?;
+        paths.push(xlsx_path.to_string_lossy().into_owned());
+
+        if let Some(fonts) = &pdf_fonts {
+            let pdf_path = directory.join(format!("{name}.pdf"));
+            let pdf_path = pdf_path.to_string_lossy().into_owned();
+            let title = format!(
+                "{} {}-{}",
+                tables.worker_last_name,
+                data.year,
+                data.year + 1
+            );
+            write_workbook_pdf(&pdf_path, &title, &book, fonts)?;
+            paths.push(pdf_path);
+        }
+    }
+    Ok(paths)
+}
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+
+use umya_spreadsheet::{Cell, HorizontalAlignmentValues, VerticalAlignmentValues, Worksheet};
+
+const DEFAULT_COLUMN_WIDTH: f64 = 8.43;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum HorizontalAlign {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum VerticalAlign {
+    Top,
+    Center,
+    Bottom,
+}
+
+/// Border widths in points, zero for no border.
+#[derive(Clone, Copy, Default)]
+pub struct CellBorders {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+pub struct LayoutCell {
+    pub text: String,
+    /// Value of a numeric cell, `text` holds it formatted.
+    pub number: Option<f64>,
+    pub number_format: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub font_size: f64,
+    pub color: [u8; 3],
+    /// Background, `None` for none.
+    pub fill: Option<[u8; 3]>,
+    /// Text of the cell comment.
+    pub comment: Option<String>,
+    pub horizontal: HorizontalAlign,
+    pub vertical: VerticalAlign,
+    pub vertical_text: bool,
+    pub wrap: bool,
+    pub borders: CellBorders,
+}
+
+/// Merged area, 1-based and inclusive like the worksheet coordinates.
+#[derive(Clone, Copy)]
+pub struct MergedArea {
+    pub first_row: u32,
+    pub first_column: u32,
+    pub last_row: u32,
+    pub last_column: u32,
+}
+
+/// A worksheet flattened into what the non-XLSX renderers need: sizes in
+/// points, merged areas and the formatted text and style of every cell.
+pub struct SheetLayout {
+    pub name: String,
+    pub column_widths: Vec<f64>,
+    /// `None` for rows whose height follows their content.
+    pub row_heights: Vec<Option<f64>>,
+    pub merged_areas: Vec<MergedArea>,
+    cells: HashMap<(u32, u32), LayoutCell>,
+}
+
+impl SheetLayout {
+    pub fn from_worksheet(sheet: &Worksheet) -> Self {
+        let merged_areas: Vec<MergedArea> = sheet
+            .get_merge_cells()
+            .iter()
+            .filter_map(|range| {
+                Some(MergedArea {
+                    first_row: *range.get_coordinate_start_row()?.get_num(),
+                    first_column: *range.get_coordinate_start_col()?.get_num(),
+                    last_row: *range.get_coordinate_end_row()?.get_num(),
+                    last_column: *range.get_coordinate_end_col()?.get_num(),
+                })
+            })
+            .collect();
+        // A merged area may reach past the last cell with a value, like the
+        // title over the charts of the analytics sheet.
+        let (column_count, row_count) = merged_areas.iter().fold(
+            sheet.get_highest_column_and_row(),
+            |(column_count, row_count), area| {
+                (
+                    column_count.max(area.last_column),
+                    row_count.max(area.last_row),
+                )
+            },
+        );
+        let column_widths = (1..=column_count)
+            .map(|x| {
+                let width = sheet
+                    .get_column_dimension_by_number(&x)
+                    .map_or(DEFAULT_COLUMN_WIDTH, |column| *column.get_width());
+                column_width_to_points(width)
+            })
+            .collect();
+        let row_heights = (1..=row_count)
+            .map(|y| {
+                sheet
+                    .get_row_dimension(&y)
+                    .map(|row| *row.get_height())
+                    .filter(|height| *height > 0_f64)
+            })
+            .collect();
+        let mut comments: HashMap<(u32, u32), String> = sheet
+            .get_comments()
+            .iter()
+            .map(|comment| {
+                let coordinate = comment.get_coordinate();
+                (
+                    (*coordinate.get_row_num(), *coordinate.get_col_num()),
+                    comment.get_text().get_text().into_owned(),
+                )
+            })
+            .collect();
+        let cells = sheet
+            .get_cell_collection()
+            .into_iter()
+            .map(|cell| {
+                let coordinate = cell.get_coordinate();
+                let position = (*coordinate.get_row_num(), *coordinate.get_col_num());
+                (position, layout_cell(cell, comments.remove(&position)))
+            })
+            .collect();
+        Self {
+            name: sheet.get_name().to_string(),
+            column_widths,
+            row_heights,
+            merged_areas,
+            cells,
+        }
+    }
+
+    pub fn row_count(&self) -> u32 {
+        self.row_heights.len() as u32
+    }
+
+    pub fn column_count(&self) -> u32 {
+        self.column_widths.len() as u32
+    }
+
+    pub fn cell(&self, row: u32, column: u32) -> Option<&LayoutCell> {
+        self.cells.get(&(row, column))
+    }
+
+    pub fn merged_area_at(&self, row: u32, column: u32) -> Option<&MergedArea> {
+        self.merged_areas.iter().find(|area| {
+            (area.first_row..=area.last_row).contains(&row)
+                && (area.first_column..=area.last_column).contains(&column)
+        })
+    }
+
+    /// Borders around a merged area, each taken from the cells on that edge
+    /// the way spreadsheet programs draw them.
+    pub fn merged_borders(&self, area: &MergedArea) -> CellBorders {
+        let borders = |row, column| {
+            self.cell(row, column)
+                .map_or_else(CellBorders::default, |cell| cell.borders)
+        };
+        let first = borders(area.first_row, area.first_column);
+        CellBorders {
+            top: first.top,
+            right: borders(area.first_row, area.last_column).right,
+            bottom: borders(area.last_row, area.first_column).bottom,
+            left: first.left,
+        }
+    }
+
+    /// Whether a page may end after `row` without cutting a merged area.
+    pub fn can_break_after(&self, row: u32) -> bool {
+        !self
+            .merged_areas
+            .iter()
+            .any(|area| area.first_row <= row && row < area.last_row)
+    }
+}
+
+/// Excel column width in characters of the default font to points.
+fn column_width_to_points(width: f64) -> f64 {
+    (width * 7_f64 + 5_f64) * 0.75
+}
+
+fn layout_cell(cell: &Cell, comment: Option<String>) -> LayoutCell {
+    let style = cell.get_style();
+    let font = style.get_font();
+    let alignment = style.get_alignment();
+    let is_number = cell.get_data_type() == "n";
+    let horizontal = match alignment.map(|alignment| alignment.get_horizontal()) {
+        Some(HorizontalAlignmentValues::Center) => HorizontalAlign::Center,
+        Some(HorizontalAlignmentValues::Right) => HorizontalAlign::Right,
+        Some(HorizontalAlignmentValues::Left) => HorizontalAlign::Left,
+        _ if is_number => HorizontalAlign::Right,
+        _ => HorizontalAlign::Left,
+    };
+    let vertical = match alignment.map(|alignment| alignment.get_vertical()) {
+        Some(VerticalAlignmentValues::Center) => VerticalAlign::Center,
+        Some(VerticalAlignmentValues::Top) => VerticalAlign::Top,
+        _ => VerticalAlign::Bottom,
+    };
+    let borders = style
+        .get_borders()
+        .map_or_else(CellBorders::default, |borders| CellBorders {
+            top: border_width(borders.get_top_border().get_border_style()),
+            right: border_width(borders.get_right_border().get_border_style()),
+            bottom: border_width(borders.get_bottom_border().get_border_style()),
+            left: border_width(borders.get_left_border().get_border_style()),
+        });
+    LayoutCell {
+        text: cell.get_formatted_value(),
+        number: if is_number {
+            cell.get_value_number()
+        } else {
+            None
+        },
+        number_format: style
+            .get_number_format()
+            .map_or_else(String::new, |format| format.get_format_code().to_string()),
+        bold: font.is_some_and(|font| *font.get_bold()),
+        italic: font.is_some_and(|font| *font.get_italic()),
+        font_size: font.map_or(10_f64, |font| *font.get_size()),
+        color: font.map_or([0, 0, 0], |font| argb_to_rgb(font.get_color().get_argb())),
+        fill: style
+            .get_background_color()
+            .map(|color| color.get_argb())
+            .filter(|argb| !argb.is_empty())
+            .map(argb_to_rgb),
+        comment,
+        horizontal,
+        vertical,
+        vertical_text: alignment.is_some_and(|alignment| *alignment.get_text_rotation() == 90),
+        wrap: alignment.is_some_and(|alignment| *alignment.get_wrap_text()),
+        borders,
+    }
+}
+
+fn border_width(style: &str) -> f64 {
+    match style {
+        "thin" => 0.5_f64,
+        "medium" => 1_f64,
+        "thick" => 1.5_f64,
+        _ => 0_f64,
+    }
+}
+
+fn argb_to_rgb(argb: &str) -> [u8; 3] {
+    let rgb = if argb.len() == 8 { &argb[2..] } else { argb };
+    let channel = |i: usize| {
+        rgb.get(i..i + 2)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            .unwrap_or(0)
+    };
+    [channel(0), channel(2), channel(4)]
+}
@@ -0,0 +1,82 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::excel::{
+    data::{OutputPersonalTables, PersonalSheetName},
+    teacher_names::initials,
+};
+
+pub const MAIN_SHEET_NAME: &str = "Загальна";
+pub const ANALYTICS_SHEET_NAME: &str = "Аналітика";
+const MAX_SHEET_NAME_LENGTH: usize = 31;
+const FORBIDDEN_CHARACTERS: [char; 7] = ['/', '\\', '?', '*', '[', ']', ':'];
+
+/// `name` made acceptable to Excel: forbidden characters replaced with `_`,
+/// no leading or trailing apostrophe or spaces, at most 31 characters.
+pub fn sanitize_sheet_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if FORBIDDEN_CHARACTERS.contains(&c) || c.is_control() {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let is_trimmed = |c: char| c == '\'' || c.is_whitespace();
+    let name = truncate(name.trim_matches(is_trimmed));
+    let name = name.trim_end_matches(is_trimmed);
+    if name.is_empty() {
+        "Аркуш".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Sheet names of the personal tables in `write_additional_tables` order.
+/// A last name shared by several entries gets the initials, e.g.
+/// "Шевченко О.П.", and any name still taken gets a " (2)", " (3)"... suffix.
+pub fn personal_sheet_names(tables_list: &[OutputPersonalTables]) -> Vec<PersonalSheetName> {
+    let base_names: Vec<String> = tables_list
+        .iter()
+        .map(|tables| sanitize_sheet_name(&tables.worker_last_name))
+        .collect();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for name in &base_names {
+        *counts.entry(name.to_lowercase()).or_default() += 1;
+    }
+
+    let mut used = HashSet::from([
+        MAIN_SHEET_NAME.to_lowercase(),
+        ANALYTICS_SHEET_NAME.to_lowercase(),
+    ]);
+    tables_list
+        .iter()
+        .zip(base_names)
+        .map(|(tables, base_name)| {
+            let mut name = base_name;
+            if counts[&name.to_lowercase()] > 1
+                && let Some(initials) = initials(tables)
+            {
+                name = sanitize_sheet_name(&format!("{name} {initials}"));
+            }
+            let mut sheet_name = name.clone();
+            let mut n = 1;
+            while !used.insert(sheet_name.to_lowercase()) {
+                n += 1;
+                let suffix = format!(" ({n})");
+                let length = MAX_SHEET_NAME_LENGTH - suffix.chars().count();
+                sheet_name = format!("{}{suffix}", name.chars().take(length).collect::<String>());
+            }
+            PersonalSheetName {
+                worker_last_name: tables.worker_last_name.clone(),
+                table_ids: tables.tables.iter().map(|table| table.id).collect(),
+                sheet_name,
+            }
+        })
+        .collect()
+}
+
+fn truncate(name: &str) -> String {
+    name.chars().take(MAX_SHEET_NAME_LENGTH).collect()
+}
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+
+use umya_spreadsheet::{Cell, Style, helper::coordinate::CellCoordinates};
+
+use crate::excel::{
+    data::ExportTheme,
+    excel_functions::{
+        BorderThickness, TextAlignment, TextOrientation, TextStyle, cell_set_all_borders,
+        cell_set_color, cell_set_fill, cell_set_number_format, cell_set_text_alignment,
+        cell_set_text_orientation, cell_set_text_style, create_cell, create_cell_number,
+    },
+};
+
+/// Backgrounds of the totals outside the workload norm.
+const UNDERLOAD_FILL: &str = "FFFFEB9C";
+const OVERLOAD_FILL: &str = "FFFFC7CE";
+
+pub fn default_theme() -> ExportTheme {
+    ExportTheme {
+        font_name: "Times New Roman".to_string(),
+        font_size: 10.0,
+    }
+}
+
+/// Combination of the `excel_functions` setters, turned into a `Style` once
+/// and then shared by every cell it is applied to.
+#[derive(Clone, Default)]
+pub struct CellStyle {
+    text_style: Option<TextStyle>,
+    alignments: Vec<TextAlignment>,
+    orientation: Option<TextOrientation>,
+    borders: Option<BorderThickness>,
+    number_format: Option<String>,
+    color: Option<String>,
+    fill: Option<String>,
+}
+
+impl CellStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text_style(mut self, style: TextStyle) -> Self {
+        self.text_style = Some(style);
+        self
+    }
+
+    pub fn align(mut self, alignment: TextAlignment) -> Self {
+        self.alignments.push(alignment);
+        self
+    }
+
+    /// Vertically and horizontally centered.
+    pub fn centered(self) -> Self {
+        self.align(TextAlignment::VerticalCenter)
+            .align(TextAlignment::HorizontalCenter)
+    }
+
+    pub fn orientation(mut self, orientation: TextOrientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    pub fn borders(mut self, thickness: BorderThickness) -> Self {
+        self.borders = Some(thickness);
+        self
+    }
+
+    pub fn number_format(mut self, format_code: &str) -> Self {
+        self.number_format = Some(format_code.to_string());
+        self
+    }
+
+    pub fn color(mut self, argb_color: &str) -> Self {
+        self.color = Some(argb_color.to_string());
+        self
+    }
+
+    pub fn fill(mut self, argb_color: &str) -> Self {
+        self.fill = Some(argb_color.to_string());
+        self
+    }
+
+    /// The style with wrapped text in the font of `theme`.
+    fn build(&self, theme: &ExportTheme) -> Style {
+        let mut cell = Cell::default();
+        cell.get_style_mut().get_alignment_mut().set_wrap_text(true);
+        cell.get_style_mut()
+            .get_font_mut()
+            .set_name(&theme.font_name)
+            .set_size(theme.font_size);
+        if let Some(style) = &self.text_style {
+            cell_set_text_style(&mut cell, style);
+        }
+        for alignment in &self.alignments {
+            cell_set_text_alignment(&mut cell, alignment);
+        }
+        if let Some(orientation) = &self.orientation {
+            cell_set_text_orientation(&mut cell, orientation);
+        }
+        if let Some(thickness) = &self.borders {
+            cell_set_all_borders(&mut cell, thickness);
+        }
+        if let Some(format_code) = &self.number_format {
+            cell_set_number_format(&mut cell, format_code);
+        }
+        if let Some(argb_color) = &self.color {
+            cell_set_color(&mut cell, argb_color);
+        }
+        if let Some(argb_color) = &self.fill {
+            cell_set_fill(&mut cell, argb_color);
+        }
+        cell.get_style().clone()
+    }
+}
+
+/// Named cell styles of the distribution document in one theme. Changing
+/// the theme or a style here changes every cell written with that name.
+pub struct StyleRegistry {
+    theme: ExportTheme,
+    styles: HashMap<String, Style>,
+}
+
+impl StyleRegistry {
+    pub fn new(theme: ExportTheme) -> Self {
+        let mut registry = Self {
+            theme,
+            styles: HashMap::new(),
+        };
+        let header = CellStyle::new()
+            .text_style(TextStyle::Bold)
+            .centered()
+            .borders(BorderThickness::Medium);
+        let hours = CellStyle::new().centered();
+        let number_2dp = CellStyle::new().centered().number_format("0.00");
+
+        registry.register("default", CellStyle::new());
+        registry.register(
+            "title",
+            CellStyle::new()
+                .text_style(TextStyle::Bold)
+                .align(TextAlignment::HorizontalCenter),
+        );
+        registry.register(
+            "semester-title",
+            CellStyle::new()
+                .text_style(TextStyle::Bold)
+                .align(TextAlignment::HorizontalCenter)
+                .borders(BorderThickness::Medium),
+        );
+        registry.register("header", header.clone());
+        registry.register(
+            "header-vertical",
+            header.orientation(TextOrientation::Vertical),
+        );
+        registry.register(
+            "header-blank",
+            CellStyle::new().borders(BorderThickness::Medium),
+        );
+        registry.register(
+            "column-number",
+            CellStyle::new()
+                .text_style(TextStyle::BoldItalic)
+                .align(TextAlignment::HorizontalCenter)
+                .borders(BorderThickness::Medium),
+        );
+        registry.register(
+            "text",
+            CellStyle::new().align(TextAlignment::VerticalCenter),
+        );
+        registry.register(
+            "subtotal",
+            CellStyle::new()
+                .text_style(TextStyle::Bold)
+                .align(TextAlignment::VerticalCenter),
+        );
+        registry.register("centered", CellStyle::new().centered());
+        registry.register(
+            "centered-horizontal",
+            CellStyle::new().align(TextAlignment::HorizontalCenter),
+        );
+        registry.register(
+            "last-name",
+            CellStyle::new().text_style(TextStyle::BoldItalic),
+        );
+        registry.register("first-name", CellStyle::new().text_style(TextStyle::Italic));
+        registry.register(
+            "comment",
+            CellStyle::new()
+                .text_style(TextStyle::BoldItalic)
+                .align(TextAlignment::Top),
+        );
+        registry.register("rank", CellStyle::new().align(TextAlignment::Top));
+        registry.register("number-2dp", number_2dp.clone());
+        registry.register(
+            "subtotal-number-2dp",
+            number_2dp.clone().text_style(TextStyle::Bold),
+        );
+        registry.register(
+            "number-2dp-vertical",
+            number_2dp.orientation(TextOrientation::Vertical),
+        );
+        registry.register("hours", hours.clone());
+        registry.register("subtotal-hours", hours.clone().text_style(TextStyle::Bold));
+        let total_hours = hours.text_style(TextStyle::Bold).color("FFFF0000");
+        registry.register("total-hours", total_hours.clone());
+        registry.register(
+            "total-hours-underload",
+            total_hours.clone().fill(UNDERLOAD_FILL),
+        );
+        registry.register("total-hours-overload", total_hours.fill(OVERLOAD_FILL));
+        registry.register(
+            "personal-subtotal",
+            CellStyle::new().text_style(TextStyle::BoldItalic),
+        );
+        registry.register(
+            "personal-hours",
+            CellStyle::new().align(TextAlignment::HorizontalCenter),
+        );
+        registry.register(
+            "personal-subtotal-hours",
+            CellStyle::new()
+                .text_style(TextStyle::BoldItalic)
+                .align(TextAlignment::HorizontalCenter),
+        );
+        let personal_total_hours = CellStyle::new()
+            .text_style(TextStyle::Bold)
+            .align(TextAlignment::HorizontalCenter);
+        registry.register("personal-total-hours", personal_total_hours.clone());
+        registry.register(
+            "personal-total-hours-underload",
+            personal_total_hours.clone().fill(UNDERLOAD_FILL),
+        );
+        registry.register(
+            "personal-total-hours-overload",
+            personal_total_hours.fill(OVERLOAD_FILL),
+        );
+        let analytics_group = CellStyle::new()
+            .align(TextAlignment::VerticalCenter)
+            .borders(BorderThickness::Thin);
+        let analytics_hours = CellStyle::new()
+            .centered()
+            .borders(BorderThickness::Thin)
+            .number_format("0.00");
+        let analytics_share = CellStyle::new()
+            .centered()
+            .borders(BorderThickness::Thin)
+            .number_format("0.0%");
+        registry.register("analytics-group", analytics_group.clone());
+        registry.register("analytics-hours", analytics_hours.clone());
+        registry.register("analytics-share", analytics_share.clone());
+        registry.register(
+            "analytics-total-group",
+            analytics_group.text_style(TextStyle::Bold),
+        );
+        registry.register(
+            "analytics-total-hours",
+            analytics_hours.text_style(TextStyle::Bold),
+        );
+        registry.register(
+            "analytics-total-share",
+            analytics_share.text_style(TextStyle::Bold),
+        );
+        registry
+    }
+
+    /// Adds or replaces the style called `name`.
+    pub fn register(&mut self, name: &str, style: CellStyle) {
+        self.styles
+            .insert(name.to_string(), style.build(&self.theme));
+    }
+
+    /// Replaces the style of `cell` with the one called `name`. Panics on an
+    /// unregistered name.
+    pub fn apply<'a>(&self, cell: &'a mut Cell, name: &str) -> &'a mut Cell {
+        let style = self
+            .styles
+            .get(name)
+            .unwrap_or_else(|| panic!("unknown cell style \"{name}\""));
+        cell.set_style(style.clone());
+        cell
+    }
+
+    pub fn cell<T>(&self, name: &str, val: &str, coordinate: T) -> Cell
+    where
+        T: Into<CellCoordinates>,
+    {
+        let mut cell = create_cell(val, coordinate);
+        self.apply(&mut cell, name);
+        cell
+    }
+
+    pub fn number_cell<T>(&self, name: &str, val: f64, coordinate: T) -> Cell
+    where
+        T: Into<CellCoordinates>,
+    {
+        let mut cell = create_cell_number(val, coordinate);
+        self.apply(&mut cell, name);
+        cell
+    }
+}
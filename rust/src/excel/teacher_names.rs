@@ -0,0 +1,39 @@
+use crate::excel::data::OutputPersonalTables;
+
+/// "Шевченко О.П." when the entry holds one teacher, the last name
+/// otherwise.
+pub fn short_teacher_name(tables: &OutputPersonalTables) -> String {
+    match initials(tables) {
+        Some(initials) => format!("{} {initials}", tables.worker_last_name),
+        None => tables.worker_last_name.clone(),
+    }
+}
+
+/// Full name of the teacher when the entry holds one, the last name
+/// otherwise.
+pub fn full_teacher_name(tables: &OutputPersonalTables) -> String {
+    match tables.tables.as_slice() {
+        [table] => format!(
+            "{} {} {}",
+            table.last_name, table.first_name, table.middle_name
+        ),
+        _ => tables.worker_last_name.clone(),
+    }
+}
+
+/// "О.П." of the first and middle name when the entry holds one teacher.
+pub fn initials(tables: &OutputPersonalTables) -> Option<String> {
+    let [table] = tables.tables.as_slice() else {
+        return None;
+    };
+    let initials: String = [&table.first_name, &table.middle_name]
+        .iter()
+        .filter_map(|name| name.chars().next())
+        .map(|c| format!("{c}."))
+        .collect();
+    if initials.is_empty() {
+        None
+    } else {
+        Some(initials)
+    }
+}
@@ -0,0 +1,426 @@
+use std::collections::HashMap;
+
+use crate::excel::columns::{default_workload_columns, total_of};
+
+use crate::excel::data::{
+    EmployeeWorkloadInput, ExportedData, LearningFormTotal, MainTableSection, OutputHoursRow,
+    OutputMainRateAndHours, OutputMainTable, OutputMainWorkerRow, OutputPersonalSemesterRow,
+    OutputPersonalTable, OutputPersonalTables, TotalsMismatch, WORKLOAD_VALUES_COUNT,
+    WorkloadInput,
+};
+
+pub(crate) const EMPTY: f64 = -1_f64;
+const TOLERANCE: f64 = 0.005_f64;
+const TOTAL_FIELD: &str = "Всього";
+
+/// Learning form codes used in the semester rows and the adjectives used in
+/// their "Разом (...)" captions. Day and evening subtotals are always written.
+const LEARNING_FORMS: [(&str, &str); 4] = [
+    ("Д", "денна"),
+    ("В", "вечірня"),
+    ("З", "заочна"),
+    ("Дист", "дистанційна"),
+];
+const ALWAYS_WRITTEN_FORMS: usize = 2;
+
+/// `value` rounded to hundredths, the precision hours are shown with.
+pub(crate) fn round(value: f64) -> f64 {
+    (value * 100_f64).round() / 100_f64
+}
+
+pub fn build_exported_data(input: WorkloadInput) -> ExportedData {
+    let mut sections = Vec::new();
+    let mut personal_tables: Vec<OutputPersonalTables> = Vec::new();
+    for section_input in input.sections {
+        let mut rows = Vec::new();
+        for employee in section_input.employees {
+            let table = build_personal_table(employee);
+            rows.push(OutputMainWorkerRow {
+                first_name: table.first_name.clone(),
+                middle_name: table.middle_name.clone(),
+                last_name: table.last_name.clone(),
+                rank: table.rank.clone(),
+                rate_and_hours: OutputMainRateAndHours {
+                    rate: table.semester_1_rate.max(table.semester_2_rate),
+                    semester_1: table.semester_1_total.clone(),
+                    semester_2: table.semester_2_total.clone(),
+                    year: table.year_total.clone(),
+                },
+            });
+            match personal_tables
+                .iter_mut()
+                .find(|tables| is_same_worker(&tables.tables[0], &table))
+            {
+                Some(tables) => tables.tables.push(table),
+                None => personal_tables.push(OutputPersonalTables {
+                    worker_last_name: table.last_name.clone(),
+                    tables: vec![table],
+                }),
+            }
+        }
+        let total = sum_rate_and_hours(rows.iter().map(|row| &row.rate_and_hours));
+        sections.push(MainTableSection {
+            title: section_input.title,
+            rows,
+            total,
+        });
+    }
+    let total = sum_rate_and_hours(sections.iter().map(|section| &section.total));
+    ExportedData {
+        year: input.year,
+        type_name: input.type_name,
+        main_table: OutputMainTable { sections, total },
+        personal_tables,
+    }
+}
+
+/// Personal table of every main table row, in row order. Rows go with the
+/// personal tables of the same worker in order, `None` for a row without one.
+pub fn personal_tables_of_main_rows(data: &ExportedData) -> Vec<Option<&OutputPersonalTable>> {
+    let mut personal_by_name: HashMap<(&str, &str, &str), Vec<&OutputPersonalTable>> =
+        HashMap::new();
+    for table in data
+        .personal_tables
+        .iter()
+        .flat_map(|tables| &tables.tables)
+    {
+        personal_by_name
+            .entry((
+                table.last_name.as_str(),
+                table.first_name.as_str(),
+                table.middle_name.as_str(),
+            ))
+            .or_default()
+            .push(table);
+    }
+    data.main_table
+        .sections
+        .iter()
+        .flat_map(|section| &section.rows)
+        .map(|row| {
+            personal_by_name
+                .get_mut(&(
+                    row.last_name.as_str(),
+                    row.first_name.as_str(),
+                    row.middle_name.as_str(),
+                ))
+                .filter(|tables| !tables.is_empty())
+                .map(|tables| tables.remove(0))
+        })
+        .collect()
+}
+
+/// Recomputes every total of `data` from its rows and reports the supplied
+/// values that differ from the computed ones.
+pub fn validate_exported_data(data: &ExportedData) -> Vec<TotalsMismatch> {
+    let mut mismatches = Vec::new();
+    for table in data
+        .personal_tables
+        .iter()
+        .flat_map(|tables| &tables.tables)
+    {
+        validate_personal_table(table, &mut mismatches);
+    }
+
+    let mut personal_tables = personal_tables_of_main_rows(data).into_iter();
+    let mut department_total = Vec::new();
+    for section in &data.main_table.sections {
+        for row in &section.rows {
+            let location = format!("Загальна, {}", worker_name(&row.last_name, &row.first_name));
+            validate_rate_and_hours(&row.rate_and_hours, &location, &mut mismatches);
+            // Workers without a personal table are not cross-checked.
+            if let Some(table) = personal_tables.next().flatten() {
+                compare_hours(
+                    &table.semester_1_total,
+                    &row.rate_and_hours.semester_1,
+                    &format!("{location}, I семестр"),
+                    &mut mismatches,
+                );
+                compare_hours(
+                    &table.semester_2_total,
+                    &row.rate_and_hours.semester_2,
+                    &format!("{location}, II семестр"),
+                    &mut mismatches,
+                );
+            }
+        }
+        let location = format!("Загальна, «{}»", section.title);
+        let computed = sum_rate_and_hours(section.rows.iter().map(|row| &row.rate_and_hours));
+        compare_rate_and_hours(&computed, &section.total, &location, &mut mismatches);
+        department_total.push(&section.total);
+    }
+    let computed = sum_rate_and_hours(department_total.into_iter());
+    compare_rate_and_hours(
+        &computed,
+        &data.main_table.total,
+        "Загальна, «Разом по кафедрі»",
+        &mut mismatches,
+    );
+    mismatches
+}
+
+fn build_personal_table(employee: EmployeeWorkloadInput) -> OutputPersonalTable {
+    let semester_1 = with_row_totals(employee.semester_1);
+    let semester_2 = with_row_totals(employee.semester_2);
+    let semester_1_total = sum_hours(semester_1.iter().map(|row| &row.hours));
+    let semester_2_total = sum_hours(semester_2.iter().map(|row| &row.hours));
+    let year_total = sum_hours([&semester_1_total, &semester_2_total]);
+    OutputPersonalTable {
+        id: employee.id,
+        first_name: employee.first_name,
+        middle_name: employee.middle_name,
+        last_name: employee.last_name,
+        comment_semester_1: employee.comment_semester_1,
+        comment_semester_2: employee.comment_semester_2,
+        rank: employee.rank,
+        semester_1_form_totals: learning_form_totals(&semester_1),
+        semester_1,
+        semester_1_rate: employee.semester_1_rate,
+        semester_1_total,
+        semester_2_form_totals: learning_form_totals(&semester_2),
+        semester_2,
+        semester_2_rate: employee.semester_2_rate,
+        semester_2_total,
+        year_total,
+    }
+}
+
+fn with_row_totals(mut rows: Vec<OutputPersonalSemesterRow>) -> Vec<OutputPersonalSemesterRow> {
+    for row in &mut rows {
+        row.hours.total = row_total(&row.hours);
+    }
+    rows
+}
+
+fn learning_form_totals(rows: &[OutputPersonalSemesterRow]) -> Vec<LearningFormTotal> {
+    let mut codes: Vec<&str> = LEARNING_FORMS
+        .iter()
+        .enumerate()
+        .filter(|(i, (code, _))| {
+            *i < ALWAYS_WRITTEN_FORMS || rows.iter().any(|row| row.learning_form == *code)
+        })
+        .map(|(_, (code, _))| *code)
+        .collect();
+    for row in rows {
+        if !codes.contains(&row.learning_form.as_str()) {
+            codes.push(&row.learning_form);
+        }
+    }
+    codes
+        .into_iter()
+        .map(|code| LearningFormTotal {
+            learning_form: learning_form_name(code).to_string(),
+            hours: sum_hours(
+                rows.iter()
+                    .filter(|row| row.learning_form == code)
+                    .map(|row| &row.hours),
+            ),
+        })
+        .collect()
+}
+
+fn learning_form_name(code: &str) -> &str {
+    LEARNING_FORMS
+        .iter()
+        .find(|(form_code, _)| *form_code == code)
+        .map_or(code, |(_, name)| name)
+}
+
+fn validate_personal_table(table: &OutputPersonalTable, mismatches: &mut Vec<TotalsMismatch>) {
+    let worker = format!(
+        "{} (№{})",
+        worker_name(&table.last_name, &table.first_name),
+        table.id
+    );
+    let semesters = [
+        (
+            "I",
+            &table.semester_1,
+            &table.semester_1_form_totals,
+            &table.semester_1_total,
+        ),
+        (
+            "II",
+            &table.semester_2,
+            &table.semester_2_form_totals,
+            &table.semester_2_total,
+        ),
+    ];
+    for (semester_name, rows, form_totals, total) in semesters {
+        let location = format!("{worker}, {semester_name} семестр");
+        for row in rows {
+            compare_value(
+                row_total(&row.hours),
+                row.hours.total,
+                &format!("{location}, «{}»", row.name),
+                TOTAL_FIELD,
+                mismatches,
+            );
+        }
+        let computed_forms = learning_form_totals(rows);
+        for form_total in form_totals.iter() {
+            let computed = computed_forms
+                .iter()
+                .find(|computed| computed.learning_form == form_total.learning_form)
+                .map_or_else(empty_hours, |computed| computed.hours.clone());
+            compare_hours(
+                &computed,
+                &form_total.hours,
+                &format!("{location}, «Разом ({} форма)»", form_total.learning_form),
+                mismatches,
+            );
+        }
+        for computed in computed_forms.iter().filter(|computed| {
+            !form_totals
+                .iter()
+                .any(|form_total| form_total.learning_form == computed.learning_form)
+        }) {
+            compare_hours(
+                &computed.hours,
+                &empty_hours(),
+                &format!("{location}, «Разом ({} форма)»", computed.learning_form),
+                mismatches,
+            );
+        }
+        compare_hours(
+            &sum_hours(rows.iter().map(|row| &row.hours)),
+            total,
+            &format!("{location}, «Усього за {semester_name} семестр»"),
+            mismatches,
+        );
+    }
+    compare_hours(
+        &sum_hours([&table.semester_1_total, &table.semester_2_total]),
+        &table.year_total,
+        &format!("{worker}, «Усього за рік»"),
+        mismatches,
+    );
+}
+
+fn validate_rate_and_hours(
+    hours: &OutputMainRateAndHours,
+    location: &str,
+    mismatches: &mut Vec<TotalsMismatch>,
+) {
+    for (semester_name, semester) in [("I", &hours.semester_1), ("II", &hours.semester_2)] {
+        compare_value(
+            row_total(semester),
+            semester.total,
+            &format!("{location}, {semester_name} семестр"),
+            TOTAL_FIELD,
+            mismatches,
+        );
+    }
+    compare_hours(
+        &sum_hours([&hours.semester_1, &hours.semester_2]),
+        &hours.year,
+        &format!("{location}, рік"),
+        mismatches,
+    );
+}
+
+fn compare_rate_and_hours(
+    expected: &OutputMainRateAndHours,
+    actual: &OutputMainRateAndHours,
+    location: &str,
+    mismatches: &mut Vec<TotalsMismatch>,
+) {
+    compare_value(expected.rate, actual.rate, location, "Ставка", mismatches);
+    compare_hours(
+        &expected.semester_1,
+        &actual.semester_1,
+        &format!("{location}, I семестр"),
+        mismatches,
+    );
+    compare_hours(
+        &expected.semester_2,
+        &actual.semester_2,
+        &format!("{location}, II семестр"),
+        mismatches,
+    );
+    compare_hours(
+        &expected.year,
+        &actual.year,
+        &format!("{location}, рік"),
+        mismatches,
+    );
+}
+
+fn compare_hours(
+    expected: &OutputHoursRow,
+    actual: &OutputHoursRow,
+    location: &str,
+    mismatches: &mut Vec<TotalsMismatch>,
+) {
+    for column in default_workload_columns() {
+        compare_value(
+            expected.value(column.key),
+            actual.value(column.key),
+            location,
+            &column.caption,
+            mismatches,
+        );
+    }
+}
+
+/// Empty (negative) values are compared as zero.
+fn compare_value(
+    expected: f64,
+    actual: f64,
+    location: &str,
+    field: &str,
+    mismatches: &mut Vec<TotalsMismatch>,
+) {
+    if (expected.max(0_f64) - actual.max(0_f64)).abs() > TOLERANCE {
+        mismatches.push(TotalsMismatch {
+            location: location.to_string(),
+            field: field.to_string(),
+            expected,
+            actual,
+        });
+    }
+}
+
+fn sum_rate_and_hours<'a>(
+    items: impl Iterator<Item = &'a OutputMainRateAndHours> + Clone,
+) -> OutputMainRateAndHours {
+    OutputMainRateAndHours {
+        rate: round(items.clone().map(|item| item.rate).sum()),
+        semester_1: sum_hours(items.clone().map(|item| &item.semester_1)),
+        semester_2: sum_hours(items.clone().map(|item| &item.semester_2)),
+        year: sum_hours(items.map(|item| &item.year)),
+    }
+}
+
+/// Sums every field of `rows`, skipping empty values. A field stays empty
+/// when it is empty in all rows.
+fn sum_hours<'a>(rows: impl IntoIterator<Item = &'a OutputHoursRow>) -> OutputHoursRow {
+    let mut sums = [EMPTY; WORKLOAD_VALUES_COUNT];
+    for row in rows {
+        for (sum, value) in sums.iter_mut().zip(row.values()) {
+            if value >= 0_f64 {
+                *sum = sum.max(0_f64) + value;
+            }
+        }
+    }
+    let mut hours =
+        OutputHoursRow::from_values(sums.map(|sum| if sum < 0_f64 { sum } else { round(sum) }));
+    hours.total = row_total(&hours);
+    hours
+}
+
+fn row_total(hours: &OutputHoursRow) -> f64 {
+    total_of(hours, &default_workload_columns()).unwrap_or(EMPTY)
+}
+
+fn empty_hours() -> OutputHoursRow {
+    OutputHoursRow::from_values([EMPTY; WORKLOAD_VALUES_COUNT])
+}
+
+fn is_same_worker(a: &OutputPersonalTable, b: &OutputPersonalTable) -> bool {
+    a.last_name == b.last_name && a.first_name == b.first_name && a.middle_name == b.middle_name
+}
+
+fn worker_name(last_name: &str, first_name: &str) -> String {
+    format!("{last_name} {first_name}")
+}
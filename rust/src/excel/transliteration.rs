@@ -0,0 +1,98 @@
+/// Ukrainian to Latin by the national transliteration standard (resolution
+/// of the Cabinet of Ministers No. 55 of 2010): "Хандецький" gives
+/// "Khandetskyi", "Юрій" gives "Yurii". Other characters are kept. A
+/// capital within an all-caps word is written all caps, "ЩУКА" gives
+/// "SHCHUKA".
+pub fn transliterate(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut previous: Option<char> = None;
+    let mut previous_upper = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        let word_start =
+            !previous.is_some_and(|p| p.is_alphabetic() || matches!(p, '\'' | '’' | 'ʼ'));
+        let latin = match lower {
+            'а' => "a",
+            'б' => "b",
+            'в' => "v",
+            // "зг" is written "zgh" to keep it apart from "ж".
+            'г' if previous == Some('з') => "gh",
+            'г' => "h",
+            'ґ' => "g",
+            'д' => "d",
+            'е' => "e",
+            'є' if word_start => "ye",
+            'є' => "ie",
+            'ж' => "zh",
+            'з' => "z",
+            'и' => "y",
+            'і' => "i",
+            'ї' if word_start => "yi",
+            'ї' => "i",
+            'й' if word_start => "y",
+            'й' => "i",
+            'к' => "k",
+            'л' => "l",
+            'м' => "m",
+            'н' => "n",
+            'о' => "o",
+            'п' => "p",
+            'р' => "r",
+            'с' => "s",
+            'т' => "t",
+            'у' => "u",
+            'ф' => "f",
+            'х' => "kh",
+            'ц' => "ts",
+            'ч' => "ch",
+            'ш' => "sh",
+            'щ' => "shch",
+            'ь' | '\'' | '’' | 'ʼ' => "",
+            'ю' if word_start => "yu",
+            'ю' => "iu",
+            'я' if word_start => "ya",
+            'я' => "ia",
+            _ => {
+                result.push(c);
+                previous = Some(lower);
+                previous_upper = c.is_uppercase();
+                continue;
+            }
+        };
+        let upper = c != lower;
+        if upper && (previous_upper || chars.peek().is_some_and(|next| next.is_uppercase())) {
+            result.push_str(&latin.to_uppercase());
+        } else if upper {
+            let mut latin = latin.chars();
+            if let Some(first) = latin.next() {
+                result.extend(first.to_uppercase());
+                result.push_str(latin.as_str());
+            }
+        } else {
+            result.push_str(latin);
+        }
+        previous = Some(lower);
+        previous_upper = upper;
+    }
+    result
+}
+
+/// File name without extension made of ASCII letters, digits, `-` and `_`,
+/// e.g. "Хандецький В.С." gives "Khandetskyi_V_S".
+pub fn safe_file_name(text: &str) -> String {
+    let mut name = String::new();
+    for c in transliterate(text).chars() {
+        if c.is_ascii_alphanumeric() || c == '-' {
+            name.push(c);
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    let name = name.trim_end_matches('_');
+    if name.is_empty() {
+        "_".to_string()
+    } else {
+        name.to_string()
+    }
+}
@@ -0,0 +1,60 @@
+/// Yearly hours of a full rate, as `EmployeeRate.minPossibleHours` and
+/// `maxPossibleHours` count them for a rate lasting the whole year.
+pub const MIN_HOURS_PER_RATE: f64 = 580.0;
+pub const MAX_HOURS_PER_RATE: f64 = 600.0;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NormDeviation {
+    Underload,
+    Overload,
+}
+
+/// Yearly `hours` of a teacher outside the norm of their rate.
+#[derive(Clone, Copy)]
+pub struct NormViolation {
+    pub deviation: NormDeviation,
+    pub hours: f64,
+    /// The bound that was crossed.
+    pub norm: f64,
+}
+
+impl NormViolation {
+    /// "Недовантаження: 540 із 580" or "Перевантаження: 620 із 600".
+    pub fn comment(&self) -> String {
+        let label = match self.deviation {
+            NormDeviation::Underload => "Недовантаження",
+            NormDeviation::Overload => "Перевантаження",
+        };
+        let hours = (self.hours * 100_f64).round() / 100_f64;
+        format!("{label}: {hours} із {}", self.norm)
+    }
+}
+
+/// Rate the yearly norm is counted for: the mean of the semester rates, so a
+/// teacher employed for one semester only is held to half the year's hours,
+/// as `minPossibleHours` scales the norm by the months worked.
+pub fn yearly_norm_rate(semester_1_rate: f64, semester_2_rate: f64) -> f64 {
+    (semester_1_rate + semester_2_rate) / 2_f64
+}
+
+/// `None` when `hours` are within the norm of `rate`, or there is no rate to
+/// take the norm from.
+pub fn check_workload_norm(rate: f64, hours: f64) -> Option<NormViolation> {
+    if rate <= 0_f64 {
+        return None;
+    }
+    let min = (MIN_HOURS_PER_RATE * rate).round();
+    let max = (MAX_HOURS_PER_RATE * rate).round();
+    let (deviation, norm) = if hours < min {
+        (NormDeviation::Underload, min)
+    } else if hours > max {
+        (NormDeviation::Overload, max)
+    } else {
+        return None;
+    };
+    Some(NormViolation {
+        deviation,
+        hours,
+        norm,
+    })
+}
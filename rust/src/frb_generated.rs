@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1127398859;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -645375997;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__excel_interface__init_app_impl(
+fn wire__crate__api__distribution_interface__add_workload_item_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__excel_interface__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "add_workload_item",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,19 +67,29 @@ fn wire__crate__api__excel_interface__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_employee_id = <String>::sse_decode(&mut deserializer);
+            let api_rate_id = <String>::sse_decode(&mut deserializer);
+            let api_item = <crate::distribution::data::UniversityForm3WorkloadItem>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::excel_interface::init_app();
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::add_workload_item(
+                        api_project,
+                        api_employee_id,
+                        api_rate_id,
+                        api_item,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__excel_interface__parse_excel_file_impl(
+fn wire__crate__api__distribution_interface__apply_form1_norms_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -87,7 +97,7 @@ fn wire__crate__api__excel_interface__parse_excel_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_excel_file",
+            debug_name: "apply_form1_norms",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -101,12 +111,14 @@ fn wire__crate__api__excel_interface__parse_excel_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_form1 =
+                <crate::distribution::data::UniversityForm1>::sse_decode(&mut deserializer);
+            let api_norms = <crate::distribution::data::NormsTable>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok(
-                        crate::api::excel_interface::parse_excel_file(api_file_path),
+                        crate::api::distribution_interface::apply_form1_norms(api_form1, api_norms),
                     )?;
                     Ok(output_ok)
                 })())
@@ -114,7 +126,7 @@ fn wire__crate__api__excel_interface__parse_excel_file_impl(
         },
     )
 }
-fn wire__crate__api__excel_interface__write_excel_file_impl(
+fn wire__crate__api__excel_interface__build_exported_data_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -122,7 +134,7 @@ fn wire__crate__api__excel_interface__write_excel_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "write_excel_file",
+            debug_name: "build_exported_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -136,958 +148,5691 @@ fn wire__crate__api__excel_interface__write_excel_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
-            let api_exported_tables =
-                <crate::excel::data::ExportedData>::sse_decode(&mut deserializer);
+            let api_input = <crate::excel::data::WorkloadInput>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::excel_interface::write_excel_file(
-                            api_file_path,
-                            api_exported_tables,
-                        );
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::excel_interface::build_exported_data(api_input),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for std::collections::HashMap<String, Vec<crate::excel::data::InputRawRow>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner =
-            <Vec<(String, Vec<crate::excel::data::InputRawRow>)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
+fn wire__crate__api__distribution_interface__build_project_exported_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "build_project_exported_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_type_name = <String>::sse_decode(&mut deserializer);
+            let api_calendar = <Option<crate::distribution::data::AcademicCalendar>>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::distribution_interface::build_project_exported_data(
+                            api_project,
+                            api_type_name,
+                            api_calendar,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__distribution_interface__carry_over_distribution_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "carry_over_distribution",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_previous =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_form1 =
+                <crate::distribution::data::UniversityForm1>::sse_decode(&mut deserializer);
+            let api_min_similarity = <Option<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::carry_over_distribution(
+                        api_previous,
+                        api_form1,
+                        api_min_similarity,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire__crate__api__distribution_interface__check_form1_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "check_form1_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file = <crate::excel::data::ParsedExcelFile>::sse_decode(&mut deserializer);
+            let api_sheet_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::distribution_interface::check_form1_file(
+                            api_file,
+                            api_sheet_name,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for crate::excel::data::ExportedData {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_year = <u32>::sse_decode(deserializer);
-        let mut var_typeName = <String>::sse_decode(deserializer);
-        let mut var_mainTable = <crate::excel::data::OutputMainTable>::sse_decode(deserializer);
-        let mut var_personalTables =
-            <Vec<crate::excel::data::OutputPersonalTables>>::sse_decode(deserializer);
-        return crate::excel::data::ExportedData {
-            year: var_year,
-            type_name: var_typeName,
-            main_table: var_mainTable,
-            personal_tables: var_personalTables,
-        };
-    }
+fn wire__crate__api__distribution_interface__check_form1_norms_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "check_form1_norms",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file = <crate::excel::data::ParsedExcelFile>::sse_decode(&mut deserializer);
+            let api_sheet_name = <String>::sse_decode(&mut deserializer);
+            let api_norms =
+                <Option<crate::distribution::data::NormsTable>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::check_form1_norms(
+                        api_file,
+                        api_sheet_name,
+                        api_norms,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for f64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
-    }
+fn wire__crate__api__distribution_interface__check_semester_coverage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "check_semester_coverage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_calendar = <Option<crate::distribution::data::AcademicCalendar>>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::check_semester_coverage(
+                        api_project,
+                        api_calendar,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for crate::excel::data::InputRawRow {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_learningForm = <String>::sse_decode(deserializer);
-        let mut var_speciality = <String>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_course = <String>::sse_decode(deserializer);
-        let mut var_semester = <String>::sse_decode(deserializer);
-        let mut var_weeksCount = <String>::sse_decode(deserializer);
-        let mut var_studentsCount = <String>::sse_decode(deserializer);
-        let mut var_flowsCount = <String>::sse_decode(deserializer);
-        let mut var_groupsCount = <String>::sse_decode(deserializer);
-        let mut var_subgroupsCount = <String>::sse_decode(deserializer);
-        let mut var_lecturesPlannedCount = <String>::sse_decode(deserializer);
-        let mut var_lecturesTotalCount = <String>::sse_decode(deserializer);
-        let mut var_practicesPlannedCount = <String>::sse_decode(deserializer);
-        let mut var_practicesTotalCount = <String>::sse_decode(deserializer);
-        let mut var_labsPlannedCount = <String>::sse_decode(deserializer);
-        let mut var_labsTotalCount = <String>::sse_decode(deserializer);
-        let mut var_exams = <String>::sse_decode(deserializer);
-        let mut var_examConsults = <String>::sse_decode(deserializer);
-        let mut var_tests = <String>::sse_decode(deserializer);
-        let mut var_qualWorks = <String>::sse_decode(deserializer);
-        let mut var_certificationExams = <String>::sse_decode(deserializer);
-        let mut var_workingPractice = <String>::sse_decode(deserializer);
-        let mut var_teachingPractice = <String>::sse_decode(deserializer);
-        let mut var_consults = <String>::sse_decode(deserializer);
-        let mut var_individualWorks = <String>::sse_decode(deserializer);
-        let mut var_individualWorkTypes = <String>::sse_decode(deserializer);
-        let mut var_courseWorks = <String>::sse_decode(deserializer);
-        let mut var_postgraduateExams = <String>::sse_decode(deserializer);
-        let mut var_supervising = <String>::sse_decode(deserializer);
-        let mut var_internship = <String>::sse_decode(deserializer);
-        return crate::excel::data::InputRawRow {
-            learning_form: var_learningForm,
-            speciality: var_speciality,
-            name: var_name,
-            course: var_course,
-            semester: var_semester,
-            weeks_count: var_weeksCount,
-            students_count: var_studentsCount,
-            flows_count: var_flowsCount,
-            groups_count: var_groupsCount,
-            subgroups_count: var_subgroupsCount,
-            lectures_planned_count: var_lecturesPlannedCount,
-            lectures_total_count: var_lecturesTotalCount,
-            practices_planned_count: var_practicesPlannedCount,
-            practices_total_count: var_practicesTotalCount,
+fn wire__crate__api__distribution_interface__default_academic_calendar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_academic_calendar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_academic_year = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::distribution_interface::default_academic_calendar(
+                            api_academic_year,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__default_norms_table_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_norms_table",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::distribution_interface::default_norms_table(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__default_workload_columns_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_workload_columns",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::excel_interface::default_workload_columns(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__diff_form1_files_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "diff_form1_files",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_old_file = <crate::excel::data::ParsedExcelFile>::sse_decode(&mut deserializer);
+            let api_new_file = <crate::excel::data::ParsedExcelFile>::sse_decode(&mut deserializer);
+            let api_sheet_name = <String>::sse_decode(&mut deserializer);
+            let api_project = <Option<crate::distribution::data::DistributionProject>>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::diff_form1_files(
+                        api_old_file,
+                        api_new_file,
+                        api_sheet_name,
+                        api_project,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__distribute_workload_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "distribute_workload",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::distribution::data::SolverOptions>::sse_decode(&mut deserializer);
+            let api_calendar = <Option<crate::distribution::data::AcademicCalendar>>::sse_decode(
+                &mut deserializer,
+            );
+            let api_supervision_hours_per_postgraduate = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::distribute_workload(
+                        api_project,
+                        api_options,
+                        api_calendar,
+                        api_supervision_hours_per_postgraduate,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__form1_from_parsed_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "form1_from_parsed_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file = <crate::excel::data::ParsedExcelFile>::sse_decode(&mut deserializer);
+            let api_sheet_name = <String>::sse_decode(&mut deserializer);
+            let api_academic_year = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::form1_from_parsed_file(
+                        api_file,
+                        api_sheet_name,
+                        api_academic_year,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__get_employee_norms_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_employee_norms",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_calendar = <Option<crate::distribution::data::AcademicCalendar>>::sse_decode(
+                &mut deserializer,
+            );
+            let api_supervision_hours_per_postgraduate = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::get_employee_norms(
+                        api_project,
+                        api_calendar,
+                        api_supervision_hours_per_postgraduate,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__get_employee_summaries_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_employee_summaries",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_calendar = <Option<crate::distribution::data::AcademicCalendar>>::sse_decode(
+                &mut deserializer,
+            );
+            let api_supervision_hours_per_postgraduate = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::get_employee_summaries(
+                        api_project,
+                        api_calendar,
+                        api_supervision_hours_per_postgraduate,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__get_undistributed_workload_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_undistributed_workload",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_key = <crate::distribution::data::DisciplineKey>::sse_decode(&mut deserializer);
+            let api_field =
+                <crate::distribution::data::WorkloadField>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::get_undistributed_workload(
+                        api_project,
+                        api_key,
+                        api_field,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::excel_interface::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__list_undistributed_workload_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_undistributed_workload",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::distribution_interface::list_undistributed_workload(
+                            api_project,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__parse_excel_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_excel_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::excel_interface::parse_excel_file(api_file_path),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__read_distribution_project_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "read_distribution_project",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::read_distribution_project(
+                        api_file_path,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__read_json_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "read_json_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::excel_interface::read_json_file(api_file_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__reconcile_form1_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reconcile_form1_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_file = <crate::excel::data::ParsedExcelFile>::sse_decode(&mut deserializer);
+            let api_sheet_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::reconcile_form1_file(
+                        api_project,
+                        api_file,
+                        api_sheet_name,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__remove_workload_item_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_workload_item",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_employee_id = <String>::sse_decode(&mut deserializer);
+            let api_rate_id = <String>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::remove_workload_item(
+                        api_project,
+                        api_employee_id,
+                        api_rate_id,
+                        api_item_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__update_workload_field_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_workload_field",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            let api_employee_id = <String>::sse_decode(&mut deserializer);
+            let api_rate_id = <String>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
+            let api_field =
+                <crate::distribution::data::WorkloadField>::sse_decode(&mut deserializer);
+            let api_value = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::update_workload_field(
+                        api_project,
+                        api_employee_id,
+                        api_rate_id,
+                        api_item_id,
+                        api_field,
+                        api_value,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__validate_distribution_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_distribution",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::distribution_interface::validate_distribution(api_project),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__validate_exported_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_exported_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_exported_tables =
+                <crate::excel::data::ExportedData>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::excel_interface::validate_exported_data(api_exported_tables),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__write_csv_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_csv_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_exported_tables =
+                <crate::excel::data::ExportedData>::sse_decode(&mut deserializer);
+            let api_options = <crate::excel::data::ExportOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::excel_interface::write_csv_file(
+                        api_file_path,
+                        api_exported_tables,
+                        api_options,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__distribution_interface__write_distribution_project_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_distribution_project",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_project =
+                <crate::distribution::data::DistributionProject>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::distribution_interface::write_distribution_project(
+                        api_file_path,
+                        api_project,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__write_excel_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_excel_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_exported_tables =
+                <crate::excel::data::ExportedData>::sse_decode(&mut deserializer);
+            let api_options = <crate::excel::data::ExportOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::excel_interface::write_excel_file(
+                        api_file_path,
+                        api_exported_tables,
+                        api_options,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__write_html_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_html_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_exported_tables =
+                <crate::excel::data::ExportedData>::sse_decode(&mut deserializer);
+            let api_options = <crate::excel::data::ExportOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::excel_interface::write_html_file(
+                        api_file_path,
+                        api_exported_tables,
+                        api_options,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__write_json_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_json_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_exported_tables =
+                <crate::excel::data::ExportedData>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::excel_interface::write_json_file(
+                        api_file_path,
+                        api_exported_tables,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__write_pdf_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_pdf_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_exported_tables =
+                <crate::excel::data::ExportedData>::sse_decode(&mut deserializer);
+            let api_options = <crate::excel::data::ExportOptions>::sse_decode(&mut deserializer);
+            let api_fonts = <crate::excel::data::PdfFonts>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::excel_interface::write_pdf_file(
+                        api_file_path,
+                        api_exported_tables,
+                        api_options,
+                        api_fonts,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__excel_interface__write_personal_files_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_personal_files",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_directory = <String>::sse_decode(&mut deserializer);
+            let api_exported_tables =
+                <crate::excel::data::ExportedData>::sse_decode(&mut deserializer);
+            let api_options = <crate::excel::data::ExportOptions>::sse_decode(&mut deserializer);
+            let api_pdf_fonts =
+                <Option<crate::excel::data::PdfFonts>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::excel_interface::write_personal_files(
+                        api_directory,
+                        api_exported_tables,
+                        api_options,
+                        api_pdf_fonts,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for std::collections::HashMap<String, Vec<crate::excel::data::InputRawRow>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner =
+            <Vec<(String, Vec<crate::excel::data::InputRawRow>)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::distribution::data::AcademicCalendar {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_firstSemesterStart = <String>::sse_decode(deserializer);
+        let mut var_firstSemesterEnd = <String>::sse_decode(deserializer);
+        let mut var_secondSemesterStart = <String>::sse_decode(deserializer);
+        let mut var_secondSemesterEnd = <String>::sse_decode(deserializer);
+        return crate::distribution::data::AcademicCalendar {
+            first_semester_start: var_firstSemesterStart,
+            first_semester_end: var_firstSemesterEnd,
+            second_semester_start: var_secondSemesterStart,
+            second_semester_end: var_secondSemesterEnd,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::AcademicSemester {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::distribution::data::AcademicSemester::First,
+            1 => crate::distribution::data::AcademicSemester::Second,
+            _ => unreachable!("Invalid variant for AcademicSemester: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::distribution::data::CarriedOverItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <crate::distribution::data::DisciplineKey>::sse_decode(deserializer);
+        let mut var_previousKey =
+            <crate::distribution::data::DisciplineKey>::sse_decode(deserializer);
+        let mut var_similarity = <f64>::sse_decode(deserializer);
+        let mut var_changedFields =
+            <Vec<crate::distribution::data::WorkloadField>>::sse_decode(deserializer);
+        return crate::distribution::data::CarriedOverItem {
+            key: var_key,
+            previous_key: var_previousKey,
+            similarity: var_similarity,
+            changed_fields: var_changedFields,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::CarryOverResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_project =
+            <crate::distribution::data::DistributionProject>::sse_decode(deserializer);
+        let mut var_newItems =
+            <Vec<crate::distribution::data::DisciplineKey>>::sse_decode(deserializer);
+        let mut var_removedItems =
+            <Vec<crate::distribution::data::DisciplineKey>>::sse_decode(deserializer);
+        let mut var_changedItems =
+            <Vec<crate::distribution::data::CarriedOverItem>>::sse_decode(deserializer);
+        return crate::distribution::data::CarryOverResult {
+            project: var_project,
+            new_items: var_newItems,
+            removed_items: var_removedItems,
+            changed_items: var_changedItems,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::DisciplineKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_learningForm =
+            <crate::distribution::data::LearningForm>::sse_decode(deserializer);
+        let mut var_specialty = <String>::sse_decode(deserializer);
+        let mut var_disciplineName = <String>::sse_decode(deserializer);
+        let mut var_course = <String>::sse_decode(deserializer);
+        let mut var_semester =
+            <crate::distribution::data::AcademicSemester>::sse_decode(deserializer);
+        return crate::distribution::data::DisciplineKey {
+            learning_form: var_learningForm,
+            specialty: var_specialty,
+            discipline_name: var_disciplineName,
+            course: var_course,
+            semester: var_semester,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::DistributionIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_employeeId = <Option<String>>::sse_decode(deserializer);
+        let mut var_itemId = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::distribution::data::DistributionIssue {
+            employee_id: var_employeeId,
+            item_id: var_itemId,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::DistributionProject {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_universityForm1 =
+            <crate::distribution::data::UniversityForm1>::sse_decode(deserializer);
+        let mut var_universityForm3 =
+            <crate::distribution::data::UniversityForm3>::sse_decode(deserializer);
+        let mut var_createdAt = <String>::sse_decode(deserializer);
+        let mut var_updatedAt = <String>::sse_decode(deserializer);
+        return crate::distribution::data::DistributionProject {
+            id: var_id,
+            title: var_title,
+            university_form1: var_universityForm1,
+            university_form3: var_universityForm3,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::Employee {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_firstName = <String>::sse_decode(deserializer);
+        let mut var_lastName = <String>::sse_decode(deserializer);
+        let mut var_patronymic = <String>::sse_decode(deserializer);
+        let mut var_rank = <crate::distribution::data::EmployeeRank>::sse_decode(deserializer);
+        let mut var_rates =
+            <Vec<crate::distribution::data::EmployeeRate>>::sse_decode(deserializer);
+        return crate::distribution::data::Employee {
+            id: var_id,
+            first_name: var_firstName,
+            last_name: var_lastName,
+            patronymic: var_patronymic,
+            rank: var_rank,
+            rates: var_rates,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::EmployeeNorms {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_employeeId = <String>::sse_decode(deserializer);
+        let mut var_fullName = <String>::sse_decode(deserializer);
+        let mut var_peakRate = <f64>::sse_decode(deserializer);
+        let mut var_rates = <Vec<crate::distribution::data::RateNorms>>::sse_decode(deserializer);
+        let mut var_firstSemester =
+            <crate::distribution::data::PeriodNorm>::sse_decode(deserializer);
+        let mut var_secondSemester =
+            <crate::distribution::data::PeriodNorm>::sse_decode(deserializer);
+        let mut var_year = <crate::distribution::data::PeriodNorm>::sse_decode(deserializer);
+        return crate::distribution::data::EmployeeNorms {
+            employee_id: var_employeeId,
+            full_name: var_fullName,
+            peak_rate: var_peakRate,
+            rates: var_rates,
+            first_semester: var_firstSemester,
+            second_semester: var_secondSemester,
+            year: var_year,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::EmployeePreferences {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_employeeId = <String>::sse_decode(deserializer);
+        let mut var_disciplines = <Vec<String>>::sse_decode(deserializer);
+        return crate::distribution::data::EmployeePreferences {
+            employee_id: var_employeeId,
+            disciplines: var_disciplines,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::EmployeeRank {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::distribution::data::EmployeeRank::Head,
+            1 => crate::distribution::data::EmployeeRank::Professor,
+            2 => crate::distribution::data::EmployeeRank::Associate,
+            3 => crate::distribution::data::EmployeeRank::SeniorLecturer,
+            4 => crate::distribution::data::EmployeeRank::Assistant,
+            _ => unreachable!("Invalid variant for EmployeeRank: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::EmployeeRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_rateValue = <f64>::sse_decode(deserializer);
+        let mut var_dateStart = <String>::sse_decode(deserializer);
+        let mut var_dateEnd = <String>::sse_decode(deserializer);
+        let mut var_postgraduateCount = <u32>::sse_decode(deserializer);
+        let mut var_workloadItems =
+            <Vec<crate::distribution::data::UniversityForm3WorkloadItem>>::sse_decode(deserializer);
+        return crate::distribution::data::EmployeeRate {
+            id: var_id,
+            rate_value: var_rateValue,
+            date_start: var_dateStart,
+            date_end: var_dateEnd,
+            postgraduate_count: var_postgraduateCount,
+            workload_items: var_workloadItems,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::EmployeeWorkloadInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_firstName = <String>::sse_decode(deserializer);
+        let mut var_middleName = <String>::sse_decode(deserializer);
+        let mut var_lastName = <String>::sse_decode(deserializer);
+        let mut var_rank = <String>::sse_decode(deserializer);
+        let mut var_commentSemester1 = <String>::sse_decode(deserializer);
+        let mut var_commentSemester2 = <String>::sse_decode(deserializer);
+        let mut var_semester1 =
+            <Vec<crate::excel::data::OutputPersonalSemesterRow>>::sse_decode(deserializer);
+        let mut var_semester1Rate = <f64>::sse_decode(deserializer);
+        let mut var_semester2 =
+            <Vec<crate::excel::data::OutputPersonalSemesterRow>>::sse_decode(deserializer);
+        let mut var_semester2Rate = <f64>::sse_decode(deserializer);
+        return crate::excel::data::EmployeeWorkloadInput {
+            id: var_id,
+            first_name: var_firstName,
+            middle_name: var_middleName,
+            last_name: var_lastName,
+            rank: var_rank,
+            comment_semester_1: var_commentSemester1,
+            comment_semester_2: var_commentSemester2,
+            semester_1: var_semester1,
+            semester_1_rate: var_semester1Rate,
+            semester_2: var_semester2,
+            semester_2_rate: var_semester2Rate,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::EmployeeWorkloadSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_employeeId = <String>::sse_decode(deserializer);
+        let mut var_fullName = <String>::sse_decode(deserializer);
+        let mut var_hours = <f64>::sse_decode(deserializer);
+        let mut var_minPossibleHours = <f64>::sse_decode(deserializer);
+        let mut var_maxPossibleHours = <f64>::sse_decode(deserializer);
+        return crate::distribution::data::EmployeeWorkloadSummary {
+            employee_id: var_employeeId,
+            full_name: var_fullName,
+            hours: var_hours,
+            min_possible_hours: var_minPossibleHours,
+            max_possible_hours: var_maxPossibleHours,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::ExportOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hideEmptyColumns = <bool>::sse_decode(deserializer);
+        let mut var_columns =
+            <Option<Vec<crate::excel::data::WorkloadColumn>>>::sse_decode(deserializer);
+        let mut var_theme = <Option<crate::excel::data::ExportTheme>>::sse_decode(deserializer);
+        let mut var_highlightWorkloadNorms = <bool>::sse_decode(deserializer);
+        let mut var_includeAnalytics = <bool>::sse_decode(deserializer);
+        return crate::excel::data::ExportOptions {
+            hide_empty_columns: var_hideEmptyColumns,
+            columns: var_columns,
+            theme: var_theme,
+            highlight_workload_norms: var_highlightWorkloadNorms,
+            include_analytics: var_includeAnalytics,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::ExportTheme {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fontName = <String>::sse_decode(deserializer);
+        let mut var_fontSize = <f64>::sse_decode(deserializer);
+        return crate::excel::data::ExportTheme {
+            font_name: var_fontName,
+            font_size: var_fontSize,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::ExportedData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_year = <u32>::sse_decode(deserializer);
+        let mut var_typeName = <String>::sse_decode(deserializer);
+        let mut var_mainTable = <crate::excel::data::OutputMainTable>::sse_decode(deserializer);
+        let mut var_personalTables =
+            <Vec<crate::excel::data::OutputPersonalTables>>::sse_decode(deserializer);
+        return crate::excel::data::ExportedData {
+            year: var_year,
+            type_name: var_typeName,
+            main_table: var_mainTable,
+            personal_tables: var_personalTables,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::distribution::data::FieldChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <crate::distribution::data::WorkloadField>::sse_decode(deserializer);
+        let mut var_oldValue = <f64>::sse_decode(deserializer);
+        let mut var_newValue = <f64>::sse_decode(deserializer);
+        return crate::distribution::data::FieldChange {
+            field: var_field,
+            old_value: var_oldValue,
+            new_value: var_newValue,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::FieldRankRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <crate::distribution::data::WorkloadField>::sse_decode(deserializer);
+        let mut var_minRank = <crate::distribution::data::EmployeeRank>::sse_decode(deserializer);
+        return crate::distribution::data::FieldRankRule {
+            field: var_field,
+            min_rank: var_minRank,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::Form1Diff {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_added =
+            <Vec<crate::distribution::data::Form1DiffEntry>>::sse_decode(deserializer);
+        let mut var_removed =
+            <Vec<crate::distribution::data::Form1DiffEntry>>::sse_decode(deserializer);
+        let mut var_changed =
+            <Vec<crate::distribution::data::Form1DiffEntry>>::sse_decode(deserializer);
+        return crate::distribution::data::Form1Diff {
+            added: var_added,
+            removed: var_removed,
+            changed: var_changed,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::Form1DiffEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <crate::distribution::data::DisciplineKey>::sse_decode(deserializer);
+        let mut var_changes =
+            <Vec<crate::distribution::data::FieldChange>>::sse_decode(deserializer);
+        let mut var_affectedEmployees = <Vec<String>>::sse_decode(deserializer);
+        return crate::distribution::data::Form1DiffEntry {
+            key: var_key,
+            changes: var_changes,
+            affected_employees: var_affectedEmployees,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::Form1Finding {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_row = <u32>::sse_decode(deserializer);
+        let mut var_kind = <crate::distribution::data::Form1FindingKind>::sse_decode(deserializer);
+        let mut var_key =
+            <Option<crate::distribution::data::DisciplineKey>>::sse_decode(deserializer);
+        let mut var_field =
+            <Option<crate::distribution::data::WorkloadField>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::distribution::data::Form1Finding {
+            row: var_row,
+            kind: var_kind,
+            key: var_key,
+            field: var_field,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::Form1FindingKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::distribution::data::Form1FindingKind::UnknownLearningForm,
+            1 => crate::distribution::data::Form1FindingKind::UnreadableValue,
+            2 => crate::distribution::data::Form1FindingKind::NegativeValue,
+            3 => crate::distribution::data::Form1FindingKind::TotalMismatch,
+            4 => crate::distribution::data::Form1FindingKind::SemesterMismatch,
+            5 => crate::distribution::data::Form1FindingKind::DuplicateKey,
+            _ => unreachable!("Invalid variant for Form1FindingKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::excel::data::InputRawRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_learningForm = <String>::sse_decode(deserializer);
+        let mut var_speciality = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_course = <String>::sse_decode(deserializer);
+        let mut var_semester = <String>::sse_decode(deserializer);
+        let mut var_weeksCount = <String>::sse_decode(deserializer);
+        let mut var_studentsCount = <String>::sse_decode(deserializer);
+        let mut var_flowsCount = <String>::sse_decode(deserializer);
+        let mut var_groupsCount = <String>::sse_decode(deserializer);
+        let mut var_subgroupsCount = <String>::sse_decode(deserializer);
+        let mut var_lecturesPlannedCount = <String>::sse_decode(deserializer);
+        let mut var_lecturesTotalCount = <String>::sse_decode(deserializer);
+        let mut var_practicesPlannedCount = <String>::sse_decode(deserializer);
+        let mut var_practicesTotalCount = <String>::sse_decode(deserializer);
+        let mut var_labsPlannedCount = <String>::sse_decode(deserializer);
+        let mut var_labsTotalCount = <String>::sse_decode(deserializer);
+        let mut var_exams = <String>::sse_decode(deserializer);
+        let mut var_examConsults = <String>::sse_decode(deserializer);
+        let mut var_tests = <String>::sse_decode(deserializer);
+        let mut var_qualWorks = <String>::sse_decode(deserializer);
+        let mut var_certificationExams = <String>::sse_decode(deserializer);
+        let mut var_workingPractice = <String>::sse_decode(deserializer);
+        let mut var_teachingPractice = <String>::sse_decode(deserializer);
+        let mut var_consults = <String>::sse_decode(deserializer);
+        let mut var_individualWorks = <String>::sse_decode(deserializer);
+        let mut var_individualWorkTypes = <String>::sse_decode(deserializer);
+        let mut var_courseWorks = <String>::sse_decode(deserializer);
+        let mut var_postgraduateExams = <String>::sse_decode(deserializer);
+        let mut var_supervising = <String>::sse_decode(deserializer);
+        let mut var_internship = <String>::sse_decode(deserializer);
+        return crate::excel::data::InputRawRow {
+            learning_form: var_learningForm,
+            speciality: var_speciality,
+            name: var_name,
+            course: var_course,
+            semester: var_semester,
+            weeks_count: var_weeksCount,
+            students_count: var_studentsCount,
+            flows_count: var_flowsCount,
+            groups_count: var_groupsCount,
+            subgroups_count: var_subgroupsCount,
+            lectures_planned_count: var_lecturesPlannedCount,
+            lectures_total_count: var_lecturesTotalCount,
+            practices_planned_count: var_practicesPlannedCount,
+            practices_total_count: var_practicesTotalCount,
             labs_planned_count: var_labsPlannedCount,
             labs_total_count: var_labsTotalCount,
             exams: var_exams,
             exam_consults: var_examConsults,
             tests: var_tests,
-            qual_works: var_qualWorks,
+            qual_works: var_qualWorks,
+            certification_exams: var_certificationExams,
+            working_practice: var_workingPractice,
+            teaching_practice: var_teachingPractice,
+            consults: var_consults,
+            individual_works: var_individualWorks,
+            individual_work_types: var_individualWorkTypes,
+            course_works: var_courseWorks,
+            postgraduate_exams: var_postgraduateExams,
+            supervising: var_supervising,
+            internship: var_internship,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::LearningForm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::distribution::data::LearningForm::Daytime,
+            1 => crate::distribution::data::LearningForm::Evening,
+            2 => crate::distribution::data::LearningForm::Correspondence,
+            _ => unreachable!("Invalid variant for LearningForm: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::LearningFormTotal {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_learningForm = <String>::sse_decode(deserializer);
+        let mut var_hours = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
+        return crate::excel::data::LearningFormTotal {
+            learning_form: var_learningForm,
+            hours: var_hours,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::CarriedOverItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::CarriedOverItem>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::DisciplineKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::DisciplineKey>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::DistributionIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::DistributionIssue>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::Employee> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::Employee>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::EmployeeNorms> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::EmployeeNorms>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::EmployeePreferences> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::EmployeePreferences>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::EmployeeRate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::EmployeeRate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::EmployeeWorkloadInput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::EmployeeWorkloadInput>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::EmployeeWorkloadSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::distribution::data::EmployeeWorkloadSummary>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::FieldChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::FieldChange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::FieldRankRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::FieldRankRule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::Form1DiffEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::Form1DiffEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::Form1Finding> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::Form1Finding>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::InputRawRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::InputRawRow>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::LearningFormTotal> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::LearningFormTotal>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::MainTableSection> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::MainTableSection>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::NormFinding> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::NormFinding>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::NormRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::NormRule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::OutputMainWorkerRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::OutputMainWorkerRow>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::OutputPersonalSemesterRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::OutputPersonalSemesterRow>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::OutputPersonalTable> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::OutputPersonalTable>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::OutputPersonalTables> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::OutputPersonalTables>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::PersonalSheetName> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::PersonalSheetName>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::RateNorms> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::RateNorms>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::ReconcileConflict> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::ReconcileConflict>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, Vec<crate::excel::data::InputRawRow>)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, Vec<crate::excel::data::InputRawRow>)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::TotalsMismatch> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::TotalsMismatch>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::UndistributedWorkload> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::UndistributedWorkload>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::UniversityForm1WorkloadItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::distribution::data::UniversityForm1WorkloadItem>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::UniversityForm3WorkloadItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::distribution::data::UniversityForm3WorkloadItem>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::WorkloadColumn> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::WorkloadColumn>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::distribution::data::WorkloadField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::distribution::data::WorkloadField>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::excel::data::WorkloadSectionInput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::excel::data::WorkloadSectionInput>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::excel::data::MainTableSection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_rows = <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_decode(deserializer);
+        let mut var_total = <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
+        return crate::excel::data::MainTableSection {
+            title: var_title,
+            rows: var_rows,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::NormBasis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::distribution::data::NormBasis::Students,
+            1 => crate::distribution::data::NormBasis::Flows,
+            2 => crate::distribution::data::NormBasis::Groups,
+            3 => crate::distribution::data::NormBasis::Subgroups,
+            4 => crate::distribution::data::NormBasis::Weeks,
+            _ => unreachable!("Invalid variant for NormBasis: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::NormFinding {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_row = <u32>::sse_decode(deserializer);
+        let mut var_key = <crate::distribution::data::DisciplineKey>::sse_decode(deserializer);
+        let mut var_field = <crate::distribution::data::WorkloadField>::sse_decode(deserializer);
+        let mut var_fileValue = <f64>::sse_decode(deserializer);
+        let mut var_normValue = <f64>::sse_decode(deserializer);
+        return crate::distribution::data::NormFinding {
+            row: var_row,
+            key: var_key,
+            field: var_field,
+            file_value: var_fileValue,
+            norm_value: var_normValue,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::NormRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <crate::distribution::data::WorkloadField>::sse_decode(deserializer);
+        let mut var_basis = <crate::distribution::data::NormBasis>::sse_decode(deserializer);
+        let mut var_hoursPerUnit = <f64>::sse_decode(deserializer);
+        return crate::distribution::data::NormRule {
+            field: var_field,
+            basis: var_basis,
+            hours_per_unit: var_hoursPerUnit,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::NormsTable {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rules = <Vec<crate::distribution::data::NormRule>>::sse_decode(deserializer);
+        let mut var_tolerance = <f64>::sse_decode(deserializer);
+        return crate::distribution::data::NormsTable {
+            rules: var_rules,
+            tolerance: var_tolerance,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::distribution::data::AcademicCalendar> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::distribution::data::AcademicCalendar>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::distribution::data::DisciplineKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::distribution::data::DisciplineKey>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::distribution::data::DistributionProject> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::distribution::data::DistributionProject>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::excel::data::ExportTheme> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::excel::data::ExportTheme>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::distribution::data::NormsTable> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::distribution::data::NormsTable>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::excel::data::PdfFonts> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::excel::data::PdfFonts>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::distribution::data::WorkloadField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::distribution::data::WorkloadField>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::excel::data::WorkloadColumn>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::excel::data::WorkloadColumn>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::excel::data::OutputHoursRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_lectures = <f64>::sse_decode(deserializer);
+        let mut var_practices = <f64>::sse_decode(deserializer);
+        let mut var_labs = <f64>::sse_decode(deserializer);
+        let mut var_exams = <f64>::sse_decode(deserializer);
+        let mut var_examConsults = <f64>::sse_decode(deserializer);
+        let mut var_tests = <f64>::sse_decode(deserializer);
+        let mut var_qualWorks = <f64>::sse_decode(deserializer);
+        let mut var_certificationExams = <f64>::sse_decode(deserializer);
+        let mut var_workingPractice = <f64>::sse_decode(deserializer);
+        let mut var_teachingPractice = <f64>::sse_decode(deserializer);
+        let mut var_consults = <f64>::sse_decode(deserializer);
+        let mut var_individualWorks = <f64>::sse_decode(deserializer);
+        let mut var_courseWorks = <f64>::sse_decode(deserializer);
+        let mut var_postgraduateExams = <f64>::sse_decode(deserializer);
+        let mut var_supervising = <f64>::sse_decode(deserializer);
+        let mut var_internship = <f64>::sse_decode(deserializer);
+        let mut var_total = <f64>::sse_decode(deserializer);
+        return crate::excel::data::OutputHoursRow {
+            lectures: var_lectures,
+            practices: var_practices,
+            labs: var_labs,
+            exams: var_exams,
+            exam_consults: var_examConsults,
+            tests: var_tests,
+            qual_works: var_qualWorks,
+            certification_exams: var_certificationExams,
+            working_practice: var_workingPractice,
+            teaching_practice: var_teachingPractice,
+            consults: var_consults,
+            individual_works: var_individualWorks,
+            course_works: var_courseWorks,
+            postgraduate_exams: var_postgraduateExams,
+            supervising: var_supervising,
+            internship: var_internship,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::OutputMainRateAndHours {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rate = <f64>::sse_decode(deserializer);
+        let mut var_semester1 = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
+        let mut var_semester2 = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
+        let mut var_year = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
+        return crate::excel::data::OutputMainRateAndHours {
+            rate: var_rate,
+            semester_1: var_semester1,
+            semester_2: var_semester2,
+            year: var_year,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::OutputMainTable {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sections =
+            <Vec<crate::excel::data::MainTableSection>>::sse_decode(deserializer);
+        let mut var_total = <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
+        return crate::excel::data::OutputMainTable {
+            sections: var_sections,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::OutputMainWorkerRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_firstName = <String>::sse_decode(deserializer);
+        let mut var_middleName = <String>::sse_decode(deserializer);
+        let mut var_lastName = <String>::sse_decode(deserializer);
+        let mut var_rank = <String>::sse_decode(deserializer);
+        let mut var_rateAndHours =
+            <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
+        return crate::excel::data::OutputMainWorkerRow {
+            first_name: var_firstName,
+            middle_name: var_middleName,
+            last_name: var_lastName,
+            rank: var_rank,
+            rate_and_hours: var_rateAndHours,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::OutputPersonalSemesterRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_learningForm = <String>::sse_decode(deserializer);
+        let mut var_speciality = <String>::sse_decode(deserializer);
+        let mut var_group = <String>::sse_decode(deserializer);
+        let mut var_course = <String>::sse_decode(deserializer);
+        let mut var_studentsCount = <u32>::sse_decode(deserializer);
+        let mut var_individualWorkTypes = <String>::sse_decode(deserializer);
+        let mut var_hours = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
+        let mut var_mergeLecturesWithNext = <bool>::sse_decode(deserializer);
+        return crate::excel::data::OutputPersonalSemesterRow {
+            name: var_name,
+            learning_form: var_learningForm,
+            speciality: var_speciality,
+            group: var_group,
+            course: var_course,
+            students_count: var_studentsCount,
+            individual_work_types: var_individualWorkTypes,
+            hours: var_hours,
+            merge_lectures_with_next: var_mergeLecturesWithNext,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::OutputPersonalTable {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_firstName = <String>::sse_decode(deserializer);
+        let mut var_middleName = <String>::sse_decode(deserializer);
+        let mut var_lastName = <String>::sse_decode(deserializer);
+        let mut var_commentSemester1 = <String>::sse_decode(deserializer);
+        let mut var_commentSemester2 = <String>::sse_decode(deserializer);
+        let mut var_rank = <String>::sse_decode(deserializer);
+        let mut var_semester1 =
+            <Vec<crate::excel::data::OutputPersonalSemesterRow>>::sse_decode(deserializer);
+        let mut var_semester1Rate = <f64>::sse_decode(deserializer);
+        let mut var_semester1FormTotals =
+            <Vec<crate::excel::data::LearningFormTotal>>::sse_decode(deserializer);
+        let mut var_semester1Total = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
+        let mut var_semester2 =
+            <Vec<crate::excel::data::OutputPersonalSemesterRow>>::sse_decode(deserializer);
+        let mut var_semester2Rate = <f64>::sse_decode(deserializer);
+        let mut var_semester2FormTotals =
+            <Vec<crate::excel::data::LearningFormTotal>>::sse_decode(deserializer);
+        let mut var_semester2Total = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
+        let mut var_yearTotal = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
+        return crate::excel::data::OutputPersonalTable {
+            id: var_id,
+            first_name: var_firstName,
+            middle_name: var_middleName,
+            last_name: var_lastName,
+            comment_semester_1: var_commentSemester1,
+            comment_semester_2: var_commentSemester2,
+            rank: var_rank,
+            semester_1: var_semester1,
+            semester_1_rate: var_semester1Rate,
+            semester_1_form_totals: var_semester1FormTotals,
+            semester_1_total: var_semester1Total,
+            semester_2: var_semester2,
+            semester_2_rate: var_semester2Rate,
+            semester_2_form_totals: var_semester2FormTotals,
+            semester_2_total: var_semester2Total,
+            year_total: var_yearTotal,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::OutputPersonalTables {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_workerLastName = <String>::sse_decode(deserializer);
+        let mut var_tables =
+            <Vec<crate::excel::data::OutputPersonalTable>>::sse_decode(deserializer);
+        return crate::excel::data::OutputPersonalTables {
+            worker_last_name: var_workerLastName,
+            tables: var_tables,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::ParsedExcelFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data =
+            <std::collections::HashMap<String, Vec<crate::excel::data::InputRawRow>>>::sse_decode(
+                deserializer,
+            );
+        return crate::excel::data::ParsedExcelFile { data: var_data };
+    }
+}
+
+impl SseDecode for crate::excel::data::PdfFonts {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_regular = <String>::sse_decode(deserializer);
+        let mut var_bold = <Option<String>>::sse_decode(deserializer);
+        let mut var_italic = <Option<String>>::sse_decode(deserializer);
+        let mut var_boldItalic = <Option<String>>::sse_decode(deserializer);
+        return crate::excel::data::PdfFonts {
+            regular: var_regular,
+            bold: var_bold,
+            italic: var_italic,
+            bold_italic: var_boldItalic,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::PeriodNorm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rateMonths = <f64>::sse_decode(deserializer);
+        let mut var_supervisionHours = <f64>::sse_decode(deserializer);
+        let mut var_minHours = <f64>::sse_decode(deserializer);
+        let mut var_maxHours = <f64>::sse_decode(deserializer);
+        let mut var_hours = <f64>::sse_decode(deserializer);
+        return crate::distribution::data::PeriodNorm {
+            rate_months: var_rateMonths,
+            supervision_hours: var_supervisionHours,
+            min_hours: var_minHours,
+            max_hours: var_maxHours,
+            hours: var_hours,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::PersonalSheetName {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_workerLastName = <String>::sse_decode(deserializer);
+        let mut var_tableIds = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_sheetName = <String>::sse_decode(deserializer);
+        return crate::excel::data::PersonalSheetName {
+            worker_last_name: var_workerLastName,
+            table_ids: var_tableIds,
+            sheet_name: var_sheetName,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::RateNorms {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rateId = <String>::sse_decode(deserializer);
+        let mut var_rateValue = <f64>::sse_decode(deserializer);
+        let mut var_months = <f64>::sse_decode(deserializer);
+        let mut var_firstSemester =
+            <crate::distribution::data::PeriodNorm>::sse_decode(deserializer);
+        let mut var_secondSemester =
+            <crate::distribution::data::PeriodNorm>::sse_decode(deserializer);
+        let mut var_year = <crate::distribution::data::PeriodNorm>::sse_decode(deserializer);
+        return crate::distribution::data::RateNorms {
+            rate_id: var_rateId,
+            rate_value: var_rateValue,
+            months: var_months,
+            first_semester: var_firstSemester,
+            second_semester: var_secondSemester,
+            year: var_year,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::ReconcileConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::distribution::data::ReconcileConflictKind>::sse_decode(deserializer);
+        let mut var_key = <crate::distribution::data::DisciplineKey>::sse_decode(deserializer);
+        let mut var_field =
+            <Option<crate::distribution::data::WorkloadField>>::sse_decode(deserializer);
+        let mut var_total = <f64>::sse_decode(deserializer);
+        let mut var_distributed = <f64>::sse_decode(deserializer);
+        let mut var_employees = <Vec<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::distribution::data::ReconcileConflict {
+            kind: var_kind,
+            key: var_key,
+            field: var_field,
+            total: var_total,
+            distributed: var_distributed,
+            employees: var_employees,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::ReconcileConflictKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::distribution::data::ReconcileConflictKind::VanishedDiscipline,
+            1 => crate::distribution::data::ReconcileConflictKind::OverDistributed,
+            _ => unreachable!("Invalid variant for ReconcileConflictKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::ReconcileResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_project =
+            <crate::distribution::data::DistributionProject>::sse_decode(deserializer);
+        let mut var_diff = <crate::distribution::data::Form1Diff>::sse_decode(deserializer);
+        let mut var_conflicts =
+            <Vec<crate::distribution::data::ReconcileConflict>>::sse_decode(deserializer);
+        return crate::distribution::data::ReconcileResult {
+            project: var_project,
+            diff: var_diff,
+            conflicts: var_conflicts,
+        };
+    }
+}
+
+impl SseDecode for (String, Vec<crate::excel::data::InputRawRow>) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <Vec<crate::excel::data::InputRawRow>>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::distribution::data::SolverOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_preferences =
+            <Vec<crate::distribution::data::EmployeePreferences>>::sse_decode(deserializer);
+        let mut var_rankRules =
+            <Vec<crate::distribution::data::FieldRankRule>>::sse_decode(deserializer);
+        return crate::distribution::data::SolverOptions {
+            preferences: var_preferences,
+            rank_rules: var_rankRules,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::SolverResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_project =
+            <crate::distribution::data::DistributionProject>::sse_decode(deserializer);
+        let mut var_unplaced =
+            <Vec<crate::distribution::data::UndistributedWorkload>>::sse_decode(deserializer);
+        let mut var_splitItems =
+            <Vec<crate::distribution::data::DisciplineKey>>::sse_decode(deserializer);
+        let mut var_employees =
+            <Vec<crate::distribution::data::EmployeeWorkloadSummary>>::sse_decode(deserializer);
+        return crate::distribution::data::SolverResult {
+            project: var_project,
+            unplaced: var_unplaced,
+            split_items: var_splitItems,
+            employees: var_employees,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::TotalsMismatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_location = <String>::sse_decode(deserializer);
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_expected = <f64>::sse_decode(deserializer);
+        let mut var_actual = <f64>::sse_decode(deserializer);
+        return crate::excel::data::TotalsMismatch {
+            location: var_location,
+            field: var_field,
+            expected: var_expected,
+            actual: var_actual,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for crate::distribution::data::UndistributedWorkload {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_workloadKey =
+            <crate::distribution::data::DisciplineKey>::sse_decode(deserializer);
+        let mut var_field = <crate::distribution::data::WorkloadField>::sse_decode(deserializer);
+        let mut var_total = <f64>::sse_decode(deserializer);
+        let mut var_distributed = <f64>::sse_decode(deserializer);
+        let mut var_undistributed = <f64>::sse_decode(deserializer);
+        return crate::distribution::data::UndistributedWorkload {
+            workload_key: var_workloadKey,
+            field: var_field,
+            total: var_total,
+            distributed: var_distributed,
+            undistributed: var_undistributed,
+        };
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::distribution::data::UniversityForm1 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_academicYear = <u32>::sse_decode(deserializer);
+        let mut var_workloadItems =
+            <Vec<crate::distribution::data::UniversityForm1WorkloadItem>>::sse_decode(deserializer);
+        return crate::distribution::data::UniversityForm1 {
+            id: var_id,
+            academic_year: var_academicYear,
+            workload_items: var_workloadItems,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::UniversityForm1WorkloadItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_workloadKey =
+            <crate::distribution::data::DisciplineKey>::sse_decode(deserializer);
+        let mut var_weekCount = <f64>::sse_decode(deserializer);
+        let mut var_studentCount = <u32>::sse_decode(deserializer);
+        let mut var_flowCount = <f64>::sse_decode(deserializer);
+        let mut var_groupCount = <f64>::sse_decode(deserializer);
+        let mut var_subgroupCount = <f64>::sse_decode(deserializer);
+        let mut var_lecturesPlanned = <f64>::sse_decode(deserializer);
+        let mut var_lecturesTotal = <f64>::sse_decode(deserializer);
+        let mut var_practicesPlanned = <f64>::sse_decode(deserializer);
+        let mut var_practicesTotal = <f64>::sse_decode(deserializer);
+        let mut var_labsPlanned = <f64>::sse_decode(deserializer);
+        let mut var_labsTotal = <f64>::sse_decode(deserializer);
+        let mut var_exams = <f64>::sse_decode(deserializer);
+        let mut var_examConsults = <f64>::sse_decode(deserializer);
+        let mut var_tests = <f64>::sse_decode(deserializer);
+        let mut var_qualificationWorks = <f64>::sse_decode(deserializer);
+        let mut var_certificationExams = <f64>::sse_decode(deserializer);
+        let mut var_productionPractices = <f64>::sse_decode(deserializer);
+        let mut var_teachingPractices = <f64>::sse_decode(deserializer);
+        let mut var_currentConsults = <f64>::sse_decode(deserializer);
+        let mut var_individualWorks = <f64>::sse_decode(deserializer);
+        let mut var_courseWorks = <f64>::sse_decode(deserializer);
+        let mut var_postgraduateExams = <f64>::sse_decode(deserializer);
+        return crate::distribution::data::UniversityForm1WorkloadItem {
+            id: var_id,
+            workload_key: var_workloadKey,
+            week_count: var_weekCount,
+            student_count: var_studentCount,
+            flow_count: var_flowCount,
+            group_count: var_groupCount,
+            subgroup_count: var_subgroupCount,
+            lectures_planned: var_lecturesPlanned,
+            lectures_total: var_lecturesTotal,
+            practices_planned: var_practicesPlanned,
+            practices_total: var_practicesTotal,
+            labs_planned: var_labsPlanned,
+            labs_total: var_labsTotal,
+            exams: var_exams,
+            exam_consults: var_examConsults,
+            tests: var_tests,
+            qualification_works: var_qualificationWorks,
             certification_exams: var_certificationExams,
-            working_practice: var_workingPractice,
-            teaching_practice: var_teachingPractice,
-            consults: var_consults,
+            production_practices: var_productionPractices,
+            teaching_practices: var_teachingPractices,
+            current_consults: var_currentConsults,
             individual_works: var_individualWorks,
-            individual_work_types: var_individualWorkTypes,
             course_works: var_courseWorks,
             postgraduate_exams: var_postgraduateExams,
-            supervising: var_supervising,
-            internship: var_internship,
         };
     }
 }
 
-impl SseDecode for Vec<crate::excel::data::InputRawRow> {
+impl SseDecode for crate::distribution::data::UniversityForm3 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_academicYear = <u32>::sse_decode(deserializer);
+        let mut var_employees =
+            <Vec<crate::distribution::data::Employee>>::sse_decode(deserializer);
+        return crate::distribution::data::UniversityForm3 {
+            id: var_id,
+            academic_year: var_academicYear,
+            employees: var_employees,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::UniversityForm3WorkloadItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_workloadKey =
+            <crate::distribution::data::DisciplineKey>::sse_decode(deserializer);
+        let mut var_studentCount = <u32>::sse_decode(deserializer);
+        let mut var_academicGroups = <Vec<String>>::sse_decode(deserializer);
+        let mut var_lectures = <f64>::sse_decode(deserializer);
+        let mut var_practices = <f64>::sse_decode(deserializer);
+        let mut var_labs = <f64>::sse_decode(deserializer);
+        let mut var_exams = <f64>::sse_decode(deserializer);
+        let mut var_examConsults = <f64>::sse_decode(deserializer);
+        let mut var_tests = <f64>::sse_decode(deserializer);
+        let mut var_qualificationWorks = <f64>::sse_decode(deserializer);
+        let mut var_certificationExams = <f64>::sse_decode(deserializer);
+        let mut var_productionPractices = <f64>::sse_decode(deserializer);
+        let mut var_teachingPractices = <f64>::sse_decode(deserializer);
+        let mut var_currentConsults = <f64>::sse_decode(deserializer);
+        let mut var_individualWorks = <f64>::sse_decode(deserializer);
+        let mut var_courseWorks = <f64>::sse_decode(deserializer);
+        let mut var_postgraduateExams = <f64>::sse_decode(deserializer);
+        return crate::distribution::data::UniversityForm3WorkloadItem {
+            id: var_id,
+            workload_key: var_workloadKey,
+            student_count: var_studentCount,
+            academic_groups: var_academicGroups,
+            lectures: var_lectures,
+            practices: var_practices,
+            labs: var_labs,
+            exams: var_exams,
+            exam_consults: var_examConsults,
+            tests: var_tests,
+            qualification_works: var_qualificationWorks,
+            certification_exams: var_certificationExams,
+            production_practices: var_productionPractices,
+            teaching_practices: var_teachingPractices,
+            current_consults: var_currentConsults,
+            individual_works: var_individualWorks,
+            course_works: var_courseWorks,
+            postgraduate_exams: var_postgraduateExams,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::WorkloadColumn {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <crate::excel::data::WorkloadKey>::sse_decode(deserializer);
+        let mut var_caption = <String>::sse_decode(deserializer);
+        let mut var_width = <f64>::sse_decode(deserializer);
+        let mut var_numberFormat = <String>::sse_decode(deserializer);
+        let mut var_includeInTotal = <bool>::sse_decode(deserializer);
+        return crate::excel::data::WorkloadColumn {
+            key: var_key,
+            caption: var_caption,
+            width: var_width,
+            number_format: var_numberFormat,
+            include_in_total: var_includeInTotal,
+        };
+    }
+}
+
+impl SseDecode for crate::distribution::data::WorkloadField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::distribution::data::WorkloadField::StudentCount,
+            1 => crate::distribution::data::WorkloadField::Lectures,
+            2 => crate::distribution::data::WorkloadField::Practices,
+            3 => crate::distribution::data::WorkloadField::Labs,
+            4 => crate::distribution::data::WorkloadField::Exams,
+            5 => crate::distribution::data::WorkloadField::ExamConsults,
+            6 => crate::distribution::data::WorkloadField::Tests,
+            7 => crate::distribution::data::WorkloadField::QualificationWorks,
+            8 => crate::distribution::data::WorkloadField::CertificationExams,
+            9 => crate::distribution::data::WorkloadField::ProductionPractices,
+            10 => crate::distribution::data::WorkloadField::TeachingPractices,
+            11 => crate::distribution::data::WorkloadField::CurrentConsults,
+            12 => crate::distribution::data::WorkloadField::IndividualWorks,
+            13 => crate::distribution::data::WorkloadField::CourseWorks,
+            14 => crate::distribution::data::WorkloadField::PostgraduateExams,
+            _ => unreachable!("Invalid variant for WorkloadField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::WorkloadInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_year = <u32>::sse_decode(deserializer);
+        let mut var_typeName = <String>::sse_decode(deserializer);
+        let mut var_sections =
+            <Vec<crate::excel::data::WorkloadSectionInput>>::sse_decode(deserializer);
+        return crate::excel::data::WorkloadInput {
+            year: var_year,
+            type_name: var_typeName,
+            sections: var_sections,
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::WorkloadKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::excel::data::WorkloadKey::Lectures,
+            1 => crate::excel::data::WorkloadKey::Practices,
+            2 => crate::excel::data::WorkloadKey::Labs,
+            3 => crate::excel::data::WorkloadKey::Exams,
+            4 => crate::excel::data::WorkloadKey::ExamConsults,
+            5 => crate::excel::data::WorkloadKey::Tests,
+            6 => crate::excel::data::WorkloadKey::QualWorks,
+            7 => crate::excel::data::WorkloadKey::CertificationExams,
+            8 => crate::excel::data::WorkloadKey::WorkingPractice,
+            9 => crate::excel::data::WorkloadKey::TeachingPractice,
+            10 => crate::excel::data::WorkloadKey::Consults,
+            11 => crate::excel::data::WorkloadKey::IndividualWorks,
+            12 => crate::excel::data::WorkloadKey::CourseWorks,
+            13 => crate::excel::data::WorkloadKey::PostgraduateExams,
+            14 => crate::excel::data::WorkloadKey::Supervising,
+            15 => crate::excel::data::WorkloadKey::Internship,
+            16 => crate::excel::data::WorkloadKey::Total,
+            _ => unreachable!("Invalid variant for WorkloadKey: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::excel::data::WorkloadSectionInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_employees =
+            <Vec<crate::excel::data::EmployeeWorkloadInput>>::sse_decode(deserializer);
+        return crate::excel::data::WorkloadSectionInput {
+            title: var_title,
+            employees: var_employees,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__distribution_interface__add_workload_item_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__distribution_interface__apply_form1_norms_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__excel_interface__build_exported_data_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__distribution_interface__build_project_exported_data_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__distribution_interface__carry_over_distribution_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__distribution_interface__check_form1_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__distribution_interface__check_form1_norms_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__distribution_interface__check_semester_coverage_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__distribution_interface__default_academic_calendar_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__distribution_interface__default_norms_table_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__excel_interface__default_workload_columns_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__distribution_interface__diff_form1_files_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__distribution_interface__distribute_workload_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__distribution_interface__form1_from_parsed_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__distribution_interface__get_employee_norms_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__distribution_interface__get_employee_summaries_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__distribution_interface__get_undistributed_workload_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__excel_interface__init_app_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__distribution_interface__list_undistributed_workload_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__excel_interface__parse_excel_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__distribution_interface__read_distribution_project_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__excel_interface__read_json_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__distribution_interface__reconcile_form1_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__distribution_interface__remove_workload_item_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__distribution_interface__update_workload_field_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__distribution_interface__validate_distribution_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__excel_interface__validate_exported_data_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__excel_interface__write_csv_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__distribution_interface__write_distribution_project_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__excel_interface__write_excel_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__excel_interface__write_html_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__excel_interface__write_json_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__excel_interface__write_pdf_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__excel_interface__write_personal_files_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::AcademicCalendar {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.first_semester_start.into_into_dart().into_dart(),
+            self.first_semester_end.into_into_dart().into_dart(),
+            self.second_semester_start.into_into_dart().into_dart(),
+            self.second_semester_end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::AcademicCalendar
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::AcademicCalendar>
+    for crate::distribution::data::AcademicCalendar
+{
+    fn into_into_dart(self) -> crate::distribution::data::AcademicCalendar {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::AcademicSemester {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::First => 0.into_dart(),
+            Self::Second => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::AcademicSemester
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::AcademicSemester>
+    for crate::distribution::data::AcademicSemester
+{
+    fn into_into_dart(self) -> crate::distribution::data::AcademicSemester {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::CarriedOverItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.previous_key.into_into_dart().into_dart(),
+            self.similarity.into_into_dart().into_dart(),
+            self.changed_fields.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::CarriedOverItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::CarriedOverItem>
+    for crate::distribution::data::CarriedOverItem
+{
+    fn into_into_dart(self) -> crate::distribution::data::CarriedOverItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::CarryOverResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.project.into_into_dart().into_dart(),
+            self.new_items.into_into_dart().into_dart(),
+            self.removed_items.into_into_dart().into_dart(),
+            self.changed_items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::CarryOverResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::CarryOverResult>
+    for crate::distribution::data::CarryOverResult
+{
+    fn into_into_dart(self) -> crate::distribution::data::CarryOverResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::DisciplineKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.learning_form.into_into_dart().into_dart(),
+            self.specialty.into_into_dart().into_dart(),
+            self.discipline_name.into_into_dart().into_dart(),
+            self.course.into_into_dart().into_dart(),
+            self.semester.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::DisciplineKey
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::DisciplineKey>
+    for crate::distribution::data::DisciplineKey
+{
+    fn into_into_dart(self) -> crate::distribution::data::DisciplineKey {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::DistributionIssue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.employee_id.into_into_dart().into_dart(),
+            self.item_id.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::DistributionIssue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::DistributionIssue>
+    for crate::distribution::data::DistributionIssue
+{
+    fn into_into_dart(self) -> crate::distribution::data::DistributionIssue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::DistributionProject {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.university_form1.into_into_dart().into_dart(),
+            self.university_form3.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::DistributionProject
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::DistributionProject>
+    for crate::distribution::data::DistributionProject
+{
+    fn into_into_dart(self) -> crate::distribution::data::DistributionProject {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::Employee {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.first_name.into_into_dart().into_dart(),
+            self.last_name.into_into_dart().into_dart(),
+            self.patronymic.into_into_dart().into_dart(),
+            self.rank.into_into_dart().into_dart(),
+            self.rates.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::Employee
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::Employee>
+    for crate::distribution::data::Employee
+{
+    fn into_into_dart(self) -> crate::distribution::data::Employee {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::EmployeeNorms {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.employee_id.into_into_dart().into_dart(),
+            self.full_name.into_into_dart().into_dart(),
+            self.peak_rate.into_into_dart().into_dart(),
+            self.rates.into_into_dart().into_dart(),
+            self.first_semester.into_into_dart().into_dart(),
+            self.second_semester.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::EmployeeNorms
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::EmployeeNorms>
+    for crate::distribution::data::EmployeeNorms
+{
+    fn into_into_dart(self) -> crate::distribution::data::EmployeeNorms {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::EmployeePreferences {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.employee_id.into_into_dart().into_dart(),
+            self.disciplines.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::EmployeePreferences
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::EmployeePreferences>
+    for crate::distribution::data::EmployeePreferences
+{
+    fn into_into_dart(self) -> crate::distribution::data::EmployeePreferences {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::EmployeeRank {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Head => 0.into_dart(),
+            Self::Professor => 1.into_dart(),
+            Self::Associate => 2.into_dart(),
+            Self::SeniorLecturer => 3.into_dart(),
+            Self::Assistant => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::EmployeeRank
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::EmployeeRank>
+    for crate::distribution::data::EmployeeRank
+{
+    fn into_into_dart(self) -> crate::distribution::data::EmployeeRank {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::EmployeeRate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.rate_value.into_into_dart().into_dart(),
+            self.date_start.into_into_dart().into_dart(),
+            self.date_end.into_into_dart().into_dart(),
+            self.postgraduate_count.into_into_dart().into_dart(),
+            self.workload_items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::EmployeeRate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::EmployeeRate>
+    for crate::distribution::data::EmployeeRate
+{
+    fn into_into_dart(self) -> crate::distribution::data::EmployeeRate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::EmployeeWorkloadInput {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.first_name.into_into_dart().into_dart(),
+            self.middle_name.into_into_dart().into_dart(),
+            self.last_name.into_into_dart().into_dart(),
+            self.rank.into_into_dart().into_dart(),
+            self.comment_semester_1.into_into_dart().into_dart(),
+            self.comment_semester_2.into_into_dart().into_dart(),
+            self.semester_1.into_into_dart().into_dart(),
+            self.semester_1_rate.into_into_dart().into_dart(),
+            self.semester_2.into_into_dart().into_dart(),
+            self.semester_2_rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::EmployeeWorkloadInput
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::EmployeeWorkloadInput>
+    for crate::excel::data::EmployeeWorkloadInput
+{
+    fn into_into_dart(self) -> crate::excel::data::EmployeeWorkloadInput {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::EmployeeWorkloadSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.employee_id.into_into_dart().into_dart(),
+            self.full_name.into_into_dart().into_dart(),
+            self.hours.into_into_dart().into_dart(),
+            self.min_possible_hours.into_into_dart().into_dart(),
+            self.max_possible_hours.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::EmployeeWorkloadSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::EmployeeWorkloadSummary>
+    for crate::distribution::data::EmployeeWorkloadSummary
+{
+    fn into_into_dart(self) -> crate::distribution::data::EmployeeWorkloadSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::ExportOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hide_empty_columns.into_into_dart().into_dart(),
+            self.columns.into_into_dart().into_dart(),
+            self.theme.into_into_dart().into_dart(),
+            self.highlight_workload_norms.into_into_dart().into_dart(),
+            self.include_analytics.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::ExportOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::ExportOptions>
+    for crate::excel::data::ExportOptions
+{
+    fn into_into_dart(self) -> crate::excel::data::ExportOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::ExportTheme {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.font_name.into_into_dart().into_dart(),
+            self.font_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::ExportTheme
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::ExportTheme>
+    for crate::excel::data::ExportTheme
+{
+    fn into_into_dart(self) -> crate::excel::data::ExportTheme {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::ExportedData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.year.into_into_dart().into_dart(),
+            self.type_name.into_into_dart().into_dart(),
+            self.main_table.into_into_dart().into_dart(),
+            self.personal_tables.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::ExportedData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::ExportedData>
+    for crate::excel::data::ExportedData
+{
+    fn into_into_dart(self) -> crate::excel::data::ExportedData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::FieldChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.old_value.into_into_dart().into_dart(),
+            self.new_value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::FieldChange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::FieldChange>
+    for crate::distribution::data::FieldChange
+{
+    fn into_into_dart(self) -> crate::distribution::data::FieldChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::FieldRankRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.min_rank.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::FieldRankRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::FieldRankRule>
+    for crate::distribution::data::FieldRankRule
+{
+    fn into_into_dart(self) -> crate::distribution::data::FieldRankRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::Form1Diff {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.added.into_into_dart().into_dart(),
+            self.removed.into_into_dart().into_dart(),
+            self.changed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::Form1Diff
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::Form1Diff>
+    for crate::distribution::data::Form1Diff
+{
+    fn into_into_dart(self) -> crate::distribution::data::Form1Diff {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::Form1DiffEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.changes.into_into_dart().into_dart(),
+            self.affected_employees.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::Form1DiffEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::Form1DiffEntry>
+    for crate::distribution::data::Form1DiffEntry
+{
+    fn into_into_dart(self) -> crate::distribution::data::Form1DiffEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::Form1Finding {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.row.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::Form1Finding
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::Form1Finding>
+    for crate::distribution::data::Form1Finding
+{
+    fn into_into_dart(self) -> crate::distribution::data::Form1Finding {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::Form1FindingKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::UnknownLearningForm => 0.into_dart(),
+            Self::UnreadableValue => 1.into_dart(),
+            Self::NegativeValue => 2.into_dart(),
+            Self::TotalMismatch => 3.into_dart(),
+            Self::SemesterMismatch => 4.into_dart(),
+            Self::DuplicateKey => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::Form1FindingKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::Form1FindingKind>
+    for crate::distribution::data::Form1FindingKind
+{
+    fn into_into_dart(self) -> crate::distribution::data::Form1FindingKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::InputRawRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.learning_form.into_into_dart().into_dart(),
+            self.speciality.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.course.into_into_dart().into_dart(),
+            self.semester.into_into_dart().into_dart(),
+            self.weeks_count.into_into_dart().into_dart(),
+            self.students_count.into_into_dart().into_dart(),
+            self.flows_count.into_into_dart().into_dart(),
+            self.groups_count.into_into_dart().into_dart(),
+            self.subgroups_count.into_into_dart().into_dart(),
+            self.lectures_planned_count.into_into_dart().into_dart(),
+            self.lectures_total_count.into_into_dart().into_dart(),
+            self.practices_planned_count.into_into_dart().into_dart(),
+            self.practices_total_count.into_into_dart().into_dart(),
+            self.labs_planned_count.into_into_dart().into_dart(),
+            self.labs_total_count.into_into_dart().into_dart(),
+            self.exams.into_into_dart().into_dart(),
+            self.exam_consults.into_into_dart().into_dart(),
+            self.tests.into_into_dart().into_dart(),
+            self.qual_works.into_into_dart().into_dart(),
+            self.certification_exams.into_into_dart().into_dart(),
+            self.working_practice.into_into_dart().into_dart(),
+            self.teaching_practice.into_into_dart().into_dart(),
+            self.consults.into_into_dart().into_dart(),
+            self.individual_works.into_into_dart().into_dart(),
+            self.individual_work_types.into_into_dart().into_dart(),
+            self.course_works.into_into_dart().into_dart(),
+            self.postgraduate_exams.into_into_dart().into_dart(),
+            self.supervising.into_into_dart().into_dart(),
+            self.internship.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::InputRawRow
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::InputRawRow>
+    for crate::excel::data::InputRawRow
+{
+    fn into_into_dart(self) -> crate::excel::data::InputRawRow {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::LearningForm {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Daytime => 0.into_dart(),
+            Self::Evening => 1.into_dart(),
+            Self::Correspondence => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::LearningForm
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::LearningForm>
+    for crate::distribution::data::LearningForm
+{
+    fn into_into_dart(self) -> crate::distribution::data::LearningForm {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::LearningFormTotal {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.learning_form.into_into_dart().into_dart(),
+            self.hours.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::LearningFormTotal
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::LearningFormTotal>
+    for crate::excel::data::LearningFormTotal
+{
+    fn into_into_dart(self) -> crate::excel::data::LearningFormTotal {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::MainTableSection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.rows.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::MainTableSection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::MainTableSection>
+    for crate::excel::data::MainTableSection
+{
+    fn into_into_dart(self) -> crate::excel::data::MainTableSection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::NormBasis {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Students => 0.into_dart(),
+            Self::Flows => 1.into_dart(),
+            Self::Groups => 2.into_dart(),
+            Self::Subgroups => 3.into_dart(),
+            Self::Weeks => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::NormBasis
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::NormBasis>
+    for crate::distribution::data::NormBasis
+{
+    fn into_into_dart(self) -> crate::distribution::data::NormBasis {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::NormFinding {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.row.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.file_value.into_into_dart().into_dart(),
+            self.norm_value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::NormFinding
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::NormFinding>
+    for crate::distribution::data::NormFinding
+{
+    fn into_into_dart(self) -> crate::distribution::data::NormFinding {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::NormRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.basis.into_into_dart().into_dart(),
+            self.hours_per_unit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::NormRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::NormRule>
+    for crate::distribution::data::NormRule
+{
+    fn into_into_dart(self) -> crate::distribution::data::NormRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::NormsTable {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rules.into_into_dart().into_dart(),
+            self.tolerance.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::NormsTable
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::NormsTable>
+    for crate::distribution::data::NormsTable
+{
+    fn into_into_dart(self) -> crate::distribution::data::NormsTable {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputHoursRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.lectures.into_into_dart().into_dart(),
+            self.practices.into_into_dart().into_dart(),
+            self.labs.into_into_dart().into_dart(),
+            self.exams.into_into_dart().into_dart(),
+            self.exam_consults.into_into_dart().into_dart(),
+            self.tests.into_into_dart().into_dart(),
+            self.qual_works.into_into_dart().into_dart(),
+            self.certification_exams.into_into_dart().into_dart(),
+            self.working_practice.into_into_dart().into_dart(),
+            self.teaching_practice.into_into_dart().into_dart(),
+            self.consults.into_into_dart().into_dart(),
+            self.individual_works.into_into_dart().into_dart(),
+            self.course_works.into_into_dart().into_dart(),
+            self.postgraduate_exams.into_into_dart().into_dart(),
+            self.supervising.into_into_dart().into_dart(),
+            self.internship.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::OutputHoursRow
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputHoursRow>
+    for crate::excel::data::OutputHoursRow
+{
+    fn into_into_dart(self) -> crate::excel::data::OutputHoursRow {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputMainRateAndHours {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rate.into_into_dart().into_dart(),
+            self.semester_1.into_into_dart().into_dart(),
+            self.semester_2.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::OutputMainRateAndHours
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputMainRateAndHours>
+    for crate::excel::data::OutputMainRateAndHours
+{
+    fn into_into_dart(self) -> crate::excel::data::OutputMainRateAndHours {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputMainTable {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sections.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::OutputMainTable
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputMainTable>
+    for crate::excel::data::OutputMainTable
+{
+    fn into_into_dart(self) -> crate::excel::data::OutputMainTable {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputMainWorkerRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.first_name.into_into_dart().into_dart(),
+            self.middle_name.into_into_dart().into_dart(),
+            self.last_name.into_into_dart().into_dart(),
+            self.rank.into_into_dart().into_dart(),
+            self.rate_and_hours.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::OutputMainWorkerRow
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputMainWorkerRow>
+    for crate::excel::data::OutputMainWorkerRow
+{
+    fn into_into_dart(self) -> crate::excel::data::OutputMainWorkerRow {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputPersonalSemesterRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.learning_form.into_into_dart().into_dart(),
+            self.speciality.into_into_dart().into_dart(),
+            self.group.into_into_dart().into_dart(),
+            self.course.into_into_dart().into_dart(),
+            self.students_count.into_into_dart().into_dart(),
+            self.individual_work_types.into_into_dart().into_dart(),
+            self.hours.into_into_dart().into_dart(),
+            self.merge_lectures_with_next.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::OutputPersonalSemesterRow
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputPersonalSemesterRow>
+    for crate::excel::data::OutputPersonalSemesterRow
+{
+    fn into_into_dart(self) -> crate::excel::data::OutputPersonalSemesterRow {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputPersonalTable {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.first_name.into_into_dart().into_dart(),
+            self.middle_name.into_into_dart().into_dart(),
+            self.last_name.into_into_dart().into_dart(),
+            self.comment_semester_1.into_into_dart().into_dart(),
+            self.comment_semester_2.into_into_dart().into_dart(),
+            self.rank.into_into_dart().into_dart(),
+            self.semester_1.into_into_dart().into_dart(),
+            self.semester_1_rate.into_into_dart().into_dart(),
+            self.semester_1_form_totals.into_into_dart().into_dart(),
+            self.semester_1_total.into_into_dart().into_dart(),
+            self.semester_2.into_into_dart().into_dart(),
+            self.semester_2_rate.into_into_dart().into_dart(),
+            self.semester_2_form_totals.into_into_dart().into_dart(),
+            self.semester_2_total.into_into_dart().into_dart(),
+            self.year_total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::OutputPersonalTable
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputPersonalTable>
+    for crate::excel::data::OutputPersonalTable
+{
+    fn into_into_dart(self) -> crate::excel::data::OutputPersonalTable {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputPersonalTables {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.worker_last_name.into_into_dart().into_dart(),
+            self.tables.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::OutputPersonalTables
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputPersonalTables>
+    for crate::excel::data::OutputPersonalTables
+{
+    fn into_into_dart(self) -> crate::excel::data::OutputPersonalTables {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::ParsedExcelFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.data.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::ParsedExcelFile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::ParsedExcelFile>
+    for crate::excel::data::ParsedExcelFile
+{
+    fn into_into_dart(self) -> crate::excel::data::ParsedExcelFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::PdfFonts {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.regular.into_into_dart().into_dart(),
+            self.bold.into_into_dart().into_dart(),
+            self.italic.into_into_dart().into_dart(),
+            self.bold_italic.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::excel::data::PdfFonts {}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::PdfFonts>
+    for crate::excel::data::PdfFonts
+{
+    fn into_into_dart(self) -> crate::excel::data::PdfFonts {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::PeriodNorm {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rate_months.into_into_dart().into_dart(),
+            self.supervision_hours.into_into_dart().into_dart(),
+            self.min_hours.into_into_dart().into_dart(),
+            self.max_hours.into_into_dart().into_dart(),
+            self.hours.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::PeriodNorm
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::PeriodNorm>
+    for crate::distribution::data::PeriodNorm
+{
+    fn into_into_dart(self) -> crate::distribution::data::PeriodNorm {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::PersonalSheetName {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.worker_last_name.into_into_dart().into_dart(),
+            self.table_ids.into_into_dart().into_dart(),
+            self.sheet_name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::PersonalSheetName
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::PersonalSheetName>
+    for crate::excel::data::PersonalSheetName
+{
+    fn into_into_dart(self) -> crate::excel::data::PersonalSheetName {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::RateNorms {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rate_id.into_into_dart().into_dart(),
+            self.rate_value.into_into_dart().into_dart(),
+            self.months.into_into_dart().into_dart(),
+            self.first_semester.into_into_dart().into_dart(),
+            self.second_semester.into_into_dart().into_dart(),
+            self.year.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::RateNorms
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::RateNorms>
+    for crate::distribution::data::RateNorms
+{
+    fn into_into_dart(self) -> crate::distribution::data::RateNorms {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::ReconcileConflict {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.distributed.into_into_dart().into_dart(),
+            self.employees.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::ReconcileConflict
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::ReconcileConflict>
+    for crate::distribution::data::ReconcileConflict
+{
+    fn into_into_dart(self) -> crate::distribution::data::ReconcileConflict {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::ReconcileConflictKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::VanishedDiscipline => 0.into_dart(),
+            Self::OverDistributed => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::ReconcileConflictKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::ReconcileConflictKind>
+    for crate::distribution::data::ReconcileConflictKind
+{
+    fn into_into_dart(self) -> crate::distribution::data::ReconcileConflictKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::ReconcileResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.project.into_into_dart().into_dart(),
+            self.diff.into_into_dart().into_dart(),
+            self.conflicts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::ReconcileResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::ReconcileResult>
+    for crate::distribution::data::ReconcileResult
+{
+    fn into_into_dart(self) -> crate::distribution::data::ReconcileResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::SolverOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.preferences.into_into_dart().into_dart(),
+            self.rank_rules.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::SolverOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::SolverOptions>
+    for crate::distribution::data::SolverOptions
+{
+    fn into_into_dart(self) -> crate::distribution::data::SolverOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::SolverResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.project.into_into_dart().into_dart(),
+            self.unplaced.into_into_dart().into_dart(),
+            self.split_items.into_into_dart().into_dart(),
+            self.employees.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::SolverResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::SolverResult>
+    for crate::distribution::data::SolverResult
+{
+    fn into_into_dart(self) -> crate::distribution::data::SolverResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::TotalsMismatch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.location.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.expected.into_into_dart().into_dart(),
+            self.actual.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::TotalsMismatch
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::TotalsMismatch>
+    for crate::excel::data::TotalsMismatch
+{
+    fn into_into_dart(self) -> crate::excel::data::TotalsMismatch {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::UndistributedWorkload {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.workload_key.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.distributed.into_into_dart().into_dart(),
+            self.undistributed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::UndistributedWorkload
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::UndistributedWorkload>
+    for crate::distribution::data::UndistributedWorkload
+{
+    fn into_into_dart(self) -> crate::distribution::data::UndistributedWorkload {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::UniversityForm1 {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.academic_year.into_into_dart().into_dart(),
+            self.workload_items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::UniversityForm1
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::UniversityForm1>
+    for crate::distribution::data::UniversityForm1
+{
+    fn into_into_dart(self) -> crate::distribution::data::UniversityForm1 {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::UniversityForm1WorkloadItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.workload_key.into_into_dart().into_dart(),
+            self.week_count.into_into_dart().into_dart(),
+            self.student_count.into_into_dart().into_dart(),
+            self.flow_count.into_into_dart().into_dart(),
+            self.group_count.into_into_dart().into_dart(),
+            self.subgroup_count.into_into_dart().into_dart(),
+            self.lectures_planned.into_into_dart().into_dart(),
+            self.lectures_total.into_into_dart().into_dart(),
+            self.practices_planned.into_into_dart().into_dart(),
+            self.practices_total.into_into_dart().into_dart(),
+            self.labs_planned.into_into_dart().into_dart(),
+            self.labs_total.into_into_dart().into_dart(),
+            self.exams.into_into_dart().into_dart(),
+            self.exam_consults.into_into_dart().into_dart(),
+            self.tests.into_into_dart().into_dart(),
+            self.qualification_works.into_into_dart().into_dart(),
+            self.certification_exams.into_into_dart().into_dart(),
+            self.production_practices.into_into_dart().into_dart(),
+            self.teaching_practices.into_into_dart().into_dart(),
+            self.current_consults.into_into_dart().into_dart(),
+            self.individual_works.into_into_dart().into_dart(),
+            self.course_works.into_into_dart().into_dart(),
+            self.postgraduate_exams.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::UniversityForm1WorkloadItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::UniversityForm1WorkloadItem>
+    for crate::distribution::data::UniversityForm1WorkloadItem
+{
+    fn into_into_dart(self) -> crate::distribution::data::UniversityForm1WorkloadItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::UniversityForm3 {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.academic_year.into_into_dart().into_dart(),
+            self.employees.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::UniversityForm3
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::UniversityForm3>
+    for crate::distribution::data::UniversityForm3
+{
+    fn into_into_dart(self) -> crate::distribution::data::UniversityForm3 {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::UniversityForm3WorkloadItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.workload_key.into_into_dart().into_dart(),
+            self.student_count.into_into_dart().into_dart(),
+            self.academic_groups.into_into_dart().into_dart(),
+            self.lectures.into_into_dart().into_dart(),
+            self.practices.into_into_dart().into_dart(),
+            self.labs.into_into_dart().into_dart(),
+            self.exams.into_into_dart().into_dart(),
+            self.exam_consults.into_into_dart().into_dart(),
+            self.tests.into_into_dart().into_dart(),
+            self.qualification_works.into_into_dart().into_dart(),
+            self.certification_exams.into_into_dart().into_dart(),
+            self.production_practices.into_into_dart().into_dart(),
+            self.teaching_practices.into_into_dart().into_dart(),
+            self.current_consults.into_into_dart().into_dart(),
+            self.individual_works.into_into_dart().into_dart(),
+            self.course_works.into_into_dart().into_dart(),
+            self.postgraduate_exams.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::UniversityForm3WorkloadItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::UniversityForm3WorkloadItem>
+    for crate::distribution::data::UniversityForm3WorkloadItem
+{
+    fn into_into_dart(self) -> crate::distribution::data::UniversityForm3WorkloadItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::WorkloadColumn {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.caption.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.number_format.into_into_dart().into_dart(),
+            self.include_in_total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::WorkloadColumn
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::WorkloadColumn>
+    for crate::excel::data::WorkloadColumn
+{
+    fn into_into_dart(self) -> crate::excel::data::WorkloadColumn {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::distribution::data::WorkloadField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::StudentCount => 0.into_dart(),
+            Self::Lectures => 1.into_dart(),
+            Self::Practices => 2.into_dart(),
+            Self::Labs => 3.into_dart(),
+            Self::Exams => 4.into_dart(),
+            Self::ExamConsults => 5.into_dart(),
+            Self::Tests => 6.into_dart(),
+            Self::QualificationWorks => 7.into_dart(),
+            Self::CertificationExams => 8.into_dart(),
+            Self::ProductionPractices => 9.into_dart(),
+            Self::TeachingPractices => 10.into_dart(),
+            Self::CurrentConsults => 11.into_dart(),
+            Self::IndividualWorks => 12.into_dart(),
+            Self::CourseWorks => 13.into_dart(),
+            Self::PostgraduateExams => 14.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::distribution::data::WorkloadField
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::distribution::data::WorkloadField>
+    for crate::distribution::data::WorkloadField
+{
+    fn into_into_dart(self) -> crate::distribution::data::WorkloadField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::WorkloadInput {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.year.into_into_dart().into_dart(),
+            self.type_name.into_into_dart().into_dart(),
+            self.sections.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::WorkloadInput
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::WorkloadInput>
+    for crate::excel::data::WorkloadInput
+{
+    fn into_into_dart(self) -> crate::excel::data::WorkloadInput {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::WorkloadKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Lectures => 0.into_dart(),
+            Self::Practices => 1.into_dart(),
+            Self::Labs => 2.into_dart(),
+            Self::Exams => 3.into_dart(),
+            Self::ExamConsults => 4.into_dart(),
+            Self::Tests => 5.into_dart(),
+            Self::QualWorks => 6.into_dart(),
+            Self::CertificationExams => 7.into_dart(),
+            Self::WorkingPractice => 8.into_dart(),
+            Self::TeachingPractice => 9.into_dart(),
+            Self::Consults => 10.into_dart(),
+            Self::IndividualWorks => 11.into_dart(),
+            Self::CourseWorks => 12.into_dart(),
+            Self::PostgraduateExams => 13.into_dart(),
+            Self::Supervising => 14.into_dart(),
+            Self::Internship => 15.into_dart(),
+            Self::Total => 16.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::WorkloadKey
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::WorkloadKey>
+    for crate::excel::data::WorkloadKey
+{
+    fn into_into_dart(self) -> crate::excel::data::WorkloadKey {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::excel::data::WorkloadSectionInput {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.employees.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::excel::data::WorkloadSectionInput
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::WorkloadSectionInput>
+    for crate::excel::data::WorkloadSectionInput
+{
+    fn into_into_dart(self) -> crate::excel::data::WorkloadSectionInput {
+        self
+    }
+}
+
+impl SseEncode for std::collections::HashMap<String, Vec<crate::excel::data::InputRawRow>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, Vec<crate::excel::data::InputRawRow>)>>::sse_encode(
+            self.into_iter().collect(),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::AcademicCalendar {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.first_semester_start, serializer);
+        <String>::sse_encode(self.first_semester_end, serializer);
+        <String>::sse_encode(self.second_semester_start, serializer);
+        <String>::sse_encode(self.second_semester_end, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::AcademicSemester {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::distribution::data::AcademicSemester::First => 0,
+                crate::distribution::data::AcademicSemester::Second => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::distribution::data::CarriedOverItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::DisciplineKey>::sse_encode(self.key, serializer);
+        <crate::distribution::data::DisciplineKey>::sse_encode(self.previous_key, serializer);
+        <f64>::sse_encode(self.similarity, serializer);
+        <Vec<crate::distribution::data::WorkloadField>>::sse_encode(
+            self.changed_fields,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::distribution::data::CarryOverResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::DistributionProject>::sse_encode(self.project, serializer);
+        <Vec<crate::distribution::data::DisciplineKey>>::sse_encode(self.new_items, serializer);
+        <Vec<crate::distribution::data::DisciplineKey>>::sse_encode(self.removed_items, serializer);
+        <Vec<crate::distribution::data::CarriedOverItem>>::sse_encode(
+            self.changed_items,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::distribution::data::DisciplineKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::LearningForm>::sse_encode(self.learning_form, serializer);
+        <String>::sse_encode(self.specialty, serializer);
+        <String>::sse_encode(self.discipline_name, serializer);
+        <String>::sse_encode(self.course, serializer);
+        <crate::distribution::data::AcademicSemester>::sse_encode(self.semester, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::DistributionIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.employee_id, serializer);
+        <Option<String>>::sse_encode(self.item_id, serializer);
+        <String>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::DistributionProject {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <crate::distribution::data::UniversityForm1>::sse_encode(self.university_form1, serializer);
+        <crate::distribution::data::UniversityForm3>::sse_encode(self.university_form3, serializer);
+        <String>::sse_encode(self.created_at, serializer);
+        <String>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::Employee {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.first_name, serializer);
+        <String>::sse_encode(self.last_name, serializer);
+        <String>::sse_encode(self.patronymic, serializer);
+        <crate::distribution::data::EmployeeRank>::sse_encode(self.rank, serializer);
+        <Vec<crate::distribution::data::EmployeeRate>>::sse_encode(self.rates, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::EmployeeNorms {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.employee_id, serializer);
+        <String>::sse_encode(self.full_name, serializer);
+        <f64>::sse_encode(self.peak_rate, serializer);
+        <Vec<crate::distribution::data::RateNorms>>::sse_encode(self.rates, serializer);
+        <crate::distribution::data::PeriodNorm>::sse_encode(self.first_semester, serializer);
+        <crate::distribution::data::PeriodNorm>::sse_encode(self.second_semester, serializer);
+        <crate::distribution::data::PeriodNorm>::sse_encode(self.year, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::EmployeePreferences {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.employee_id, serializer);
+        <Vec<String>>::sse_encode(self.disciplines, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::EmployeeRank {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::distribution::data::EmployeeRank::Head => 0,
+                crate::distribution::data::EmployeeRank::Professor => 1,
+                crate::distribution::data::EmployeeRank::Associate => 2,
+                crate::distribution::data::EmployeeRank::SeniorLecturer => 3,
+                crate::distribution::data::EmployeeRank::Assistant => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::distribution::data::EmployeeRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <f64>::sse_encode(self.rate_value, serializer);
+        <String>::sse_encode(self.date_start, serializer);
+        <String>::sse_encode(self.date_end, serializer);
+        <u32>::sse_encode(self.postgraduate_count, serializer);
+        <Vec<crate::distribution::data::UniversityForm3WorkloadItem>>::sse_encode(
+            self.workload_items,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::excel::data::EmployeeWorkloadInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.first_name, serializer);
+        <String>::sse_encode(self.middle_name, serializer);
+        <String>::sse_encode(self.last_name, serializer);
+        <String>::sse_encode(self.rank, serializer);
+        <String>::sse_encode(self.comment_semester_1, serializer);
+        <String>::sse_encode(self.comment_semester_2, serializer);
+        <Vec<crate::excel::data::OutputPersonalSemesterRow>>::sse_encode(
+            self.semester_1,
+            serializer,
+        );
+        <f64>::sse_encode(self.semester_1_rate, serializer);
+        <Vec<crate::excel::data::OutputPersonalSemesterRow>>::sse_encode(
+            self.semester_2,
+            serializer,
+        );
+        <f64>::sse_encode(self.semester_2_rate, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::EmployeeWorkloadSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::excel::data::InputRawRow>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.employee_id, serializer);
+        <String>::sse_encode(self.full_name, serializer);
+        <f64>::sse_encode(self.hours, serializer);
+        <f64>::sse_encode(self.min_possible_hours, serializer);
+        <f64>::sse_encode(self.max_possible_hours, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::excel::data::OutputMainWorkerRow> {
+impl SseEncode for crate::excel::data::ExportOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::excel::data::OutputMainWorkerRow>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.hide_empty_columns, serializer);
+        <Option<Vec<crate::excel::data::WorkloadColumn>>>::sse_encode(self.columns, serializer);
+        <Option<crate::excel::data::ExportTheme>>::sse_encode(self.theme, serializer);
+        <bool>::sse_encode(self.highlight_workload_norms, serializer);
+        <bool>::sse_encode(self.include_analytics, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::excel::data::OutputPersonalSemesterRow> {
+impl SseEncode for crate::excel::data::ExportTheme {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::excel::data::OutputPersonalSemesterRow>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.font_name, serializer);
+        <f64>::sse_encode(self.font_size, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::excel::data::OutputPersonalTable> {
+impl SseEncode for crate::excel::data::ExportedData {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::excel::data::OutputPersonalTable>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.year, serializer);
+        <String>::sse_encode(self.type_name, serializer);
+        <crate::excel::data::OutputMainTable>::sse_encode(self.main_table, serializer);
+        <Vec<crate::excel::data::OutputPersonalTables>>::sse_encode(
+            self.personal_tables,
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<crate::excel::data::OutputPersonalTables> {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::excel::data::OutputPersonalTables>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for crate::distribution::data::FieldChange {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::WorkloadField>::sse_encode(self.field, serializer);
+        <f64>::sse_encode(self.old_value, serializer);
+        <f64>::sse_encode(self.new_value, serializer);
     }
 }
 
-impl SseDecode for Vec<(String, Vec<crate::excel::data::InputRawRow>)> {
+impl SseEncode for crate::distribution::data::FieldRankRule {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, Vec<crate::excel::data::InputRawRow>)>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::WorkloadField>::sse_encode(self.field, serializer);
+        <crate::distribution::data::EmployeeRank>::sse_encode(self.min_rank, serializer);
     }
 }
 
-impl SseDecode for crate::excel::data::OutputHoursRow {
+impl SseEncode for crate::distribution::data::Form1Diff {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_lectures = <f64>::sse_decode(deserializer);
-        let mut var_practices = <f64>::sse_decode(deserializer);
-        let mut var_labs = <f64>::sse_decode(deserializer);
-        let mut var_exams = <f64>::sse_decode(deserializer);
-        let mut var_examConsults = <f64>::sse_decode(deserializer);
-        let mut var_tests = <f64>::sse_decode(deserializer);
-        let mut var_qualWorks = <f64>::sse_decode(deserializer);
-        let mut var_workingPractice = <f64>::sse_decode(deserializer);
-        let mut var_teachingPractice = <f64>::sse_decode(deserializer);
-        let mut var_consults = <f64>::sse_decode(deserializer);
-        let mut var_individualWorks = <f64>::sse_decode(deserializer);
-        let mut var_courseWorks = <f64>::sse_decode(deserializer);
-        let mut var_supervising = <f64>::sse_decode(deserializer);
-        let mut var_total = <f64>::sse_decode(deserializer);
-        return crate::excel::data::OutputHoursRow {
-            lectures: var_lectures,
-            practices: var_practices,
-            labs: var_labs,
-            exams: var_exams,
-            exam_consults: var_examConsults,
-            tests: var_tests,
-            qual_works: var_qualWorks,
-            working_practice: var_workingPractice,
-            teaching_practice: var_teachingPractice,
-            consults: var_consults,
-            individual_works: var_individualWorks,
-            course_works: var_courseWorks,
-            supervising: var_supervising,
-            total: var_total,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::distribution::data::Form1DiffEntry>>::sse_encode(self.added, serializer);
+        <Vec<crate::distribution::data::Form1DiffEntry>>::sse_encode(self.removed, serializer);
+        <Vec<crate::distribution::data::Form1DiffEntry>>::sse_encode(self.changed, serializer);
     }
 }
 
-impl SseDecode for crate::excel::data::OutputMainRateAndHours {
+impl SseEncode for crate::distribution::data::Form1DiffEntry {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_rate = <f64>::sse_decode(deserializer);
-        let mut var_semester1 = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        let mut var_semester2 = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        let mut var_year = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        return crate::excel::data::OutputMainRateAndHours {
-            rate: var_rate,
-            semester_1: var_semester1,
-            semester_2: var_semester2,
-            year: var_year,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::DisciplineKey>::sse_encode(self.key, serializer);
+        <Vec<crate::distribution::data::FieldChange>>::sse_encode(self.changes, serializer);
+        <Vec<String>>::sse_encode(self.affected_employees, serializer);
     }
 }
 
-impl SseDecode for crate::excel::data::OutputMainTable {
+impl SseEncode for crate::distribution::data::Form1Finding {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_heads =
-            <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_decode(deserializer);
-        let mut var_headsTotal =
-            <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
-        let mut var_professors =
-            <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_decode(deserializer);
-        let mut var_professorsTotal =
-            <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
-        let mut var_associateProfessors =
-            <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_decode(deserializer);
-        let mut var_associateProfessorsTotal =
-            <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
-        let mut var_lecturers =
-            <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_decode(deserializer);
-        let mut var_lecturersTotal =
-            <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
-        let mut var_assistants =
-            <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_decode(deserializer);
-        let mut var_assistantsTotal =
-            <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
-        let mut var_partTimers =
-            <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_decode(deserializer);
-        let mut var_partTimersTotal =
-            <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
-        let mut var_total = <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
-        return crate::excel::data::OutputMainTable {
-            heads: var_heads,
-            heads_total: var_headsTotal,
-            professors: var_professors,
-            professors_total: var_professorsTotal,
-            associate_professors: var_associateProfessors,
-            associate_professors_total: var_associateProfessorsTotal,
-            lecturers: var_lecturers,
-            lecturers_total: var_lecturersTotal,
-            assistants: var_assistants,
-            assistants_total: var_assistantsTotal,
-            part_timers: var_partTimers,
-            part_timers_total: var_partTimersTotal,
-            total: var_total,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.row, serializer);
+        <crate::distribution::data::Form1FindingKind>::sse_encode(self.kind, serializer);
+        <Option<crate::distribution::data::DisciplineKey>>::sse_encode(self.key, serializer);
+        <Option<crate::distribution::data::WorkloadField>>::sse_encode(self.field, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
 
-impl SseDecode for crate::excel::data::OutputMainWorkerRow {
+impl SseEncode for crate::distribution::data::Form1FindingKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_firstName = <String>::sse_decode(deserializer);
-        let mut var_middleName = <String>::sse_decode(deserializer);
-        let mut var_lastName = <String>::sse_decode(deserializer);
-        let mut var_rank = <String>::sse_decode(deserializer);
-        let mut var_rateAndHours =
-            <crate::excel::data::OutputMainRateAndHours>::sse_decode(deserializer);
-        return crate::excel::data::OutputMainWorkerRow {
-            first_name: var_firstName,
-            middle_name: var_middleName,
-            last_name: var_lastName,
-            rank: var_rank,
-            rate_and_hours: var_rateAndHours,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::distribution::data::Form1FindingKind::UnknownLearningForm => 0,
+                crate::distribution::data::Form1FindingKind::UnreadableValue => 1,
+                crate::distribution::data::Form1FindingKind::NegativeValue => 2,
+                crate::distribution::data::Form1FindingKind::TotalMismatch => 3,
+                crate::distribution::data::Form1FindingKind::SemesterMismatch => 4,
+                crate::distribution::data::Form1FindingKind::DuplicateKey => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for crate::excel::data::OutputPersonalSemesterRow {
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_learningForm = <String>::sse_decode(deserializer);
-        let mut var_speciality = <String>::sse_decode(deserializer);
-        let mut var_group = <String>::sse_decode(deserializer);
-        let mut var_course = <String>::sse_decode(deserializer);
-        let mut var_studentsCount = <u32>::sse_decode(deserializer);
-        let mut var_hours = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        let mut var_mergeLecturesWithNext = <bool>::sse_decode(deserializer);
-        return crate::excel::data::OutputPersonalSemesterRow {
-            name: var_name,
-            learning_form: var_learningForm,
-            speciality: var_speciality,
-            group: var_group,
-            course: var_course,
-            students_count: var_studentsCount,
-            hours: var_hours,
-            merge_lectures_with_next: var_mergeLecturesWithNext,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::excel::data::InputRawRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.learning_form, serializer);
+        <String>::sse_encode(self.speciality, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.course, serializer);
+        <String>::sse_encode(self.semester, serializer);
+        <String>::sse_encode(self.weeks_count, serializer);
+        <String>::sse_encode(self.students_count, serializer);
+        <String>::sse_encode(self.flows_count, serializer);
+        <String>::sse_encode(self.groups_count, serializer);
+        <String>::sse_encode(self.subgroups_count, serializer);
+        <String>::sse_encode(self.lectures_planned_count, serializer);
+        <String>::sse_encode(self.lectures_total_count, serializer);
+        <String>::sse_encode(self.practices_planned_count, serializer);
+        <String>::sse_encode(self.practices_total_count, serializer);
+        <String>::sse_encode(self.labs_planned_count, serializer);
+        <String>::sse_encode(self.labs_total_count, serializer);
+        <String>::sse_encode(self.exams, serializer);
+        <String>::sse_encode(self.exam_consults, serializer);
+        <String>::sse_encode(self.tests, serializer);
+        <String>::sse_encode(self.qual_works, serializer);
+        <String>::sse_encode(self.certification_exams, serializer);
+        <String>::sse_encode(self.working_practice, serializer);
+        <String>::sse_encode(self.teaching_practice, serializer);
+        <String>::sse_encode(self.consults, serializer);
+        <String>::sse_encode(self.individual_works, serializer);
+        <String>::sse_encode(self.individual_work_types, serializer);
+        <String>::sse_encode(self.course_works, serializer);
+        <String>::sse_encode(self.postgraduate_exams, serializer);
+        <String>::sse_encode(self.supervising, serializer);
+        <String>::sse_encode(self.internship, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::LearningForm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::distribution::data::LearningForm::Daytime => 0,
+                crate::distribution::data::LearningForm::Evening => 1,
+                crate::distribution::data::LearningForm::Correspondence => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::excel::data::LearningFormTotal {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.learning_form, serializer);
+        <crate::excel::data::OutputHoursRow>::sse_encode(self.hours, serializer);
     }
 }
 
-impl SseDecode for crate::excel::data::OutputPersonalTable {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <u32>::sse_decode(deserializer);
-        let mut var_firstName = <String>::sse_decode(deserializer);
-        let mut var_middleName = <String>::sse_decode(deserializer);
-        let mut var_lastName = <String>::sse_decode(deserializer);
-        let mut var_commentSemester1 = <String>::sse_decode(deserializer);
-        let mut var_commentSemester2 = <String>::sse_decode(deserializer);
-        let mut var_rank = <String>::sse_decode(deserializer);
-        let mut var_semester1 =
-            <Vec<crate::excel::data::OutputPersonalSemesterRow>>::sse_decode(deserializer);
-        let mut var_semester1Rate = <f64>::sse_decode(deserializer);
-        let mut var_semester1TotalDay =
-            <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        let mut var_semester1TotalEvening =
-            <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        let mut var_semester1Total = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        let mut var_semester2 =
-            <Vec<crate::excel::data::OutputPersonalSemesterRow>>::sse_decode(deserializer);
-        let mut var_semester2Rate = <f64>::sse_decode(deserializer);
-        let mut var_semester2TotalDay =
-            <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        let mut var_semester2TotalEvening =
-            <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        let mut var_semester2Total = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        let mut var_yearTotal = <crate::excel::data::OutputHoursRow>::sse_decode(deserializer);
-        return crate::excel::data::OutputPersonalTable {
-            id: var_id,
-            first_name: var_firstName,
-            middle_name: var_middleName,
-            last_name: var_lastName,
-            comment_semester_1: var_commentSemester1,
-            comment_semester_2: var_commentSemester2,
-            rank: var_rank,
-            semester_1: var_semester1,
-            semester_1_rate: var_semester1Rate,
-            semester_1_total_day: var_semester1TotalDay,
-            semester_1_total_evening: var_semester1TotalEvening,
-            semester_1_total: var_semester1Total,
-            semester_2: var_semester2,
-            semester_2_rate: var_semester2Rate,
-            semester_2_total_day: var_semester2TotalDay,
-            semester_2_total_evening: var_semester2TotalEvening,
-            semester_2_total: var_semester2Total,
-            year_total: var_yearTotal,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::excel::data::OutputPersonalTables {
+impl SseEncode for Vec<crate::distribution::data::CarriedOverItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_workerLastName = <String>::sse_decode(deserializer);
-        let mut var_tables =
-            <Vec<crate::excel::data::OutputPersonalTable>>::sse_decode(deserializer);
-        return crate::excel::data::OutputPersonalTables {
-            worker_last_name: var_workerLastName,
-            tables: var_tables,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::CarriedOverItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::excel::data::ParsedExcelFile {
+impl SseEncode for Vec<crate::distribution::data::DisciplineKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_data =
-            <std::collections::HashMap<String, Vec<crate::excel::data::InputRawRow>>>::sse_decode(
-                deserializer,
-            );
-        return crate::excel::data::ParsedExcelFile { data: var_data };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::DisciplineKey>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for (String, Vec<crate::excel::data::InputRawRow>) {
+impl SseEncode for Vec<crate::distribution::data::DistributionIssue> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <Vec<crate::excel::data::InputRawRow>>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::DistributionIssue>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Vec<crate::distribution::data::Employee> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::Employee>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<crate::distribution::data::EmployeeNorms> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::EmployeeNorms>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Vec<crate::distribution::data::EmployeePreferences> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::EmployeePreferences>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<crate::distribution::data::EmployeeRate> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::EmployeeRate>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__excel_interface__init_app_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__excel_interface__parse_excel_file_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__excel_interface__write_excel_file_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::excel::data::EmployeeWorkloadInput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::EmployeeWorkloadInput>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::distribution::data::EmployeeWorkloadSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::EmployeeWorkloadSummary>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::ExportedData {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.year.into_into_dart().into_dart(),
-            self.type_name.into_into_dart().into_dart(),
-            self.main_table.into_into_dart().into_dart(),
-            self.personal_tables.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Vec<crate::distribution::data::FieldChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::FieldChange>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::ExportedData
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::ExportedData>
-    for crate::excel::data::ExportedData
-{
-    fn into_into_dart(self) -> crate::excel::data::ExportedData {
-        self
+
+impl SseEncode for Vec<crate::distribution::data::FieldRankRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::FieldRankRule>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::InputRawRow {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.learning_form.into_into_dart().into_dart(),
-            self.speciality.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.course.into_into_dart().into_dart(),
-            self.semester.into_into_dart().into_dart(),
-            self.weeks_count.into_into_dart().into_dart(),
-            self.students_count.into_into_dart().into_dart(),
-            self.flows_count.into_into_dart().into_dart(),
-            self.groups_count.into_into_dart().into_dart(),
-            self.subgroups_count.into_into_dart().into_dart(),
-            self.lectures_planned_count.into_into_dart().into_dart(),
-            self.lectures_total_count.into_into_dart().into_dart(),
-            self.practices_planned_count.into_into_dart().into_dart(),
-            self.practices_total_count.into_into_dart().into_dart(),
-            self.labs_planned_count.into_into_dart().into_dart(),
-            self.labs_total_count.into_into_dart().into_dart(),
-            self.exams.into_into_dart().into_dart(),
-            self.exam_consults.into_into_dart().into_dart(),
-            self.tests.into_into_dart().into_dart(),
-            self.qual_works.into_into_dart().into_dart(),
-            self.certification_exams.into_into_dart().into_dart(),
-            self.working_practice.into_into_dart().into_dart(),
-            self.teaching_practice.into_into_dart().into_dart(),
-            self.consults.into_into_dart().into_dart(),
-            self.individual_works.into_into_dart().into_dart(),
-            self.individual_work_types.into_into_dart().into_dart(),
-            self.course_works.into_into_dart().into_dart(),
-            self.postgraduate_exams.into_into_dart().into_dart(),
-            self.supervising.into_into_dart().into_dart(),
-            self.internship.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::distribution::data::Form1DiffEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::Form1DiffEntry>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::InputRawRow
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::InputRawRow>
-    for crate::excel::data::InputRawRow
-{
-    fn into_into_dart(self) -> crate::excel::data::InputRawRow {
-        self
+
+impl SseEncode for Vec<crate::distribution::data::Form1Finding> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::Form1Finding>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputHoursRow {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.lectures.into_into_dart().into_dart(),
-            self.practices.into_into_dart().into_dart(),
-            self.labs.into_into_dart().into_dart(),
-            self.exams.into_into_dart().into_dart(),
-            self.exam_consults.into_into_dart().into_dart(),
-            self.tests.into_into_dart().into_dart(),
-            self.qual_works.into_into_dart().into_dart(),
-            self.working_practice.into_into_dart().into_dart(),
-            self.teaching_practice.into_into_dart().into_dart(),
-            self.consults.into_into_dart().into_dart(),
-            self.individual_works.into_into_dart().into_dart(),
-            self.course_works.into_into_dart().into_dart(),
-            self.supervising.into_into_dart().into_dart(),
-            self.total.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::excel::data::InputRawRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::InputRawRow>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::OutputHoursRow
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputHoursRow>
-    for crate::excel::data::OutputHoursRow
-{
-    fn into_into_dart(self) -> crate::excel::data::OutputHoursRow {
-        self
+
+impl SseEncode for Vec<crate::excel::data::LearningFormTotal> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::LearningFormTotal>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputMainRateAndHours {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.rate.into_into_dart().into_dart(),
-            self.semester_1.into_into_dart().into_dart(),
-            self.semester_2.into_into_dart().into_dart(),
-            self.year.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::excel::data::MainTableSection> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::MainTableSection>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::OutputMainRateAndHours
-{
+
+impl SseEncode for Vec<crate::distribution::data::NormFinding> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::NormFinding>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputMainRateAndHours>
-    for crate::excel::data::OutputMainRateAndHours
-{
-    fn into_into_dart(self) -> crate::excel::data::OutputMainRateAndHours {
-        self
+
+impl SseEncode for Vec<crate::distribution::data::NormRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::NormRule>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputMainTable {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.heads.into_into_dart().into_dart(),
-            self.heads_total.into_into_dart().into_dart(),
-            self.professors.into_into_dart().into_dart(),
-            self.professors_total.into_into_dart().into_dart(),
-            self.associate_professors.into_into_dart().into_dart(),
-            self.associate_professors_total.into_into_dart().into_dart(),
-            self.lecturers.into_into_dart().into_dart(),
-            self.lecturers_total.into_into_dart().into_dart(),
-            self.assistants.into_into_dart().into_dart(),
-            self.assistants_total.into_into_dart().into_dart(),
-            self.part_timers.into_into_dart().into_dart(),
-            self.part_timers_total.into_into_dart().into_dart(),
-            self.total.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::excel::data::OutputMainWorkerRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::OutputMainWorkerRow>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::OutputMainTable
-{
+
+impl SseEncode for Vec<crate::excel::data::OutputPersonalSemesterRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::OutputPersonalSemesterRow>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputMainTable>
-    for crate::excel::data::OutputMainTable
-{
-    fn into_into_dart(self) -> crate::excel::data::OutputMainTable {
-        self
+
+impl SseEncode for Vec<crate::excel::data::OutputPersonalTable> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::OutputPersonalTable>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputMainWorkerRow {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.first_name.into_into_dart().into_dart(),
-            self.middle_name.into_into_dart().into_dart(),
-            self.last_name.into_into_dart().into_dart(),
-            self.rank.into_into_dart().into_dart(),
-            self.rate_and_hours.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::excel::data::OutputPersonalTables> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::OutputPersonalTables>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::OutputMainWorkerRow
-{
+
+impl SseEncode for Vec<crate::excel::data::PersonalSheetName> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::PersonalSheetName>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputMainWorkerRow>
-    for crate::excel::data::OutputMainWorkerRow
-{
-    fn into_into_dart(self) -> crate::excel::data::OutputMainWorkerRow {
-        self
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputPersonalSemesterRow {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.learning_form.into_into_dart().into_dart(),
-            self.speciality.into_into_dart().into_dart(),
-            self.group.into_into_dart().into_dart(),
-            self.course.into_into_dart().into_dart(),
-            self.students_count.into_into_dart().into_dart(),
-            self.hours.into_into_dart().into_dart(),
-            self.merge_lectures_with_next.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::OutputPersonalSemesterRow
-{
+
+impl SseEncode for Vec<crate::distribution::data::RateNorms> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::RateNorms>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputPersonalSemesterRow>
-    for crate::excel::data::OutputPersonalSemesterRow
-{
-    fn into_into_dart(self) -> crate::excel::data::OutputPersonalSemesterRow {
-        self
+
+impl SseEncode for Vec<crate::distribution::data::ReconcileConflict> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::ReconcileConflict>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputPersonalTable {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.first_name.into_into_dart().into_dart(),
-            self.middle_name.into_into_dart().into_dart(),
-            self.last_name.into_into_dart().into_dart(),
-            self.comment_semester_1.into_into_dart().into_dart(),
-            self.comment_semester_2.into_into_dart().into_dart(),
-            self.rank.into_into_dart().into_dart(),
-            self.semester_1.into_into_dart().into_dart(),
-            self.semester_1_rate.into_into_dart().into_dart(),
-            self.semester_1_total_day.into_into_dart().into_dart(),
-            self.semester_1_total_evening.into_into_dart().into_dart(),
-            self.semester_1_total.into_into_dart().into_dart(),
-            self.semester_2.into_into_dart().into_dart(),
-            self.semester_2_rate.into_into_dart().into_dart(),
-            self.semester_2_total_day.into_into_dart().into_dart(),
-            self.semester_2_total_evening.into_into_dart().into_dart(),
-            self.semester_2_total.into_into_dart().into_dart(),
-            self.year_total.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<(String, Vec<crate::excel::data::InputRawRow>)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, Vec<crate::excel::data::InputRawRow>)>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::OutputPersonalTable
-{
+
+impl SseEncode for Vec<crate::excel::data::TotalsMismatch> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::TotalsMismatch>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputPersonalTable>
-    for crate::excel::data::OutputPersonalTable
-{
-    fn into_into_dart(self) -> crate::excel::data::OutputPersonalTable {
-        self
+
+impl SseEncode for Vec<crate::distribution::data::UndistributedWorkload> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::UndistributedWorkload>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::OutputPersonalTables {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.worker_last_name.into_into_dart().into_dart(),
-            self.tables.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::distribution::data::UniversityForm1WorkloadItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::UniversityForm1WorkloadItem>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::OutputPersonalTables
-{
+
+impl SseEncode for Vec<crate::distribution::data::UniversityForm3WorkloadItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::UniversityForm3WorkloadItem>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::OutputPersonalTables>
-    for crate::excel::data::OutputPersonalTables
-{
-    fn into_into_dart(self) -> crate::excel::data::OutputPersonalTables {
-        self
+
+impl SseEncode for Vec<crate::excel::data::WorkloadColumn> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::WorkloadColumn>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::excel::data::ParsedExcelFile {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.data.into_into_dart().into_dart()].into_dart()
+
+impl SseEncode for Vec<crate::distribution::data::WorkloadField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::distribution::data::WorkloadField>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::excel::data::ParsedExcelFile
-{
+
+impl SseEncode for Vec<crate::excel::data::WorkloadSectionInput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::excel::data::WorkloadSectionInput>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::excel::data::ParsedExcelFile>
-    for crate::excel::data::ParsedExcelFile
-{
-    fn into_into_dart(self) -> crate::excel::data::ParsedExcelFile {
-        self
+
+impl SseEncode for crate::excel::data::MainTableSection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.title, serializer);
+        <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_encode(self.rows, serializer);
+        <crate::excel::data::OutputMainRateAndHours>::sse_encode(self.total, serializer);
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, Vec<crate::excel::data::InputRawRow>> {
+impl SseEncode for crate::distribution::data::NormBasis {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, Vec<crate::excel::data::InputRawRow>)>>::sse_encode(
-            self.into_iter().collect(),
+        <i32>::sse_encode(
+            match self {
+                crate::distribution::data::NormBasis::Students => 0,
+                crate::distribution::data::NormBasis::Flows => 1,
+                crate::distribution::data::NormBasis::Groups => 2,
+                crate::distribution::data::NormBasis::Subgroups => 3,
+                crate::distribution::data::NormBasis::Weeks => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
             serializer,
         );
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::distribution::data::NormFinding {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <u32>::sse_encode(self.row, serializer);
+        <crate::distribution::data::DisciplineKey>::sse_encode(self.key, serializer);
+        <crate::distribution::data::WorkloadField>::sse_encode(self.field, serializer);
+        <f64>::sse_encode(self.file_value, serializer);
+        <f64>::sse_encode(self.norm_value, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::distribution::data::NormRule {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <crate::distribution::data::WorkloadField>::sse_encode(self.field, serializer);
+        <crate::distribution::data::NormBasis>::sse_encode(self.basis, serializer);
+        <f64>::sse_encode(self.hours_per_unit, serializer);
     }
 }
 
-impl SseEncode for crate::excel::data::ExportedData {
+impl SseEncode for crate::distribution::data::NormsTable {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.year, serializer);
-        <String>::sse_encode(self.type_name, serializer);
-        <crate::excel::data::OutputMainTable>::sse_encode(self.main_table, serializer);
-        <Vec<crate::excel::data::OutputPersonalTables>>::sse_encode(
-            self.personal_tables,
-            serializer,
-        );
+        <Vec<crate::distribution::data::NormRule>>::sse_encode(self.rules, serializer);
+        <f64>::sse_encode(self.tolerance, serializer);
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::excel::data::InputRawRow {
+impl SseEncode for Option<crate::distribution::data::AcademicCalendar> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.learning_form, serializer);
-        <String>::sse_encode(self.speciality, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <String>::sse_encode(self.course, serializer);
-        <String>::sse_encode(self.semester, serializer);
-        <String>::sse_encode(self.weeks_count, serializer);
-        <String>::sse_encode(self.students_count, serializer);
-        <String>::sse_encode(self.flows_count, serializer);
-        <String>::sse_encode(self.groups_count, serializer);
-        <String>::sse_encode(self.subgroups_count, serializer);
-        <String>::sse_encode(self.lectures_planned_count, serializer);
-        <String>::sse_encode(self.lectures_total_count, serializer);
-        <String>::sse_encode(self.practices_planned_count, serializer);
-        <String>::sse_encode(self.practices_total_count, serializer);
-        <String>::sse_encode(self.labs_planned_count, serializer);
-        <String>::sse_encode(self.labs_total_count, serializer);
-        <String>::sse_encode(self.exams, serializer);
-        <String>::sse_encode(self.exam_consults, serializer);
-        <String>::sse_encode(self.tests, serializer);
-        <String>::sse_encode(self.qual_works, serializer);
-        <String>::sse_encode(self.certification_exams, serializer);
-        <String>::sse_encode(self.working_practice, serializer);
-        <String>::sse_encode(self.teaching_practice, serializer);
-        <String>::sse_encode(self.consults, serializer);
-        <String>::sse_encode(self.individual_works, serializer);
-        <String>::sse_encode(self.individual_work_types, serializer);
-        <String>::sse_encode(self.course_works, serializer);
-        <String>::sse_encode(self.postgraduate_exams, serializer);
-        <String>::sse_encode(self.supervising, serializer);
-        <String>::sse_encode(self.internship, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::distribution::data::AcademicCalendar>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<crate::excel::data::InputRawRow> {
+impl SseEncode for Option<crate::distribution::data::DisciplineKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::excel::data::InputRawRow>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::distribution::data::DisciplineKey>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::excel::data::OutputMainWorkerRow> {
+impl SseEncode for Option<crate::distribution::data::DistributionProject> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::excel::data::OutputMainWorkerRow>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::distribution::data::DistributionProject>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::excel::data::OutputPersonalSemesterRow> {
+impl SseEncode for Option<crate::excel::data::ExportTheme> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::excel::data::OutputPersonalSemesterRow>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::excel::data::ExportTheme>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::excel::data::OutputPersonalTable> {
+impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::excel::data::OutputPersonalTable>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::excel::data::OutputPersonalTables> {
+impl SseEncode for Option<crate::distribution::data::NormsTable> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::excel::data::OutputPersonalTables>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::distribution::data::NormsTable>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Option<crate::excel::data::PdfFonts> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::excel::data::PdfFonts>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<(String, Vec<crate::excel::data::InputRawRow>)> {
+impl SseEncode for Option<crate::distribution::data::WorkloadField> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, Vec<crate::excel::data::InputRawRow>)>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::distribution::data::WorkloadField>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<crate::excel::data::WorkloadColumn>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::excel::data::WorkloadColumn>>::sse_encode(value, serializer);
         }
     }
 }
@@ -1102,12 +5847,15 @@ impl SseEncode for crate::excel::data::OutputHoursRow {
         <f64>::sse_encode(self.exam_consults, serializer);
         <f64>::sse_encode(self.tests, serializer);
         <f64>::sse_encode(self.qual_works, serializer);
+        <f64>::sse_encode(self.certification_exams, serializer);
         <f64>::sse_encode(self.working_practice, serializer);
         <f64>::sse_encode(self.teaching_practice, serializer);
         <f64>::sse_encode(self.consults, serializer);
         <f64>::sse_encode(self.individual_works, serializer);
         <f64>::sse_encode(self.course_works, serializer);
+        <f64>::sse_encode(self.postgraduate_exams, serializer);
         <f64>::sse_encode(self.supervising, serializer);
+        <f64>::sse_encode(self.internship, serializer);
         <f64>::sse_encode(self.total, serializer);
     }
 }
@@ -1125,27 +5873,7 @@ impl SseEncode for crate::excel::data::OutputMainRateAndHours {
 impl SseEncode for crate::excel::data::OutputMainTable {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_encode(self.heads, serializer);
-        <crate::excel::data::OutputMainRateAndHours>::sse_encode(self.heads_total, serializer);
-        <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_encode(self.professors, serializer);
-        <crate::excel::data::OutputMainRateAndHours>::sse_encode(self.professors_total, serializer);
-        <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_encode(
-            self.associate_professors,
-            serializer,
-        );
-        <crate::excel::data::OutputMainRateAndHours>::sse_encode(
-            self.associate_professors_total,
-            serializer,
-        );
-        <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_encode(self.lecturers, serializer);
-        <crate::excel::data::OutputMainRateAndHours>::sse_encode(self.lecturers_total, serializer);
-        <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_encode(self.assistants, serializer);
-        <crate::excel::data::OutputMainRateAndHours>::sse_encode(self.assistants_total, serializer);
-        <Vec<crate::excel::data::OutputMainWorkerRow>>::sse_encode(self.part_timers, serializer);
-        <crate::excel::data::OutputMainRateAndHours>::sse_encode(
-            self.part_timers_total,
-            serializer,
-        );
+        <Vec<crate::excel::data::MainTableSection>>::sse_encode(self.sections, serializer);
         <crate::excel::data::OutputMainRateAndHours>::sse_encode(self.total, serializer);
     }
 }
@@ -1170,6 +5898,7 @@ impl SseEncode for crate::excel::data::OutputPersonalSemesterRow {
         <String>::sse_encode(self.group, serializer);
         <String>::sse_encode(self.course, serializer);
         <u32>::sse_encode(self.students_count, serializer);
+        <String>::sse_encode(self.individual_work_types, serializer);
         <crate::excel::data::OutputHoursRow>::sse_encode(self.hours, serializer);
         <bool>::sse_encode(self.merge_lectures_with_next, serializer);
     }
@@ -1190,16 +5919,20 @@ impl SseEncode for crate::excel::data::OutputPersonalTable {
             serializer,
         );
         <f64>::sse_encode(self.semester_1_rate, serializer);
-        <crate::excel::data::OutputHoursRow>::sse_encode(self.semester_1_total_day, serializer);
-        <crate::excel::data::OutputHoursRow>::sse_encode(self.semester_1_total_evening, serializer);
+        <Vec<crate::excel::data::LearningFormTotal>>::sse_encode(
+            self.semester_1_form_totals,
+            serializer,
+        );
         <crate::excel::data::OutputHoursRow>::sse_encode(self.semester_1_total, serializer);
         <Vec<crate::excel::data::OutputPersonalSemesterRow>>::sse_encode(
             self.semester_2,
             serializer,
         );
         <f64>::sse_encode(self.semester_2_rate, serializer);
-        <crate::excel::data::OutputHoursRow>::sse_encode(self.semester_2_total_day, serializer);
-        <crate::excel::data::OutputHoursRow>::sse_encode(self.semester_2_total_evening, serializer);
+        <Vec<crate::excel::data::LearningFormTotal>>::sse_encode(
+            self.semester_2_form_totals,
+            serializer,
+        );
         <crate::excel::data::OutputHoursRow>::sse_encode(self.semester_2_total, serializer);
         <crate::excel::data::OutputHoursRow>::sse_encode(self.year_total, serializer);
     }
@@ -1222,6 +5955,86 @@ impl SseEncode for crate::excel::data::ParsedExcelFile {
     }
 }
 
+impl SseEncode for crate::excel::data::PdfFonts {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.regular, serializer);
+        <Option<String>>::sse_encode(self.bold, serializer);
+        <Option<String>>::sse_encode(self.italic, serializer);
+        <Option<String>>::sse_encode(self.bold_italic, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::PeriodNorm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.rate_months, serializer);
+        <f64>::sse_encode(self.supervision_hours, serializer);
+        <f64>::sse_encode(self.min_hours, serializer);
+        <f64>::sse_encode(self.max_hours, serializer);
+        <f64>::sse_encode(self.hours, serializer);
+    }
+}
+
+impl SseEncode for crate::excel::data::PersonalSheetName {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.worker_last_name, serializer);
+        <Vec<u32>>::sse_encode(self.table_ids, serializer);
+        <String>::sse_encode(self.sheet_name, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::RateNorms {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.rate_id, serializer);
+        <f64>::sse_encode(self.rate_value, serializer);
+        <f64>::sse_encode(self.months, serializer);
+        <crate::distribution::data::PeriodNorm>::sse_encode(self.first_semester, serializer);
+        <crate::distribution::data::PeriodNorm>::sse_encode(self.second_semester, serializer);
+        <crate::distribution::data::PeriodNorm>::sse_encode(self.year, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::ReconcileConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::ReconcileConflictKind>::sse_encode(self.kind, serializer);
+        <crate::distribution::data::DisciplineKey>::sse_encode(self.key, serializer);
+        <Option<crate::distribution::data::WorkloadField>>::sse_encode(self.field, serializer);
+        <f64>::sse_encode(self.total, serializer);
+        <f64>::sse_encode(self.distributed, serializer);
+        <Vec<String>>::sse_encode(self.employees, serializer);
+        <String>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::ReconcileConflictKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::distribution::data::ReconcileConflictKind::VanishedDiscipline => 0,
+                crate::distribution::data::ReconcileConflictKind::OverDistributed => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::distribution::data::ReconcileResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::DistributionProject>::sse_encode(self.project, serializer);
+        <crate::distribution::data::Form1Diff>::sse_encode(self.diff, serializer);
+        <Vec<crate::distribution::data::ReconcileConflict>>::sse_encode(self.conflicts, serializer);
+    }
+}
+
 impl SseEncode for (String, Vec<crate::excel::data::InputRawRow>) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1230,6 +6043,43 @@ impl SseEncode for (String, Vec<crate::excel::data::InputRawRow>) {
     }
 }
 
+impl SseEncode for crate::distribution::data::SolverOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::distribution::data::EmployeePreferences>>::sse_encode(
+            self.preferences,
+            serializer,
+        );
+        <Vec<crate::distribution::data::FieldRankRule>>::sse_encode(self.rank_rules, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::SolverResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::DistributionProject>::sse_encode(self.project, serializer);
+        <Vec<crate::distribution::data::UndistributedWorkload>>::sse_encode(
+            self.unplaced,
+            serializer,
+        );
+        <Vec<crate::distribution::data::DisciplineKey>>::sse_encode(self.split_items, serializer);
+        <Vec<crate::distribution::data::EmployeeWorkloadSummary>>::sse_encode(
+            self.employees,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::excel::data::TotalsMismatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.location, serializer);
+        <String>::sse_encode(self.field, serializer);
+        <f64>::sse_encode(self.expected, serializer);
+        <f64>::sse_encode(self.actual, serializer);
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1244,15 +6094,182 @@ impl SseEncode for u8 {
     }
 }
 
+impl SseEncode for crate::distribution::data::UndistributedWorkload {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::distribution::data::DisciplineKey>::sse_encode(self.workload_key, serializer);
+        <crate::distribution::data::WorkloadField>::sse_encode(self.field, serializer);
+        <f64>::sse_encode(self.total, serializer);
+        <f64>::sse_encode(self.distributed, serializer);
+        <f64>::sse_encode(self.undistributed, serializer);
+    }
+}
+
 impl SseEncode for () {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::distribution::data::UniversityForm1 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <String>::sse_encode(self.id, serializer);
+        <u32>::sse_encode(self.academic_year, serializer);
+        <Vec<crate::distribution::data::UniversityForm1WorkloadItem>>::sse_encode(
+            self.workload_items,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::distribution::data::UniversityForm1WorkloadItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <crate::distribution::data::DisciplineKey>::sse_encode(self.workload_key, serializer);
+        <f64>::sse_encode(self.week_count, serializer);
+        <u32>::sse_encode(self.student_count, serializer);
+        <f64>::sse_encode(self.flow_count, serializer);
+        <f64>::sse_encode(self.group_count, serializer);
+        <f64>::sse_encode(self.subgroup_count, serializer);
+        <f64>::sse_encode(self.lectures_planned, serializer);
+        <f64>::sse_encode(self.lectures_total, serializer);
+        <f64>::sse_encode(self.practices_planned, serializer);
+        <f64>::sse_encode(self.practices_total, serializer);
+        <f64>::sse_encode(self.labs_planned, serializer);
+        <f64>::sse_encode(self.labs_total, serializer);
+        <f64>::sse_encode(self.exams, serializer);
+        <f64>::sse_encode(self.exam_consults, serializer);
+        <f64>::sse_encode(self.tests, serializer);
+        <f64>::sse_encode(self.qualification_works, serializer);
+        <f64>::sse_encode(self.certification_exams, serializer);
+        <f64>::sse_encode(self.production_practices, serializer);
+        <f64>::sse_encode(self.teaching_practices, serializer);
+        <f64>::sse_encode(self.current_consults, serializer);
+        <f64>::sse_encode(self.individual_works, serializer);
+        <f64>::sse_encode(self.course_works, serializer);
+        <f64>::sse_encode(self.postgraduate_exams, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::UniversityForm3 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <u32>::sse_encode(self.academic_year, serializer);
+        <Vec<crate::distribution::data::Employee>>::sse_encode(self.employees, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::UniversityForm3WorkloadItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <crate::distribution::data::DisciplineKey>::sse_encode(self.workload_key, serializer);
+        <u32>::sse_encode(self.student_count, serializer);
+        <Vec<String>>::sse_encode(self.academic_groups, serializer);
+        <f64>::sse_encode(self.lectures, serializer);
+        <f64>::sse_encode(self.practices, serializer);
+        <f64>::sse_encode(self.labs, serializer);
+        <f64>::sse_encode(self.exams, serializer);
+        <f64>::sse_encode(self.exam_consults, serializer);
+        <f64>::sse_encode(self.tests, serializer);
+        <f64>::sse_encode(self.qualification_works, serializer);
+        <f64>::sse_encode(self.certification_exams, serializer);
+        <f64>::sse_encode(self.production_practices, serializer);
+        <f64>::sse_encode(self.teaching_practices, serializer);
+        <f64>::sse_encode(self.current_consults, serializer);
+        <f64>::sse_encode(self.individual_works, serializer);
+        <f64>::sse_encode(self.course_works, serializer);
+        <f64>::sse_encode(self.postgraduate_exams, serializer);
+    }
+}
+
+impl SseEncode for crate::excel::data::WorkloadColumn {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::excel::data::WorkloadKey>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.caption, serializer);
+        <f64>::sse_encode(self.width, serializer);
+        <String>::sse_encode(self.number_format, serializer);
+        <bool>::sse_encode(self.include_in_total, serializer);
+    }
+}
+
+impl SseEncode for crate::distribution::data::WorkloadField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::distribution::data::WorkloadField::StudentCount => 0,
+                crate::distribution::data::WorkloadField::Lectures => 1,
+                crate::distribution::data::WorkloadField::Practices => 2,
+                crate::distribution::data::WorkloadField::Labs => 3,
+                crate::distribution::data::WorkloadField::Exams => 4,
+                crate::distribution::data::WorkloadField::ExamConsults => 5,
+                crate::distribution::data::WorkloadField::Tests => 6,
+                crate::distribution::data::WorkloadField::QualificationWorks => 7,
+                crate::distribution::data::WorkloadField::CertificationExams => 8,
+                crate::distribution::data::WorkloadField::ProductionPractices => 9,
+                crate::distribution::data::WorkloadField::TeachingPractices => 10,
+                crate::distribution::data::WorkloadField::CurrentConsults => 11,
+                crate::distribution::data::WorkloadField::IndividualWorks => 12,
+                crate::distribution::data::WorkloadField::CourseWorks => 13,
+                crate::distribution::data::WorkloadField::PostgraduateExams => 14,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::excel::data::WorkloadInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.year, serializer);
+        <String>::sse_encode(self.type_name, serializer);
+        <Vec<crate::excel::data::WorkloadSectionInput>>::sse_encode(self.sections, serializer);
+    }
+}
+
+impl SseEncode for crate::excel::data::WorkloadKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::excel::data::WorkloadKey::Lectures => 0,
+                crate::excel::data::WorkloadKey::Practices => 1,
+                crate::excel::data::WorkloadKey::Labs => 2,
+                crate::excel::data::WorkloadKey::Exams => 3,
+                crate::excel::data::WorkloadKey::ExamConsults => 4,
+                crate::excel::data::WorkloadKey::Tests => 5,
+                crate::excel::data::WorkloadKey::QualWorks => 6,
+                crate::excel::data::WorkloadKey::CertificationExams => 7,
+                crate::excel::data::WorkloadKey::WorkingPractice => 8,
+                crate::excel::data::WorkloadKey::TeachingPractice => 9,
+                crate::excel::data::WorkloadKey::Consults => 10,
+                crate::excel::data::WorkloadKey::IndividualWorks => 11,
+                crate::excel::data::WorkloadKey::CourseWorks => 12,
+                crate::excel::data::WorkloadKey::PostgraduateExams => 13,
+                crate::excel::data::WorkloadKey::Supervising => 14,
+                crate::excel::data::WorkloadKey::Internship => 15,
+                crate::excel::data::WorkloadKey::Total => 16,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::excel::data::WorkloadSectionInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.title, serializer);
+        <Vec<crate::excel::data::EmployeeWorkloadInput>>::sse_encode(self.employees, serializer);
     }
 }
 
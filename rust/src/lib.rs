@@ -1,4 +1,5 @@
 pub mod api;
+mod distribution;
 mod excel;
 mod frb_generated;
 
@@ -19,8 +20,1155 @@ mod tests {
 
     #[test]
     fn test_generating() {
-        let file_path = "output.xlsx";
+        let file_path = std::env::temp_dir().join("aw_flutter_test_generating.xlsx");
+        let file_path = file_path.to_str().unwrap();
         let exported_tables = excel::document_generator::get_test_output_data();
-        api::excel_interface::write_excel_file(file_path.to_string(), exported_tables);
+        let options = excel::data::ExportOptions {
+            hide_empty_columns: true,
+            columns: None,
+            theme: None,
+            highlight_workload_norms: true,
+            include_analytics: true,
+        };
+        api::excel_interface::write_excel_file(file_path.to_string(), exported_tables, options)
+            .unwrap();
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_totals() {
+        let exported_tables = excel::document_generator::get_test_output_data();
+        let employees = exported_tables
+            .personal_tables
+            .into_iter()
+            .flat_map(|tables| tables.tables)
+            .map(|table| excel::data::EmployeeWorkloadInput {
+                id: table.id,
+                first_name: table.first_name,
+                middle_name: table.middle_name,
+                last_name: table.last_name,
+                rank: table.rank,
+                comment_semester_1: table.comment_semester_1,
+                comment_semester_2: table.comment_semester_2,
+                semester_1: table.semester_1,
+                semester_1_rate: table.semester_1_rate,
+                semester_2: table.semester_2,
+                semester_2_rate: table.semester_2_rate,
+            })
+            .collect();
+        let input = excel::data::WorkloadInput {
+            year: exported_tables.year,
+            type_name: exported_tables.type_name,
+            sections: vec![excel::data::WorkloadSectionInput {
+                title: "Разом".to_string(),
+                employees,
+            }],
+        };
+        let built = api::excel_interface::build_exported_data(input);
+        let mismatches = excel::totals::validate_exported_data(&built);
+        assert!(mismatches.is_empty(), "{} mismatches", mismatches.len());
+    }
+    #[test]
+    fn test_totals_mismatch() {
+        let mismatches = |data: &excel::data::ExportedData| -> Vec<(String, String, f64, f64)> {
+            excel::totals::validate_exported_data(data)
+                .into_iter()
+                .map(|mismatch| {
+                    (
+                        mismatch.location,
+                        mismatch.field,
+                        mismatch.expected,
+                        mismatch.actual,
+                    )
+                })
+                .collect()
+        };
+        let data =
+            || excel::totals::build_exported_data(test_workload_input(1.0, 1.0, &[32.0], &[]));
+
+        let mut section_total = data();
+        section_total.main_table.sections[0]
+            .total
+            .semester_1
+            .lectures = 30.0;
+        // The department total is summed from the section totals.
+        assert_eq!(
+            mismatches(&section_total),
+            [
+                (
+                    "Загальна, «Всього за доцентами», I семестр".to_string(),
+                    "Лекції".to_string(),
+                    32.0,
+                    30.0,
+                ),
+                (
+                    "Загальна, «Разом по кафедрі», I семестр".to_string(),
+                    "Лекції".to_string(),
+                    30.0,
+                    32.0,
+                ),
+                (
+                    "Загальна, «Разом по кафедрі», I семестр".to_string(),
+                    "Всього".to_string(),
+                    30.0,
+                    32.0,
+                ),
+            ]
+        );
+
+        let mut personal_total = data();
+        personal_total.personal_tables[0].tables[0]
+            .semester_1_total
+            .lectures = 40.0;
+        assert_eq!(
+            mismatches(&personal_total),
+            [
+                (
+                    "Коваленко Олена (№1), I семестр, «Усього за I семестр»".to_string(),
+                    "Лекції".to_string(),
+                    32.0,
+                    40.0,
+                ),
+                (
+                    "Коваленко Олена (№1), «Усього за рік»".to_string(),
+                    "Лекції".to_string(),
+                    40.0,
+                    32.0,
+                ),
+                (
+                    "Коваленко Олена (№1), «Усього за рік»".to_string(),
+                    "Всього".to_string(),
+                    40.0,
+                    32.0,
+                ),
+                (
+                    "Загальна, Коваленко Олена, I семестр".to_string(),
+                    "Лекції".to_string(),
+                    40.0,
+                    32.0,
+                ),
+            ]
+        );
+    }
+    #[test]
+    fn test_json_round_trip() {
+        let exported_tables = excel::document_generator::get_test_output_data();
+        let json = serde_json::to_string(&exported_tables).unwrap();
+        let parsed: excel::data::ExportedData = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+    }
+    #[test]
+    fn test_csv() {
+        let mut data = excel::totals::build_exported_data(test_workload_input(
+            1.0,
+            1.0,
+            &[32.0, 16.0],
+            &[8.0],
+        ));
+        let row = &mut data.personal_tables[0].tables[0].semester_1[0];
+        row.name = "Бази \"даних\"; частина 1\nлаб.".to_string();
+        row.group = "ПЗ-24; ПЗ-25".to_string();
+        row.hours.practices = 4.0;
+        let options = excel::data::ExportOptions {
+            hide_empty_columns: false,
+            columns: None,
+            theme: None,
+            highlight_workload_norms: false,
+            include_analytics: false,
+        };
+        let csv = excel::data_export::generate_csv(&data, &options);
+        let teacher = "1,Коваленко,Олена,Петрівна,доцент";
+        let row = "\"Бази \"\"даних\"\"; частина 1\nлаб.\",Д,121,\"ПЗ-24; ПЗ-25\",1,25";
+        let lines: Vec<&str> = csv.split_terminator("\r\n").collect();
+        assert_eq!(
+            lines,
+            [
+                "teacher_id,last_name,first_name,middle_name,rank,semester,discipline,\
+                 learning_form,speciality,group,course,students_count,workload_key,\
+                 workload_type,hours"
+                    .to_string(),
+                format!("{teacher},1,{row},lectures,Лекції,32"),
+                format!("{teacher},1,{row},practices,Практичні (семінарські) заняття,4"),
+                format!("{teacher},1,Програмування,Д,121,ПЗ-24,1,25,lectures,Лекції,16"),
+                format!("{teacher},2,Програмування,Д,121,ПЗ-24,1,25,lectures,Лекції,8"),
+            ]
+        );
+    }
+    #[test]
+    fn test_distribution_limits() {
+        let (mut project, key) = test_project();
+        let lectures = distribution::data::WorkloadField::Lectures;
+        project
+            .add_workload_item("e1", "r1", test_item(&key, 20.0))
+            .unwrap();
+        assert_eq!(
+            project.undistributed_workload(&key, lectures).unwrap(),
+            12.0
+        );
+        assert!(
+            project
+                .update_workload_field("e1", "r1", "i1", lectures, 40.0)
+                .is_err()
+        );
+        project
+            .update_workload_field("e1", "r1", "i1", lectures, 32.0)
+            .unwrap();
+        assert!(distribution::project::validate_distribution(&project).is_empty());
+    }
+    #[test]
+    fn test_project_export() {
+        let (mut project, key) = test_project();
+        project
+            .add_workload_item("e1", "r1", test_item(&key, 32.0))
+            .unwrap();
+        let calendar = distribution::rate_norms::default_academic_calendar(2024);
+        let exported =
+            distribution::export::build_project_exported_data(&project, "КЕО", &calendar).unwrap();
+        assert!(excel::totals::validate_exported_data(&exported).is_empty());
+        let section = &exported.main_table.sections[0];
+        assert_eq!(section.title, "Всього за доцентами");
+        assert_eq!(section.rows[0].rate_and_hours.year.total, 32.0);
+        assert!(exported.personal_tables[0].tables[0].semester_2.is_empty());
+    }
+    #[test]
+    fn test_solver_rank_rules() {
+        let (project, _) = test_project();
+        let options = |min_rank| distribution::data::SolverOptions {
+            preferences: vec![],
+            rank_rules: vec![distribution::data::FieldRankRule {
+                field: distribution::data::WorkloadField::Lectures,
+                min_rank,
+            }],
+        };
+        let calendar = distribution::rate_norms::default_academic_calendar(2024);
+        let result = distribution::solver::distribute_workload(
+            &project,
+            &options(distribution::data::EmployeeRank::Associate),
+            &calendar,
+            0.0,
+        )
+        .unwrap();
+        assert!(result.unplaced.is_empty());
+        assert_eq!(result.employees[0].hours, 32.0);
+        let result = distribution::solver::distribute_workload(
+            &project,
+            &options(distribution::data::EmployeeRank::Professor),
+            &calendar,
+            0.0,
+        )
+        .unwrap();
+        assert_eq!(result.unplaced[0].undistributed, 32.0);
+    }
+    #[test]
+    fn test_carry_over() {
+        let (mut previous, key) = test_project();
+        previous
+            .add_workload_item("e1", "r1", test_item(&key, 32.0))
+            .unwrap();
+        let mut form1 = previous.university_form1.clone();
+        form1.academic_year = 2025;
+        form1.workload_items[0].workload_key.discipline_name = "Бази данних".to_string();
+        form1.workload_items[0].lectures_total = 40.0;
+        let result =
+            distribution::carry_over::carry_over_distribution(&previous, form1, 0.8).unwrap();
+        let rate = &result.project.university_form3.employees[0].rates[0];
+        assert_eq!(rate.date_start, "2025-09-01");
+        assert_eq!(rate.workload_items[0].lectures, 40.0);
+        assert_eq!(
+            result.changed_items[0].changed_fields,
+            [distribution::data::WorkloadField::Lectures]
+        );
+        assert!(result.new_items.is_empty() && result.removed_items.is_empty());
+        assert_eq!(result.project.id, previous.id);
+        assert_eq!(result.project.university_form3.id, "u3");
+        assert_eq!(result.project.created_at, previous.created_at);
+    }
+    #[test]
+    fn test_form1_diff() {
+        let (mut project, key) = test_project();
+        project
+            .add_workload_item("e1", "r1", test_item(&key, 32.0))
+            .unwrap();
+        let old_rows = project.university_form1.workload_items.clone();
+        let mut new_rows = old_rows.clone();
+        new_rows[0].lectures_total = 48.0;
+        new_rows.push(old_rows[0].clone());
+        new_rows[1].workload_key.course = "3".to_string();
+        let diff = distribution::form1_diff::diff_form1(&old_rows, &new_rows, Some(&project));
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.added.len(), 1);
+        let changed = &diff.changed[0];
+        assert_eq!(changed.changes.len(), 1);
+        assert_eq!(changed.changes[0].new_value, 48.0);
+        assert_eq!(changed.affected_employees, ["Іван Петренко"]);
+    }
+    #[test]
+    fn test_reconcile() {
+        let (mut project, key) = test_project();
+        project
+            .add_workload_item("e1", "r1", test_item(&key, 32.0))
+            .unwrap();
+        let mut form1 = project.university_form1.clone();
+        form1.workload_items[0].lectures_total = 16.0;
+        let result = distribution::reconcile::reconcile_distribution(&project, form1.clone());
+        assert!(result.conflicts.is_empty());
+        let rate = &result.project.university_form3.employees[0].rates[0];
+        assert_eq!(rate.workload_items[0].lectures, 16.0);
+
+        form1.workload_items[0].workload_key.course = "3".to_string();
+        let result = distribution::reconcile::reconcile_distribution(&project, form1);
+        assert_eq!(result.conflicts.len(), 1);
+        assert_eq!(result.conflicts[0].distributed, 32.0);
+    }
+    #[test]
+    fn test_form1_norms() {
+        let (project, _) = test_project();
+        let mut form1 = project.university_form1;
+        form1.workload_items[0].exams = 5.0;
+        let norms = distribution::form1_norms::default_norms_table();
+        let findings = distribution::form1_norms::check_form1_norms(&form1, &norms);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].norm_value, 8.25);
+        let form1 = distribution::form1_norms::apply_form1_norms(form1, &norms);
+        assert_eq!(form1.workload_items[0].exams, 8.25);
+        assert!(distribution::form1_norms::check_form1_norms(&form1, &norms).is_empty());
+    }
+    #[test]
+    fn test_form1_check() {
+        let row = excel::data::InputRawRow {
+            learning_form: "Д".to_string(),
+            speciality: "121".to_string(),
+            name: "Програмування".to_string(),
+            course: "2".to_string(),
+            semester: "5".to_string(),
+            flows_count: "2".to_string(),
+            lectures_planned_count: "16".to_string(),
+            lectures_total_count: "30".to_string(),
+            exams: "-1".to_string(),
+            tests: "abc".to_string(),
+            ..Default::default()
+        };
+        let duplicate = excel::data::InputRawRow {
+            lectures_total_count: "32".to_string(),
+            exams: String::new(),
+            tests: "-".to_string(),
+            semester: "1".to_string(),
+            ..row.clone()
+        };
+        // Counted from the start of studies, but imported as the first.
+        let study_numbered = excel::data::InputRawRow {
+            name: "Бази даних".to_string(),
+            semester: "4".to_string(),
+            ..duplicate.clone()
+        };
+        let findings =
+            distribution::form1_check::check_form1_rows(&[row, duplicate, study_numbered]);
+        let kinds: Vec<_> = findings
+            .iter()
+            .map(|finding| (finding.row, finding.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (1, distribution::data::Form1FindingKind::NegativeValue),
+                (1, distribution::data::Form1FindingKind::UnreadableValue),
+                (1, distribution::data::Form1FindingKind::TotalMismatch),
+                (1, distribution::data::Form1FindingKind::SemesterMismatch),
+                (2, distribution::data::Form1FindingKind::DuplicateKey),
+                (3, distribution::data::Form1FindingKind::SemesterMismatch),
+            ]
+        );
+    }
+    #[test]
+    fn test_rate_norms() {
+        let (mut project, key) = test_project();
+        let employee = &mut project.university_form3.employees[0];
+        let mut rate = employee.rates[0].clone();
+        employee.rates[0].postgraduate_count = 1;
+        employee.rates[0].workload_items.push(test_item(&key, 32.0));
+        rate.id = "r2".to_string();
+        rate.rate_value = 0.5;
+        rate.date_start = "2025-02-15".to_string();
+        employee.rates.push(rate);
+
+        let calendar = distribution::rate_norms::default_academic_calendar(2024);
+        let norms = distribution::rate_norms::employee_norms(&project, &calendar, 45.0).unwrap();
+        let norms = &norms[0];
+        assert_eq!(norms.peak_rate, 1.5);
+        assert_eq!(norms.rates[0].months, 10.0);
+        assert_eq!(norms.rates[0].year.min_hours, 580.0);
+        // 22.5 hours a semester, rounded once for the year.
+        assert_eq!(norms.rates[0].year.supervision_hours, 45.0);
+        assert_eq!(norms.rates[0].first_semester.supervision_hours, 23.0);
+        assert_eq!(norms.rates[0].second_semester.supervision_hours, 22.0);
+        assert_eq!(norms.rates[0].first_semester.hours, 55.0);
+        assert_eq!(norms.rates[1].months, 4.5);
+        assert_eq!(norms.rates[1].first_semester.max_hours, 0.0);
+        assert_eq!(norms.rates[1].second_semester.min_hours, 131.0);
+        assert_eq!(norms.year.max_hours, 735.0);
+        // The summaries and the solver count the same norm.
+        let summaries =
+            distribution::project::employee_summaries(&project, &calendar, 45.0).unwrap();
+        assert_eq!(summaries[0].min_possible_hours, norms.year.min_hours);
+        assert_eq!(summaries[0].max_possible_hours, norms.year.max_hours);
+        assert_eq!(summaries[0].hours, norms.year.hours);
+
+        let mut calendar = calendar;
+        calendar.first_semester_end = "2024-08-31".to_string();
+        assert!(distribution::rate_norms::employee_norms(&project, &calendar, 45.0).is_err());
+    }
+    #[test]
+    fn test_semester_coverage() {
+        let (mut project, key) = test_project();
+        let rate = &mut project.university_form3.employees[0].rates[0];
+        rate.date_start = "2025-02-01".to_string();
+        rate.workload_items.push(test_item(&key, 32.0));
+        let calendar = distribution::rate_norms::default_academic_calendar(2024);
+        let exported =
+            distribution::export::build_project_exported_data(&project, "КЕО", &calendar).unwrap();
+        let table = &exported.personal_tables[0].tables[0];
+        assert_eq!(table.semester_1_rate, 0.0);
+        assert_eq!(table.semester_2_rate, 1.0);
+        let issues =
+            distribution::rate_norms::check_semester_coverage(&project, &calendar).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].item_id.as_deref(), Some("i1"));
+    }
+    #[test]
+    fn test_short_personal_table() {
+        let mut data =
+            excel::totals::build_exported_data(test_workload_input(1.0, 1.0, &[32.0], &[]));
+        let table = &mut data.personal_tables[0].tables[0];
+        table.semester_1_form_totals.clear();
+        table.semester_2_form_totals.clear();
+        let options = excel::data::ExportOptions {
+            hide_empty_columns: false,
+            columns: None,
+            theme: None,
+            highlight_workload_norms: false,
+            include_analytics: false,
+        };
+        let book = excel::document_generator::build_workbook(&data, &options);
+        let sheet = book.get_sheet_by_name("Коваленко").unwrap();
+        let layout = excel::sheet_layout::SheetLayout::from_worksheet(sheet);
+        for area in &layout.merged_areas {
+            assert!(area.first_row <= area.last_row && area.first_column <= area.last_column);
+        }
+        // The name and both semester titles keep rows of their own.
+        assert_eq!(layout.cell(10, 2).unwrap().text, "Олена");
+        assert!(layout.merged_area_at(10, 2).is_none());
+        assert_eq!(layout.cell(12, 1).unwrap().text, "II семестр");
+    }
+    #[test]
+    fn test_pdf_pages() {
+        // Any system font with Cyrillic, the test is skipped without one.
+        let Some(regular) = [
+            "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+            "/System/Library/Fonts/Supplemental/Arial.ttf",
+            "C:\\Windows\\Fonts\\arial.ttf",
+        ]
+        .into_iter()
+        .find(|path| std::path::Path::new(path).is_file()) else {
+            eprintln!("test_pdf_pages skipped: no font found");
+            return;
+        };
+        let file_path = std::env::temp_dir().join("aw_flutter_test_pdf_pages.pdf");
+        let file_path = file_path.to_str().unwrap();
+        let fonts = excel::data::PdfFonts {
+            regular: regular.to_string(),
+            bold: None,
+            italic: None,
+            bold_italic: None,
+        };
+        let mut book = umya_spreadsheet::new_file();
+        book.new_sheet("Порожній").unwrap();
+        excel::pdf_generator::write_workbook_pdf(file_path, "Тест", &book, &fonts).unwrap();
+        let document = printpdf::lopdf::Document::load(file_path).unwrap();
+        assert_eq!(document.get_pages().len(), 2);
+
+        let data = excel::totals::build_exported_data(test_workload_input(1.0, 1.0, &[32.0], &[]));
+        let options = excel::data::ExportOptions {
+            hide_empty_columns: false,
+            columns: None,
+            theme: None,
+            highlight_workload_norms: false,
+            include_analytics: false,
+        };
+        let sheet_count = excel::document_generator::build_workbook(&data, &options)
+            .get_sheet_collection()
+            .len();
+        excel::pdf_generator::generate_pdf_file(file_path, data, options, fonts).unwrap();
+        let document = printpdf::lopdf::Document::load(file_path).unwrap();
+        assert!(document.get_pages().len() >= sheet_count);
+        std::fs::remove_file(file_path).unwrap();
+    }
+    #[test]
+    fn test_ods_merges() {
+        let file_path = std::env::temp_dir().join("aw_flutter_test_ods_merges.ods");
+        let file_path = file_path.to_str().unwrap();
+        let mut book = umya_spreadsheet::new_file();
+        let sheet = book.get_sheet_mut(&0).unwrap();
+        sheet.add_merge_cells("A1:B2");
+        sheet.get_cell_mut((3, 2)).set_value("Текст");
+        let layouts = [excel::sheet_layout::SheetLayout::from_worksheet(sheet)];
+        excel::ods_generator::write_ods_file(file_path, &layouts).unwrap();
+        let tables = read_ods_tables(file_path);
+        // The empty top-left cell of the merge still spans it.
+        assert_eq!(tables[0][0][..2], [Some((2, 2)), None]);
+        assert_eq!(tables[0][1][..2], [None, None]);
+
+        let data = excel::totals::build_exported_data(test_workload_input(1.0, 1.0, &[32.0], &[]));
+        let options = excel::data::ExportOptions {
+            hide_empty_columns: false,
+            columns: None,
+            theme: None,
+            highlight_workload_norms: true,
+            include_analytics: true,
+        };
+        excel::ods_generator::generate_ods_file(file_path, data, options).unwrap();
+        for table in read_ods_tables(file_path) {
+            let mut covered = std::collections::HashSet::new();
+            for (row, cells) in (1..).zip(&table) {
+                for (column, cell) in (1..).zip(cells) {
+                    let Some((columns, rows)) = *cell else {
+                        assert!(covered.remove(&(row, column)));
+                        continue;
+                    };
+                    assert!(!covered.contains(&(row, column)));
+                    for covered_row in row..row + rows {
+                        for covered_column in column..column + columns {
+                            covered.insert((covered_row, covered_column));
+                        }
+                    }
+                    covered.remove(&(row, column));
+                }
+            }
+            assert!(covered.is_empty());
+        }
+        std::fs::remove_file(file_path).unwrap();
+    }
+    #[test]
+    fn test_html_theme() {
+        let file_path = std::env::temp_dir().join("aw_flutter_test_html_theme.html");
+        let file_path = file_path.to_str().unwrap();
+        let data = excel::totals::build_exported_data(test_workload_input(1.0, 1.0, &[32.0], &[]));
+        let options = excel::data::ExportOptions {
+            hide_empty_columns: false,
+            columns: None,
+            theme: Some(excel::data::ExportTheme {
+                font_name: "PT Serif".to_string(),
+                font_size: 12.0,
+            }),
+            highlight_workload_norms: false,
+            include_analytics: true,
+        };
+        excel::html_generator::generate_html_file(file_path, data, options).unwrap();
+        let html = std::fs::read_to_string(file_path).unwrap();
+        assert!(html.contains(r#"body { font-family: "PT Serif", serif; }"#));
+        assert!(!html.contains("Times New Roman"));
+        assert!(html.contains("font-size:12pt;"));
+        for id in ["main", "teacher-1", "analytics"] {
+            assert!(html.contains(&format!(r#"<section id="{id}"><table"#)));
+        }
+        assert_eq!(html.matches("<table").count(), 3);
+        assert_eq!(html.matches("</table>").count(), 3);
+        std::fs::remove_file(file_path).unwrap();
+    }
+    #[test]
+    fn test_transliteration() {
+        let cases = [
+            ("Хандецький", "Khandetskyi"),
+            ("Юрій", "Yurii"),
+            ("Згурський", "Zghurskyi"),
+            ("Єрмоленко Їжакевич", "Yermolenko Yizhakevych"),
+            ("Костянтин", "Kostiantyn"),
+            ("Знам'янка", "Znamianka"),
+            ("ЩУКА", "SHCHUKA"),
+            ("Ю. ЯЩЕНКО", "Yu. YASHCHENKO"),
+            ("Ivan 2", "Ivan 2"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(excel::transliteration::transliterate(text), expected);
+        }
+        let cases = [
+            ("Хандецький В.С.", "Khandetskyi_V_S"),
+            ("  Іванов / Петров-Сидоренко ", "Ivanov_Petrov-Sydorenko"),
+            ("Розподіл 2024-2025", "Rozpodil_2024-2025"),
+            ("???", "_"),
+            ("", "_"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(excel::transliteration::safe_file_name(text), expected);
+        }
+    }
+    #[test]
+    fn test_sheet_names() {
+        use excel::sheet_names::sanitize_sheet_name;
+
+        assert_eq!(
+            sanitize_sheet_name("Іванов/Петров: [1]?*"),
+            "Іванов_Петров_ _1___"
+        );
+        assert_eq!(sanitize_sheet_name(" 'Шевченко' "), "Шевченко");
+        assert_eq!(sanitize_sheet_name(&"Я".repeat(40)), "Я".repeat(31));
+        // The space the truncation ends on is trimmed too.
+        let name = format!("{} Б", "Я".repeat(30));
+        assert_eq!(sanitize_sheet_name(&name), "Я".repeat(30));
+        assert_eq!(sanitize_sheet_name("''"), "Аркуш");
+
+        let long_name = "Римський-Корсаков-Мусоргський-Бородін";
+        let people = [
+            ("Шевченко", "Олена", "Петрівна"),
+            ("Шевченко", "Олег", "Іванович"),
+            ("Загальна", "Ірина", "Олегівна"),
+            ("аналітика", "Ігор", "Олегович"),
+            (long_name, "Олена", "Петрівна"),
+            (long_name, "Олена", "Павлівна"),
+        ];
+        let employees = (1..)
+            .zip(people)
+            .map(|(id, (last_name, first_name, middle_name))| {
+                let mut employee = test_workload_input(1.0, 1.0, &[32.0], &[])
+                    .sections
+                    .remove(0)
+                    .employees
+                    .remove(0);
+                employee.id = id;
+                employee.last_name = last_name.to_string();
+                employee.first_name = first_name.to_string();
+                employee.middle_name = middle_name.to_string();
+                employee
+            })
+            .collect();
+        let mut input = test_workload_input(1.0, 1.0, &[], &[]);
+        input.sections[0].employees = employees;
+        let data = excel::totals::build_exported_data(input);
+        let names: Vec<String> = excel::sheet_names::personal_sheet_names(&data.personal_tables)
+            .into_iter()
+            .map(|name| name.sheet_name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "Шевченко О.П.",
+                "Шевченко О.І.",
+                "Загальна (2)",
+                "аналітика (2)",
+                "Римський-Корсаков-Мусоргський-Б",
+                "Римський-Корсаков-Мусоргськ (2)",
+            ]
+        );
+    }
+    #[test]
+    fn test_column_letter() {
+        let cases = [
+            (1, "A"),
+            (26, "Z"),
+            (27, "AA"),
+            (52, "AZ"),
+            (702, "ZZ"),
+            (703, "AAA"),
+        ];
+        for (x, letters) in cases {
+            assert_eq!(excel::excel_functions::column_letter(x), letters);
+        }
+        let mut book = umya_spreadsheet::new_file();
+        let sheet = book.get_sheet_mut(&0).unwrap();
+        excel::excel_functions::merge_cells(sheet, (26, 1), (27, 2));
+        assert_eq!(sheet.get_merge_cells()[0].get_range(), "Z1:AA2");
+    }
+    #[test]
+    fn test_partial_year_norm() {
+        let options = excel::data::ExportOptions {
+            hide_empty_columns: false,
+            columns: None,
+            theme: None,
+            highlight_workload_norms: true,
+            include_analytics: false,
+        };
+        let comments = |input| {
+            let data = excel::totals::build_exported_data(input);
+            let book = excel::document_generator::build_workbook(&data, &options);
+            ["Загальна", "Коваленко"].map(|name| {
+                let sheet = book.get_sheet_by_name(name).unwrap();
+                let layout = excel::sheet_layout::SheetLayout::from_worksheet(sheet);
+                let mut comments = Vec::new();
+                for row in 1..=layout.row_count() {
+                    for column in 1..=layout.column_count() {
+                        let comment = layout
+                            .cell(row, column)
+                            .and_then(|cell| cell.comment.clone());
+                        comments.extend(comment);
+                    }
+                }
+                comments
+            })
+        };
+        // Hired in February, half of the year's norm.
+        let no_comments: [Vec<String>; 2] = Default::default();
+        assert_eq!(
+            comments(test_workload_input(0.0, 1.0, &[], &[290.0])),
+            no_comments
+        );
+        let underload = vec!["Недовантаження: 200 із 290".to_string()];
+        assert_eq!(
+            comments(test_workload_input(0.0, 1.0, &[], &[200.0])),
+            [underload.clone(), underload]
+        );
+        let underload = vec!["Недовантаження: 290 із 580".to_string()];
+        assert_eq!(
+            comments(test_workload_input(1.0, 1.0, &[], &[290.0])),
+            [underload.clone(), underload]
+        );
+    }
+    #[test]
+    fn test_analytics() {
+        let employee = |id, last_name: &str, rank: &str, semester_1: &[f64], semester_2: &[f64]| {
+            let mut employee = test_workload_input(1.0, 1.0, semester_1, semester_2)
+                .sections
+                .remove(0)
+                .employees
+                .remove(0);
+            employee.id = id;
+            employee.last_name = last_name.to_string();
+            employee.rank = rank.to_string();
+            employee
+        };
+        let mut economics = employee(2, "Шевченко", "доцент, к.е.н.", &[10.004], &[]);
+        economics.semester_1[0].name = "Економіка".to_string();
+        let mut input = test_workload_input(1.0, 1.0, &[], &[]);
+        input.sections[0].employees = vec![
+            employee(1, "Коваленко", "доцент, к.т.н.", &[32.0], &[16.0]),
+            economics,
+        ];
+        input.sections.push(excel::data::WorkloadSectionInput {
+            title: "Всього за професорами".to_string(),
+            employees: vec![employee(3, "Бондар", "професор, д.т.н.", &[], &[20.0])],
+        });
+        let data = excel::totals::build_exported_data(input);
+        let columns = excel::columns::default_workload_columns();
+        let tables = excel::analytics::workload_analytics(&data, &columns);
+        let rows = |title: &str| {
+            let table = tables.iter().find(|table| table.title == title).unwrap();
+            table
+                .rows
+                .iter()
+                .map(|(group, hours)| (group.as_str(), *hours))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            rows("Години за дисциплінами"),
+            [("Програмування", 68.0), ("Економіка", 10.0)]
+        );
+        assert_eq!(
+            rows("Години за семестрами"),
+            [("I семестр", 42.0), ("II семестр", 36.0)]
+        );
+        assert_eq!(
+            rows("Частка лекцій за посадами"),
+            [
+                ("Всього за доцентами", 58.0),
+                ("Всього за професорами", 20.0)
+            ]
+        );
+        assert_eq!(tables[0].total(), 78.0);
+    }
+    #[test]
+    fn test_dart_project_json() {
+        // As `WorkloadDistributionProject.toJson` writes it.
+        let json = r#"{
+            "id": 7,
+            "title": "Розподіл 2024",
+            "universityForm1": {
+                "id": "9b2f6a1e-3c4d-4e5f-8a7b-1c2d3e4f5a6b",
+                "academicYear": 2024,
+                "workloadItems": [{
+                    "id": "Лист1-1",
+                    "workloadKey": {"learningForm": "daytime", "specialty": "121",
+                        "disciplineName": "Бази даних", "course": "2", "semester": 1},
+                    "weekCount": 15.0, "studentCount": 25, "flowCount": 1.0,
+                    "groupCount": 1.0, "subgroupCount": 2.0, "lecturesPlanned": 32.0,
+                    "lecturesTotal": 32.0, "practicesPlanned": 0.0, "practicesTotal": 0.0,
+                    "labsPlanned": 16.0, "labsTotal": 32.0, "exams": 8.33,
+                    "examConsults": 2.0, "tests": 0.0, "qualificationWorks": 0.0,
+                    "certificationExams": 0.0, "productionPractices": 0.0,
+                    "teachingPractices": 0.0, "currentConsults": 4.8,
+                    "individualWorks": 0.0, "courseWorks": 0.0, "postgraduateExams": 0.0
+                }]
+            },
+            "universityForm3": {
+                "id": "0f1e2d3c-4b5a-4968-b7a6-958473625140",
+                "academicYear": 2024,
+                "employees": [{
+                    "id": "e1", "firstName": "Іван", "lastName": "Петренко",
+                    "patronymic": "Олегович", "rank": "seniorLecturer",
+                    "rates": [{
+                        "id": "r1", "rateValue": 0.75,
+                        "dateStart": "2024-09-01T00:00:00.000",
+                        "dateEnd": "2025-06-30T00:00:00.000",
+                        "postgraduateCount": 1,
+                        "workloadItems": [{
+                            "id": "3e8a4b2c-1d0f-4a9e-8b7c-6d5e4f3a2b1c",
+                            "workloadKey": {"learningForm": "daytime", "specialty": "121",
+                                "disciplineName": "Бази даних", "course": "2", "semester": 1},
+                            "studentCount": 25, "academicGroups": ["КН-21", "КН-22"],
+                            "lectures": 32.0, "practices": 0.0, "labs": 16.0, "exams": 0.0,
+                            "examConsults": 0.0, "tests": 0.0, "qualificationWorks": 0.0,
+                            "certificationExams": 0.0, "productionPractices": 0.0,
+                            "teachingPractices": 0.0, "currentConsults": 0.0,
+                            "individualWorks": 0.0, "courseWorks": 0.0,
+                            "postgraduateExams": 0.0
+                        }]
+                    }]
+                }]
+            },
+            "createdAt": "2024-08-20T09:15:00.000",
+            "updatedAt": "2024-08-21T16:40:12.345"
+        }"#;
+        let dart: serde_json::Value = serde_json::from_str(json).unwrap();
+        let project: distribution::data::DistributionProject = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&project).unwrap(), dart);
+
+        let file_path = std::env::temp_dir().join("aw_flutter_test_dart_project.json");
+        let file_path = file_path.to_str().unwrap().to_string();
+        api::distribution_interface::write_distribution_project(file_path.clone(), project)
+            .unwrap();
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&file_path).unwrap()).unwrap();
+        assert_eq!(written, dart);
+        std::fs::remove_file(file_path).unwrap();
+    }
+    #[test]
+    fn test_duplicate_item_ids() {
+        let (mut project, key) = test_project();
+        project
+            .add_workload_item("e1", "r1", test_item(&key, 16.0))
+            .unwrap();
+        assert!(
+            project
+                .add_workload_item("e1", "r1", test_item(&key, 16.0))
+                .is_err()
+        );
+        assert!(distribution::project::validate_distribution(&project).is_empty());
+
+        // Within the plan, only the id is wrong.
+        let mut duplicate = test_item(&key, 16.0);
+        duplicate.student_count = 0;
+        project.university_form3.employees[0].rates[0]
+            .workload_items
+            .push(duplicate);
+        let issues = distribution::project::validate_distribution(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].item_id.as_deref(), Some("i1"));
+    }
+    #[test]
+    fn test_solver_repeat_run() {
+        let (project, key) = test_project();
+        let options = distribution::data::SolverOptions {
+            preferences: vec![],
+            rank_rules: vec![],
+        };
+        let calendar = distribution::rate_norms::default_academic_calendar(2024);
+        let mut project =
+            distribution::solver::distribute_workload(&project, &options, &calendar, 0.0)
+                .unwrap()
+                .project;
+        let mut row = project.university_form1.workload_items[0].clone();
+        row.id = "f2".to_string();
+        row.workload_key.discipline_name = "Операційні системи".to_string();
+        row.lectures_total = 16.0;
+        project.university_form1.workload_items.push(row);
+        let mut project =
+            distribution::solver::distribute_workload(&project, &options, &calendar, 0.0)
+                .unwrap()
+                .project;
+
+        let ids: Vec<String> = project
+            .assigned_items()
+            .map(|(_, _, item)| item.id.clone())
+            .collect();
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        assert!(distribution::project::validate_distribution(&project).is_empty());
+        project
+            .update_workload_field(
+                "e1",
+                "r1",
+                &ids[0],
+                distribution::data::WorkloadField::Lectures,
+                30.0,
+            )
+            .unwrap();
+        let lectures: Vec<f64> = project
+            .assigned_items()
+            .map(|(_, _, item)| item.lectures)
+            .collect();
+        assert_eq!(lectures, [30.0, 16.0]);
+        assert!(project.assigned_items().next().unwrap().2.workload_key == key);
+    }
+    #[test]
+    fn test_solver_semester_rates() {
+        let (mut project, _) = test_project();
+        let mut employee = project.university_form3.employees[0].clone();
+        employee.id = "e2".to_string();
+        employee.rates[0].id = "r2".to_string();
+        let rate = &mut project.university_form3.employees[0].rates[0];
+        rate.date_start = "2025-02-01".to_string();
+        rate.rate_value = 3.0;
+        project.university_form3.employees.push(employee);
+        let options = distribution::data::SolverOptions {
+            preferences: vec![],
+            rank_rules: vec![],
+        };
+        let calendar = distribution::rate_norms::default_academic_calendar(2024);
+        let result =
+            distribution::solver::distribute_workload(&project, &options, &calendar, 0.0).unwrap();
+        // The first employee is further below the yearly norm but only
+        // teaches from February.
+        assert!(result.unplaced.is_empty());
+        let employees = &result.project.university_form3.employees;
+        assert!(employees[0].rates[0].workload_items.is_empty());
+        assert_eq!(employees[1].rates[0].workload_items[0].lectures, 32.0);
+        let issues =
+            distribution::rate_norms::check_semester_coverage(&result.project, &calendar).unwrap();
+        assert!(issues.is_empty());
+    }
+    #[test]
+    fn test_personal_files() {
+        let mut input = test_workload_input(1.0, 1.0, &[32.0], &[]);
+        for (id, first_name, middle_name) in [
+            (2, "Олена", "Петрівна"),
+            (3, "Іван", "Васильович"),
+            (4, "Оксана", "Павлівна"),
+        ] {
+            let mut employee = test_workload_input(1.0, 1.0, &[32.0], &[]).sections[0]
+                .employees
+                .remove(0);
+            employee.id = id;
+            employee.first_name = first_name.to_string();
+            employee.middle_name = middle_name.to_string();
+            employee.last_name = "Шевченко".to_string();
+            input.sections[0].employees.push(employee);
+        }
+        let data = excel::totals::build_exported_data(input);
+        let options = excel::data::ExportOptions {
+            hide_empty_columns: false,
+            columns: None,
+            theme: None,
+            highlight_workload_norms: false,
+            include_analytics: false,
+        };
+        let directory = std::env::temp_dir().join("aw_flutter_test_personal_files");
+        let paths = excel::personal_export::generate_personal_files(
+            directory.to_str().unwrap(),
+            data,
+            options,
+            None,
+        )
+        .unwrap();
+        let expected: Vec<_> = [
+            "Kovalenko_O_P",
+            "Shevchenko_O_P",
+            "Shevchenko_I_V",
+            "Shevchenko_O_P_2",
+        ]
+        .iter()
+        .map(|name| directory.join(format!("{name}.xlsx")))
+        .collect();
+        let paths: Vec<_> = paths.iter().map(std::path::PathBuf::from).collect();
+        assert_eq!(paths, expected);
+        assert!(paths.iter().all(|path| path.is_file()));
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    /// Rows of an ODS table, the column and row spans of a cell or `None` for
+    /// a covered one.
+    type OdsTable = Vec<Vec<Option<(u32, u32)>>>;
+
+    /// Tables of the ODS file in sheet order.
+    fn read_ods_tables(file_path: &str) -> Vec<OdsTable> {
+        use quick_xml::events::Event;
+
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(file_path).unwrap()).unwrap();
+        assert_eq!(archive.by_index(0).unwrap().name(), "mimetype");
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("content.xml").unwrap(), &mut content)
+            .unwrap();
+
+        let mut reader = quick_xml::Reader::from_str(&content);
+        let mut tables: Vec<OdsTable> = Vec::new();
+        loop {
+            let element = match reader.read_event().unwrap() {
+                Event::Start(element) | Event::Empty(element) => element,
+                Event::Eof => break,
+                _ => continue,
+            };
+            let span = |name| {
+                element
+                    .try_get_attribute(name)
+                    .unwrap()
+                    .map_or(1, |attribute| {
+                        attribute.unescape_value().unwrap().parse().unwrap()
+                    })
+            };
+            match element.name().as_ref() {
+                b"table:table" => tables.push(Vec::new()),
+                b"table:table-row" => tables.last_mut().unwrap().push(Vec::new()),
+                b"table:table-cell" => {
+                    let cell = Some((
+                        span("table:number-columns-spanned"),
+                        span("table:number-rows-spanned"),
+                    ));
+                    tables.last_mut().unwrap().last_mut().unwrap().push(cell);
+                }
+                b"table:covered-table-cell" => {
+                    tables.last_mut().unwrap().last_mut().unwrap().push(None);
+                }
+                _ => {}
+            }
+        }
+        tables
+    }
+
+    fn test_project() -> (
+        distribution::data::DistributionProject,
+        distribution::data::DisciplineKey,
+    ) {
+        let key = serde_json::json!({
+            "learningForm": "daytime",
+            "specialty": "121",
+            "disciplineName": "Бази даних",
+            "course": "2",
+            "semester": 1,
+        });
+        let mut form1_item =
+            serde_json::json!({"id": "f1", "workloadKey": key, "studentCount": 25});
+        for field in [
+            "weekCount",
+            "flowCount",
+            "groupCount",
+            "subgroupCount",
+            "lecturesPlanned",
+            "lecturesTotal",
+            "practicesPlanned",
+            "practicesTotal",
+            "labsPlanned",
+            "labsTotal",
+            "exams",
+            "examConsults",
+            "tests",
+            "qualificationWorks",
+            "certificationExams",
+            "productionPractices",
+            "teachingPractices",
+            "currentConsults",
+            "individualWorks",
+            "courseWorks",
+            "postgraduateExams",
+        ] {
+            form1_item[field] = serde_json::json!(0);
+        }
+        form1_item["lecturesTotal"] = serde_json::json!(32);
+        let project = serde_json::json!({
+            "id": 1,
+            "title": "2024",
+            "universityForm1": {"id": "u1", "academicYear": 2024, "workloadItems": [form1_item]},
+            "universityForm3": {"id": "u3", "academicYear": 2024, "employees": [{
+                "id": "e1",
+                "firstName": "Іван",
+                "lastName": "Петренко",
+                "patronymic": "",
+                "rank": "associate",
+                "rates": [{
+                    "id": "r1",
+                    "rateValue": 1.0,
+                    "dateStart": "2024-09-01T00:00:00.000",
+                    "dateEnd": "2025-06-30T00:00:00.000",
+                    "postgraduateCount": 0,
+                    "workloadItems": [],
+                }],
+            }]},
+            "createdAt": "2024-08-20T09:15:00.000",
+            "updatedAt": "2024-08-21T16:40:12.345",
+        });
+        (
+            serde_json::from_value(project).unwrap(),
+            serde_json::from_value(key).unwrap(),
+        )
+    }
+
+    fn test_item(
+        key: &distribution::data::DisciplineKey,
+        lectures: f64,
+    ) -> distribution::data::UniversityForm3WorkloadItem {
+        distribution::data::UniversityForm3WorkloadItem {
+            id: "i1".to_string(),
+            workload_key: key.clone(),
+            student_count: 25,
+            academic_groups: vec![],
+            lectures,
+            practices: 0.0,
+            labs: 0.0,
+            exams: 0.0,
+            exam_consults: 0.0,
+            tests: 0.0,
+            qualification_works: 0.0,
+            certification_exams: 0.0,
+            production_practices: 0.0,
+            teaching_practices: 0.0,
+            current_consults: 0.0,
+            individual_works: 0.0,
+            course_works: 0.0,
+            postgraduate_exams: 0.0,
+        }
+    }
+
+    /// One associate professor with a row of `lectures` hours per value.
+    fn test_workload_input(
+        semester_1_rate: f64,
+        semester_2_rate: f64,
+        semester_1: &[f64],
+        semester_2: &[f64],
+    ) -> excel::data::WorkloadInput {
+        let rows = |lectures: &[f64]| {
+            lectures
+                .iter()
+                .map(|lectures| {
+                    let mut hours = [excel::totals::EMPTY; excel::data::WORKLOAD_VALUES_COUNT];
+                    hours[excel::data::WorkloadKey::Lectures as usize] = *lectures;
+                    excel::data::OutputPersonalSemesterRow {
+                        name: "Програмування".to_string(),
+                        learning_form: "Д".to_string(),
+                        speciality: "121".to_string(),
+                        group: "ПЗ-24".to_string(),
+                        course: "1".to_string(),
+                        students_count: 25,
+                        individual_work_types: String::new(),
+                        hours: excel::data::OutputHoursRow::from_values(hours),
+                        merge_lectures_with_next: false,
+                    }
+                })
+                .collect()
+        };
+        excel::data::WorkloadInput {
+            year: 2024,
+            type_name: "КЕО".to_string(),
+            sections: vec![excel::data::WorkloadSectionInput {
+                title: "Всього за доцентами".to_string(),
+                employees: vec![excel::data::EmployeeWorkloadInput {
+                    id: 1,
+                    first_name: "Олена".to_string(),
+                    middle_name: "Петрівна".to_string(),
+                    last_name: "Коваленко".to_string(),
+                    rank: "доцент".to_string(),
+                    comment_semester_1: String::new(),
+                    comment_semester_2: String::new(),
+                    semester_1: rows(semester_1),
+                    semester_1_rate,
+                    semester_2: rows(semester_2),
+                    semester_2_rate,
+                }],
+            }],
+        }
     }
 }